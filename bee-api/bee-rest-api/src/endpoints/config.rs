@@ -17,6 +17,7 @@ pub(crate) const ROUTE_INFO: &str = "/api/v1/info";
 pub(crate) const ROUTE_MESSAGE: &str = "/api/v1/messages/:messageId";
 pub(crate) const ROUTE_MESSAGE_CHILDREN: &str = "/api/v1/messages/:messageId/children";
 pub(crate) const ROUTE_MESSAGE_METADATA: &str = "/api/v1/messages/:messageId/metadata";
+pub(crate) const ROUTE_MESSAGE_PROOF: &str = "/api/v1/messages/:messageId/proof";
 pub(crate) const ROUTE_MESSAGE_RAW: &str = "/api/v1/messages/:messageId/raw";
 pub(crate) const ROUTE_MESSAGES_FIND: &str = "/api/v1/messages";
 pub(crate) const ROUTE_MILESTONE: &str = "/api/v1/milestones/:milestoneIndex";
@@ -25,6 +26,8 @@ pub(crate) const ROUTE_OUTPUT: &str = "/api/v1/outputs/:outputId";
 pub(crate) const ROUTE_OUTPUTS_BECH32: &str = "/api/v1/addresses/:address/outputs";
 pub(crate) const ROUTE_OUTPUTS_ED25519: &str = "/api/v1/addresses/ed25519/:address/outputs";
 pub(crate) const ROUTE_PEER: &str = "/api/v1/peers/:peerId";
+pub(crate) const ROUTE_PEER_GROUP: &str = "/api/v1/peers/:peerId/group";
+pub(crate) const ROUTE_PEER_GROUPS: &str = "/api/v1/peers/groups";
 pub(crate) const ROUTE_PEERS: &str = "/api/v1/peers";
 pub(crate) const ROUTE_REMOVE_PEER: &str = "/api/v1/peers/:peerId";
 pub(crate) const ROUTE_SUBMIT_MESSAGE: &str = "/api/v1/messages";
@@ -35,9 +38,15 @@ pub(crate) const ROUTE_RECEIPTS_AT: &str = "/api/v1/receipts/:milestoneIndex";
 pub(crate) const ROUTE_TREASURY: &str = "/api/v1/treasury";
 pub(crate) const ROUTE_TRANSACTION_INCLUDED_MESSAGE: &str = "/api/v1/transactions/:transactionId/included-message";
 pub(crate) const ROUTE_WHITE_FLAG: &str = "/api/plugins/debug/whiteflag";
+pub(crate) const ROUTE_AUTOPEERING_PEERS: &str = "/api/plugins/autopeering/peers/:list";
+pub(crate) const ROUTE_AUTOPEERING_NEIGHBORS: &str = "/api/plugins/autopeering/neighbors";
+pub(crate) const ROUTE_AUTOPEERING_SALTS: &str = "/api/plugins/autopeering/salts";
+pub(crate) const ROUTE_AUTOPEERING_QUERY: &str = "/api/plugins/autopeering/query";
+pub(crate) const ROUTE_AUTOPEERING_DROP_NEIGHBOR: &str = "/api/plugins/autopeering/neighbors/:peerId";
+pub(crate) const ROUTE_AUTOPEERING_BLACKLIST_PEER: &str = "/api/plugins/autopeering/blacklist/:peerId";
 
 /// the routes that are available for public use
-pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 21] = [
+pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 22] = [
     ROUTE_BALANCE_BECH32,
     ROUTE_BALANCE_ED25519,
     ROUTE_HEALTH,
@@ -45,6 +54,7 @@ pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 21] = [
     ROUTE_MESSAGE,
     ROUTE_MESSAGE_CHILDREN,
     ROUTE_MESSAGE_METADATA,
+    ROUTE_MESSAGE_PROOF,
     ROUTE_MESSAGE_RAW,
     ROUTE_MESSAGES_FIND,
     ROUTE_MILESTONE,
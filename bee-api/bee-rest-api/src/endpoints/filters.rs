@@ -3,8 +3,9 @@
 
 use std::convert::Infallible;
 
+use bee_autopeering::AutopeeringHandle;
 use bee_gossip::NetworkCommandSender;
-use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_ledger::workers::consensus::IncludedMessagesCache;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -51,6 +52,12 @@ pub(crate) fn with_storage<B: StorageBackend>(
     warp::any().map(move || storage.clone())
 }
 
+pub(crate) fn with_included_messages_cache(
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
+) -> impl Filter<Extract = (ResourceHandle<IncludedMessagesCache>,), Error = Infallible> + Clone {
+    warp::any().map(move || included_messages_cache.clone())
+}
+
 pub(crate) fn with_message_submitter(
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
 ) -> impl Filter<Extract = (mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,), Error = Infallible> + Clone {
@@ -93,8 +100,8 @@ pub(crate) fn with_requested_messages(
     warp::any().map(move || requested_messages.clone())
 }
 
-pub(crate) fn with_consensus_worker(
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
-) -> impl Filter<Extract = (mpsc::UnboundedSender<ConsensusWorkerCommand>,), Error = Infallible> + Clone {
-    warp::any().map(move || consensus_worker.clone())
+pub(crate) fn with_autopeering_handle(
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> impl Filter<Extract = (Option<AutopeeringHandle>,), Error = Infallible> + Clone {
+    warp::any().map(move || autopeering_handle.clone())
 }
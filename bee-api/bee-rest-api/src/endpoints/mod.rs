@@ -13,8 +13,9 @@ pub mod storage;
 use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
+use bee_autopeering::AutopeeringHandle;
 use bee_gossip::NetworkCommandSender;
-use bee_ledger::workers::consensus::ConsensusWorker;
+use bee_ledger::workers::consensus::{ConsensusWorker, IncludedMessagesCache};
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, PeerManager, PeerManagerResWorker,
     RequestedMessages,
@@ -40,12 +41,19 @@ pub fn init_full_node<N: Node>(
     protocol_config: ProtocolConfig,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
+    autopeering_handle: Option<AutopeeringHandle>,
     node_builder: N::Builder,
 ) -> N::Builder
 where
     N::Backend: StorageBackend,
 {
-    node_builder.with_worker_cfg::<ApiWorkerFullNode>((rest_api_config, protocol_config, network_id, bech32_hrp))
+    node_builder.with_worker_cfg::<ApiWorkerFullNode>((
+        rest_api_config,
+        protocol_config,
+        network_id,
+        bech32_hrp,
+        autopeering_handle,
+    ))
 }
 
 pub struct ApiWorkerFullNode;
@@ -55,12 +63,19 @@ impl<N: Node> Worker<N> for ApiWorkerFullNode
 where
     N::Backend: StorageBackend,
 {
-    type Config = (RestApiConfig, ProtocolConfig, NetworkId, Bech32Hrp);
+    type Config = (
+        RestApiConfig,
+        ProtocolConfig,
+        NetworkId,
+        Bech32Hrp,
+        Option<AutopeeringHandle>,
+    );
     type Error = WorkerError;
 
     fn dependencies() -> &'static [TypeId] {
         vec![
             TypeId::of::<TangleWorker>(),
+            TypeId::of::<ConsensusWorker>(),
             TypeId::of::<MessageSubmitterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
         ]
@@ -72,10 +87,11 @@ where
         let protocol_config = config.1;
         let network_id = config.2;
         let bech32_hrp = config.3;
+        let autopeering_handle = config.4;
 
-        let consensus_worker = node.worker::<ConsensusWorker>().unwrap().tx.clone();
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
+        let included_messages_cache = node.resource::<IncludedMessagesCache>();
         let message_submitter = node.worker::<MessageSubmitterWorker>().unwrap().tx.clone();
         let message_requester = node.worker::<MessageRequesterWorker>().unwrap().clone();
         let requested_messages = node.resource::<RequestedMessages>();
@@ -92,6 +108,7 @@ where
                 rest_api_config.allowed_ips.clone(),
                 tangle,
                 storage,
+                included_messages_cache,
                 message_submitter,
                 network_id,
                 bech32_hrp,
@@ -103,7 +120,7 @@ where
                 bus,
                 message_requester,
                 requested_messages,
-                consensus_worker,
+                autopeering_handle,
             )
             .recover(|err| async { handle_rejection(err) });
 
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use bee_autopeering::PeerId as AutopeeringPeerId;
 use bee_gossip::PeerId;
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -68,3 +69,11 @@ pub(super) fn peer_id() -> impl Filter<Extract = (PeerId,), Error = Rejection> +
             .map_err(|_| reject::custom(CustomRejection::BadRequest("invalid peer id".to_string())))
     })
 }
+
+pub(super) fn autopeering_peer_id() -> impl Filter<Extract = (AutopeeringPeerId,), Error = Rejection> + Copy {
+    warp::path::param().and_then(|value: String| async move {
+        value
+            .parse::<AutopeeringPeerId>()
+            .map_err(|_| reject::custom(CustomRejection::BadRequest("invalid autopeering peer id".to_string())))
+    })
+}
@@ -6,8 +6,9 @@ pub mod v1;
 
 use std::net::IpAddr;
 
+use bee_autopeering::AutopeeringHandle;
 use bee_gossip::NetworkCommandSender;
-use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_ledger::workers::consensus::IncludedMessagesCache;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -28,6 +29,7 @@ pub(crate) fn filter<B: StorageBackend>(
     allowed_ips: Box<[IpAddr]>,
     tangle: ResourceHandle<Tangle<B>>,
     storage: ResourceHandle<B>,
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
@@ -39,13 +41,14 @@ pub(crate) fn filter<B: StorageBackend>(
     bus: ResourceHandle<Bus<'static>>,
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    autopeering_handle: Option<AutopeeringHandle>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     v1::filter(
         public_routes.clone(),
         allowed_ips.clone(),
         tangle.clone(),
         storage.clone(),
+        included_messages_cache,
         message_submitter,
         network_id,
         bech32_hrp,
@@ -54,7 +57,6 @@ pub(crate) fn filter<B: StorageBackend>(
         peer_manager,
         network_command_sender,
         node_info,
-        consensus_worker,
     )
     .or(plugins::filter(
         public_routes,
@@ -65,5 +67,6 @@ pub(crate) fn filter<B: StorageBackend>(
         message_requester,
         requested_messages,
         rest_api_config,
+        autopeering_handle,
     ))
 }
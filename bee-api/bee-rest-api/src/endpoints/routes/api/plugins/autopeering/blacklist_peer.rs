@@ -0,0 +1,46 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::{AutopeeringHandle, PeerId};
+use warp::{filters::BoxedFilter, http::StatusCode, Filter, Rejection, Reply};
+
+use super::enabled;
+use crate::endpoints::{
+    config::ROUTE_AUTOPEERING_BLACKLIST_PEER, filters::with_autopeering_handle, path_params::autopeering_peer_id,
+    permission::has_permission,
+};
+
+fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("blacklist"))
+        .and(autopeering_peer_id())
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(
+            ROUTE_AUTOPEERING_BLACKLIST_PEER,
+            public_routes,
+            allowed_ips,
+        ))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|peer_id, autopeering_handle| async move { blacklist_peer(peer_id, autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn blacklist_peer(
+    peer_id: PeerId,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> Result<impl Reply, Rejection> {
+    enabled(autopeering_handle)?.blacklist(peer_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
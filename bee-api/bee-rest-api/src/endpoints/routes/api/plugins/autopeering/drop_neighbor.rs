@@ -0,0 +1,51 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::{AutopeeringHandle, PeerId};
+use warp::{filters::BoxedFilter, http::StatusCode, reject, Filter, Rejection, Reply};
+
+use super::enabled;
+use crate::endpoints::{
+    config::ROUTE_AUTOPEERING_DROP_NEIGHBOR, filters::with_autopeering_handle, path_params::autopeering_peer_id,
+    permission::has_permission, rejection::CustomRejection,
+};
+
+fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("neighbors"))
+        .and(autopeering_peer_id())
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::delete())
+        .and(has_permission(
+            ROUTE_AUTOPEERING_DROP_NEIGHBOR,
+            public_routes,
+            allowed_ips,
+        ))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|peer_id, autopeering_handle| async move { drop_neighbor(peer_id, autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn drop_neighbor(
+    peer_id: PeerId,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> Result<impl Reply, Rejection> {
+    if enabled(autopeering_handle)?.drop_neighbor(&peer_id) {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(reject::custom(CustomRejection::NotFound(format!(
+            "peer {} is not a neighbor",
+            peer_id.to_base58()
+        ))))
+    }
+}
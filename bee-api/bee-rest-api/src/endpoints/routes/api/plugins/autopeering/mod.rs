@@ -0,0 +1,81 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod blacklist_peer;
+mod drop_neighbor;
+mod neighbors;
+mod peers;
+mod query;
+mod salts;
+
+use std::net::IpAddr;
+
+use bee_autopeering::{AutopeeringHandle, Peer};
+use warp::{self, reject, Filter, Rejection, Reply};
+
+use crate::{
+    endpoints::rejection::CustomRejection,
+    types::dtos::{AutopeeringPeerDto, AutopeeringServiceDto},
+};
+
+pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    super::path().and(warp::path("autopeering"))
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    peers::filter(public_routes.clone(), allowed_ips.clone(), autopeering_handle.clone())
+        .or(neighbors::filter(
+            public_routes.clone(),
+            allowed_ips.clone(),
+            autopeering_handle.clone(),
+        ))
+        .or(salts::filter(
+            public_routes.clone(),
+            allowed_ips.clone(),
+            autopeering_handle.clone(),
+        ))
+        .or(query::filter(
+            public_routes.clone(),
+            allowed_ips.clone(),
+            autopeering_handle.clone(),
+        ))
+        .or(drop_neighbor::filter(
+            public_routes.clone(),
+            allowed_ips.clone(),
+            autopeering_handle.clone(),
+        ))
+        .or(blacklist_peer::filter(public_routes, allowed_ips, autopeering_handle))
+}
+
+/// Returns the handle of the autopeering service, or rejects the request if autopeering is disabled.
+fn enabled(autopeering_handle: Option<AutopeeringHandle>) -> Result<AutopeeringHandle, Rejection> {
+    autopeering_handle.ok_or_else(|| {
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "autopeering is disabled".to_string(),
+        ))
+    })
+}
+
+fn peer_dto(peer: &Peer) -> AutopeeringPeerDto {
+    let mut services = peer
+        .services()
+        .iter()
+        .map(|(name, endpoint)| AutopeeringServiceDto {
+            name: name.clone(),
+            protocol: endpoint.protocol().to_string(),
+            port: endpoint.port(),
+        })
+        .collect::<Vec<_>>();
+
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+
+    AutopeeringPeerDto {
+        id: peer.peer_id().to_base58(),
+        ip_address: peer.ip_address().to_string(),
+        services,
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::{AutopeeringHandle, Distance, Peer};
+use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+
+use super::{enabled, peer_dto};
+use crate::{
+    endpoints::{config::ROUTE_AUTOPEERING_NEIGHBORS, filters::with_autopeering_handle, permission::has_permission},
+    types::{body::SuccessBody, dtos::AutopeeringNeighborDto, responses::AutopeeringNeighborsResponse},
+};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path().and(warp::path("neighbors")).and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_AUTOPEERING_NEIGHBORS, public_routes, allowed_ips))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|autopeering_handle| async move { neighbors(autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn neighbors(autopeering_handle: Option<AutopeeringHandle>) -> Result<impl Reply, Rejection> {
+    let autopeering_handle = enabled(autopeering_handle)?;
+
+    Ok(warp::reply::json(&SuccessBody::new(AutopeeringNeighborsResponse {
+        inbound: neighbor_dtos(autopeering_handle.inbound_neighbors()),
+        outbound: neighbor_dtos(autopeering_handle.outbound_neighbors()),
+    })))
+}
+
+fn neighbor_dtos(neighbors: Vec<(Peer, Distance)>) -> Vec<AutopeeringNeighborDto> {
+    neighbors
+        .iter()
+        .map(|(peer, distance)| AutopeeringNeighborDto {
+            peer: peer_dto(peer),
+            distance: *distance,
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::AutopeeringHandle;
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
+
+use super::{enabled, peer_dto};
+use crate::{
+    endpoints::{
+        config::ROUTE_AUTOPEERING_PEERS, filters::with_autopeering_handle, permission::has_permission,
+        rejection::CustomRejection,
+    },
+    types::{body::SuccessBody, responses::AutopeeringPeersResponse},
+};
+
+fn path() -> impl Filter<Extract = (String,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("peers"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_AUTOPEERING_PEERS, public_routes, allowed_ips))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|list, autopeering_handle| async move { peers(list, autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn peers(list: String, autopeering_handle: Option<AutopeeringHandle>) -> Result<impl Reply, Rejection> {
+    let autopeering_handle = enabled(autopeering_handle)?;
+
+    let peers = match list.as_str() {
+        "known" => autopeering_handle.known_peers(),
+        "verified" => autopeering_handle.verified_peers(),
+        "active" => autopeering_handle.active_peers(),
+        "replacements" => autopeering_handle.replacement_peers(),
+        _ => {
+            return Err(reject::custom(CustomRejection::BadRequest(
+                "invalid peer list: expected one of known, verified, active or replacements".to_string(),
+            )));
+        }
+    };
+
+    Ok(warp::reply::json(&SuccessBody::new(AutopeeringPeersResponse(
+        peers.iter().map(peer_dto).collect(),
+    ))))
+}
@@ -0,0 +1,33 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::AutopeeringHandle;
+use warp::{filters::BoxedFilter, http::StatusCode, Filter, Rejection, Reply};
+
+use super::enabled;
+use crate::endpoints::{config::ROUTE_AUTOPEERING_QUERY, filters::with_autopeering_handle, permission::has_permission};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path().and(warp::path("query")).and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_AUTOPEERING_QUERY, public_routes, allowed_ips))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|autopeering_handle| async move { query(autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn query(autopeering_handle: Option<AutopeeringHandle>) -> Result<impl Reply, Rejection> {
+    enabled(autopeering_handle)?.query();
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,39 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_autopeering::AutopeeringHandle;
+use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+
+use super::enabled;
+use crate::{
+    endpoints::{config::ROUTE_AUTOPEERING_SALTS, filters::with_autopeering_handle, permission::has_permission},
+    types::{body::SuccessBody, responses::AutopeeringSaltsResponse},
+};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path().and(warp::path("salts")).and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_AUTOPEERING_SALTS, public_routes, allowed_ips))
+        .and(with_autopeering_handle(autopeering_handle))
+        .and_then(|autopeering_handle| async move { salts(autopeering_handle) })
+        .boxed()
+}
+
+pub(crate) fn salts(autopeering_handle: Option<AutopeeringHandle>) -> Result<impl Reply, Rejection> {
+    let autopeering_handle = enabled(autopeering_handle)?;
+
+    Ok(warp::reply::json(&SuccessBody::new(AutopeeringSaltsResponse {
+        public_salt_expiration_time: autopeering_handle.public_salt_expiration_time(),
+        private_salt_expiration_time: autopeering_handle.private_salt_expiration_time(),
+    })))
+}
@@ -1,10 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod autopeering;
 mod debug;
 
 use std::net::IpAddr;
 
+use bee_autopeering::AutopeeringHandle;
 use bee_protocol::workers::{MessageRequesterWorker, RequestedMessages};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
@@ -26,10 +28,11 @@ pub(crate) fn filter<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     rest_api_config: RestApiConfig,
+    autopeering_handle: Option<AutopeeringHandle>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     debug::filter(
-        public_routes,
-        allowed_ips,
+        public_routes.clone(),
+        allowed_ips.clone(),
         storage,
         tangle,
         bus,
@@ -37,4 +40,5 @@ pub(crate) fn filter<B: StorageBackend>(
         requested_messages,
         rest_api_config,
     )
+    .or(autopeering::filter(public_routes, allowed_ips, autopeering_handle))
 }
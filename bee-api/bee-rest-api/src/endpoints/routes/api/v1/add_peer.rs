@@ -106,7 +106,7 @@ pub(crate) fn add_peer(
                 alias: alias.clone(),
                 relation: PeerRelation::Known,
             }) {
-                return Err(reject::custom(CustomRejection::NotFound(format!(
+                return Err(reject::custom(CustomRejection::ServiceUnavailable(format!(
                     "failed to add peer: {}",
                     e
                 ))));
@@ -119,7 +119,10 @@ pub(crate) fn add_peer(
                     multi_addresses: vec![multi_address.to_string()],
                     relation: RelationDto::Known,
                     connected: false,
+                    group: None,
+                    transport: None,
                     gossip: None,
+                    reconnect: None,
                 }))),
                 StatusCode::OK,
             ))
@@ -3,14 +3,13 @@
 
 use std::net::IpAddr;
 
-use bee_ledger::workers::consensus::ConsensusWorkerCommand;
 use bee_message::address::Address;
-use tokio::sync::mpsc;
+use bee_runtime::resource::ResourceHandle;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::endpoints::{
-    config::ROUTE_BALANCE_BECH32, filters::with_consensus_worker, path_params::bech32_address,
-    permission::has_permission, routes::api::v1::balance_ed25519::balance_ed25519,
+    config::ROUTE_BALANCE_BECH32, filters::with_storage, path_params::bech32_address, permission::has_permission,
+    routes::api::v1::balance_ed25519::balance_ed25519, storage::StorageBackend,
 };
 
 fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone {
@@ -20,24 +19,24 @@ fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone
         .and(warp::path::end())
 }
 
-pub(crate) fn filter(
+pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
     allowed_ips: Box<[IpAddr]>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
         .and(has_permission(ROUTE_BALANCE_BECH32, public_routes, allowed_ips))
-        .and(with_consensus_worker(consensus_worker))
-        .and_then(|addr, consensus_worker| async move { balance_bech32(addr, consensus_worker).await })
+        .and(with_storage(storage))
+        .and_then(|addr, storage| async move { balance_bech32(addr, storage) })
         .boxed()
 }
 
-pub(crate) async fn balance_bech32(
+pub(crate) fn balance_bech32<B: StorageBackend>(
     addr: Address,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> Result<impl Reply, Rejection> {
     match addr {
-        Address::Ed25519(a) => balance_ed25519(a, consensus_worker).await,
+        Address::Ed25519(a) => balance_ed25519(a, storage),
     }
 }
@@ -3,20 +3,16 @@
 
 use std::net::IpAddr;
 
-use bee_ledger::{
-    types::{Balance, LedgerIndex},
-    workers::{consensus::ConsensusWorkerCommand, error::Error},
-};
+use bee_ledger::workers::storage;
 use bee_message::address::{Address, Ed25519Address};
-use futures::channel::oneshot;
+use bee_runtime::resource::ResourceHandle;
 use log::error;
-use tokio::sync::mpsc;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_BALANCE_ED25519, filters::with_consensus_worker, path_params::ed25519_address,
-        permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_BALANCE_ED25519, filters::with_storage, path_params::ed25519_address, permission::has_permission,
+        rejection::CustomRejection, storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::BalanceAddressResponse},
 };
@@ -29,55 +25,42 @@ fn path() -> impl Filter<Extract = (Ed25519Address,), Error = warp::Rejection> +
         .and(warp::path::end())
 }
 
-pub(crate) fn filter(
+pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
     allowed_ips: Box<[IpAddr]>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
         .and(has_permission(ROUTE_BALANCE_ED25519, public_routes, allowed_ips))
-        .and(with_consensus_worker(consensus_worker))
-        .and_then(|addr, consensus_worker| async move { balance_ed25519(addr, consensus_worker).await })
+        .and(with_storage(storage))
+        .and_then(|addr, storage| async move { balance_ed25519(addr, storage) })
         .boxed()
 }
 
-pub(crate) async fn balance_ed25519(
+pub(crate) fn balance_ed25519<B: StorageBackend>(
     addr: Ed25519Address,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> Result<impl Reply, Rejection> {
-    let (cmd_tx, cmd_rx) = oneshot::channel::<(Result<Option<Balance>, Error>, LedgerIndex)>();
-
-    if let Err(e) = consensus_worker.send(ConsensusWorkerCommand::FetchBalance(Address::Ed25519(addr), cmd_tx)) {
-        error!("request to consensus worker failed: {}.", e);
-    }
-
-    match cmd_rx.await.map_err(|e| {
-        error!("response from consensus worker failed: {}.", e);
-        reject::custom(CustomRejection::ServiceUnavailable(
-            "unable to fetch the balance of the address".to_string(),
-        ))
-    })? {
-        (Ok(response), ledger_index) => {
-            let (balance, dust_allowed) = if let Some(balance) = response {
-                (balance.amount(), balance.dust_allowed())
-            } else {
-                (0, false)
-            };
-
-            Ok(warp::reply::json(&SuccessBody::new(BalanceAddressResponse {
-                address_type: Ed25519Address::KIND,
-                address: addr.to_string(),
-                balance,
-                dust_allowed,
-                ledger_index: *ledger_index,
-            })))
-        }
-        (Err(e), _) => {
+    let (response, ledger_index) = storage::fetch_balance_with_ledger_index(&*storage, &Address::Ed25519(addr))
+        .map_err(|e| {
             error!("unable to fetch the balance of the address: {}", e);
-            Err(reject::custom(CustomRejection::ServiceUnavailable(
+            reject::custom(CustomRejection::ServiceUnavailable(
                 "unable to fetch the balance of the address".to_string(),
-            )))
-        }
-    }
+            ))
+        })?;
+
+    let (balance, dust_allowed) = if let Some(balance) = response {
+        (balance.amount(), balance.dust_allowed())
+    } else {
+        (0, false)
+    };
+
+    Ok(warp::reply::json(&SuccessBody::new(BalanceAddressResponse {
+        address_type: Ed25519Address::KIND,
+        address: addr.to_string(),
+        balance,
+        dust_allowed,
+        ledger_index: *ledger_index,
+    })))
 }
@@ -0,0 +1,87 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_ledger::workers::{
+    consensus::{self, IncludedMessagesCache},
+    error::Error as LedgerError,
+};
+use bee_message::{payload::Payload, MessageId};
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::Tangle;
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
+
+use crate::{
+    endpoints::{
+        config::ROUTE_MESSAGE_PROOF,
+        filters::{with_included_messages_cache, with_tangle},
+        path_params::message_id,
+        permission::has_permission,
+        rejection::CustomRejection,
+        storage::StorageBackend,
+    },
+    types::{
+        body::SuccessBody,
+        dtos::{InclusionProofDto, MilestonePayloadDto},
+        responses::MessageProofResponse,
+    },
+};
+
+fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(message_id())
+        .and(warp::path("proof"))
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter<B: StorageBackend>(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    tangle: ResourceHandle<Tangle<B>>,
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_MESSAGE_PROOF, public_routes, allowed_ips))
+        .and(with_tangle(tangle))
+        .and(with_included_messages_cache(included_messages_cache))
+        .and_then(message_proof)
+        .boxed()
+}
+
+pub(crate) async fn message_proof<B: StorageBackend>(
+    message_id: MessageId,
+    tangle: ResourceHandle<Tangle<B>>,
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
+) -> Result<impl Reply, Rejection> {
+    let (milestone_index, proof) = consensus::inclusion_proof(&*tangle, &*included_messages_cache, &message_id)
+        .await
+        .map_err(|e| match e {
+            LedgerError::MessageNotFound(_) => {
+                reject::custom(CustomRejection::NotFound("can not find message".to_string()))
+            }
+            LedgerError::MessageNotIncluded(_) => reject::custom(CustomRejection::NotFound(
+                "message is not included by a milestone".to_string(),
+            )),
+            e => reject::custom(CustomRejection::ServiceUnavailable(format!(
+                "can not compute the inclusion proof: {}",
+                e
+            ))),
+        })?;
+
+    match tangle.get_milestone_message(milestone_index) {
+        Some(message) => match message.payload() {
+            Some(Payload::Milestone(milestone)) => Ok(warp::reply::json(&SuccessBody::new(MessageProofResponse {
+                message_id: message_id.to_string(),
+                milestone: MilestonePayloadDto::from(milestone.as_ref()),
+                proof: InclusionProofDto::from(&proof),
+            }))),
+            _ => Err(reject::custom(CustomRejection::InternalError)),
+        },
+        None => Err(reject::custom(CustomRejection::NotFound(
+            "can not find milestone".to_string(),
+        ))),
+    }
+}
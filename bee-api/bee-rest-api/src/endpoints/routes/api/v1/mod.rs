@@ -8,6 +8,7 @@ pub mod info;
 pub mod message;
 pub mod message_children;
 pub mod message_metadata;
+pub mod message_proof;
 pub mod message_raw;
 pub mod messages_find;
 pub mod milestone;
@@ -16,6 +17,8 @@ pub mod output;
 pub mod outputs_bech32;
 pub mod outputs_ed25519;
 pub mod peer;
+pub mod peer_group;
+pub mod peer_groups;
 pub mod peers;
 pub mod receipts;
 pub mod receipts_at;
@@ -28,7 +31,7 @@ pub mod treasury;
 use std::net::IpAddr;
 
 use bee_gossip::NetworkCommandSender;
-use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_ledger::workers::consensus::IncludedMessagesCache;
 use bee_protocol::workers::{config::ProtocolConfig, MessageSubmitterWorkerEvent, PeerManager};
 use bee_runtime::{node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::Tangle;
@@ -47,6 +50,7 @@ pub(crate) fn filter<B: StorageBackend>(
     allowed_ips: Box<[IpAddr]>,
     tangle: ResourceHandle<Tangle<B>>,
     storage: ResourceHandle<B>,
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
@@ -55,7 +59,6 @@ pub(crate) fn filter<B: StorageBackend>(
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     add_peer::filter(
         public_routes.clone(),
@@ -66,12 +69,12 @@ pub(crate) fn filter<B: StorageBackend>(
     .or(balance_bech32::filter(
         public_routes.clone(),
         allowed_ips.clone(),
-        consensus_worker.clone(),
+        storage.clone(),
     ))
     .or(balance_ed25519::filter(
         public_routes.clone(),
         allowed_ips.clone(),
-        consensus_worker.clone(),
+        storage.clone(),
     ))
     .or(info::filter(
         public_routes.clone(),
@@ -99,6 +102,12 @@ pub(crate) fn filter<B: StorageBackend>(
         allowed_ips.clone(),
         tangle.clone(),
     ))
+    .or(message_proof::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        tangle.clone(),
+        included_messages_cache,
+    ))
     .or(message_raw::filter(
         public_routes.clone(),
         allowed_ips.clone(),
@@ -123,23 +132,33 @@ pub(crate) fn filter<B: StorageBackend>(
         public_routes.clone(),
         allowed_ips.clone(),
         storage.clone(),
-        consensus_worker.clone(),
     ))
     .or(outputs_bech32::filter(
         public_routes.clone(),
         allowed_ips.clone(),
-        consensus_worker.clone(),
+        storage.clone(),
     ))
     .or(outputs_ed25519::filter(
         public_routes.clone(),
         allowed_ips.clone(),
-        consensus_worker,
+        storage.clone(),
+    ))
+    .or(peer_groups::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        peer_manager.clone(),
     ))
     .or(peer::filter(
         public_routes.clone(),
         allowed_ips.clone(),
         peer_manager.clone(),
     ))
+    .or(peer_group::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        peer_manager.clone(),
+        network_command_sender.clone(),
+    ))
     .or(peers::filter(public_routes.clone(), allowed_ips.clone(), peer_manager))
     .or(receipts::filter(
         public_routes.clone(),
@@ -3,20 +3,16 @@
 
 use std::net::IpAddr;
 
-use bee_ledger::{
-    types::LedgerIndex,
-    workers::{consensus::ConsensusWorkerCommand, error::Error},
-};
-use bee_message::{address::Ed25519Address, output::OutputId, prelude::Address};
-use futures::channel::oneshot;
+use bee_ledger::workers::storage;
+use bee_message::address::Ed25519Address;
+use bee_runtime::resource::ResourceHandle;
 use log::error;
-use tokio::sync::mpsc;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_OUTPUTS_ED25519, filters::with_consensus_worker, path_params::ed25519_address,
-        permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_OUTPUTS_ED25519, filters::with_storage, path_params::ed25519_address, permission::has_permission,
+        rejection::CustomRejection, storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::OutputsAddressResponse},
 };
@@ -30,47 +26,32 @@ fn path() -> impl Filter<Extract = (Ed25519Address,), Error = Rejection> + Clone
         .and(warp::path::end())
 }
 
-pub(crate) fn filter(
+pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
     allowed_ips: Box<[IpAddr]>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
         .and(has_permission(ROUTE_OUTPUTS_ED25519, public_routes, allowed_ips))
-        .and(with_consensus_worker(consensus_worker))
-        .and_then(|addr, consensus_worker| async move { outputs_ed25519(addr, consensus_worker).await })
+        .and(with_storage(storage))
+        .and_then(|addr, storage| async move { outputs_ed25519(addr, storage) })
         .boxed()
 }
 
-pub(crate) async fn outputs_ed25519(
+pub(crate) fn outputs_ed25519<B: StorageBackend>(
     addr: Ed25519Address,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    storage: ResourceHandle<B>,
 ) -> Result<impl Reply, Rejection> {
-    let (cmd_tx, cmd_rx) = oneshot::channel::<(Result<Option<Vec<OutputId>>, Error>, LedgerIndex)>();
-
-    if let Err(e) = consensus_worker.send(ConsensusWorkerCommand::FetchOutputs(Address::Ed25519(addr), cmd_tx)) {
-        error!("Request to consensus worker failed: {}.", e);
-    }
-
-    let (mut fetched, ledger_index) = match cmd_rx.await.map_err(|e| {
-        error!("Response from consensus worker failed: {}.", e);
-        reject::custom(CustomRejection::ServiceUnavailable(
-            "unable to fetch the outputs of the address".to_string(),
-        ))
-    })? {
-        (Ok(response), ledger_index) => match response {
-            Some(ids) => (ids, ledger_index),
-            None => (vec![], ledger_index),
-        },
-        (Err(e), _) => {
+    let (fetched, ledger_index) = storage::fetch_outputs_for_ed25519_address_with_ledger_index(&*storage, &addr)
+        .map_err(|e| {
             error!("unable to fetch the outputs of the address: {}", e);
-            return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            reject::custom(CustomRejection::ServiceUnavailable(
                 "unable to fetch the outputs of the address".to_string(),
-            )));
-        }
-    };
+            ))
+        })?;
 
+    let mut fetched = fetched.unwrap_or_default();
     let count = fetched.len();
     let max_results = 1000;
     fetched.truncate(max_results);
@@ -0,0 +1,88 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use bee_gossip::{peer_group as configured_peer_group, Command::ChangeGroup, NetworkCommandSender, PeerId};
+use bee_protocol::workers::PeerManager;
+use bee_runtime::resource::ResourceHandle;
+use serde_json::Value as JsonValue;
+use warp::{filters::BoxedFilter, http::StatusCode, reject, Filter, Rejection, Reply};
+
+use crate::endpoints::{
+    config::ROUTE_PEER_GROUP,
+    filters::{with_network_command_sender, with_peer_manager},
+    path_params::peer_id,
+    permission::has_permission,
+    rejection::CustomRejection,
+};
+
+fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("peers"))
+        .and(peer_id())
+        .and(warp::path("group"))
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    peer_manager: ResourceHandle<PeerManager>,
+    network_command_sender: ResourceHandle<NetworkCommandSender>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::put())
+        .and(has_permission(ROUTE_PEER_GROUP, public_routes, allowed_ips))
+        .and(warp::body::json())
+        .and(with_peer_manager(peer_manager))
+        .and(with_network_command_sender(network_command_sender))
+        .and_then(|peer_id, value, peer_manager, network_controller| async move {
+            peer_group(peer_id, value, peer_manager, network_controller)
+        })
+        .boxed()
+}
+
+pub(crate) fn peer_group(
+    peer_id: PeerId,
+    value: JsonValue,
+    peer_manager: ResourceHandle<PeerManager>,
+    network_controller: ResourceHandle<NetworkCommandSender>,
+) -> Result<impl Reply, Rejection> {
+    let group_v = &value["group"];
+
+    let group = if group_v.is_null() {
+        None
+    } else {
+        Some(
+            group_v
+                .as_str()
+                .ok_or_else(|| {
+                    reject::custom(CustomRejection::BadRequest(
+                        "invalid group: expected a string".to_string(),
+                    ))
+                })?
+                .to_string(),
+        )
+    };
+
+    if let Some(group) = group.as_ref().filter(|group| configured_peer_group(group).is_none()) {
+        return Err(reject::custom(CustomRejection::NotFound(format!(
+            "peer group not found: {}",
+            group
+        ))));
+    }
+
+    if peer_manager.get_map(&peer_id, |_| ()).is_none() {
+        return Err(reject::custom(CustomRejection::NotFound("peer not found".to_string())));
+    }
+
+    if let Err(e) = network_controller.send(ChangeGroup { peer_id, to: group }) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(format!(
+            "failed to change peer group: {}",
+            e
+        ))));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,82 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, convert::Infallible, net::IpAddr};
+
+use bee_protocol::workers::PeerManager;
+use bee_runtime::resource::ResourceHandle;
+use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+
+use crate::{
+    endpoints::{config::ROUTE_PEER_GROUPS, filters::with_peer_manager, permission::has_permission},
+    types::{
+        body::SuccessBody,
+        dtos::{MetricsDto, PeerDto, PeerGroupDto},
+        responses::PeerGroupsResponse,
+    },
+};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("peers"))
+        .and(warp::path("groups"))
+        .and(warp::path::end())
+}
+
+pub(crate) fn filter(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+    peer_manager: ResourceHandle<PeerManager>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_PEER_GROUPS, public_routes, allowed_ips))
+        .and(with_peer_manager(peer_manager))
+        .and_then(|peer_manager| async move { peer_groups(peer_manager) })
+        .boxed()
+}
+
+pub(crate) fn peer_groups(peer_manager: ResourceHandle<PeerManager>) -> Result<impl Reply, Infallible> {
+    let mut groups = BTreeMap::<String, PeerGroupDto>::new();
+
+    for peer in peer_manager.get_all() {
+        if let Some(name) = peer.group() {
+            let group = groups.entry(name.clone()).or_insert_with(|| PeerGroupDto {
+                name,
+                peers: 0,
+                connected_peers: 0,
+                metrics: MetricsDto::default(),
+            });
+
+            group.peers += 1;
+
+            if peer.is_connected() {
+                group.connected_peers += 1;
+            }
+
+            if let Some(gossip) = PeerDto::from(peer.as_ref()).gossip {
+                add_metrics(&mut group.metrics, &gossip.metrics);
+            }
+        }
+    }
+
+    Ok(warp::reply::json(&SuccessBody::new(PeerGroupsResponse(
+        groups.into_values().collect(),
+    ))))
+}
+
+fn add_metrics(sum: &mut MetricsDto, metrics: &MetricsDto) {
+    sum.new_messages += metrics.new_messages;
+    sum.received_messages += metrics.received_messages;
+    sum.known_messages += metrics.known_messages;
+    sum.received_message_requests += metrics.received_message_requests;
+    sum.received_milestone_requests += metrics.received_milestone_requests;
+    sum.received_heartbeats += metrics.received_heartbeats;
+    sum.sent_messages += metrics.sent_messages;
+    sum.sent_message_requests += metrics.sent_message_requests;
+    sum.sent_milestone_requests += metrics.sent_milestone_requests;
+    sum.sent_heartbeats += metrics.sent_heartbeats;
+    sum.dropped_packets += metrics.dropped_packets;
+    sum.inbound_gossip_overflows += metrics.inbound_gossip_overflows;
+    sum.outbound_gossip_overflows += metrics.outbound_gossip_overflows;
+}
@@ -37,7 +37,7 @@ pub(crate) fn remove_peer(
     network_controller: ResourceHandle<NetworkCommandSender>,
 ) -> Result<impl Reply, Rejection> {
     if let Err(e) = network_controller.send(RemovePeer { peer_id }) {
-        return Err(reject::custom(CustomRejection::NotFound(format!(
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(format!(
             "failed to remove peer: {}",
             e
         ))));
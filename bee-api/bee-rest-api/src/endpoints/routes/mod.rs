@@ -6,8 +6,9 @@ pub mod health;
 
 use std::net::IpAddr;
 
+use bee_autopeering::AutopeeringHandle;
 use bee_gossip::NetworkCommandSender;
-use bee_ledger::workers::consensus::ConsensusWorkerCommand;
+use bee_ledger::workers::consensus::IncludedMessagesCache;
 use bee_protocol::workers::{
     config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
 };
@@ -24,6 +25,7 @@ pub(crate) fn filter_all<B: StorageBackend>(
     allowed_ips: Box<[IpAddr]>,
     tangle: ResourceHandle<Tangle<B>>,
     storage: ResourceHandle<B>,
+    included_messages_cache: ResourceHandle<IncludedMessagesCache>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
@@ -35,13 +37,14 @@ pub(crate) fn filter_all<B: StorageBackend>(
     bus: ResourceHandle<Bus<'static>>,
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
-    consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    autopeering_handle: Option<AutopeeringHandle>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     api::filter(
         public_routes.clone(),
         allowed_ips.clone(),
         tangle.clone(),
         storage,
+        included_messages_cache,
         message_submitter,
         network_id,
         bech32_hrp,
@@ -53,7 +56,7 @@ pub(crate) fn filter_all<B: StorageBackend>(
         bus,
         message_requester,
         requested_messages,
-        consensus_worker,
+        autopeering_handle,
     )
     .or(health::filter(public_routes, allowed_ips, tangle, peer_manager))
 }
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ledger::types::Receipt;
+use bee_ledger::types::{InclusionProof, Receipt};
 use bee_message::{
     address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
     input::{Input, TreasuryInput, UtxoInput},
@@ -678,6 +678,43 @@ impl TryFrom<&MilestonePayloadDto> for MilestonePayload {
     }
 }
 
+/// The proof that a message was included by a milestone.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InclusionProofDto {
+    pub index: u32,
+    #[serde(rename = "leafCount")]
+    pub leaf_count: u32,
+    pub hashes: Vec<String>,
+}
+
+impl From<&InclusionProof> for InclusionProofDto {
+    fn from(value: &InclusionProof) -> Self {
+        InclusionProofDto {
+            index: value.index(),
+            leaf_count: value.leaf_count(),
+            hashes: value.hashes().iter().map(hex::encode).collect(),
+        }
+    }
+}
+
+impl TryFrom<&InclusionProofDto> for InclusionProof {
+    type Error = Error;
+
+    fn try_from(value: &InclusionProofDto) -> Result<Self, Self::Error> {
+        let mut hashes = Vec::new();
+        for v in &value.hashes {
+            let hash = {
+                let mut buf = [0u8; 32];
+                hex::decode_to_slice(v, &mut buf).map_err(|_| Error::InvalidSyntaxField("hashes"))?;
+                buf
+            };
+            hashes.push(hash);
+        }
+
+        InclusionProof::new(value.index, value.leaf_count, hashes).map_err(|_| Error::InvalidSemanticField("proof"))
+    }
+}
+
 /// The payload type to define a indexation payload.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IndexationPayloadDto {
@@ -819,7 +856,13 @@ pub struct PeerDto {
     pub relation: RelationDto,
     pub connected: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gossip: Option<GossipDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconnect: Option<ReconnectDto>,
 }
 
 #[cfg(feature = "peer")]
@@ -839,6 +882,8 @@ impl From<&Peer> for PeerDto {
                 }
             },
             connected: peer.is_connected(),
+            group: peer.group(),
+            transport: peer.transport().map(|transport| transport.to_string()),
             gossip: Some(GossipDto {
                 heartbeat: HeartbeatDto {
                     solid_milestone_index: *peer.solid_milestone_index(),
@@ -859,12 +904,47 @@ impl From<&Peer> for PeerDto {
                     sent_milestone_requests: peer.metrics().milestone_requests_sent(),
                     sent_heartbeats: peer.metrics().heartbeats_sent(),
                     dropped_packets: 0,
+                    inbound_gossip_overflows: peer.metrics().inbound_gossip_overflows(),
+                    outbound_gossip_overflows: peer.metrics().outbound_gossip_overflows(),
                 },
             }),
+            reconnect: {
+                let backoff = peer.reconnect_backoff();
+
+                if peer.is_connected() || backoff.next_attempt.is_none() {
+                    None
+                } else {
+                    Some(ReconnectDto {
+                        attempts: backoff.attempts,
+                        delay_secs: backoff.delay.as_secs(),
+                        next_attempt: backoff.next_attempt,
+                    })
+                }
+            },
         }
     }
 }
 
+/// Describes the reconnection back-off state of a disconnected peer.
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct ReconnectDto {
+    pub attempts: u32,
+    #[serde(rename = "delaySecs")]
+    pub delay_secs: u64,
+    #[serde(rename = "nextAttempt")]
+    pub next_attempt: Option<u64>,
+}
+
+/// Describes a peer group and the aggregated gossip metrics of its members.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PeerGroupDto {
+    pub name: String,
+    pub peers: usize,
+    #[serde(rename = "connectedPeers")]
+    pub connected_peers: usize,
+    pub metrics: MetricsDto,
+}
+
 /// Returns all information about the gossip stream with the peer.
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct GossipDto {
@@ -923,6 +1003,34 @@ pub struct MetricsDto {
     pub sent_heartbeats: u64,
     #[serde(rename = "droppedPackets")]
     pub dropped_packets: u64,
+    #[serde(rename = "inboundGossipOverflows", default)]
+    pub inbound_gossip_overflows: u64,
+    #[serde(rename = "outboundGossipOverflows", default)]
+    pub outbound_gossip_overflows: u64,
+}
+
+/// Describes a peer known to the autopeering service.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringPeerDto {
+    pub id: String,
+    #[serde(rename = "ipAddress")]
+    pub ip_address: String,
+    pub services: Vec<AutopeeringServiceDto>,
+}
+
+/// Describes a service announced by an autopeering peer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringServiceDto {
+    pub name: String,
+    pub protocol: String,
+    pub port: u16,
+}
+
+/// Describes an autopeering neighbor and its distance to the node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringNeighborDto {
+    pub peer: AutopeeringPeerDto,
+    pub distance: u32,
 }
 
 /// Describes a receipt.
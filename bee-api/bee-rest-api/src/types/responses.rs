@@ -5,7 +5,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::{
     body::BodyInner,
-    dtos::{LedgerInclusionStateDto, MessageDto, OutputDto, PeerDto, ReceiptDto},
+    dtos::{
+        AutopeeringNeighborDto, AutopeeringPeerDto, InclusionProofDto, LedgerInclusionStateDto, MessageDto,
+        MilestonePayloadDto, OutputDto, PeerDto, PeerGroupDto, ReceiptDto,
+    },
 };
 
 /// Response of GET /api/v1/info.
@@ -108,6 +111,18 @@ pub struct MessageMetadataResponse {
 
 impl BodyInner for MessageMetadataResponse {}
 
+/// Response of GET /api/v1/messages/{message_id}/proof.
+/// Returns the proof of inclusion of a message along with the milestone that included it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MessageProofResponse {
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    pub milestone: MilestonePayloadDto,
+    pub proof: InclusionProofDto,
+}
+
+impl BodyInner for MessageProofResponse {}
+
 /// Response of GET /api/v1/messages/{message_id}/children.
 /// Returns all children of a specific message.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -248,6 +263,13 @@ pub struct PeerResponse(pub PeerDto);
 
 impl BodyInner for PeerResponse {}
 
+/// Response of GET /api/v1/peers/groups.
+/// Returns information about the peer groups with at least one member.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PeerGroupsResponse(pub Vec<PeerGroupDto>);
+
+impl BodyInner for PeerGroupsResponse {}
+
 /// Response of GET /api/plugins/debug/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -257,3 +279,32 @@ pub struct WhiteFlagResponse {
 }
 
 impl BodyInner for WhiteFlagResponse {}
+
+/// Response of GET /api/plugins/autopeering/peers/{list}.
+/// Returns the peers of the given autopeering peer list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringPeersResponse(pub Vec<AutopeeringPeerDto>);
+
+impl BodyInner for AutopeeringPeersResponse {}
+
+/// Response of GET /api/plugins/autopeering/neighbors.
+/// Returns the inbound and outbound autopeering neighbors.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringNeighborsResponse {
+    pub inbound: Vec<AutopeeringNeighborDto>,
+    pub outbound: Vec<AutopeeringNeighborDto>,
+}
+
+impl BodyInner for AutopeeringNeighborsResponse {}
+
+/// Response of GET /api/plugins/autopeering/salts.
+/// Returns the expiration times of the autopeering salts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutopeeringSaltsResponse {
+    #[serde(rename = "publicSaltExpirationTime")]
+    pub public_salt_expiration_time: u64,
+    #[serde(rename = "privateSaltExpirationTime")]
+    pub private_salt_expiration_time: u64,
+}
+
+impl BodyInner for AutopeeringSaltsResponse {}
@@ -3,62 +3,74 @@
 
 #![allow(deprecated)]
 
-use bee_crypto::ternary::sponge::{BatchHasher, CurlPRounds, BATCH_SIZE};
+use bee_crypto::ternary::sponge::{BatchHasher, CurlPRounds, MAX_BATCH_SIZE};
 use bee_ternary::{T1B1Buf, T5B1Buf, TritBuf, TryteBuf};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 fn batched_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.clone());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.clone());
+        }
+        for _ in hasher.hash_batched() {}
     }
-    for _ in hasher.hash_batched() {}
 }
 
 fn unbatched_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.clone());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.clone());
+        }
+        for _ in hasher.hash_unbatched() {}
     }
-    for _ in hasher.hash_unbatched() {}
 }
 
 fn batched_hasher_encoding_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.encode::<T1B1Buf>());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.encode::<T1B1Buf>());
+        }
+        for _ in hasher.hash_batched() {}
     }
-    for _ in hasher.hash_batched() {}
 }
 
 fn unbatched_hasher_encoding_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.encode::<T1B1Buf>());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.encode::<T1B1Buf>());
+        }
+        for _ in hasher.hash_unbatched() {}
     }
-    for _ in hasher.hash_unbatched() {}
 }
 
 fn batched_hasher_t1b1(input: &TritBuf<T1B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.clone());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.clone());
+        }
+        for _ in hasher.hash_batched() {}
     }
-    for _ in hasher.hash_batched() {}
 }
 
 fn unbatched_hasher_t1b1(input: &TritBuf<T1B1Buf>) {
     let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.clone());
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.clone());
+        }
+        for _ in hasher.hash_unbatched() {}
     }
-    for _ in hasher.hash_unbatched() {}
 }
 
 fn bench_hasher(c: &mut Criterion) {
@@ -75,7 +87,7 @@ fn bench_hasher(c: &mut Criterion) {
         .encode::<T5B1Buf>();
 
     let mut group = c.benchmark_group("CurlP");
-    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+    group.throughput(Throughput::Elements(MAX_BATCH_SIZE as u64));
     for input in [input_243, input_8019].iter() {
         let length = input.len();
 
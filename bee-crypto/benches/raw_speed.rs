@@ -3,25 +3,29 @@
 
 #![allow(deprecated)]
 
-use bee_crypto::ternary::sponge::{BatchHasher, CurlP81, CurlPRounds, Sponge, UnrolledCurlP81, BATCH_SIZE};
+use bee_crypto::ternary::sponge::{
+    BatchBackend, BatchHasher, CurlP81, CurlPRounds, Sponge, UnrolledCurlP81, MAX_BATCH_SIZE,
+};
 use bee_ternary::{T1B1Buf, T5B1Buf, TritBuf, TryteBuf};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use crypto::hashes::ternary::curl_p::CurlP;
 
-fn batched_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
-    let mut hasher = BatchHasher::new(input.len(), CurlPRounds::Rounds81);
+fn batched_hasher_t5b1(input: &TritBuf<T5B1Buf>, backend: BatchBackend) {
+    let mut hasher = BatchHasher::with_backend(backend, input.len(), CurlPRounds::Rounds81).unwrap();
 
-    for _ in 0..BATCH_SIZE {
-        hasher.add(input.clone());
-    }
+    for _ in 0..MAX_BATCH_SIZE / hasher.batch_size() {
+        for _ in 0..hasher.batch_size() {
+            hasher.add(input.clone());
+        }
 
-    for _ in hasher.hash_batched() {}
+        for _ in hasher.hash_batched() {}
+    }
 }
 
 fn regular_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = CurlP::new();
 
-    for _ in 0..BATCH_SIZE {
+    for _ in 0..MAX_BATCH_SIZE {
         hasher.digest(&input.encode::<T1B1Buf>());
     }
 }
@@ -29,7 +33,7 @@ fn regular_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
 fn regular_hasher_t5b1_legacy(input: &TritBuf<T5B1Buf>) {
     let mut hasher = CurlP81::new();
 
-    for _ in 0..BATCH_SIZE {
+    for _ in 0..MAX_BATCH_SIZE {
         hasher.digest(&input.encode::<T1B1Buf>()).unwrap();
     }
 }
@@ -37,7 +41,7 @@ fn regular_hasher_t5b1_legacy(input: &TritBuf<T5B1Buf>) {
 fn unrolled_hasher_t5b1(input: &TritBuf<T5B1Buf>) {
     let mut hasher = UnrolledCurlP81::new();
 
-    for _ in 0..BATCH_SIZE {
+    for _ in 0..MAX_BATCH_SIZE {
         hasher.digest(&input.encode::<T1B1Buf>()).unwrap();
     }
 }
@@ -56,16 +60,25 @@ fn bench_hasher(c: &mut Criterion) {
         .encode::<T5B1Buf>();
 
     let mut group = c.benchmark_group("CurlP");
-    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+    group.throughput(Throughput::Elements(MAX_BATCH_SIZE as u64));
     for input in [input_243, input_8019].iter() {
         let length = input.len();
 
         // Using T5B1 directly.
-        group.bench_with_input(
-            BenchmarkId::new("Batched", format!("{} T5B1", length)),
-            input,
-            |b, i| b.iter(|| batched_hasher_t5b1(i)),
-        );
+        for backend in [
+            BatchBackend::Portable,
+            BatchBackend::Sse2,
+            BatchBackend::Avx2,
+            BatchBackend::Avx512,
+        ] {
+            if backend.is_supported() {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("Batched ({:?})", backend), format!("{} T5B1", length)),
+                    input,
+                    |b, i| b.iter(|| batched_hasher_t5b1(i, backend)),
+                );
+            }
+        }
         group.bench_with_input(
             BenchmarkId::new("Regular", format!("{} T5B1", length)),
             input,
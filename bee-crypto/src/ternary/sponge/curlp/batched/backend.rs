@@ -0,0 +1,233 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ternary::{
+    raw::{RawEncoding, RawEncodingBuf},
+    Btrit, TritBuf,
+};
+
+#[cfg(target_arch = "x86_64")]
+use crate::ternary::sponge::curlp::batched::x86::{self, Avx2Word, Avx512Word, Sse2Word};
+use crate::ternary::{
+    sponge::{
+        curlp::batched::{
+            bct::{BcTrit, BcTritArr, BcTritBuf},
+            bct_curlp::BctCurlP,
+            word::BcWord,
+        },
+        CurlPRounds,
+    },
+    HASH_LENGTH,
+};
+
+/// The implementations of the batched `CurlP` transform, differing in the width of the words they operate on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchBackend {
+    /// Portable implementation using `usize` words.
+    Portable,
+    /// SSE2 implementation using 128-bit words.
+    Sse2,
+    /// AVX2 implementation using 256-bit words.
+    Avx2,
+    /// AVX-512 implementation using 512-bit words.
+    Avx512,
+}
+
+impl BatchBackend {
+    /// Returns the widest backend supported by the CPU, detected at runtime.
+    pub fn detect() -> Self {
+        [Self::Avx512, Self::Avx2, Self::Sse2]
+            .into_iter()
+            .find(Self::is_supported)
+            .unwrap_or(Self::Portable)
+    }
+
+    /// Checks if the CPU supports the backend.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Portable => true,
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse2 => std::is_x86_feature_detected!("sse2"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => std::is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512 => std::is_x86_feature_detected!("avx512f"),
+            #[cfg(not(target_arch = "x86_64"))]
+            Self::Sse2 | Self::Avx2 | Self::Avx512 => false,
+        }
+    }
+
+    /// Returns the number of inputs the backend processes in a single batch.
+    pub fn batch_size(&self) -> usize {
+        match self {
+            Self::Portable => <usize as BcWord>::LANES,
+            Self::Sse2 => 128,
+            Self::Avx2 => 256,
+            Self::Avx512 => 512,
+        }
+    }
+}
+
+/// The buffers and the hasher of the batched `CurlP` for a given word type.
+pub(crate) struct BctState<W> {
+    /// An interleaved representation of the input trits.
+    inputs: BcTritBuf<W>,
+    /// An interleaved representation of the output trits.
+    hashes: BcTritArr<W, HASH_LENGTH>,
+    /// The CurlP hasher for binary coded trits.
+    curlp: BctCurlP<W>,
+}
+
+impl<W: BcWord> BctState<W> {
+    fn new(input_length: usize, rounds: CurlPRounds) -> Box<Self> {
+        Box::new(Self {
+            inputs: BcTritBuf::zeros(input_length),
+            hashes: BcTritArr::zeros(),
+            curlp: BctCurlP::new(rounds),
+        })
+    }
+
+    /// See `BatchHasher::mux`.
+    fn mux<B>(&mut self, trit_inputs: &[TritBuf<B>])
+    where
+        B: RawEncodingBuf,
+        B::Slice: RawEncoding<Trit = Btrit>,
+    {
+        const CHUNK_SIZE: usize = 8 * std::mem::size_of::<usize>();
+
+        for i in 0..self.inputs.len() {
+            // This is safe because `i < self.inputs.len()`.
+            let BcTrit(lo, hi) = unsafe { self.inputs.get_unchecked_mut(i) };
+
+            // The bits are gathered in chunks of `usize` before being written to the words.
+            for (chunk, trit_inputs) in trit_inputs.chunks(CHUNK_SIZE).enumerate() {
+                let mut lo_bits = 0usize;
+                let mut hi_bits = 0usize;
+
+                for (j, input) in trit_inputs.iter().enumerate() {
+                    // this is safe because `i < input.len()` (the `add` method guarantees that all the inputs have
+                    // the same length as `self.inputs`).
+                    match unsafe { input.get_unchecked(i) } {
+                        Btrit::NegOne => lo_bits |= 1 << j,
+                        Btrit::PlusOne => hi_bits |= 1 << j,
+                        Btrit::Zero => {
+                            lo_bits |= 1 << j;
+                            hi_bits |= 1 << j;
+                        }
+                    }
+                }
+
+                lo.or_chunk(chunk, lo_bits);
+                hi.or_chunk(chunk, hi_bits);
+            }
+        }
+    }
+
+    /// Absorbs the multiplexed inputs, squeezes the hashes and clears the inputs for the next batch.
+    #[inline(always)]
+    pub(crate) fn hash(&mut self) {
+        self.curlp.reset();
+        self.curlp.absorb(&self.inputs);
+        self.curlp.squeeze_into(&mut self.hashes);
+        self.inputs.fill(W::zero());
+    }
+
+    /// See `BatchHasher::demux`.
+    fn demux(&self, index: usize, buf: &mut TritBuf) {
+        for (BcTrit(lo, hi), btrit) in self.hashes.iter().zip(buf.iter_mut()) {
+            *btrit = match (lo.lane(index), hi.lane(index)) {
+                (true, false) => Btrit::NegOne,
+                (false, true) => Btrit::PlusOne,
+                // This can only be `(false, false)` or `(true, true)`.
+                _ => Btrit::Zero,
+            };
+        }
+    }
+}
+
+/// The batched `CurlP` of the selected backend.
+pub(crate) enum BctHasher {
+    Portable(Box<BctState<usize>>),
+    #[cfg(target_arch = "x86_64")]
+    Sse2(Box<BctState<Sse2Word>>),
+    #[cfg(target_arch = "x86_64")]
+    Avx2(Box<BctState<Avx2Word>>),
+    #[cfg(target_arch = "x86_64")]
+    Avx512(Box<BctState<Avx512Word>>),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $state:ident => $body:expr) => {
+        match $self {
+            BctHasher::Portable($state) => $body,
+            #[cfg(target_arch = "x86_64")]
+            BctHasher::Sse2($state) => $body,
+            #[cfg(target_arch = "x86_64")]
+            BctHasher::Avx2($state) => $body,
+            #[cfg(target_arch = "x86_64")]
+            BctHasher::Avx512($state) => $body,
+        }
+    };
+}
+
+impl BctHasher {
+    /// Creates the hasher of a backend, which must be supported by the CPU.
+    pub(crate) fn new(backend: BatchBackend, input_length: usize, rounds: CurlPRounds) -> Self {
+        debug_assert!(backend.is_supported());
+
+        match backend {
+            BatchBackend::Portable => Self::Portable(BctState::new(input_length, rounds)),
+            #[cfg(target_arch = "x86_64")]
+            BatchBackend::Sse2 => Self::Sse2(BctState::new(input_length, rounds)),
+            #[cfg(target_arch = "x86_64")]
+            BatchBackend::Avx2 => Self::Avx2(BctState::new(input_length, rounds)),
+            #[cfg(target_arch = "x86_64")]
+            BatchBackend::Avx512 => Self::Avx512(BctState::new(input_length, rounds)),
+            #[cfg(not(target_arch = "x86_64"))]
+            BatchBackend::Sse2 | BatchBackend::Avx2 | BatchBackend::Avx512 => {
+                unreachable!("{:?} is not supported on this architecture", backend)
+            }
+        }
+    }
+
+    pub(crate) fn backend(&self) -> BatchBackend {
+        match self {
+            Self::Portable(_) => BatchBackend::Portable,
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse2(_) => BatchBackend::Sse2,
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2(_) => BatchBackend::Avx2,
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512(_) => BatchBackend::Avx512,
+        }
+    }
+
+    pub(crate) fn input_length(&self) -> usize {
+        dispatch!(self, state => state.inputs.len())
+    }
+
+    pub(crate) fn mux<B>(&mut self, trit_inputs: &[TritBuf<B>])
+    where
+        B: RawEncodingBuf,
+        B::Slice: RawEncoding<Trit = Btrit>,
+    {
+        dispatch!(self, state => state.mux(trit_inputs))
+    }
+
+    pub(crate) fn hash(&mut self) {
+        // The SIMD variants are safe because they are only created for backends supported by the CPU.
+        match self {
+            Self::Portable(state) => state.hash(),
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse2(state) => unsafe { x86::hash_sse2(state) },
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2(state) => unsafe { x86::hash_avx2(state) },
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512(state) => unsafe { x86::hash_avx512(state) },
+        }
+    }
+
+    pub(crate) fn demux(&self, index: usize, buf: &mut TritBuf) {
+        dispatch!(self, state => state.demux(index, buf))
+    }
+}
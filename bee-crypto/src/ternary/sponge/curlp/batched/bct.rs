@@ -3,29 +3,34 @@
 
 use std::ops::{Deref, DerefMut, Range};
 
-#[derive(Clone, Copy, Debug)]
-pub(crate) struct BcTrit(pub(crate) usize, pub(crate) usize);
+use crate::ternary::sponge::curlp::batched::word::BcWord;
 
-impl BcTrit {
-    const fn zero() -> Self {
-        Self(0, 0)
+#[derive(Clone, Copy)]
+pub(crate) struct BcTrit<W>(pub(crate) W, pub(crate) W);
+
+impl<W: BcWord> BcTrit<W> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self(W::zero(), W::zero())
     }
 
-    pub(crate) fn lo(&self) -> usize {
+    #[inline(always)]
+    pub(crate) fn lo(&self) -> W {
         self.0
     }
 
-    pub(crate) fn hi(&self) -> usize {
+    #[inline(always)]
+    pub(crate) fn hi(&self) -> W {
         self.1
     }
 }
 
 #[derive(Clone)]
-pub(crate) struct BcTritBuf {
-    inner: Vec<BcTrit>,
+pub(crate) struct BcTritBuf<W> {
+    inner: Vec<BcTrit<W>>,
 }
 
-impl BcTritBuf {
+impl<W: BcWord> BcTritBuf<W> {
     pub(crate) fn zeros(len: usize) -> Self {
         Self {
             inner: vec![BcTrit::zero(); len],
@@ -33,114 +38,127 @@ impl BcTritBuf {
     }
 }
 
-impl Deref for BcTritBuf {
-    type Target = BcTrits;
+impl<W> Deref for BcTritBuf<W> {
+    type Target = BcTrits<W>;
 
+    #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self.inner.deref() as *const [BcTrit] as *const BcTrits) }
+        unsafe { &*(self.inner.deref() as *const [BcTrit<W>] as *const BcTrits<W>) }
     }
 }
 
-impl DerefMut for BcTritBuf {
+impl<W> DerefMut for BcTritBuf<W> {
+    #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut *(self.inner.deref_mut() as *mut [BcTrit] as *mut BcTrits) }
+        unsafe { &mut *(self.inner.deref_mut() as *mut [BcTrit<W>] as *mut BcTrits<W>) }
     }
 }
 
 #[derive(Clone)]
-pub(crate) struct BcTritArr<const N: usize> {
-    inner: [BcTrit; N],
+pub(crate) struct BcTritArr<W, const N: usize> {
+    inner: [BcTrit<W>; N],
 }
 
-impl<const N: usize> BcTritArr<N> {
+impl<W: BcWord, const N: usize> BcTritArr<W, N> {
     pub(crate) fn zeros() -> Self {
         Self {
             inner: [BcTrit::zero(); N],
         }
     }
 
-    pub(crate) fn filled(value: usize) -> Self {
+    pub(crate) fn filled(value: W) -> Self {
         Self {
             inner: [BcTrit(value, value); N],
         }
     }
 }
 
-impl<const N: usize> Deref for BcTritArr<N> {
-    type Target = BcTrits;
+impl<W, const N: usize> Deref for BcTritArr<W, N> {
+    type Target = BcTrits<W>;
 
+    #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self.inner.as_ref() as *const [BcTrit] as *const BcTrits) }
+        unsafe { &*(self.inner.as_ref() as *const [BcTrit<W>] as *const BcTrits<W>) }
     }
 }
 
-impl<const N: usize> DerefMut for BcTritArr<N> {
+impl<W, const N: usize> DerefMut for BcTritArr<W, N> {
+    #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut *(self.inner.as_mut() as *mut [BcTrit] as *mut BcTrits) }
+        unsafe { &mut *(self.inner.as_mut() as *mut [BcTrit<W>] as *mut BcTrits<W>) }
     }
 }
 
 #[repr(transparent)]
-pub(crate) struct BcTrits {
-    inner: [BcTrit],
+pub(crate) struct BcTrits<W> {
+    inner: [BcTrit<W>],
 }
 
-impl BcTrits {
-    pub(crate) fn fill(&mut self, value: usize) {
+impl<W: BcWord> BcTrits<W> {
+    #[inline(always)]
+    pub(crate) fn fill(&mut self, value: W) {
         for BcTrit(hi, lo) in &mut self.inner {
             *lo = value;
             *hi = value;
         }
     }
 
+    #[inline(always)]
     pub(crate) fn len(&self) -> usize {
         self.inner.len()
     }
 
+    #[inline(always)]
     pub(crate) fn copy_from_slice(&mut self, slice: &Self) {
         self.inner.copy_from_slice(&slice.inner)
     }
 
-    pub(crate) unsafe fn get_unchecked<I: BcTritsIndex>(&self, index: I) -> &I::Output {
+    #[inline(always)]
+    pub(crate) unsafe fn get_unchecked<I: BcTritsIndex<W>>(&self, index: I) -> &I::Output {
         index.get_unchecked(self)
     }
 
-    pub(crate) unsafe fn get_unchecked_mut<I: BcTritsIndex>(&mut self, index: I) -> &mut I::Output {
+    #[inline(always)]
+    pub(crate) unsafe fn get_unchecked_mut<I: BcTritsIndex<W>>(&mut self, index: I) -> &mut I::Output {
         index.get_unchecked_mut(self)
     }
 
-    pub(crate) fn iter(&self) -> impl Iterator<Item = &BcTrit> {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &BcTrit<W>> {
         self.inner.iter()
     }
 }
 
-pub(crate) trait BcTritsIndex {
+pub(crate) trait BcTritsIndex<W> {
     type Output: ?Sized;
 
-    unsafe fn get_unchecked(self, trits: &BcTrits) -> &Self::Output;
-    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits) -> &mut Self::Output;
+    unsafe fn get_unchecked(self, trits: &BcTrits<W>) -> &Self::Output;
+    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits<W>) -> &mut Self::Output;
 }
 
-impl BcTritsIndex for usize {
-    type Output = BcTrit;
+impl<W> BcTritsIndex<W> for usize {
+    type Output = BcTrit<W>;
 
-    unsafe fn get_unchecked(self, trits: &BcTrits) -> &Self::Output {
+    #[inline(always)]
+    unsafe fn get_unchecked(self, trits: &BcTrits<W>) -> &Self::Output {
         trits.inner.get_unchecked(self)
     }
 
-    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits) -> &mut Self::Output {
+    #[inline(always)]
+    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits<W>) -> &mut Self::Output {
         trits.inner.get_unchecked_mut(self)
     }
 }
 
-impl BcTritsIndex for Range<usize> {
-    type Output = BcTrits;
+impl<W> BcTritsIndex<W> for Range<usize> {
+    type Output = BcTrits<W>;
 
-    unsafe fn get_unchecked(self, trits: &BcTrits) -> &Self::Output {
-        &*(trits.inner.get_unchecked(self) as *const [BcTrit] as *const BcTrits)
+    #[inline(always)]
+    unsafe fn get_unchecked(self, trits: &BcTrits<W>) -> &Self::Output {
+        &*(trits.inner.get_unchecked(self) as *const [BcTrit<W>] as *const BcTrits<W>)
     }
 
-    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits) -> &mut Self::Output {
-        &mut *(trits.inner.get_unchecked_mut(self) as *mut [BcTrit] as *mut BcTrits)
+    #[inline(always)]
+    unsafe fn get_unchecked_mut(self, trits: &mut BcTrits<W>) -> &mut Self::Output {
+        &mut *(trits.inner.get_unchecked_mut(self) as *mut [BcTrit<W>] as *mut BcTrits<W>)
     }
 }
@@ -5,65 +5,86 @@ use crate::ternary::{
     sponge::{
         curlp::batched::{
             bct::{BcTrit, BcTritArr, BcTrits},
-            HIGH_BITS,
+            word::BcWord,
         },
         CurlPRounds,
     },
     HASH_LENGTH,
 };
 
-pub(crate) struct BctCurlP {
+pub(crate) struct BctCurlP<W> {
     rounds: CurlPRounds,
-    state: BcTritArr<{ 3 * HASH_LENGTH }>,
-    scratch_pad: BcTritArr<{ 3 * HASH_LENGTH }>,
+    // The state and the scratch pad. Their roles are swapped after each round instead of copying the state into the
+    // scratch pad, which halves the memory traffic of the transform.
+    buffers: [BcTritArr<W, { 3 * HASH_LENGTH }>; 2],
+    // The index of the buffer holding the state.
+    current: usize,
 }
 
-impl BctCurlP {
+// Everything but `new` is inlined so that the transform gets compiled with the target features of the caller.
+impl<W: BcWord> BctCurlP<W> {
     #[allow(clippy::assertions_on_constants)]
     pub(crate) fn new(rounds: CurlPRounds) -> Self {
         // Ensure that changing the hash length will not cause undefined behaviour.
         assert!(3 * HASH_LENGTH > 728);
         Self {
             rounds,
-            state: BcTritArr::filled(HIGH_BITS),
-            scratch_pad: BcTritArr::filled(HIGH_BITS),
+            buffers: [BcTritArr::filled(W::ones()), BcTritArr::filled(W::ones())],
+            current: 0,
         }
     }
 
+    #[inline(always)]
+    fn state(&self) -> &BcTritArr<W, { 3 * HASH_LENGTH }> {
+        &self.buffers[self.current]
+    }
+
+    #[inline(always)]
+    fn state_mut(&mut self) -> &mut BcTritArr<W, { 3 * HASH_LENGTH }> {
+        &mut self.buffers[self.current]
+    }
+
+    #[inline(always)]
     pub(crate) fn reset(&mut self) {
-        self.state.fill(HIGH_BITS);
+        self.state_mut().fill(W::ones());
     }
 
+    #[inline(always)]
     pub(crate) fn transform(&mut self) {
         let mut scratch_pad_index = 0;
 
         // All the unchecked accesses here are guaranteed to be safe by the assertion inside `new`.
         for _round in 0..self.rounds as usize {
-            self.scratch_pad.copy_from_slice(&self.state);
+            let [first, second] = &mut self.buffers;
+            let (scratch_pad, state) = if self.current == 0 {
+                (&*first, second)
+            } else {
+                (&*second, first)
+            };
 
-            let BcTrit(mut alpha, mut beta) = unsafe { *self.scratch_pad.get_unchecked(scratch_pad_index) };
+            let BcTrit(mut alpha, mut beta) = unsafe { *scratch_pad.get_unchecked(scratch_pad_index) };
 
             scratch_pad_index += 364;
 
-            let mut temp = unsafe { *self.scratch_pad.get_unchecked(scratch_pad_index) };
+            let mut temp = unsafe { *scratch_pad.get_unchecked(scratch_pad_index) };
 
-            let delta = beta ^ temp.lo();
+            let delta = beta.xor(temp.lo());
 
-            *unsafe { self.state.get_unchecked_mut(0) } = BcTrit(!(delta & alpha), delta | (alpha ^ temp.hi()));
+            *unsafe { state.get_unchecked_mut(0) } = BcTrit(delta.and(alpha).not(), delta.or(alpha.xor(temp.hi())));
 
             let mut state_index = 1;
 
-            while state_index < self.state.len() {
+            while state_index < state.len() {
                 scratch_pad_index += 364;
 
                 alpha = temp.lo();
                 beta = temp.hi();
-                temp = unsafe { *self.scratch_pad.get_unchecked(scratch_pad_index) };
+                temp = unsafe { *scratch_pad.get_unchecked(scratch_pad_index) };
 
-                let delta = beta ^ temp.lo();
+                let delta = beta.xor(temp.lo());
 
-                *unsafe { self.state.get_unchecked_mut(state_index) } =
-                    BcTrit(!(delta & alpha), delta | (alpha ^ temp.hi()));
+                *unsafe { state.get_unchecked_mut(state_index) } =
+                    BcTrit(delta.and(alpha).not(), delta.or(alpha.xor(temp.hi())));
 
                 state_index += 1;
 
@@ -71,26 +92,29 @@ impl BctCurlP {
 
                 alpha = temp.lo();
                 beta = temp.hi();
-                temp = unsafe { *self.scratch_pad.get_unchecked(scratch_pad_index) };
+                temp = unsafe { *scratch_pad.get_unchecked(scratch_pad_index) };
 
-                let delta = beta ^ temp.lo();
+                let delta = beta.xor(temp.lo());
 
-                *unsafe { self.state.get_unchecked_mut(state_index) } =
-                    BcTrit(!(delta & alpha), delta | (alpha ^ temp.hi()));
+                *unsafe { state.get_unchecked_mut(state_index) } =
+                    BcTrit(delta.and(alpha).not(), delta.or(alpha.xor(temp.hi())));
 
                 state_index += 1;
             }
+
+            self.current ^= 1;
         }
     }
 
-    pub(crate) fn absorb(&mut self, bc_trits: &BcTrits) {
+    #[inline(always)]
+    pub(crate) fn absorb(&mut self, bc_trits: &BcTrits<W>) {
         let mut length = bc_trits.len();
         let mut offset = 0;
 
         loop {
             let length_to_copy = if length < HASH_LENGTH { length } else { HASH_LENGTH };
             // This is safe as `length_to_copy <= HASH_LENGTH`.
-            unsafe { self.state.get_unchecked_mut(0..length_to_copy) }
+            unsafe { self.state_mut().get_unchecked_mut(0..length_to_copy) }
                 .copy_from_slice(unsafe { bc_trits.get_unchecked(offset..offset + length_to_copy) });
 
             self.transform();
@@ -106,14 +130,15 @@ impl BctCurlP {
 
     // This method shouldn't assume that `result` has any particular content, just that it has an
     // adequate size.
-    pub(crate) fn squeeze_into(&mut self, result: &mut BcTrits) {
+    #[inline(always)]
+    pub(crate) fn squeeze_into(&mut self, result: &mut BcTrits<W>) {
         let trit_count = result.len();
 
         let hash_count = trit_count / HASH_LENGTH;
 
         for i in 0..hash_count {
             unsafe { result.get_unchecked_mut(i * HASH_LENGTH..(i + 1) * HASH_LENGTH) }
-                .copy_from_slice(unsafe { self.state.get_unchecked(0..HASH_LENGTH) });
+                .copy_from_slice(unsafe { self.state().get_unchecked(0..HASH_LENGTH) });
 
             self.transform();
         }
@@ -121,7 +146,7 @@ impl BctCurlP {
         let last = trit_count - hash_count * HASH_LENGTH;
 
         unsafe { result.get_unchecked_mut(trit_count - last..trit_count) }
-            .copy_from_slice(unsafe { self.state.get_unchecked(0..last) });
+            .copy_from_slice(unsafe { self.state().get_unchecked(0..last) });
 
         if trit_count % HASH_LENGTH != 0 {
             self.transform();
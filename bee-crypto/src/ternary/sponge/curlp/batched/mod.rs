@@ -3,40 +3,44 @@
 
 //! A batched version of the `CurlP` hash.
 
+mod backend;
 mod bct;
 mod bct_curlp;
+mod word;
+#[cfg(target_arch = "x86_64")]
+mod x86;
 
 use bee_ternary::{
     raw::{RawEncoding, RawEncodingBuf},
     Btrit, T1B1Buf, TritBuf,
 };
 
-use self::{
-    bct::{BcTrit, BcTritArr, BcTritBuf},
-    bct_curlp::BctCurlP,
-};
+pub use self::backend::BatchBackend;
+use self::backend::BctHasher;
 use crate::ternary::sponge::{CurlP, CurlPRounds, Sponge, HASH_LENGTH};
 
-/// The number of inputs that can be processed in a single batch.
+/// The number of inputs that can be processed in a single batch by the portable backend.
 pub const BATCH_SIZE: usize = 8 * std::mem::size_of::<usize>();
-const HIGH_BITS: usize = usize::max_value();
+/// The largest number of inputs that can be processed in a single batch by any backend.
+pub const MAX_BATCH_SIZE: usize = 512;
 
 /// A hasher that can process several inputs at the same time in batches.
 ///
 /// This hasher works by interleaving the trits of the inputs in each batch and hashing this
 /// interleaved representation. It is also able to fall back to the regular CurlP algorithm if
 /// required.
+///
+/// The size of a batch depends on the `BatchBackend`, which is by default the widest one supported
+/// by the CPU.
 pub struct BatchHasher<B: RawEncodingBuf> {
     /// The trits of the inputs before being interleaved.
     trit_inputs: Vec<TritBuf<B>>,
-    /// An interleaved representation of the input trits.
-    bct_inputs: BcTritBuf,
-    /// An interleaved representation of the output trits.
-    bct_hashes: BcTritArr<HASH_LENGTH>,
+    /// The CurlP hasher for binary coded trits, with its interleaved inputs and outputs.
+    bct_hasher: BctHasher,
+    /// The number of inputs processed in a single batch.
+    batch_size: usize,
     /// A buffer for demultiplexing.
     buf_demux: TritBuf,
-    /// The CurlP hasher for binary coded trits.
-    bct_curlp: BctCurlP,
     /// The regular CurlP hasher.
     curlp: CurlP,
 }
@@ -46,28 +50,56 @@ where
     B: RawEncodingBuf,
     B::Slice: RawEncoding<Trit = Btrit>,
 {
-    /// Create a new hasher.
+    /// Create a new hasher using the widest backend supported by the CPU.
     ///
     /// It requires the length of the input, the length of the output hash and the number of
     /// rounds.
     pub fn new(input_length: usize, rounds: CurlPRounds) -> Self {
+        Self::with_backend_unchecked(BatchBackend::detect(), input_length, rounds)
+    }
+
+    /// Create a new hasher using a specific backend.
+    ///
+    /// Returns `None` if the backend is not supported by the CPU.
+    pub fn with_backend(backend: BatchBackend, input_length: usize, rounds: CurlPRounds) -> Option<Self> {
+        if backend.is_supported() {
+            Some(Self::with_backend_unchecked(backend, input_length, rounds))
+        } else {
+            None
+        }
+    }
+
+    fn with_backend_unchecked(backend: BatchBackend, input_length: usize, rounds: CurlPRounds) -> Self {
         Self {
-            trit_inputs: Vec::with_capacity(BATCH_SIZE),
-            bct_inputs: BcTritBuf::zeros(input_length),
-            bct_hashes: BcTritArr::<HASH_LENGTH>::zeros(),
+            trit_inputs: Vec::with_capacity(backend.batch_size()),
+            bct_hasher: BctHasher::new(backend, input_length, rounds),
+            batch_size: backend.batch_size(),
             buf_demux: TritBuf::zeros(HASH_LENGTH),
-            bct_curlp: BctCurlP::new(rounds),
             curlp: CurlP::new(rounds),
         }
     }
 
+    /// Return the backend used by the hasher.
+    pub fn backend(&self) -> BatchBackend {
+        self.bct_hasher.backend()
+    }
+
+    /// Return the number of inputs that can be processed in a single batch.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
     /// Add a new input to the batch.
     ///
-    /// It panics if the size of the batch exceeds `BATCH_SIZE` or if `input.len()` is not equal to
-    /// the `input_length` parameter of the constructor.
+    /// It panics if the size of the batch exceeds `batch_size()` or if `input.len()` is not equal
+    /// to the `input_length` parameter of the constructor.
     pub fn add(&mut self, input: TritBuf<B>) {
-        assert!(self.trit_inputs.len() < BATCH_SIZE, "Batch is full.");
-        assert_eq!(input.len(), self.bct_inputs.len(), "Input has an incorrect size.");
+        assert!(self.trit_inputs.len() < self.batch_size, "Batch is full.");
+        assert_eq!(
+            input.len(),
+            self.bct_hasher.input_length(),
+            "Input has an incorrect size."
+        );
         self.trit_inputs.push(input);
     }
 
@@ -92,52 +124,24 @@ where
     /// |   0  |    0    |     1    |
     /// |   1  |    1    |     1    |
     ///
-    /// Then the low and high bits are interleaved into two vectors of integers. Each integer has
-    /// size `BATCH_SIZE` and there are `input_length` integers in each vector.  This means that
+    /// Then the low and high bits are interleaved into two vectors of words. Each word has
+    /// `batch_size()` bits and there are `input_length` words in each vector.  This means that
     /// the low and high bits of the transaction number `N` in the batch are stored in the position
-    /// `N` of each integer.
+    /// `N` of each word.
     ///
-    /// This step works correctly even if there are less than `BATCH_SIZE` inputs.
+    /// This step works correctly even if there are less than `batch_size()` inputs.
     fn mux(&mut self) {
-        let count = self.trit_inputs.len();
-        for i in 0..self.bct_inputs.len() {
-            // This is safe because `i < self.bct_inputs.len()`.
-            let BcTrit(lo, hi) = unsafe { self.bct_inputs.get_unchecked_mut(i) };
-
-            for j in 0..count {
-                // this is safe because `j < self.trit_inputs.len()` and
-                // `i < self.trit_inputs[j].len()` (the `add` method guarantees that all the inputs
-                // have the same length as `self.trit_inputs`).
-                match unsafe { self.trit_inputs.get_unchecked(j).get_unchecked(i) } {
-                    Btrit::NegOne => *lo |= 1 << j,
-                    Btrit::PlusOne => *hi |= 1 << j,
-                    Btrit::Zero => {
-                        *lo |= 1 << j;
-                        *hi |= 1 << j;
-                    }
-                }
-            }
-        }
+        self.bct_hasher.mux(&self.trit_inputs);
     }
 
     /// Demultiplex the bits of the output to obtain the hash of the input with a specific index.
     ///
     /// This is the inverse of the `mux` function, but it is applied over the vector with the
-    /// binary encoding of the output hashes. Each pair of low and high bits in the `bct_hashes`
-    /// field is decoded into a trit using the same convention as the `mux` step with an additional
-    /// rule for the `(0, 0)` pair of bits which is mapped to the `0` trit.
+    /// binary encoding of the output hashes. Each pair of low and high bits is decoded into a trit
+    /// using the same convention as the `mux` step with an additional rule for the `(0, 0)` pair
+    /// of bits which is mapped to the `0` trit.
     fn demux(&mut self, index: usize) -> TritBuf {
-        for (bc_trit, btrit) in self.bct_hashes.iter().zip(self.buf_demux.iter_mut()) {
-            let lo = (bc_trit.lo() >> index) & 1;
-            let hi = (bc_trit.hi() >> index) & 1;
-
-            *btrit = match (lo, hi) {
-                (1, 0) => Btrit::NegOne,
-                (0, 1) => Btrit::PlusOne,
-                // This can only be `(0, 0)` or `(1, 1)`.
-                _ => Btrit::Zero,
-            };
-        }
+        self.bct_hasher.demux(index, &mut self.buf_demux);
 
         self.buf_demux.clone()
     }
@@ -148,17 +152,13 @@ where
     /// batched CurlP hasher so it can be called at any time.
     pub fn hash_batched(&mut self) -> impl Iterator<Item = TritBuf> + '_ {
         let total = self.trit_inputs.len();
-        // Reset batched CurlP hasher.
-        self.bct_curlp.reset();
-        // Multiplex the trits in `trits` and dump them into `inputs`
+        // Multiplex the trits in `trit_inputs` into the interleaved inputs.
         self.mux();
-        // Do the regular sponge steps.
-        self.bct_curlp.absorb(&self.bct_inputs);
-        self.bct_curlp.squeeze_into(&mut self.bct_hashes);
+        // Do the regular sponge steps, which also resets the batched CurlP hasher and fills the
+        // interleaved inputs with zeros.
+        self.bct_hasher.hash();
         // Clear the `trits` buffer to allow receiving a new batch.
         self.trit_inputs.clear();
-        // Fill the `inputs` buffer with zeros.
-        self.bct_inputs.fill(0);
         // Return an iterator for the output hashes.
         BatchedHashes {
             hasher: self,
@@ -0,0 +1,79 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// A word of bits in which each bit (or lane) belongs to a different input of the batch.
+///
+/// All the operations are expected to be inlined into the caller, so that wider words are compiled with the target
+/// features enabled by the caller.
+pub(crate) trait BcWord: Copy {
+    /// The number of lanes, i.e. the number of inputs that can be processed in parallel.
+    const LANES: usize;
+
+    /// Returns a word with all bits unset.
+    fn zero() -> Self;
+
+    /// Returns a word with all bits set.
+    fn ones() -> Self;
+
+    /// Bitwise AND.
+    fn and(self, other: Self) -> Self;
+
+    /// Bitwise OR.
+    fn or(self, other: Self) -> Self;
+
+    /// Bitwise XOR.
+    fn xor(self, other: Self) -> Self;
+
+    /// Bitwise NOT.
+    fn not(self) -> Self;
+
+    /// Sets the bits of the lanes `chunk * usize::BITS..(chunk + 1) * usize::BITS` that are set in `bits`.
+    fn or_chunk(&mut self, chunk: usize, bits: usize);
+
+    /// Returns whether the bit of the given lane is set.
+    fn lane(&self, lane: usize) -> bool;
+}
+
+impl BcWord for usize {
+    const LANES: usize = 8 * std::mem::size_of::<usize>();
+
+    #[inline(always)]
+    fn zero() -> Self {
+        0
+    }
+
+    #[inline(always)]
+    fn ones() -> Self {
+        usize::MAX
+    }
+
+    #[inline(always)]
+    fn and(self, other: Self) -> Self {
+        self & other
+    }
+
+    #[inline(always)]
+    fn or(self, other: Self) -> Self {
+        self | other
+    }
+
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        self ^ other
+    }
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        !self
+    }
+
+    #[inline(always)]
+    fn or_chunk(&mut self, _chunk: usize, bits: usize) {
+        *self |= bits;
+    }
+
+    #[inline(always)]
+    fn lane(&self, lane: usize) -> bool {
+        (*self >> lane) & 1 == 1
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Words backed by the vector registers of x86-64 CPUs.
+//!
+//! The bitwise operations of these words use intrinsics that are only available if the CPU supports the corresponding
+//! target feature. The words must therefore only be created once the feature has been detected at runtime, and the
+//! batched transform must be compiled with that feature enabled, which is what the `hash_*` functions are for.
+
+use std::arch::x86_64::{
+    __m128i, __m256i, __m512i, _mm256_and_si256, _mm256_or_si256, _mm256_xor_si256, _mm512_and_si512, _mm512_or_si512,
+    _mm512_xor_si512, _mm_and_si128, _mm_or_si128, _mm_xor_si128,
+};
+
+use crate::ternary::sponge::curlp::batched::{backend::BctState, word::BcWord};
+
+macro_rules! simd_word {
+    ($(#[$meta:meta])* $name:ident($simd:ty, $lanes:literal), $and:ident, $or:ident, $xor:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy)]
+        #[repr(transparent)]
+        pub(crate) struct $name($simd);
+
+        impl $name {
+            #[inline(always)]
+            fn from_u64s(words: [u64; $lanes / 64]) -> Self {
+                // This is safe because the vector type and the array have the same size and every bit pattern is valid
+                // for both.
+                Self(unsafe { std::mem::transmute::<[u64; $lanes / 64], $simd>(words) })
+            }
+
+            #[inline(always)]
+            fn as_u64s(&self) -> &[u64; $lanes / 64] {
+                // This is safe because the vector type and the array have the same size and the vector type has a
+                // stricter alignment.
+                unsafe { &*(&self.0 as *const $simd as *const [u64; $lanes / 64]) }
+            }
+
+            #[inline(always)]
+            fn as_u64s_mut(&mut self) -> &mut [u64; $lanes / 64] {
+                // This is safe for the same reasons as in `as_u64s`.
+                unsafe { &mut *(&mut self.0 as *mut $simd as *mut [u64; $lanes / 64]) }
+            }
+        }
+
+        impl BcWord for $name {
+            const LANES: usize = $lanes;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                Self::from_u64s([0; $lanes / 64])
+            }
+
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::from_u64s([u64::MAX; $lanes / 64])
+            }
+
+            #[inline(always)]
+            fn and(self, other: Self) -> Self {
+                // This is safe because this word is only created once the target feature has been detected.
+                Self(unsafe { $and(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            fn or(self, other: Self) -> Self {
+                // This is safe because this word is only created once the target feature has been detected.
+                Self(unsafe { $or(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            fn xor(self, other: Self) -> Self {
+                // This is safe because this word is only created once the target feature has been detected.
+                Self(unsafe { $xor(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            fn not(self) -> Self {
+                self.xor(Self::ones())
+            }
+
+            #[inline(always)]
+            fn or_chunk(&mut self, chunk: usize, bits: usize) {
+                // `usize` and `u64` have the same size on x86-64.
+                self.as_u64s_mut()[chunk] |= bits as u64;
+            }
+
+            #[inline(always)]
+            fn lane(&self, lane: usize) -> bool {
+                (self.as_u64s()[lane / 64] >> (lane % 64)) & 1 == 1
+            }
+        }
+    };
+}
+
+simd_word!(
+    /// A 128-bit word backed by an SSE2 register.
+    Sse2Word(__m128i, 128),
+    _mm_and_si128,
+    _mm_or_si128,
+    _mm_xor_si128
+);
+
+simd_word!(
+    /// A 256-bit word backed by an AVX2 register.
+    Avx2Word(__m256i, 256),
+    _mm256_and_si256,
+    _mm256_or_si256,
+    _mm256_xor_si256
+);
+
+simd_word!(
+    /// A 512-bit word backed by an AVX-512 register.
+    Avx512Word(__m512i, 512),
+    _mm512_and_si512,
+    _mm512_or_si512,
+    _mm512_xor_si512
+);
+
+/// Hashes the multiplexed inputs of `state` with SSE2 enabled.
+///
+/// # Safety
+///
+/// The CPU must support SSE2.
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn hash_sse2(state: &mut BctState<Sse2Word>) {
+    state.hash();
+}
+
+/// Hashes the multiplexed inputs of `state` with AVX2 enabled.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn hash_avx2(state: &mut BctState<Avx2Word>) {
+    state.hash();
+}
+
+/// Hashes the multiplexed inputs of `state` with AVX-512 enabled.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F.
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn hash_avx512(state: &mut BctState<Avx512Word>) {
+    state.hash();
+}
@@ -12,7 +12,7 @@ use std::{
 use bee_ternary::{Btrit, TritBuf, Trits};
 
 pub use self::{
-    batched::{BatchHasher, BATCH_SIZE},
+    batched::{BatchBackend, BatchHasher, BATCH_SIZE, MAX_BATCH_SIZE},
     unrolled::UnrolledCurlP81,
 };
 use crate::ternary::{sponge::Sponge, HASH_LENGTH};
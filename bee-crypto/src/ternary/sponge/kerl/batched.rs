@@ -0,0 +1,109 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A batched version of the `Kerl` hash.
+
+use bee_ternary::{
+    raw::{RawEncoding, RawEncodingBuf},
+    Btrit, TritBuf,
+};
+
+use crate::ternary::{
+    sponge::{Kerl, Sponge},
+    HASH_LENGTH,
+};
+
+/// A hasher that can process several inputs of the same length in batches.
+///
+/// Unlike the batched `CurlP`, the inputs are hashed one after the other, but all the buffers are allocated once when
+/// the hasher is created and reused by every batch. This makes hashing many legacy addresses or bundles, e.g. during a
+/// migration, considerably cheaper than using a new `Kerl` for each of them.
+pub struct KerlBatchHasher<B: RawEncodingBuf> {
+    /// The trits of the inputs.
+    trit_inputs: Vec<TritBuf<B>>,
+    /// The length of the inputs.
+    input_length: usize,
+    /// The maximum number of inputs in a batch.
+    batch_size: usize,
+    /// A buffer holding the input currently hashed.
+    buf_input: TritBuf,
+    /// The hashes of the inputs, one after the other.
+    buf_hashes: TritBuf,
+    /// The `Kerl` hasher.
+    kerl: Kerl,
+}
+
+impl<B> KerlBatchHasher<B>
+where
+    B: RawEncodingBuf,
+    B::Slice: RawEncoding<Trit = Btrit>,
+{
+    /// Create a new hasher.
+    ///
+    /// It requires the length of the inputs and the maximum number of inputs in a batch. It panics if `input_length`
+    /// is not a multiple of `HASH_LENGTH`.
+    pub fn new(input_length: usize, batch_size: usize) -> Self {
+        assert_eq!(
+            input_length % HASH_LENGTH,
+            0,
+            "Input length is not a multiple of the hash length."
+        );
+
+        Self {
+            trit_inputs: Vec::with_capacity(batch_size),
+            input_length,
+            batch_size,
+            buf_input: TritBuf::zeros(input_length),
+            buf_hashes: TritBuf::zeros(batch_size * HASH_LENGTH),
+            kerl: Kerl::new(),
+        }
+    }
+
+    /// Return the number of inputs that can be processed in a single batch.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Add a new input to the batch.
+    ///
+    /// It panics if the size of the batch exceeds `batch_size()` or if `input.len()` is not equal
+    /// to the `input_length` parameter of the constructor.
+    pub fn add(&mut self, input: TritBuf<B>) {
+        assert!(self.trit_inputs.len() < self.batch_size, "Batch is full.");
+        assert_eq!(input.len(), self.input_length, "Input has an incorrect size.");
+        self.trit_inputs.push(input);
+    }
+
+    /// Return the length of the current batch.
+    pub fn len(&self) -> usize {
+        self.trit_inputs.len()
+    }
+
+    /// Check if the current batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hash the received inputs.
+    ///
+    /// This function also takes care of clearing the batch and resetting the `Kerl` hasher so it
+    /// can be called at any time.
+    pub fn hash_batched(&mut self) -> impl Iterator<Item = TritBuf> + '_ {
+        let total = self.trit_inputs.len();
+
+        for (input, hash) in self.trit_inputs.drain(..).zip(self.buf_hashes.chunks_mut(HASH_LENGTH)) {
+            // Copy the input trit by trit, as it may not use the encoding expected by `Kerl`.
+            for (index, trit) in input.iter().enumerate() {
+                self.buf_input.set(index, trit);
+            }
+
+            // This never fails because the input and hash lengths are multiples of `HASH_LENGTH`.
+            self.kerl.digest_into(&self.buf_input, hash).unwrap();
+        }
+
+        self.buf_hashes
+            .chunks(HASH_LENGTH)
+            .take(total)
+            .map(|hash| hash.to_buf())
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-place conversions between the 243-trit chunks of `Kerl` and the 48-byte words of Keccak-384.
+//!
+//! These are equivalent to the conversions between `T242` and `I384` but work on fixed-size stack buffers of `u32`
+//! limbs, and process 20 trits per limb operation instead of a single one.
+
+use crate::ternary::{bigint::split_integer::SplitInteger, HASH_LENGTH};
+
+/// The length of a Keccak-384 word in bytes.
+pub(super) const BYTE_LENGTH: usize = 48;
+
+/// The number of trits converted; the most significant trit of a chunk is always ignored.
+const TRIT_LENGTH: usize = HASH_LENGTH - 1;
+/// The number of `u32` limbs of a 384-bit integer.
+const LIMB_LENGTH: usize = BYTE_LENGTH / 4;
+/// The number of trits processed at once, `3^20` being the largest power of 3 that fits in a `u32`.
+const CHUNK_LENGTH: usize = 20;
+/// `3^CHUNK_LENGTH`.
+const CHUNK_RADIX: u32 = 3_486_784_401;
+
+type Limbs = [u32; LIMB_LENGTH];
+
+/// `(3^242 - 1) / 2`, the largest value of a balanced `T242`, as little-endian limbs.
+const HALF_MAX_T242: Limbs = [
+    0xa5ce_8964,
+    0x9f00_7669,
+    0x1484_504f,
+    0x3ade_00d9,
+    0x0c24_486e,
+    0x5097_9d57,
+    0x79a4_c702,
+    0x48bb_ae36,
+    0xa9f6_808b,
+    0xaa06_a805,
+    0xa87f_abdf,
+    0x5e69_ebef,
+];
+
+/// `3^242`, the value of a `T243` with only its most significant trit set, as little-endian limbs.
+const ONLY_T243_OCCUPIED: Limbs = [
+    0x4b9d_12c9,
+    0x3e00_ecd3,
+    0x2908_a09f,
+    0x75bc_01b2,
+    0x1848_90dc,
+    0xa12f_3aae,
+    0xf349_8e04,
+    0x9177_5c6c,
+    0x53ed_0116,
+    0x540d_500b,
+    0x50ff_57bf,
+    0xbcd3_d7df,
+];
+
+/// Converts the balanced trits of a chunk into the big-endian two's complement representation of their value, ignoring
+/// the most significant trit.
+pub(super) fn trits_to_bytes(trits: &[i8], bytes: &mut [u8; BYTE_LENGTH]) {
+    debug_assert_eq!(trits.len(), HASH_LENGTH);
+
+    let mut limbs = [0u32; LIMB_LENGTH];
+
+    // Horner's method over the shifted trits, starting with the most significant chunk.
+    for chunk in trits[..TRIT_LENGTH].rchunks(CHUNK_LENGTH) {
+        let mut radix = 1;
+        let mut digits = 0;
+
+        for trit in chunk.iter().rev() {
+            radix *= 3;
+            #[allow(clippy::cast_sign_loss)] // A shifted trit is between `0` and `2`.
+            let digit = (trit + 1) as u32;
+            digits = digits * 3 + digit;
+        }
+
+        mul_add(&mut limbs, radix, digits);
+    }
+
+    // Shift the unbalanced value back to the balanced range.
+    sub(&mut limbs, &HALF_MAX_T242);
+
+    for (limb, bytes) in limbs.iter().rev().zip(bytes.chunks_exact_mut(4)) {
+        bytes.copy_from_slice(&limb.to_be_bytes());
+    }
+}
+
+/// Converts the big-endian two's complement representation of an integer into balanced trits, ignoring its most
+/// significant trit which is always set to zero.
+pub(super) fn bytes_to_trits(bytes: &[u8; BYTE_LENGTH], trits: &mut [i8]) {
+    debug_assert_eq!(trits.len(), HASH_LENGTH);
+
+    let mut limbs = [0u32; LIMB_LENGTH];
+
+    for (limb, bytes) in limbs.iter_mut().rev().zip(bytes.chunks_exact(4)) {
+        // This never fails because the chunks are exactly 4 bytes long.
+        *limb = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+
+    zero_most_significant_trit(&mut limbs);
+
+    // Shift the balanced value to the unbalanced range.
+    add(&mut limbs, &HALF_MAX_T242);
+
+    for chunk in trits[..TRIT_LENGTH].chunks_mut(CHUNK_LENGTH) {
+        let mut digits = div_rem(&mut limbs, CHUNK_RADIX);
+
+        for trit in chunk.iter_mut() {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // A digit is between `0` and `2`.
+            let digit = (digits % 3) as i8;
+            *trit = digit - 1;
+            digits /= 3;
+        }
+    }
+
+    trits[TRIT_LENGTH] = 0;
+}
+
+/// Brings a signed value back to the range of a balanced `T242` by adding or subtracting `3^242`.
+fn zero_most_significant_trit(limbs: &mut Limbs) {
+    let is_negative = limbs[LIMB_LENGTH - 1] >> 31 == 1;
+
+    if is_negative {
+        // Compare the absolute values, as `-x < -HALF_MAX_T242` is equivalent to `x > HALF_MAX_T242`.
+        let mut abs = [0u32; LIMB_LENGTH];
+        sub(&mut abs, limbs);
+
+        if greater_than(&abs, &HALF_MAX_T242) {
+            add(limbs, &ONLY_T243_OCCUPIED);
+        }
+    } else if greater_than(limbs, &HALF_MAX_T242) {
+        sub(limbs, &ONLY_T243_OCCUPIED);
+    }
+}
+
+/// Compares two unsigned values.
+fn greater_than(a: &Limbs, b: &Limbs) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_gt()
+}
+
+/// Computes `limbs * mul + add`, wrapping on overflow.
+fn mul_add(limbs: &mut Limbs, mul: u32, add: u32) {
+    let mut carry = u64::from(add);
+
+    for limb in limbs.iter_mut() {
+        let value = u64::from(*limb) * u64::from(mul) + carry;
+        *limb = value.lo();
+        carry = u64::from(value.hi());
+    }
+}
+
+/// Divides `limbs` by `divisor` in place and returns the remainder.
+fn div_rem(limbs: &mut Limbs, divisor: u32) -> u32 {
+    let divisor = u64::from(divisor);
+    let mut rem = 0;
+
+    for limb in limbs.iter_mut().rev() {
+        let value = (rem << 32) | u64::from(*limb);
+        // The quotient fits in a `u32` because `rem < divisor`.
+        *limb = (value / divisor).lo();
+        rem = value % divisor;
+    }
+
+    rem.lo()
+}
+
+/// Computes `a + b`, wrapping on overflow.
+fn add(a: &mut Limbs, b: &Limbs) {
+    let mut carry = false;
+
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        let (sum, overflown_1) = a.overflowing_add(*b);
+        let (sum, overflown_2) = sum.overflowing_add(u32::from(carry));
+        *a = sum;
+        carry = overflown_1 || overflown_2;
+    }
+}
+
+/// Computes `a - b`, wrapping on overflow.
+fn sub(a: &mut Limbs, b: &Limbs) {
+    let mut borrow = false;
+
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        let (diff, overflown_1) = a.overflowing_sub(*b);
+        let (diff, overflown_2) = diff.overflowing_sub(u32::from(borrow));
+        *a = diff;
+        borrow = overflown_1 || overflown_2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants() {
+        let mut half_max = [0u32; LIMB_LENGTH];
+        let mut only_t243_occupied = [0u32; LIMB_LENGTH];
+
+        mul_add(&mut only_t243_occupied, 1, 1);
+        for _ in 0..TRIT_LENGTH {
+            mul_add(&mut half_max, 3, 1);
+            mul_add(&mut only_t243_occupied, 3, 0);
+        }
+
+        assert_eq!(half_max, HALF_MAX_T242);
+        assert_eq!(only_t243_occupied, ONLY_T243_OCCUPIED);
+    }
+}
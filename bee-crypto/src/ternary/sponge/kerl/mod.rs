@@ -1,32 +1,39 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ternary::{Btrit, Trits, T1B1};
+mod batched;
+mod convert;
+
+use bee_ternary::{Trits, T1B1};
+use lazy_static::lazy_static;
 use tiny_keccak::{Hasher, Keccak};
 
-use crate::ternary::{
-    bigint::{binary_representation::U8Repr, endianness::BigEndian, error::Error as ConversionError, I384, T242, T243},
-    sponge::Sponge,
-    HASH_LENGTH,
-};
+pub use self::batched::KerlBatchHasher;
+use self::convert::BYTE_LENGTH;
+use crate::ternary::{sponge::Sponge, HASH_LENGTH};
+
+lazy_static! {
+    /// The initial Keccak-384 state, cloned to reset a `Kerl` as `Keccak` doesn't expose a reset.
+    static ref KECCAK_384: Keccak = Keccak::v384();
+}
 
 /// State of the ternary cryptographic function `Kerl`.
+///
+/// The conversions between trits and bytes are done in place on fixed-size buffers, so absorbing and squeezing don't
+/// allocate.
 #[derive(Clone)]
 pub struct Kerl {
     /// Actual keccak hash function.
     keccak: Keccak,
     /// Binary working state.
-    binary_state: I384<BigEndian, U8Repr>,
-    /// Ternary working state.
-    ternary_state: T243<Btrit>,
+    binary_state: [u8; BYTE_LENGTH],
 }
 
 impl Default for Kerl {
     fn default() -> Self {
         Self {
-            keccak: Keccak::v384(),
-            binary_state: Default::default(),
-            ternary_state: Default::default(),
+            keccak: KECCAK_384.clone(),
+            binary_state: [0; BYTE_LENGTH],
         }
     }
 }
@@ -41,23 +48,14 @@ impl Kerl {
 #[derive(Debug)]
 pub enum Error {
     NotMultipleOfHashLength,
-    TernaryBinaryConversion(ConversionError),
-}
-
-impl From<ConversionError> for Error {
-    fn from(error: ConversionError) -> Self {
-        Error::TernaryBinaryConversion(error)
-    }
 }
 
 impl Sponge for Kerl {
     type Error = Error;
 
-    /// Reset the internal state by overwriting it with zeros.
+    /// Reset the internal state by overwriting it with the initial state.
     fn reset(&mut self) {
-        // TODO: Overwrite the internal buffer directly rather than setting it to a new Keccak object.
-        // However, `Keccak::v384::reset() is currently not exposed.
-        self.keccak = Keccak::v384();
+        self.keccak.clone_from(&KECCAK_384);
     }
 
     /// Absorb `input` into the sponge by copying `HASH_LENGTH` chunks of it into its internal state and transforming
@@ -72,12 +70,9 @@ impl Sponge for Kerl {
             return Err(Error::NotMultipleOfHashLength);
         }
 
-        for trits_chunk in input.chunks(HASH_LENGTH) {
-            self.ternary_state.copy_from(trits_chunk);
-            // TODO: Convert to `t242` without cloning.
-            self.binary_state = self.ternary_state.clone().into_t242().into();
-
-            self.keccak.update(&self.binary_state[..]);
+        for trits_chunk in input.as_i8_slice().chunks(HASH_LENGTH) {
+            convert::trits_to_bytes(trits_chunk, &mut self.binary_state);
+            self.keccak.update(&self.binary_state);
         }
 
         Ok(())
@@ -92,19 +87,19 @@ impl Sponge for Kerl {
             return Err(Error::NotMultipleOfHashLength);
         }
 
-        for trit_chunk in buf.chunks_mut(HASH_LENGTH) {
-            // Create a new Keccak instead of resetting the internal one.
-            let mut keccak = Keccak::v384();
-
-            // Swap out the internal one and the new one.
-            std::mem::swap(&mut self.keccak, &mut keccak);
+        // This is safe because only valid balanced trits are written to the buffer.
+        let buf = unsafe { buf.as_i8_slice_mut() };
 
-            keccak.finalize(&mut self.binary_state[..]);
-            let ternary_value = T242::from_i384_ignoring_mst(self.binary_state).into_t243();
-
-            trit_chunk.copy_from(&ternary_value);
-            self.binary_state.not_inplace();
-            self.keccak.update(&self.binary_state[..]);
+        for trit_chunk in buf.chunks_mut(HASH_LENGTH) {
+            // Finalizing consumes the Keccak, so a copy is finalized before the sponge is reset.
+            self.keccak.clone().finalize(&mut self.binary_state);
+            self.reset();
+            convert::bytes_to_trits(&self.binary_state, trit_chunk);
+
+            for byte in self.binary_state.iter_mut() {
+                *byte = !*byte;
+            }
+            self.keccak.update(&self.binary_state);
         }
         Ok(())
     }
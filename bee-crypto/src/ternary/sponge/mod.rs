@@ -12,8 +12,10 @@ use std::ops::DerefMut;
 use bee_ternary::{TritBuf, Trits};
 
 pub use self::{
-    curlp::{BatchHasher, CurlP, CurlP27, CurlP81, CurlPRounds, UnrolledCurlP81, BATCH_SIZE},
-    kerl::Kerl,
+    curlp::{
+        BatchBackend, BatchHasher, CurlP, CurlP27, CurlP81, CurlPRounds, UnrolledCurlP81, BATCH_SIZE, MAX_BATCH_SIZE,
+    },
+    kerl::{Kerl, KerlBatchHasher},
     kind::SpongeKind,
 };
 use super::HASH_LENGTH;
@@ -4,7 +4,7 @@
 #![allow(deprecated)]
 
 use bee_crypto::ternary::{
-    sponge::{BatchHasher, CurlPRounds, BATCH_SIZE},
+    sponge::{BatchBackend, BatchHasher, CurlPRounds, BATCH_SIZE},
     HASH_LENGTH,
 };
 use bee_ternary::{
@@ -324,6 +324,38 @@ where
     }
 }
 
+fn batched_curlp_backend(backend: BatchBackend) {
+    let mut batch_hasher = match BatchHasher::<T1B1Buf>::with_backend(backend, 8019, CurlPRounds::Rounds81) {
+        Some(batch_hasher) => batch_hasher,
+        // The CPU doesn't support this backend.
+        None => return,
+    };
+
+    assert_eq!(batch_hasher.backend(), backend);
+    assert_eq!(batch_hasher.batch_size(), backend.batch_size());
+
+    // A full batch followed by a partial one, to check that the hasher is properly reset in between.
+    for batch_size in [batch_hasher.batch_size(), batch_hasher.batch_size() / 2 + 1] {
+        let mut expected_hashes = vec![];
+
+        for (input, output) in DATA_8019.iter().cycle().take(batch_size) {
+            let input_trit_buf = TryteBuf::try_from_str(input).unwrap().as_trits().encode::<T1B1Buf>();
+            let expected_hash = TryteBuf::try_from_str(output).unwrap().as_trits().encode::<T1B1Buf>();
+
+            batch_hasher.add(input_trit_buf);
+            expected_hashes.push(expected_hash);
+        }
+
+        let hashes = batch_hasher.hash_batched().collect::<Vec<_>>();
+
+        assert_eq!(hashes.len(), batch_size);
+
+        for (index, hash) in hashes.iter().enumerate() {
+            assert_eq!(&expected_hashes[index], hash, "{:?}: input {} failed", backend, index);
+        }
+    }
+}
+
 #[test]
 fn batched_curlp_t1b1_input_243() {
     batched_curlp::<T1B1Buf, T1B1>(INPUT_243, OUTPUT_243);
@@ -353,3 +385,34 @@ fn batched_curlp_t1b1_different_input_8019() {
 fn batched_curlp_t5b1_different_input_8019() {
     batched_curlp_different_input::<T5B1Buf, T5B1>();
 }
+
+#[test]
+fn batched_curlp_portable_backend() {
+    batched_curlp_backend(BatchBackend::Portable);
+}
+
+#[test]
+fn batched_curlp_sse2_backend() {
+    batched_curlp_backend(BatchBackend::Sse2);
+}
+
+#[test]
+fn batched_curlp_avx2_backend() {
+    batched_curlp_backend(BatchBackend::Avx2);
+}
+
+#[test]
+fn batched_curlp_avx512_backend() {
+    batched_curlp_backend(BatchBackend::Avx512);
+}
+
+#[test]
+fn batched_curlp_detected_backend() {
+    let backend = BatchBackend::detect();
+
+    assert!(backend.is_supported());
+    assert_eq!(
+        BatchHasher::<T1B1Buf>::new(243, CurlPRounds::Rounds81).backend(),
+        backend
+    );
+}
@@ -0,0 +1,106 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(deprecated)]
+
+use bee_crypto::ternary::{
+    sponge::{Kerl, KerlBatchHasher, Sponge},
+    HASH_LENGTH,
+};
+use bee_ternary::{
+    raw::{RawEncoding, RawEncodingBuf},
+    Btrit, T1B1Buf, T5B1Buf, TritBuf, TryteBuf, T1B1, T5B1,
+};
+
+const DATA_243: [(&str, &str); 5] = [
+    (
+        "HHPELNTNJIOKLYDUW9NDULWPHCWFRPTDIUWLYUHQWWJVPAKKGKOAZFJPQJBLNDPALCVXGJLRBFSHATF9C",
+        "DMJWZTDJTASXZTHZFXFZXWMNFHRTKWFUPCQJXEBJCLRZOM9LPVJSTCLFLTQTDGMLVUHOVJHBBUYFD9AXX",
+    ),
+    (
+        "QAUGQZQKRAW9GKEFIBUD9BMJQOABXBTFELCT9GVSZCPTZOSFBSHPQRWJLLWURPXKNAOWCSVWUBNDSWMPW",
+        "HOVOHFEPCIGTOFEAZVXAHQRFFRTPQEEKANKFKIHUKSGRICVADWDMBINDYKRCCIWBEOPXXIKMLNSOHEAQZ",
+    ),
+    (
+        "FKKKPTUKJOQXVOQSYBZLPWXPSB9IRTKUPM9OWDSLHYDKJUHCCEWAXRJXXZVGMYWSNTVNJCBSJLDFGYQRX",
+        "ADJCUWPLCAGLSTPSOHVQCBCAGJHPWRXFWYNAYLADTHNRECVQDXM9OYGZWBARIEJTBISFKRTMBBXXLXDOW",
+    ),
+    (
+        "AU9AW9RRLZQGMXDYIYADHAGSNLDQEEGSM9IOBFVEKTDYSVXEUJATJJFYAFA9KGL9JHKJOMSHTP9YYZBKT",
+        "FCYDZDQFTFLQ9EBSFACTWTVGKCUBGJZPVDVPISGGJHHMNOBXXFATZFX9GNHUNGLILYQ9JPSBHBWWCNGUX",
+    ),
+    (
+        "J9HER9GGWHKHTLPHYUXWEKAIKMXHPVWXQSBYMMDEFUSAYZDHKUBHQRYWICOSQRASXHEFDECMCHJHJKIVK",
+        "NATACRWZMFAPEKI9GNZCRXZFJXCZHVZQOFHQTXKMNQHEQOYMPVQWVGELKYIZVLNCIMRLDH9QKRUJFJYTX",
+    ),
+];
+
+fn batched_kerl_input_243<B, S>()
+where
+    B: RawEncodingBuf<Slice = S> + Clone,
+    S: RawEncoding<Trit = Btrit, Buf = B> + ?Sized,
+{
+    let mut batch_hasher = KerlBatchHasher::new(HASH_LENGTH, DATA_243.len());
+
+    // Two rounds, to check that the hasher is properly reset in between.
+    for _ in 0..2 {
+        let mut expected_hashes = vec![];
+
+        for (input, output) in DATA_243.iter() {
+            let input_trit_buf = TryteBuf::try_from_str(input).unwrap().as_trits().encode::<B>();
+            let expected_hash = TryteBuf::try_from_str(output).unwrap().as_trits().encode::<T1B1Buf>();
+
+            batch_hasher.add(input_trit_buf);
+            expected_hashes.push(expected_hash);
+        }
+
+        let hashes = batch_hasher.hash_batched().collect::<Vec<_>>();
+
+        assert_eq!(hashes.len(), DATA_243.len());
+        assert!(batch_hasher.is_empty());
+
+        for (index, hash) in hashes.iter().enumerate() {
+            assert_eq!(&expected_hashes[index], hash, "input {} failed", index);
+        }
+    }
+}
+
+#[test]
+fn batched_kerl_t1b1_input_243() {
+    batched_kerl_input_243::<T1B1Buf, T1B1>();
+}
+
+#[test]
+fn batched_kerl_t5b1_input_243() {
+    batched_kerl_input_243::<T5B1Buf, T5B1>();
+}
+
+#[test]
+fn batched_kerl_input_486_partial_batch() {
+    let mut batch_hasher = KerlBatchHasher::<T1B1Buf>::new(2 * HASH_LENGTH, 8);
+    let mut kerl = Kerl::new();
+    let mut expected_hashes = vec![];
+
+    assert_eq!(batch_hasher.batch_size(), 8);
+
+    for window in DATA_243.windows(2) {
+        let mut input = TryteBuf::try_from_str(window[0].0)
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+        input.append(TryteBuf::try_from_str(window[1].0).unwrap().as_trits());
+
+        expected_hashes.push(kerl.digest(&input).unwrap());
+        batch_hasher.add(input);
+    }
+
+    let hashes = batch_hasher.hash_batched().collect::<Vec<TritBuf>>();
+
+    assert_eq!(hashes, expected_hashes);
+}
+
+#[test]
+#[should_panic(expected = "Input length is not a multiple of the hash length.")]
+fn batched_kerl_invalid_input_length() {
+    KerlBatchHasher::<T1B1Buf>::new(HASH_LENGTH + 1, 8);
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_common::packable::{Packable, Read, Write};
-use bee_message::{constants::IOTA_SUPPLY, output::dust_outputs_max};
+use bee_message::{constants::IOTA_SUPPLY, output::dust_outputs_max, payload::transaction::DustBalance};
 
 use crate::types::{error::Error, BalanceDiff};
 
@@ -83,6 +83,12 @@ impl Balance {
     }
 }
 
+impl From<&Balance> for DustBalance {
+    fn from(balance: &Balance) -> Self {
+        DustBalance::new(balance.dust_allowance(), balance.dust_outputs())
+    }
+}
+
 impl Packable for Balance {
     type Error = Error;
 
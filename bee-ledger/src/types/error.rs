@@ -1,7 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_message::{payload::milestone::MilestoneId, Error as MessageError};
+use bee_message::{
+    payload::milestone::{MilestoneId, MilestoneValidationError},
+    Error as MessageError, MessageId,
+};
 
 /// Errors related to ledger types.
 #[derive(Debug, thiserror::Error)]
@@ -72,4 +75,16 @@ pub enum Error {
     /// Milestone length mismatch.
     #[error("Milestone length mismatch: expected {0}, got {1}")]
     MilestoneLengthMismatch(usize, usize),
+    /// Invalid inclusion proof index.
+    #[error("Invalid inclusion proof index: {0} >= leaf count {1}")]
+    InvalidInclusionProofIndex(u32, u32),
+    /// Invalid inclusion proof length.
+    #[error("Invalid inclusion proof length: expected {0}, got {1}")]
+    InvalidInclusionProofLength(usize, usize),
+    /// Invalid milestone.
+    #[error("Invalid milestone: {0:?}")]
+    InvalidMilestone(MilestoneValidationError),
+    /// Inclusion proof mismatch.
+    #[error("Inclusion proof of message {0} does not match the merkle proof of milestone {1}")]
+    InclusionProofMismatch(MessageId, u32),
 }
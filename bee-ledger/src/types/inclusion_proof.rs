@@ -0,0 +1,168 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::{Packable, Read, Write};
+use bee_message::{payload::milestone::MilestonePayload, MessageId};
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+
+use crate::types::error::Error;
+
+/// Leaf domain separation prefix of the white flag Merkle tree.
+pub(crate) const LEAF_HASH_PREFIX: u8 = 0x00;
+/// Node domain separation prefix of the white flag Merkle tree.
+pub(crate) const NODE_HASH_PREFIX: u8 = 0x01;
+
+/// Computes the largest power of two less than or equal to `n`.
+/// Undefined behaviour: 0 is not a valid value for `n`.
+pub(crate) fn largest_power_of_two(n: u32) -> usize {
+    1 << (32 - n.leading_zeros() - 1)
+}
+
+/// Returns the length of the audit path of the leaf at `index` in a tree of `leaf_count` leaves.
+fn audit_path_len(mut index: usize, mut leaf_count: usize) -> usize {
+    let mut len = 0;
+
+    while leaf_count > 1 {
+        let split = largest_power_of_two(leaf_count as u32 - 1);
+
+        if index < split {
+            leaf_count = split;
+        } else {
+            index -= split;
+            leaf_count -= split;
+        }
+        len += 1;
+    }
+
+    len
+}
+
+/// A proof that a message is part of the white flag ordered set of messages included by a milestone.
+/// It holds the audit path of the message in the Merkle tree whose root is the milestone inclusion Merkle proof.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InclusionProof {
+    index: u32,
+    leaf_count: u32,
+    hashes: Vec<[u8; 32]>,
+}
+
+impl InclusionProof {
+    /// Creates a new `InclusionProof` from the position of a message among the included messages, the number of
+    /// included messages and the sibling hashes of the audit path, ordered from the leaf to the root.
+    pub fn new(index: u32, leaf_count: u32, hashes: Vec<[u8; 32]>) -> Result<Self, Error> {
+        if index >= leaf_count {
+            return Err(Error::InvalidInclusionProofIndex(index, leaf_count));
+        }
+
+        let expected_len = audit_path_len(index as usize, leaf_count as usize);
+
+        if hashes.len() != expected_len {
+            return Err(Error::InvalidInclusionProofLength(expected_len, hashes.len()));
+        }
+
+        Ok(Self {
+            index,
+            leaf_count,
+            hashes,
+        })
+    }
+
+    /// Returns the position of the message among the included messages of the `InclusionProof`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the number of messages included by the milestone of the `InclusionProof`.
+    pub fn leaf_count(&self) -> u32 {
+        self.leaf_count
+    }
+
+    /// Returns the audit path hashes of the `InclusionProof`, ordered from the leaf to the root.
+    pub fn hashes(&self) -> &[[u8; 32]] {
+        &self.hashes
+    }
+
+    /// Computes the Merkle root committed to by the `InclusionProof` for a given message.
+    pub fn root(&self, message_id: &MessageId) -> [u8; 32] {
+        fn root_inner(index: usize, leaf_count: usize, message_id: &MessageId, hashes: &[[u8; 32]]) -> [u8; 32] {
+            match hashes.split_last() {
+                Some((sibling, hashes)) => {
+                    let split = largest_power_of_two(leaf_count as u32 - 1);
+                    let mut hasher = Blake2b256::default();
+
+                    hasher.update([NODE_HASH_PREFIX]);
+                    if index < split {
+                        hasher.update(root_inner(index, split, message_id, hashes));
+                        hasher.update(sibling);
+                    } else {
+                        hasher.update(sibling);
+                        hasher.update(root_inner(index - split, leaf_count - split, message_id, hashes));
+                    }
+                    hasher.finalize().into()
+                }
+                None => {
+                    let mut hasher = Blake2b256::default();
+
+                    hasher.update([LEAF_HASH_PREFIX]);
+                    hasher.update(message_id);
+                    hasher.finalize().into()
+                }
+            }
+        }
+
+        root_inner(self.index as usize, self.leaf_count as usize, message_id, &self.hashes)
+    }
+
+    /// Verifies that a message was included by a milestone.
+    /// The milestone is first validated against the applicable hex encoded coordinator public keys and the minimum
+    /// signature threshold, then the root committed to by the `InclusionProof` is checked against its inclusion Merkle
+    /// proof. Neither a ledger nor a tangle is required, which makes it suitable for light clients.
+    pub fn verify(
+        &self,
+        message_id: &MessageId,
+        milestone: &MilestonePayload,
+        applicable_public_keys: &[String],
+        min_threshold: usize,
+    ) -> Result<(), Error> {
+        milestone
+            .validate(applicable_public_keys, min_threshold)
+            .map_err(Error::InvalidMilestone)?;
+
+        if self.root(message_id) != *milestone.essence().merkle_proof() {
+            return Err(Error::InclusionProofMismatch(*message_id, *milestone.essence().index()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Packable for InclusionProof {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        self.index.packed_len() + self.leaf_count.packed_len() + 0u8.packed_len() + self.hashes.len() * 32
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.index.pack(writer)?;
+        self.leaf_count.pack(writer)?;
+        (self.hashes.len() as u8).pack(writer)?;
+        for hash in self.hashes.iter() {
+            hash.pack(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        let index = u32::unpack_inner::<R, CHECK>(reader)?;
+        let leaf_count = u32::unpack_inner::<R, CHECK>(reader)?;
+        let hashes_len = u8::unpack_inner::<R, CHECK>(reader)? as usize;
+        let mut hashes = Vec::with_capacity(hashes_len);
+        for _ in 0..hashes_len {
+            hashes.push(<[u8; 32]>::unpack_inner::<R, CHECK>(reader)?);
+        }
+
+        Self::new(index, leaf_count, hashes)
+    }
+}
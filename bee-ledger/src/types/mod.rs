@@ -10,6 +10,7 @@ mod balance_diff;
 mod consumed_output;
 mod created_output;
 mod error;
+pub(crate) mod inclusion_proof;
 mod ledger_index;
 mod migration;
 mod output_diff;
@@ -24,6 +25,7 @@ pub use self::{
     consumed_output::ConsumedOutput,
     created_output::CreatedOutput,
     error::Error,
+    inclusion_proof::InclusionProof,
     ledger_index::LedgerIndex,
     migration::Migration,
     output_diff::OutputDiff,
@@ -0,0 +1,176 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use bee_message::{milestone::MilestoneIndex, payload::Payload, MessageId};
+use bee_tangle::{ConflictReason, Tangle};
+use crypto::hashes::blake2b::Blake2b256;
+
+use crate::{
+    types::InclusionProof,
+    workers::{consensus::merkle_hasher::MerkleHasher, error::Error, storage::StorageBackend},
+};
+
+/// Maximum number of milestones whose included messages are kept by the `IncludedMessagesCache`.
+const INCLUDED_MESSAGES_CACHE_CAPACITY: usize = 256;
+
+#[derive(Default)]
+struct IncludedMessagesCacheInner {
+    messages: HashMap<MilestoneIndex, Arc<Vec<MessageId>>>,
+    indexes: VecDeque<MilestoneIndex>,
+}
+
+/// A bounded cache of the messages included by milestones, in the order in which White Flag applied them.
+/// It is filled by the consensus worker when it confirms a milestone, so that inclusion proofs of recently confirmed
+/// messages don't require replaying the White Flag traversal. The oldest entries are evicted first.
+#[derive(Default)]
+pub struct IncludedMessagesCache(Mutex<IncludedMessagesCacheInner>);
+
+impl IncludedMessagesCache {
+    /// Returns the messages included by the milestone with the given index, if they are cached.
+    pub fn get(&self, index: MilestoneIndex) -> Option<Arc<Vec<MessageId>>> {
+        // Panic: the lock is never held across a panicking call.
+        self.0.lock().unwrap().messages.get(&index).cloned()
+    }
+
+    /// Caches the messages included by the milestone with the given index.
+    pub fn insert(&self, index: MilestoneIndex, included_messages: Arc<Vec<MessageId>>) {
+        // Panic: the lock is never held across a panicking call.
+        let mut inner = self.0.lock().unwrap();
+
+        if inner.messages.insert(index, included_messages).is_none() {
+            inner.indexes.push_back(index);
+        }
+
+        while inner.indexes.len() > INCLUDED_MESSAGES_CACHE_CAPACITY {
+            if let Some(evicted) = inner.indexes.pop_front() {
+                inner.messages.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// Returns the messages included by a confirmed milestone, in the order in which White Flag applied them.
+/// The traversal of the White Flag confirmation is replayed on the past cone of the milestone, using the referencing
+/// milestone index and the conflict reason recorded in the metadata of each message during confirmation. The result is
+/// checked against the inclusion merkle proof of the milestone.
+pub async fn included_messages<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    index: MilestoneIndex,
+) -> Result<Vec<MessageId>, Error> {
+    let milestone_message_id = tangle
+        .get_milestone_message_id(index)
+        .ok_or(Error::MilestoneNotFound(index))?;
+    let milestone_message = tangle
+        .get(&milestone_message_id)
+        .ok_or(Error::MilestoneMessageNotFound(milestone_message_id))?;
+    let milestone = match milestone_message.payload() {
+        Some(Payload::Milestone(milestone)) => milestone,
+        _ => return Err(Error::NoMilestonePayload),
+    };
+
+    let parents: &[MessageId] = milestone_message.parents();
+    let mut message_ids = parents.iter().rev().copied().collect::<Vec<_>>();
+    let mut visited = HashSet::new();
+    let mut included_messages = Vec::new();
+
+    while let Some(message_id) = message_ids.last() {
+        if let Some((message, metadata)) = tangle.get_message_and_metadata(message_id) {
+            // Messages referenced by a previous milestone were not part of the traversal.
+            if metadata.milestone_index() != Some(index) {
+                visited.insert(*message_id);
+                message_ids.pop();
+                continue;
+            }
+
+            if let Some(unvisited) = message.parents().iter().find(|p| !visited.contains(p)) {
+                message_ids.push(*unvisited);
+            } else {
+                if let (Some(Payload::Transaction(_)), ConflictReason::None) = (message.payload(), metadata.conflict())
+                {
+                    included_messages.push(*message_id);
+                }
+                visited.insert(*message_id);
+                message_ids.pop();
+            }
+        } else if !tangle.is_solid_entry_point(message_id).await {
+            return Err(Error::MissingMessage(*message_id));
+        } else {
+            visited.insert(*message_id);
+            message_ids.pop();
+        }
+    }
+
+    let merkle_proof = MerkleHasher::<Blake2b256>::new().digest(&included_messages);
+
+    if merkle_proof != milestone.essence().merkle_proof() {
+        return Err(Error::MerkleProofMismatch(
+            index,
+            hex::encode(merkle_proof),
+            hex::encode(milestone.essence().merkle_proof()),
+        ));
+    }
+
+    Ok(included_messages)
+}
+
+/// Computes the proof that a message was included by the milestone that confirmed it.
+/// Returns the index of that milestone along with the proof. The included messages of the milestone are taken from the
+/// cache when possible, otherwise the White Flag traversal is replayed and its result cached.
+pub async fn inclusion_proof<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    cache: &IncludedMessagesCache,
+    message_id: &MessageId,
+) -> Result<(MilestoneIndex, InclusionProof), Error> {
+    let index = tangle
+        .get_metadata(message_id)
+        .ok_or(Error::MessageNotFound(*message_id))?
+        .milestone_index()
+        .ok_or(Error::MessageNotIncluded(*message_id))?;
+    let included_messages = match cache.get(index) {
+        Some(included_messages) => included_messages,
+        None => {
+            let included_messages = Arc::new(included_messages(tangle, index).await?);
+            cache.insert(index, included_messages.clone());
+            included_messages
+        }
+    };
+    let position = included_messages
+        .iter()
+        .position(|included| included == message_id)
+        .ok_or(Error::MessageNotIncluded(*message_id))?;
+    let audit_path = MerkleHasher::<Blake2b256>::new()
+        .audit_path(&included_messages, position)
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok((
+        index,
+        InclusionProof::new(position as u32, included_messages.len() as u32, audit_path)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_evicts_oldest_milestones() {
+        let cache = IncludedMessagesCache::default();
+
+        for index in 0..=INCLUDED_MESSAGES_CACHE_CAPACITY as u32 {
+            cache.insert(MilestoneIndex(index), Arc::new(vec![MessageId::new([index as u8; 32])]));
+        }
+
+        assert!(cache.get(MilestoneIndex(0)).is_none());
+        assert_eq!(*cache.get(MilestoneIndex(1)).unwrap(), vec![MessageId::new([1; 32])]);
+        assert!(cache
+            .get(MilestoneIndex(INCLUDED_MESSAGES_CACHE_CAPACITY as u32))
+            .is_some());
+    }
+}
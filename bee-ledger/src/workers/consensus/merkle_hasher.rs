@@ -6,16 +6,7 @@ use std::marker::PhantomData;
 use bee_message::MessageId;
 use crypto::hashes::{Digest, Output};
 
-/// Leaf domain separation prefix.
-const LEAF_HASH_PREFIX: u8 = 0x00;
-/// Node domain separation prefix.
-const NODE_HASH_PREFIX: u8 = 0x01;
-
-/// Computes the largest power of two less than or equal to `n`.
-/// Undefined behaviour: 0 is not a valid value for `n`.
-fn largest_power_of_two(n: u32) -> usize {
-    1 << (32 - n.leading_zeros() - 1)
-}
+use crate::types::inclusion_proof::{largest_power_of_two, LEAF_HASH_PREFIX, NODE_HASH_PREFIX};
 
 /// A Merkle hasher based on a digest function.
 pub(crate) struct MerkleHasher<D> {
@@ -66,6 +57,30 @@ impl<D: Default + Digest> MerkleHasher<D> {
     pub(crate) fn digest(&mut self, message_ids: &[MessageId]) -> Vec<u8> {
         self.digest_inner(message_ids).to_vec()
     }
+
+    /// Pushes the audit path of the hash at `index` to `path`, from the leaf to the root.
+    fn audit_path_inner(&mut self, message_ids: &[MessageId], index: usize, path: &mut Vec<Output<D>>) {
+        if message_ids.len() > 1 {
+            let (left, right) = message_ids.split_at(largest_power_of_two(message_ids.len() as u32 - 1));
+
+            if index < left.len() {
+                self.audit_path_inner(left, index, path);
+                path.push(self.digest_inner(right));
+            } else {
+                self.audit_path_inner(right, index - left.len(), path);
+                path.push(self.digest_inner(left));
+            }
+        }
+    }
+
+    /// Returns the audit path of the hash at `index` in a list of hashes, from the leaf to the root.
+    pub(crate) fn audit_path(&mut self, message_ids: &[MessageId], index: usize) -> Vec<Output<D>> {
+        let mut path = Vec::new();
+
+        self.audit_path_inner(message_ids, index, &mut path);
+
+        path
+    }
 }
 
 #[cfg(test)]
@@ -73,9 +88,15 @@ mod tests {
 
     use std::str::FromStr;
 
-    use crypto::hashes::blake2b::Blake2b256;
+    use bee_message::{
+        milestone::MilestoneIndex,
+        parents::Parents,
+        payload::milestone::{MilestonePayload, MilestonePayloadEssence},
+    };
+    use crypto::{hashes::blake2b::Blake2b256, signatures::ed25519};
 
     use super::*;
+    use crate::types::{Error as TypesError, InclusionProof};
 
     #[test]
     fn tree() {
@@ -99,4 +120,63 @@ mod tests {
             "bf67ce7ba23e8c0951b5abaec4f5524360d2c26d971ff226d3359fa70cdb0beb"
         )
     }
+
+    #[test]
+    fn audit_paths() {
+        let hashes = (1..14u8).map(|i| MessageId::new([i; 32])).collect::<Vec<_>>();
+        let root = MerkleHasher::<Blake2b256>::new().digest(&hashes);
+
+        for (index, message_id) in hashes.iter().enumerate() {
+            let path = MerkleHasher::<Blake2b256>::new()
+                .audit_path(&hashes, index)
+                .into_iter()
+                .map(Into::into)
+                .collect();
+            let proof = InclusionProof::new(index as u32, hashes.len() as u32, path).unwrap();
+
+            assert_eq!(proof.root(message_id).to_vec(), root);
+            assert_ne!(proof.root(&MessageId::null()).to_vec(), root);
+        }
+    }
+
+    #[test]
+    fn verify_against_signed_milestone() {
+        let hashes = (1..6u8).map(|i| MessageId::new([i; 32])).collect::<Vec<_>>();
+        let mut merkle_proof = [0u8; 32];
+        merkle_proof.copy_from_slice(&MerkleHasher::<Blake2b256>::new().digest(&hashes));
+
+        let secret_key = ed25519::SecretKey::from_bytes([42; 32]);
+        let public_key = secret_key.public_key().to_bytes();
+        let essence = MilestonePayloadEssence::new(
+            MilestoneIndex(10),
+            1_644_000_000,
+            Parents::new(vec![MessageId::new([7; 32])]).unwrap(),
+            merkle_proof,
+            0,
+            0,
+            vec![public_key],
+            None,
+        )
+        .unwrap();
+        let signature = secret_key.sign(&essence.hash()).to_bytes();
+        let milestone = MilestonePayload::new(essence, vec![signature]).unwrap();
+        let public_keys = vec![hex::encode(public_key)];
+
+        let path = MerkleHasher::<Blake2b256>::new()
+            .audit_path(&hashes, 3)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let proof = InclusionProof::new(3, hashes.len() as u32, path).unwrap();
+
+        assert!(proof.verify(&hashes[3], &milestone, &public_keys, 1).is_ok());
+        assert!(matches!(
+            proof.verify(&hashes[2], &milestone, &public_keys, 1),
+            Err(TypesError::InclusionProofMismatch(..))
+        ));
+        assert!(matches!(
+            proof.verify(&hashes[3], &milestone, &[hex::encode([0u8; 32])], 1),
+            Err(TypesError::InvalidMilestone(_))
+        ));
+    }
 }
@@ -3,6 +3,7 @@
 
 //! Module containing the worker required to compute and maintain the ledger state.
 
+pub(crate) mod inclusion_proof;
 pub(crate) mod merkle_hasher;
 pub(crate) mod metadata;
 pub(crate) mod state;
@@ -10,6 +11,7 @@ pub(crate) mod white_flag;
 pub(crate) mod worker;
 
 pub use self::{
+    inclusion_proof::{included_messages, inclusion_proof, IncludedMessagesCache},
     metadata::WhiteFlagMetadata,
     white_flag::white_flag,
     worker::{ConsensusWorker, ConsensusWorkerCommand},
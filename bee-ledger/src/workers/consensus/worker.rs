@@ -1,11 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::any::TypeId;
+use std::{any::TypeId, sync::Arc};
 
 use async_trait::async_trait;
 use bee_message::{
-    address::Address,
     milestone::MilestoneIndex,
     output::{Output, OutputId},
     payload::{milestone::MilestoneId, receipt::ReceiptPayload, transaction::TransactionId, Payload},
@@ -13,18 +12,20 @@ use bee_message::{
 };
 use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{ConflictReason, Tangle, TangleWorker};
-use futures::{channel::oneshot, stream::StreamExt};
+use futures::stream::StreamExt;
 use log::{debug, error, info, warn};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::{
-    types::{Balance, CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
+    types::{CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
     workers::{
-        consensus::{metadata::WhiteFlagMetadata, state::validate_ledger_state, white_flag},
+        consensus::{
+            inclusion_proof::IncludedMessagesCache, metadata::WhiteFlagMetadata, state::validate_ledger_state,
+            white_flag,
+        },
         error::Error,
         event::{MessageReferenced, MilestoneConfirmed, OutputConsumed, OutputCreated},
-        pruning::{condition::should_prune, config::PruningConfig, prune},
         snapshot::{condition::should_snapshot, config::SnapshotConfig, worker::SnapshotWorker},
         storage::{self, StorageBackend},
     },
@@ -34,22 +35,9 @@ pub(crate) const EXTRA_SNAPSHOT_DEPTH: u32 = 5;
 pub(crate) const EXTRA_PRUNING_DEPTH: u32 = 5;
 
 /// Commands of the consensus worker.
-#[allow(clippy::type_complexity)]
 pub enum ConsensusWorkerCommand {
     /// Command to confirm a milestone.
     ConfirmMilestone(MessageId),
-    /// Command to fetch the balance of an address.
-    FetchBalance(Address, oneshot::Sender<(Result<Option<Balance>, Error>, LedgerIndex)>),
-    /// Command to fetch an output.
-    FetchOutput(
-        OutputId,
-        oneshot::Sender<(Result<Option<CreatedOutput>, Error>, LedgerIndex)>,
-    ),
-    /// Command to fetch the outputs of an address.
-    FetchOutputs(
-        Address,
-        oneshot::Sender<(Result<Option<Vec<OutputId>>, Error>, LedgerIndex)>,
-    ),
 }
 
 /// The consensus worker.
@@ -90,6 +78,7 @@ async fn confirm<N: Node>(
     tangle: &Tangle<N::Backend>,
     storage: &N::Backend,
     bus: &Bus<'static>,
+    included_messages_cache: &IncludedMessagesCache,
     message_id: MessageId,
     ledger_index: &mut LedgerIndex,
     receipt_migrated_at: &mut MilestoneIndex,
@@ -222,6 +211,11 @@ where
         milestone.essence().receipt().is_some()
     );
 
+    included_messages_cache.insert(
+        milestone.essence().index(),
+        Arc::new(metadata.included_messages.clone()),
+    );
+
     bus.dispatch(MilestoneConfirmed {
         message_id,
         index: milestone.essence().index(),
@@ -259,20 +253,22 @@ impl<N: Node> Worker<N> for ConsensusWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (SnapshotConfig, PruningConfig);
+    type Config = SnapshotConfig;
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
         vec![TypeId::of::<TangleWorker>(), TypeId::of::<SnapshotWorker>()].leak()
     }
 
-    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (snapshot_config, pruning_config) = config;
+    async fn start(node: &mut N, snapshot_config: Self::Config) -> Result<Self, Self::Error> {
         let (tx, rx) = mpsc::unbounded_channel();
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
         let bus = node.bus();
 
+        node.register_resource(IncludedMessagesCache::default());
+        let included_messages_cache = node.resource::<IncludedMessagesCache>();
+
         validate_ledger_state(&*storage)?;
 
         let bmd = tangle.config().below_max_depth();
@@ -289,19 +285,6 @@ where
             snapshot_config.depth()
         };
 
-        let snapshot_pruning_delta = bmd + EXTRA_PRUNING_DEPTH;
-        let pruning_delay_min = snapshot_depth + snapshot_pruning_delta;
-        let pruning_delay = if pruning_config.delay() < pruning_delay_min {
-            warn!(
-                "Configuration value for \"pruning.delay\" is too low ({}), value changed to {}.",
-                pruning_config.delay(),
-                pruning_delay_min
-            );
-            pruning_delay_min
-        } else {
-            pruning_config.delay()
-        };
-
         // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
         let mut ledger_index = storage::fetch_ledger_index(&*storage)?.unwrap();
         let mut receipt_migrated_at = MilestoneIndex(0);
@@ -318,6 +301,7 @@ where
                             &tangle,
                             &storage,
                             &bus,
+                            &included_messages_cache,
                             message_id,
                             &mut ledger_index,
                             &mut receipt_migrated_at,
@@ -343,41 +327,7 @@ where
                                 debug!("Snapshotting skipped: {:?}", reason);
                             }
                         }
-
-                        match should_prune(&tangle, ledger_index, pruning_delay, &pruning_config) {
-                            Ok((start_index, target_index)) => {
-                                if let Err(e) =
-                                    prune::prune(&tangle, &storage, &bus, start_index, target_index, &pruning_config)
-                                        .await
-                                {
-                                    error!("Pruning failed: {:?}.", e);
-                                }
-                            }
-                            Err(reason) => {
-                                debug!("Pruning skipped: {:?}", reason);
-                            }
-                        }
-                    }
-                    ConsensusWorkerCommand::FetchBalance(address, sender) => {
-                        if let Err(e) = sender.send((storage::fetch_balance(&*storage, &address), ledger_index)) {
-                            error!("Error while sending balance: {:?}", e);
-                        }
-                    }
-                    ConsensusWorkerCommand::FetchOutput(output_id, sender) => {
-                        if let Err(e) = sender.send((storage::fetch_output(&*storage, &output_id), ledger_index)) {
-                            error!("Error while sending output: {:?}", e);
-                        }
                     }
-                    ConsensusWorkerCommand::FetchOutputs(address, sender) => match address {
-                        Address::Ed25519(address) => {
-                            if let Err(e) = sender.send((
-                                storage::fetch_outputs_for_ed25519_address(&*storage, &address),
-                                ledger_index,
-                            )) {
-                                error!("Error while sending output: {:?}", e);
-                            }
-                        }
-                    },
                 }
             }
 
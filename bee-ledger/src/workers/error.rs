@@ -37,6 +37,9 @@ pub enum Error {
     /// Milestone message not found.
     #[error("Milestone message not found: {0}")]
     MilestoneMessageNotFound(MessageId),
+    /// Milestone not found.
+    #[error("Milestone not found: {0}")]
+    MilestoneNotFound(MilestoneIndex),
     /// Message payload is not a milestone
     #[error("Message payload is not a milestone")]
     NoMilestonePayload,
@@ -76,6 +79,15 @@ pub enum Error {
     /// Missing unspent output.
     #[error("Missing unspent output {0}")]
     MissingUnspentOutput(Unspent),
+    /// Missing ledger index.
+    #[error("Missing ledger index")]
+    MissingLedgerIndex,
+    /// Message not found.
+    #[error("Message not found: {0}")]
+    MessageNotFound(MessageId),
+    /// Message not included by a milestone.
+    #[error("Message {0} is not included by a milestone")]
+    MessageNotIncluded(MessageId),
     /// Storage backend error.
     #[error("Storage backend error: {0}")]
     Storage(Box<dyn std::error::Error + Send>),
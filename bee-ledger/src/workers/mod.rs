@@ -15,7 +15,7 @@ use bee_runtime::node::{Node, NodeBuilder};
 pub use self::storage::StorageBackend;
 use self::{
     consensus::ConsensusWorker,
-    pruning::config::PruningConfig,
+    pruning::{config::PruningConfig, worker::PruningWorker},
     snapshot::{config::SnapshotConfig, worker::SnapshotWorker},
 };
 
@@ -32,5 +32,6 @@ where
 {
     node_builder
         .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone()))
-        .with_worker_cfg::<ConsensusWorker>((snapshot_config, pruning_config))
+        .with_worker_cfg::<ConsensusWorker>(snapshot_config.clone())
+        .with_worker_cfg::<PruningWorker>((snapshot_config, pruning_config))
 }
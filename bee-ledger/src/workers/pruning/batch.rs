@@ -4,19 +4,23 @@
 use std::collections::VecDeque;
 
 use bee_message::{
+    address::{Address, Ed25519Address},
     milestone::{Milestone, MilestoneIndex},
-    output::OutputId,
+    output::{Output, OutputId},
     payload::{
         indexation::{IndexationPayload, PaddedIndex},
         transaction::Essence,
         Payload,
     },
+    signature::SignatureUnlock,
+    unlock::UnlockBlock,
     Message, MessageId,
 };
 use bee_storage::access::{Batch, Fetch};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage, Tangle,
 };
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 use hashbrown::{HashMap, HashSet};
 use ref_cast::RefCast;
 
@@ -25,6 +29,7 @@ use crate::{
     workers::{
         consensus::worker::EXTRA_PRUNING_DEPTH,
         pruning::{
+            config::PruningRetentionConfig,
             error::Error,
             metrics::{ConfirmedDataPruningMetrics, MilestoneDataPruningMetrics, UnconfirmedDataPruningMetrics},
         },
@@ -34,6 +39,7 @@ use crate::{
 
 pub type Messages = HashSet<MessageId>;
 pub type ApproverCache = HashMap<MessageId, MilestoneIndex>;
+pub type RetentionCache = HashMap<MessageId, bool>;
 pub type Seps = HashMap<SolidEntryPoint, MilestoneIndex>;
 
 #[derive(Eq, PartialEq, Hash)]
@@ -48,11 +54,14 @@ pub fn prune_confirmed_data<S: StorageBackend>(
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
     current_seps: &Seps,
+    retention: &PruningRetentionConfig,
 ) -> Result<(Seps, ConfirmedDataPruningMetrics), Error> {
     // We keep a list of already visited messages.
     let mut visited = Messages::with_capacity(512);
     // We keep a cache of approvers to prevent fetch the same data from the storage more than once.
     let mut approver_cache = ApproverCache::with_capacity(512);
+    // We keep a cache of whether messages are retained to prevent fetching parents shared by siblings more than once.
+    let mut retention_cache = RetentionCache::with_capacity(512);
     // We collect new SEPs during the traversal, and return them as a result of this function.
     let mut new_seps = Seps::with_capacity(512);
     // We collect stats during the traversal, and return them as a result of this function.
@@ -104,20 +113,28 @@ pub fn prune_confirmed_data<S: StorageBackend>(
             }
         };
 
+        // Keep the message, and its `Indexation` payload, if the retention filter matches it.
+        let retained = is_retained(&msg, retention);
+        retention_cache.insert(message_id, retained);
+
         // Delete its `Indexation` payload (if existent).
         let payload = msg.payload().as_ref();
-        if let Some(indexation) = unwrap_indexation(payload) {
+        if let Some(indexation) = unwrap_indexation(payload).filter(|_| !retained) {
             let padded_index = indexation.padded_index();
 
             prune_indexation_data(storage, batch, &(padded_index, message_id))?;
             metrics.prunable_indexations += 1;
         }
 
-        // Delete its edges.
-        let parents = msg.parents();
-        for parent_id in parents.iter() {
-            prune_edge(storage, batch, &(*parent_id, message_id))?;
-            metrics.prunable_edges += 1;
+        // Delete its edges, unless they connect it to a retained message, so that retained messages keep their
+        // approvers.
+        if !retained {
+            for parent_id in msg.parents().iter() {
+                if !is_retained_id(storage, &mut retention_cache, parent_id, retention)? {
+                    prune_edge(storage, batch, &(*parent_id, message_id))?;
+                    metrics.prunable_edges += 1;
+                }
+            }
         }
 
         // Add its parents to the queue of yet to traverse messages.
@@ -127,7 +144,11 @@ pub fn prune_confirmed_data<S: StorageBackend>(
         visited.insert(message_id);
 
         // Delete its associated data.
-        prune_message_and_metadata(storage, batch, &message_id)?;
+        if retained {
+            metrics.retained_messages += 1;
+        } else {
+            prune_message_and_metadata(storage, batch, &message_id)?;
+        }
 
         // ---
         // Everything that follows is required to decide whether this message's id should be kept as a solid entry
@@ -215,7 +236,7 @@ pub fn prune_confirmed_data<S: StorageBackend>(
         }
     }
 
-    metrics.prunable_messages = visited.len();
+    metrics.prunable_messages = visited.len() - metrics.retained_messages;
     metrics.new_seps = new_seps.len();
 
     Ok((new_seps, metrics))
@@ -225,8 +246,11 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
     storage: &S,
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
+    retention: &PruningRetentionConfig,
 ) -> Result<UnconfirmedDataPruningMetrics, Error> {
     let mut metrics = UnconfirmedDataPruningMetrics::default();
+    // We keep a cache of whether messages are retained to prevent fetching parents shared by siblings more than once.
+    let mut retention_cache = RetentionCache::new();
 
     let unconf_msgs = match Fetch::<MilestoneIndex, Vec<UnreferencedMessage>>::fetch(storage, &prune_index)
         .map_err(|e| Error::Storage(Box::new(e)))?
@@ -301,13 +325,21 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
             Some(msg) => {
                 let payload = msg.payload().as_ref();
                 let parents = msg.parents();
+                let retained = is_retained(&msg, retention);
+                retention_cache.insert(*unconf_msg_id, retained);
 
                 // Add message data to the delete batch.
-                prune_message_and_metadata(storage, batch, unconf_msg_id)?;
+                if retained {
+                    metrics.retained_messages += 1;
+                } else {
+                    prune_message_and_metadata(storage, batch, unconf_msg_id)?;
 
-                log::trace!("Pruned unconfirmed msg {} at {}.", unconf_msg_id, prune_index);
+                    log::trace!("Pruned unconfirmed msg {} at {}.", unconf_msg_id, prune_index);
+
+                    metrics.prunable_messages += 1;
+                }
 
-                if let Some(indexation) = unwrap_indexation(payload) {
+                if let Some(indexation) = unwrap_indexation(payload).filter(|_| !retained) {
                     let padded_index = indexation.padded_index();
                     let message_id = *unconf_msg_id;
 
@@ -317,11 +349,15 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
                     metrics.prunable_indexations += 1;
                 }
 
-                // Add prunable edges to the delete batch.
-                for parent in parents.iter() {
-                    prune_edge(storage, batch, &(*parent, *unconf_msg_id))?;
+                // Add prunable edges to the delete batch, but keep the approvers of retained messages.
+                if !retained {
+                    for parent in parents.iter() {
+                        if !is_retained_id(storage, &mut retention_cache, parent, retention)? {
+                            prune_edge(storage, batch, &(*parent, *unconf_msg_id))?;
 
-                    metrics.prunable_edges += 1;
+                            metrics.prunable_edges += 1;
+                        }
+                    }
                 }
             }
             None => {
@@ -336,8 +372,6 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
             &(prune_index, (*unconf_msg_id).into()),
         )
         .map_err(|e| Error::Storage(Box::new(e)))?;
-
-        metrics.prunable_messages += 1;
     }
 
     Ok(metrics)
@@ -348,12 +382,15 @@ pub fn prune_milestone_data<S: StorageBackend>(
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
     should_prune_receipts: bool,
+    retention: &PruningRetentionConfig,
 ) -> Result<MilestoneDataPruningMetrics, Error> {
     let mut metrics = MilestoneDataPruningMetrics::default();
 
-    prune_milestone(storage, batch, prune_index)?;
+    if !retention.retains_milestones() {
+        prune_milestone(storage, batch, prune_index)?;
+    }
 
-    prune_output_diff(storage, batch, prune_index)?;
+    metrics.retained_outputs = prune_output_diff(storage, batch, prune_index, retention)?;
 
     if should_prune_receipts {
         metrics.receipts = prune_receipts(storage, batch, prune_index)?;
@@ -402,11 +439,31 @@ fn prune_milestone<S: StorageBackend>(storage: &S, batch: &mut S::Batch, index:
     Ok(())
 }
 
-fn prune_output_diff<S: StorageBackend>(storage: &S, batch: &mut S::Batch, index: MilestoneIndex) -> Result<(), Error> {
+fn prune_output_diff<S: StorageBackend>(
+    storage: &S,
+    batch: &mut S::Batch,
+    index: MilestoneIndex,
+    retention: &PruningRetentionConfig,
+) -> Result<usize, Error> {
+    let mut retained = 0;
+
     if let Some(output_diff) =
         Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index).map_err(|e| Error::Storage(Box::new(e)))?
     {
         for consumed_output in output_diff.consumed_outputs() {
+            if retention.retains_addresses() {
+                let created_output = Fetch::<OutputId, CreatedOutput>::fetch(storage, consumed_output)
+                    .map_err(|e| Error::Storage(Box::new(e)))?;
+
+                if created_output
+                    .and_then(|created_output| output_address(created_output.inner()).copied())
+                    .map_or(false, |address| retention.retains_address(&address))
+                {
+                    retained += 1;
+                    continue;
+                }
+            }
+
             Batch::<OutputId, ConsumedOutput>::batch_delete(storage, batch, consumed_output)
                 .map_err(|e| Error::Storage(Box::new(e)))?;
             Batch::<OutputId, CreatedOutput>::batch_delete(storage, batch, consumed_output)
@@ -421,7 +478,7 @@ fn prune_output_diff<S: StorageBackend>(storage: &S, batch: &mut S::Batch, index
     Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, batch, &index)
         .map_err(|e| Error::Storage(Box::new(e)))?;
 
-    Ok(())
+    Ok(retained)
 }
 
 fn prune_receipts<S: StorageBackend>(storage: &S, batch: &mut S::Batch, index: MilestoneIndex) -> Result<usize, Error> {
@@ -441,6 +498,87 @@ fn prune_receipts<S: StorageBackend>(storage: &S, batch: &mut S::Batch, index: M
     Ok(num)
 }
 
+/// Returns whether the message with the given id is still stored and matches the retention filter, only fetching it
+/// from the storage if it isn't cached yet.
+fn is_retained_id<S: StorageBackend>(
+    storage: &S,
+    retention_cache: &mut RetentionCache,
+    message_id: &MessageId,
+    retention: &PruningRetentionConfig,
+) -> Result<bool, Error> {
+    // Avoid fetching the message when nothing is retained anyway.
+    if retention.is_empty() {
+        return Ok(false);
+    }
+
+    if let Some(retained) = retention_cache.get(message_id) {
+        return Ok(*retained);
+    }
+
+    let retained = Fetch::<MessageId, Message>::fetch(storage, message_id)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .map_or(false, |message| is_retained(&message, retention));
+
+    retention_cache.insert(*message_id, retained);
+
+    Ok(retained)
+}
+
+/// Returns whether a message matches the retention filter and should be kept.
+fn is_retained(message: &Message, retention: &PruningRetentionConfig) -> bool {
+    match message.payload() {
+        Some(Payload::Milestone(_)) => retention.retains_milestones(),
+        Some(Payload::Indexation(indexation)) => retention.retains_indexation(&indexation.padded_index()),
+        Some(Payload::Transaction(transaction)) => {
+            #[allow(irrefutable_let_patterns)]
+            let essence = if let Essence::Regular(essence) = transaction.essence() {
+                essence
+            } else {
+                return false;
+            };
+
+            if let Some(Payload::Indexation(indexation)) = essence.payload() {
+                if retention.retains_indexation(&indexation.padded_index()) {
+                    return true;
+                }
+            }
+
+            if !retention.retains_addresses() {
+                return false;
+            }
+
+            // The transaction touches an address either by sending to it or by spending from it.
+            essence
+                .outputs()
+                .iter()
+                .filter_map(output_address)
+                .any(|address| retention.retains_address(address))
+                || transaction
+                    .unlock_blocks()
+                    .iter()
+                    .any(|unlock_block| match unlock_block {
+                        UnlockBlock::Signature(SignatureUnlock::Ed25519(signature)) => {
+                            let address = Address::Ed25519(Ed25519Address::new(
+                                Blake2b256::digest(signature.public_key()).into(),
+                            ));
+
+                            retention.retains_address(&address)
+                        }
+                        UnlockBlock::Reference(_) => false,
+                    })
+        }
+        _ => false,
+    }
+}
+
+fn output_address(output: &Output) -> Option<&Address> {
+    match output {
+        Output::SignatureLockedSingle(output) => Some(output.address()),
+        Output::SignatureLockedDustAllowance(output) => Some(output.address()),
+        Output::Treasury(_) => None,
+    }
+}
+
 fn unwrap_indexation(payload: Option<&Payload>) -> Option<&IndexationPayload> {
     match payload {
         Some(Payload::Indexation(indexation)) => Some(indexation),
@@ -461,9 +599,11 @@ fn unwrap_indexation(payload: Option<&Payload>) -> Option<&IndexationPayload> {
     }
 }
 
-// TODO: consider using this instead of 'truncate'
-#[allow(dead_code)]
-fn prune_seps<S: StorageBackend>(storage: &S, batch: &mut S::Batch, seps: &[SolidEntryPoint]) -> Result<usize, Error> {
+pub(crate) fn prune_seps<S: StorageBackend>(
+    storage: &S,
+    batch: &mut S::Batch,
+    seps: &[SolidEntryPoint],
+) -> Result<usize, Error> {
     let mut num = 0;
     for sep in seps {
         Batch::<SolidEntryPoint, MilestoneIndex>::batch_delete(storage, batch, sep)
@@ -474,3 +614,36 @@ fn prune_seps<S: StorageBackend>(storage: &S, batch: &mut S::Batch, seps: &[Soli
 
     Ok(num)
 }
+
+#[cfg(test)]
+mod tests {
+
+    use bee_message::{parents::Parents, MessageBuilder};
+
+    use super::*;
+
+    fn indexation_message(index: &[u8]) -> Message {
+        MessageBuilder::new()
+            .with_network_id(0)
+            .with_parents(Parents::new(vec![MessageId::null()]).unwrap())
+            .with_payload(IndexationPayload::new(index, &[]).unwrap().into())
+            .with_nonce_provider(0, 0f64)
+            .finish()
+            .unwrap()
+    }
+
+    #[test]
+    fn retained_indexation() {
+        let retention = PruningRetentionConfig::build()
+            .indexations(vec![hex::encode("retained")])
+            .finish()
+            .unwrap();
+
+        assert!(is_retained(&indexation_message(b"retained"), &retention));
+        assert!(!is_retained(&indexation_message(b"pruned"), &retention));
+        assert!(!is_retained(
+            &indexation_message(b"retained"),
+            &PruningRetentionConfig::default()
+        ));
+    }
+}
@@ -1,10 +1,16 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use bee_message::milestone::MilestoneIndex;
 use bee_tangle::{storage::StorageBackend, Tangle};
+use log::{debug, warn};
 
-use crate::{types::LedgerIndex, workers::pruning::config::PruningConfig};
+use crate::{
+    types::LedgerIndex,
+    workers::pruning::config::{PruningConfig, PruningSizeConfig},
+};
 
 const PRUNING_BATCH_SIZE_MAX: u32 = 200;
 
@@ -17,10 +23,19 @@ pub enum PruningSkipReason {
     BelowThreshold { reached_in: u32 },
 }
 
+/// Decides whether pruning should happen and returns the range of milestones to prune.
+///
+/// Data older than `pruning_delay` milestones is always pruned. If the database exceeds its target size, or if
+/// milestones are older than the maximum age, data is pruned further, but never closer than `pruning_delay_min`
+/// milestones to the ledger index. Once the target size has been exceeded, `size_exceeded` stays set until the database
+/// shrinks below the low watermark.
 pub(crate) fn should_prune<B: StorageBackend>(
     tangle: &Tangle<B>,
+    storage: &B,
     ledger_index: LedgerIndex,
     pruning_delay: u32,
+    pruning_delay_min: u32,
+    size_exceeded: &mut bool,
     config: &PruningConfig,
 ) -> Result<(MilestoneIndex, MilestoneIndex), PruningSkipReason> {
     if config.disabled() {
@@ -30,20 +45,168 @@ pub(crate) fn should_prune<B: StorageBackend>(
     let pruning_index = *tangle.get_pruning_index() + 1;
     let pruning_threshold = pruning_index + pruning_delay;
 
-    if *ledger_index < pruning_threshold {
-        Err(PruningSkipReason::BelowThreshold {
-            reached_in: pruning_threshold - *ledger_index,
-        })
+    let mut target_pruning_index = if *ledger_index < pruning_threshold {
+        None
     } else {
-        let target_pruning_index = *ledger_index - pruning_delay;
+        Some(*ledger_index - pruning_delay)
+    };
+
+    let max_target_pruning_index = ledger_index
+        .checked_sub(pruning_delay_min)
+        .filter(|index| *index >= pruning_index);
+
+    if let Some(max_target_pruning_index) = max_target_pruning_index {
+        if let Some(size_config) = config.size() {
+            if let Some(size) = storage_size(storage) {
+                update_size_exceeded(size, size_config, size_exceeded);
+            }
+
+            if *size_exceeded {
+                target_pruning_index = Some(max_target_pruning_index);
+            }
+        }
 
-        Ok((
+        if let Some(max_age) = config.max_age() {
+            let expired_index = expiry_cutoff(max_age).and_then(|cutoff| {
+                last_expired_index(pruning_index, max_target_pruning_index, cutoff, |index| {
+                    tangle.get_milestone(index).map(|milestone| milestone.timestamp())
+                })
+            });
+
+            if let Some(expired_index) = expired_index {
+                target_pruning_index = target_pruning_index.max(Some(expired_index));
+            }
+        }
+    }
+
+    match target_pruning_index {
+        None => Err(PruningSkipReason::BelowThreshold {
+            reached_in: pruning_threshold - *ledger_index,
+        }),
+        Some(target_pruning_index) => Ok((
             pruning_index.into(),
             if target_pruning_index > pruning_index + PRUNING_BATCH_SIZE_MAX {
                 (pruning_index + PRUNING_BATCH_SIZE_MAX).into()
             } else {
                 target_pruning_index.into()
             },
-        ))
+        )),
+    }
+}
+
+fn storage_size<B: StorageBackend>(storage: &B) -> Option<usize> {
+    match storage.size() {
+        Ok(Some(size)) => Some(size),
+        Ok(None) => {
+            warn!("Size-based pruning is enabled but the storage backend does not report its size.");
+            None
+        }
+        Err(e) => {
+            warn!("Failed to get the storage size: {:?}.", e);
+            None
+        }
+    }
+}
+
+/// Sets `size_exceeded` once the storage reaches the target size, and unsets it once it shrinks to the low watermark.
+fn update_size_exceeded(size: usize, config: &PruningSizeConfig, size_exceeded: &mut bool) {
+    if size >= config.target_size() {
+        if !*size_exceeded {
+            debug!(
+                "Storage size {} exceeds the target size {}, pruning until {}.",
+                size,
+                config.target_size(),
+                config.low_watermark()
+            );
+        }
+        *size_exceeded = true;
+    } else if size <= config.low_watermark() {
+        *size_exceeded = false;
+    }
+}
+
+/// Returns the timestamp at or before which milestones are older than `max_age`.
+fn expiry_cutoff(max_age: Duration) -> Option<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+
+    Some(now.checked_sub(max_age)?.as_secs())
+}
+
+/// Returns the highest milestone index in `start..=end` whose timestamp is at or before `cutoff`.
+fn last_expired_index(
+    start: u32,
+    end: u32,
+    cutoff: u64,
+    timestamp: impl Fn(MilestoneIndex) -> Option<u64>,
+) -> Option<u32> {
+    // Milestone timestamps are monotonic, and at most a batch is pruned at once anyway.
+    (start..=end.min(start + PRUNING_BATCH_SIZE_MAX))
+        .take_while(|index| timestamp(MilestoneIndex(*index)).map_or(false, |timestamp| timestamp <= cutoff))
+        .last()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::workers::pruning::config::PruningConfigBuilder;
+
+    #[test]
+    fn size_exceeded_hysteresis() {
+        let config = PruningConfigBuilder::new()
+            .target_size(1000)
+            .low_watermark(800)
+            .finish()
+            .unwrap();
+        let size_config = config.size().unwrap();
+        let mut size_exceeded = false;
+
+        update_size_exceeded(999, size_config, &mut size_exceeded);
+        assert!(!size_exceeded);
+
+        update_size_exceeded(1000, size_config, &mut size_exceeded);
+        assert!(size_exceeded);
+
+        // Pruning continues between the low watermark and the target size.
+        update_size_exceeded(801, size_config, &mut size_exceeded);
+        assert!(size_exceeded);
+
+        update_size_exceeded(800, size_config, &mut size_exceeded);
+        assert!(!size_exceeded);
+
+        // And doesn't restart before the target size is reached again.
+        update_size_exceeded(900, size_config, &mut size_exceeded);
+        assert!(!size_exceeded);
+    }
+
+    #[test]
+    fn default_low_watermark() {
+        let config = PruningConfigBuilder::new().target_size(1000).finish().unwrap();
+
+        assert_eq!(config.size().unwrap().low_watermark(), 900);
+    }
+
+    #[test]
+    fn expired_milestones() {
+        // Milestone `i` has timestamp `10 * i`.
+        let timestamp = |index: MilestoneIndex| Some(10 * *index as u64);
+
+        assert_eq!(last_expired_index(1, 10, 5, timestamp), None);
+        assert_eq!(last_expired_index(1, 10, 10, timestamp), Some(1));
+        assert_eq!(last_expired_index(1, 10, 55, timestamp), Some(5));
+        // Never beyond the maximum target pruning index.
+        assert_eq!(last_expired_index(1, 10, 1000, timestamp), Some(10));
+        // Never beyond a batch.
+        assert_eq!(
+            last_expired_index(1, 1000, u64::MAX, timestamp),
+            Some(1 + PRUNING_BATCH_SIZE_MAX)
+        );
+    }
+
+    #[test]
+    fn expired_milestones_stop_at_missing_milestone() {
+        let timestamp = |index: MilestoneIndex| (*index != 4).then(|| 10 * *index as u64);
+
+        assert_eq!(last_expired_index(1, 10, 1000, timestamp), Some(3));
     }
 }
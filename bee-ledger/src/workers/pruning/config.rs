@@ -3,11 +3,34 @@
 
 //! Module containing pruning configuration.
 
+use std::{collections::HashSet, time::Duration};
+
+use bee_message::{
+    address::Address,
+    payload::indexation::{IndexationPayload, PaddedIndex},
+    Error as MessageError,
+};
 use serde::Deserialize;
 
 const DEFAULT_ENABLED: bool = true;
 const DEFAULT_DELAY: u32 = 60480;
 const DEFAULT_PRUNE_RECEIPTS: bool = false;
+const DEFAULT_TARGET_SIZE: Option<usize> = None;
+const DEFAULT_LOW_WATERMARK_PERCENTAGE: usize = 90;
+const DEFAULT_MAX_AGE_SECS: Option<u64> = None;
+const DEFAULT_STEP_INTERVAL_MS: u64 = 100;
+const DEFAULT_RETAIN_MILESTONES: bool = false;
+
+/// Errors occurring when building a [`PruningConfig`].
+#[derive(Debug, thiserror::Error)]
+pub enum PruningConfigError {
+    /// A retained indexation key is not hex encoded or has an invalid length.
+    #[error("invalid retained indexation key \"{0}\"")]
+    InvalidRetainedIndexation(String),
+    /// A retained address is not a valid Bech32 address.
+    #[error("invalid retained address \"{0}\": {1}")]
+    InvalidRetainedAddress(String, MessageError),
+}
 
 /// Builder for a [`PruningConfig`].
 #[derive(Default, Deserialize, PartialEq)]
@@ -17,6 +40,15 @@ pub struct PruningConfigBuilder {
     delay: Option<u32>,
     #[serde(alias = "pruneReceipts")]
     prune_receipts: Option<bool>,
+    #[serde(alias = "targetSize")]
+    target_size: Option<usize>,
+    #[serde(alias = "lowWatermark")]
+    low_watermark: Option<usize>,
+    #[serde(alias = "maxAgeSecs")]
+    max_age_secs: Option<u64>,
+    #[serde(alias = "stepIntervalMs")]
+    step_interval_ms: Option<u64>,
+    retention: Option<PruningRetentionConfigBuilder>,
 }
 
 impl PruningConfigBuilder {
@@ -43,14 +75,183 @@ impl PruningConfigBuilder {
         self
     }
 
+    /// Sets the database size, in bytes, above which pruning is triggered regardless of the pruning delay.
+    pub fn target_size(mut self, target_size: usize) -> Self {
+        self.target_size.replace(target_size);
+        self
+    }
+
+    /// Sets the database size, in bytes, below which pruning triggered by the target size stops.
+    /// Defaults to 90% of the target size.
+    pub fn low_watermark(mut self, low_watermark: usize) -> Self {
+        self.low_watermark.replace(low_watermark);
+        self
+    }
+
+    /// Sets the age, in seconds, after which milestones are pruned regardless of the pruning delay.
+    pub fn max_age_secs(mut self, max_age_secs: u64) -> Self {
+        self.max_age_secs.replace(max_age_secs);
+        self
+    }
+
+    /// Sets the minimum interval, in milliseconds, between the pruning of two consecutive milestones.
+    pub fn step_interval_ms(mut self, step_interval_ms: u64) -> Self {
+        self.step_interval_ms.replace(step_interval_ms);
+        self
+    }
+
+    /// Sets the data that is kept when pruning.
+    pub fn retention(mut self, retention: PruningRetentionConfigBuilder) -> Self {
+        self.retention.replace(retention);
+        self
+    }
+
     /// Finishes the builder into a [`PruningConfig`].
-    #[must_use]
-    pub fn finish(self) -> PruningConfig {
-        PruningConfig {
+    pub fn finish(self) -> Result<PruningConfig, PruningConfigError> {
+        let target_size = self.target_size.or(DEFAULT_TARGET_SIZE);
+
+        Ok(PruningConfig {
             enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
             delay: self.delay.unwrap_or(DEFAULT_DELAY),
             prune_receipts: self.prune_receipts.unwrap_or(DEFAULT_PRUNE_RECEIPTS),
-        }
+            size: target_size.map(|target_size| PruningSizeConfig {
+                target_size,
+                low_watermark: self
+                    .low_watermark
+                    .unwrap_or(target_size / 100 * DEFAULT_LOW_WATERMARK_PERCENTAGE)
+                    .min(target_size),
+            }),
+            max_age: self.max_age_secs.or(DEFAULT_MAX_AGE_SECS).map(Duration::from_secs),
+            step_interval: Duration::from_millis(self.step_interval_ms.unwrap_or(DEFAULT_STEP_INTERVAL_MS)),
+            retention: self.retention.unwrap_or_default().finish()?,
+        })
+    }
+}
+
+/// Builder for a [`PruningRetentionConfig`].
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct PruningRetentionConfigBuilder {
+    indexations: Option<Vec<String>>,
+    addresses: Option<Vec<String>>,
+    milestones: Option<bool>,
+}
+
+impl PruningRetentionConfigBuilder {
+    /// Creates a new [`PruningRetentionConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the indexation keys, as hex strings, of the messages that are kept.
+    pub fn indexations(mut self, indexations: Vec<String>) -> Self {
+        self.indexations.replace(indexations);
+        self
+    }
+
+    /// Sets the Bech32 addresses whose transactions are kept.
+    pub fn addresses(mut self, addresses: Vec<String>) -> Self {
+        self.addresses.replace(addresses);
+        self
+    }
+
+    /// Sets whether milestones are kept.
+    pub fn milestones(mut self, milestones: bool) -> Self {
+        self.milestones.replace(milestones);
+        self
+    }
+
+    /// Finishes the builder into a [`PruningRetentionConfig`].
+    ///
+    /// Fails on the first invalid indexation key or address.
+    pub fn finish(self) -> Result<PruningRetentionConfig, PruningConfigError> {
+        let indexations = self
+            .indexations
+            .unwrap_or_default()
+            .into_iter()
+            .map(|indexation| {
+                // Keys are hex encoded, like the index of the REST API indexation filter.
+                hex::decode(&indexation)
+                    .ok()
+                    .and_then(|index| IndexationPayload::new(&index, &[]).ok())
+                    .map(|payload| payload.padded_index())
+                    .ok_or(PruningConfigError::InvalidRetainedIndexation(indexation))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let addresses = self
+            .addresses
+            .unwrap_or_default()
+            .into_iter()
+            .map(|address| {
+                Address::try_from_bech32(&address).map_err(|e| PruningConfigError::InvalidRetainedAddress(address, e))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(PruningRetentionConfig {
+            indexations,
+            addresses,
+            milestones: self.milestones.unwrap_or(DEFAULT_RETAIN_MILESTONES),
+        })
+    }
+}
+
+/// The configuration of the data that is kept when pruning.
+#[derive(Clone, Default)]
+pub struct PruningRetentionConfig {
+    indexations: HashSet<PaddedIndex>,
+    addresses: HashSet<Address>,
+    milestones: bool,
+}
+
+impl PruningRetentionConfig {
+    /// Returns a builder to create a [`PruningRetentionConfig`].
+    pub fn build() -> PruningRetentionConfigBuilder {
+        PruningRetentionConfigBuilder::new()
+    }
+
+    /// Returns whether messages with the given indexation key are kept.
+    pub fn retains_indexation(&self, index: &PaddedIndex) -> bool {
+        self.indexations.contains(index)
+    }
+
+    /// Returns whether transactions touching the given address are kept.
+    pub fn retains_address(&self, address: &Address) -> bool {
+        self.addresses.contains(address)
+    }
+
+    /// Returns whether any address is kept.
+    pub fn retains_addresses(&self) -> bool {
+        !self.addresses.is_empty()
+    }
+
+    /// Returns whether milestones are kept.
+    pub fn retains_milestones(&self) -> bool {
+        self.milestones
+    }
+
+    /// Returns whether no data at all is kept.
+    pub fn is_empty(&self) -> bool {
+        self.indexations.is_empty() && self.addresses.is_empty() && !self.milestones
+    }
+}
+
+/// The size-based pruning configuration.
+#[derive(Clone, Copy)]
+pub struct PruningSizeConfig {
+    target_size: usize,
+    low_watermark: usize,
+}
+
+impl PruningSizeConfig {
+    /// Returns the database size above which pruning is triggered.
+    pub fn target_size(&self) -> usize {
+        self.target_size
+    }
+
+    /// Returns the database size below which pruning triggered by the target size stops.
+    pub fn low_watermark(&self) -> usize {
+        self.low_watermark
     }
 }
 
@@ -60,6 +261,10 @@ pub struct PruningConfig {
     enabled: bool,
     delay: u32,
     prune_receipts: bool,
+    size: Option<PruningSizeConfig>,
+    max_age: Option<Duration>,
+    step_interval: Duration,
+    retention: PruningRetentionConfig,
 }
 
 impl PruningConfig {
@@ -87,4 +292,94 @@ impl PruningConfig {
     pub fn prune_receipts(&self) -> bool {
         self.prune_receipts
     }
+
+    /// Returns the size-based pruning configuration, if enabled.
+    pub fn size(&self) -> Option<&PruningSizeConfig> {
+        self.size.as_ref()
+    }
+
+    /// Returns the age after which milestones are pruned, if enabled.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Returns the minimum interval between the pruning of two consecutive milestones.
+    pub fn step_interval(&self) -> Duration {
+        self.step_interval
+    }
+
+    /// Returns the configuration of the data that is kept when pruning.
+    pub fn retention(&self) -> &PruningRetentionConfig {
+        &self.retention
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use bee_message::address::Ed25519Address;
+
+    use super::*;
+
+    #[test]
+    fn retention_valid() {
+        let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let retention = PruningRetentionConfig::build()
+            .indexations(vec![hex::encode("my-application")])
+            .addresses(vec![address.to_bech32("iota")])
+            .milestones(true)
+            .finish()
+            .unwrap();
+
+        assert!(retention.retains_indexation(&IndexationPayload::new(b"my-application", &[]).unwrap().padded_index()));
+        assert!(retention.retains_address(&address));
+        assert!(!retention.retains_address(&Address::Ed25519(Ed25519Address::new([2; 32]))));
+        assert!(retention.retains_milestones());
+        assert!(!retention.is_empty());
+    }
+
+    #[test]
+    fn retention_empty() {
+        assert!(PruningRetentionConfig::build().finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn retention_invalid_indexation() {
+        assert!(matches!(
+            PruningRetentionConfig::build()
+                .indexations(vec![String::new()])
+                .finish(),
+            Err(PruningConfigError::InvalidRetainedIndexation(_))
+        ));
+        assert!(matches!(
+            PruningRetentionConfig::build()
+                .indexations(vec!["a".repeat(130)])
+                .finish(),
+            Err(PruningConfigError::InvalidRetainedIndexation(_))
+        ));
+        assert!(matches!(
+            PruningRetentionConfig::build()
+                .indexations(vec!["my-application".to_string()])
+                .finish(),
+            Err(PruningConfigError::InvalidRetainedIndexation(_))
+        ));
+    }
+
+    #[test]
+    fn retention_invalid_address() {
+        assert!(matches!(
+            PruningRetentionConfig::build()
+                .addresses(vec!["iota1invalid".to_string()])
+                .finish(),
+            Err(PruningConfigError::InvalidRetainedAddress(..))
+        ));
+    }
+
+    #[test]
+    fn pruning_config_rejects_invalid_retention() {
+        assert!(PruningConfig::build()
+            .retention(PruningRetentionConfig::build().indexations(vec![String::new()]))
+            .finish()
+            .is_err());
+    }
 }
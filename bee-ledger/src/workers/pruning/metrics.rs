@@ -14,6 +14,8 @@ pub struct PruningMetrics {
     pub indexations: usize,
     pub output_diffs: bool,
     pub receipts: usize,
+    pub retained_messages: usize,
+    pub retained_outputs: usize,
 }
 
 #[derive(Debug, Default)]
@@ -28,6 +30,7 @@ pub struct ConfirmedDataPruningMetrics {
     pub prunable_messages: usize,
     pub prunable_edges: usize,
     pub prunable_indexations: usize,
+    pub retained_messages: usize,
     pub new_seps: usize,
 }
 
@@ -37,6 +40,7 @@ pub struct UnconfirmedDataPruningMetrics {
     pub prunable_messages: usize,
     pub prunable_edges: usize,
     pub prunable_indexations: usize,
+    pub retained_messages: usize,
     pub already_pruned: usize,
     pub were_confirmed: usize,
 }
@@ -44,6 +48,7 @@ pub struct UnconfirmedDataPruningMetrics {
 #[derive(Debug, Default)]
 pub struct MilestoneDataPruningMetrics {
     pub receipts: usize,
+    pub retained_outputs: usize,
 }
 
 #[derive(Debug, Default)]
@@ -56,6 +61,6 @@ pub struct Timings {
     pub batch_unconfirmed_data: Duration,
     pub batch_milestone_data: Duration,
     pub batch_new_seps: Duration,
-    pub truncate_curr_seps: Duration,
+    pub batch_old_seps: Duration,
     pub batch_commit: Duration,
 }
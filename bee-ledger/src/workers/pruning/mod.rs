@@ -11,3 +11,4 @@ pub(crate) mod condition;
 pub(crate) mod prune;
 
 pub mod config;
+pub mod worker;
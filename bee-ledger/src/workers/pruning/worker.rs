@@ -0,0 +1,275 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the pruning worker.
+
+use std::{
+    any::TypeId,
+    convert::Infallible,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use bee_message::milestone::MilestoneIndex;
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_tangle::{Tangle, TangleWorker};
+use futures::stream::StreamExt;
+use log::{debug, error, info, warn};
+use tokio::{
+    sync::mpsc::{self, UnboundedSender},
+    task,
+    time::sleep,
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    types::LedgerIndex,
+    workers::{
+        consensus::worker::{EXTRA_PRUNING_DEPTH, EXTRA_SNAPSHOT_DEPTH},
+        event::MilestoneConfirmed,
+        pruning::{condition::should_prune, config::PruningConfig, prune},
+        snapshot::config::SnapshotConfig,
+        storage::StorageBackend,
+    },
+};
+
+/// Commands of the pruning worker.
+pub(crate) enum PruningWorkerCommand {
+    /// Command to prune the data made prunable by a new ledger index.
+    Prune(LedgerIndex),
+    /// Command to prune the next milestone of an ongoing pruning.
+    Step,
+}
+
+/// Scheduling state of the pruning worker.
+#[derive(Default)]
+struct PruningSchedule {
+    ledger_index: Option<LedgerIndex>,
+    step_scheduled: bool,
+}
+
+impl PruningSchedule {
+    /// Handles a command and returns the ledger index to prune against, if a pruning step has to be attempted.
+    fn on_command(&mut self, command: PruningWorkerCommand) -> Option<LedgerIndex> {
+        match command {
+            PruningWorkerCommand::Prune(index) => {
+                self.ledger_index.replace(index);
+                // An ongoing pruning picks up the new ledger index with its next step.
+                if self.step_scheduled {
+                    return None;
+                }
+            }
+            PruningWorkerCommand::Step => self.step_scheduled = false,
+        }
+
+        self.ledger_index
+    }
+
+    /// Records whether the next step of an ongoing pruning is scheduled.
+    fn set_step_scheduled(&mut self, step_scheduled: bool) {
+        self.step_scheduled = step_scheduled;
+    }
+}
+
+/// A pruning run, i.e. consecutive pruning steps until nothing is left to prune.
+struct PruningRun {
+    first_index: MilestoneIndex,
+    last_index: MilestoneIndex,
+    started: Instant,
+}
+
+impl PruningRun {
+    fn new(index: MilestoneIndex) -> Self {
+        Self {
+            first_index: index,
+            last_index: index,
+            started: Instant::now(),
+        }
+    }
+}
+
+/// Schedules the next step of an ongoing pruning after the step interval, without delaying other commands meanwhile.
+fn schedule_step(tx: &UnboundedSender<PruningWorkerCommand>, step_interval: Duration) -> bool {
+    if step_interval.is_zero() {
+        return tx.send(PruningWorkerCommand::Step).is_ok();
+    }
+
+    let tx = tx.clone();
+
+    tokio::spawn(async move {
+        sleep(step_interval).await;
+        // The worker may already be stopped during shutdown, in which case nothing is left to prune anyway.
+        let _ = tx.send(PruningWorkerCommand::Step);
+    });
+
+    true
+}
+
+/// The pruning worker.
+///
+/// Prunes one milestone per step, in the background of the milestone confirmation, and schedules the next step after
+/// the configured step interval.
+pub struct PruningWorker {}
+
+#[async_trait]
+impl<N: Node> Worker<N> for PruningWorker
+where
+    N::Backend: StorageBackend,
+{
+    type Config = (SnapshotConfig, PruningConfig);
+    type Error = Infallible;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![TypeId::of::<TangleWorker>()].leak()
+    }
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let (snapshot_config, pruning_config) = config;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tangle = node.resource::<Tangle<N::Backend>>();
+        let storage = node.storage();
+        let bus = node.bus();
+
+        let bmd = tangle.config().below_max_depth();
+
+        // The consensus worker already warns about a too low snapshot depth.
+        let snapshot_depth = snapshot_config.depth().max(bmd + EXTRA_SNAPSHOT_DEPTH);
+        let pruning_delay_min = snapshot_depth + bmd + EXTRA_PRUNING_DEPTH;
+        let pruning_delay = if pruning_config.delay() < pruning_delay_min {
+            warn!(
+                "Configuration value for \"pruning.delay\" is too low ({}), value changed to {}.",
+                pruning_config.delay(),
+                pruning_delay_min
+            );
+            pruning_delay_min
+        } else {
+            pruning_config.delay()
+        };
+
+        {
+            let tx = tx.clone();
+
+            bus.add_listener::<Self, MilestoneConfirmed, _>(move |event| {
+                // The worker may already be stopped during shutdown, in which case nothing is left to prune anyway.
+                let _ = tx.send(PruningWorkerCommand::Prune(LedgerIndex(event.index)));
+            });
+        }
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+            let mut schedule = PruningSchedule::default();
+            let mut size_exceeded = false;
+            let mut run: Option<PruningRun> = None;
+
+            while let Some(command) = receiver.next().await {
+                let ledger_index = match schedule.on_command(command) {
+                    Some(ledger_index) => ledger_index,
+                    None => continue,
+                };
+
+                if !tangle.is_confirmed() {
+                    continue;
+                }
+
+                match should_prune(
+                    &tangle,
+                    &storage,
+                    ledger_index,
+                    pruning_delay,
+                    pruning_delay_min,
+                    &mut size_exceeded,
+                    &pruning_config,
+                ) {
+                    Ok((index, _)) => {
+                        if let Err(e) = prune::prune_milestone(&tangle, &storage, &bus, index, &pruning_config).await {
+                            error!("Pruning failed: {:?}.", e);
+                            continue;
+                        }
+
+                        match run.as_mut() {
+                            Some(run) => run.last_index = index,
+                            None => run = Some(PruningRun::new(index)),
+                        }
+
+                        task::yield_now().await;
+
+                        // Only a single step is scheduled at a time, further ledger indexes are picked up by it.
+                        schedule.set_step_scheduled(schedule_step(&tx, pruning_config.step_interval()));
+                    }
+                    Err(reason) => {
+                        debug!("Pruning skipped: {:?}", reason);
+
+                        if let Some(run) = run.take() {
+                            info!(
+                                "Pruned milestones {} to {} in {:.2?}.",
+                                run.first_index,
+                                run.last_index,
+                                run.started.elapsed()
+                            );
+                        }
+                    }
+                }
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn prune_without_ongoing_pruning() {
+        let mut schedule = PruningSchedule::default();
+
+        assert_eq!(schedule.on_command(PruningWorkerCommand::Step), None);
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(10)))),
+            Some(LedgerIndex(MilestoneIndex(10)))
+        );
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(11)))),
+            Some(LedgerIndex(MilestoneIndex(11)))
+        );
+    }
+
+    #[test]
+    fn prune_during_ongoing_pruning() {
+        let mut schedule = PruningSchedule::default();
+
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(10)))),
+            Some(LedgerIndex(MilestoneIndex(10)))
+        );
+        schedule.set_step_scheduled(true);
+
+        // New ledger indexes don't trigger additional steps while one is scheduled.
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(11)))),
+            None
+        );
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(12)))),
+            None
+        );
+
+        // The scheduled step picks up the latest ledger index.
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Step),
+            Some(LedgerIndex(MilestoneIndex(12)))
+        );
+        schedule.set_step_scheduled(false);
+
+        assert_eq!(
+            schedule.on_command(PruningWorkerCommand::Prune(LedgerIndex(MilestoneIndex(13)))),
+            Some(LedgerIndex(MilestoneIndex(13)))
+        );
+    }
+}
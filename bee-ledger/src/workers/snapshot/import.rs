@@ -262,7 +262,9 @@ fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id
     Ok(())
 }
 
-pub(crate) async fn import_snapshots<B: StorageBackend>(
+/// Imports the full snapshot, and the delta snapshot if there is one, into the storage. The snapshot files are
+/// downloaded first if neither of them exists.
+pub async fn import_snapshots<B: StorageBackend>(
     storage: &B,
     network_id: u64,
     config: &SnapshotConfig,
@@ -10,3 +10,5 @@ pub(crate) mod import;
 pub(crate) mod worker;
 
 pub mod config;
+
+pub use self::import::import_snapshots;
@@ -13,7 +13,7 @@ use bee_message::{
     Message, MessageId,
 };
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Exist, Fetch, Insert, Snapshot, SnapshotBuilder, Truncate},
     backend,
 };
 use bee_tangle::{
@@ -66,6 +66,11 @@ pub trait StorageBackend:
     + for<'a> AsIterator<'a, Unspent, ()>
     + for<'a> AsIterator<'a, Address, Balance>
     + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+    + for<'a> Snapshot<'a, (), LedgerIndex>
+    + for<'a> Snapshot<'a, Address, Balance>
+    + for<'a> Snapshot<'a, OutputId, CreatedOutput>
+    + for<'a> Snapshot<'a, OutputId, ConsumedOutput>
+    + for<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>>
     + bee_tangle::storage::StorageBackend
 {
 }
@@ -107,6 +112,11 @@ impl<T> StorageBackend for T where
         + for<'a> AsIterator<'a, Unspent, ()>
         + for<'a> AsIterator<'a, Address, Balance>
         + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+        + for<'a> Snapshot<'a, (), LedgerIndex>
+        + for<'a> Snapshot<'a, Address, Balance>
+        + for<'a> Snapshot<'a, OutputId, CreatedOutput>
+        + for<'a> Snapshot<'a, OutputId, ConsumedOutput>
+        + for<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>>
         + bee_tangle::storage::StorageBackend
 {
 }
@@ -408,11 +418,65 @@ pub(crate) fn fetch_output<B: StorageBackend>(
     Fetch::<OutputId, CreatedOutput>::fetch(storage, output_id).map_err(|e| Error::Storage(Box::new(e)))
 }
 
-pub(crate) fn fetch_outputs_for_ed25519_address<B: StorageBackend>(
+fn fetch_ledger_index_snapshot<'a, B: StorageBackend>(
+    storage: &B,
+    snapshot: &<B as SnapshotBuilder<'a>>::Snapshot,
+) -> Result<LedgerIndex, Error> {
+    Snapshot::<(), LedgerIndex>::snapshot_fetch(storage, snapshot, &())
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .ok_or(Error::MissingLedgerIndex)
+}
+
+/// Fetches the balance of an address together with the ledger index it is valid for.
+/// Both are read from the same storage snapshot and can not be torn apart by a concurrent milestone confirmation.
+pub fn fetch_balance_with_ledger_index<B: StorageBackend>(
+    storage: &B,
+    address: &Address,
+) -> Result<(Option<Balance>, LedgerIndex), Error> {
+    let snapshot = storage.snapshot().map_err(|e| Error::Storage(Box::new(e)))?;
+    let ledger_index = fetch_ledger_index_snapshot(storage, &snapshot)?;
+    let balance = Snapshot::<Address, Balance>::snapshot_fetch(storage, &snapshot, address)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    Ok((balance, ledger_index))
+}
+
+/// Fetches an output, its consuming information if it has been spent, and the ledger index they are valid for.
+/// All of them are read from the same storage snapshot and can not be torn apart by a concurrent milestone
+/// confirmation.
+#[allow(clippy::type_complexity)]
+pub fn fetch_output_with_ledger_index<B: StorageBackend>(
+    storage: &B,
+    output_id: &OutputId,
+) -> Result<(Option<(CreatedOutput, Option<ConsumedOutput>)>, LedgerIndex), Error> {
+    let snapshot = storage.snapshot().map_err(|e| Error::Storage(Box::new(e)))?;
+    let ledger_index = fetch_ledger_index_snapshot(storage, &snapshot)?;
+    let output = match Snapshot::<OutputId, CreatedOutput>::snapshot_fetch(storage, &snapshot, output_id)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+    {
+        Some(created_output) => Some((
+            created_output,
+            Snapshot::<OutputId, ConsumedOutput>::snapshot_fetch(storage, &snapshot, output_id)
+                .map_err(|e| Error::Storage(Box::new(e)))?,
+        )),
+        None => None,
+    };
+
+    Ok((output, ledger_index))
+}
+
+/// Fetches the outputs of an `Ed25519Address` together with the ledger index they are valid for.
+/// Both are read from the same storage snapshot and can not be torn apart by a concurrent milestone confirmation.
+pub fn fetch_outputs_for_ed25519_address_with_ledger_index<B: StorageBackend>(
     storage: &B,
     address: &Ed25519Address,
-) -> Result<Option<Vec<OutputId>>, Error> {
-    Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&*storage, address).map_err(|e| Error::Storage(Box::new(e)))
+) -> Result<(Option<Vec<OutputId>>, LedgerIndex), Error> {
+    let snapshot = storage.snapshot().map_err(|e| Error::Storage(Box::new(e)))?;
+    let ledger_index = fetch_ledger_index_snapshot(storage, &snapshot)?;
+    let output_ids = Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(storage, &snapshot, address)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    Ok((output_ids, ledger_index))
 }
 
 pub(crate) fn is_output_unspent<B: StorageBackend>(storage: &B, output_id: &OutputId) -> Result<bool, Error> {
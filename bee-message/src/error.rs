@@ -16,10 +16,12 @@ pub enum Error {
     DuplicateSignature(usize),
     DuplicateUtxo(UtxoInput),
     InputUnlockBlockCountMismatch(usize, usize),
+    InsufficientFunds(u64, u64),
     InvalidAccumulatedOutput(u128),
     InvalidAddress,
     InvalidAddressKind(u8),
     InvalidAmount(u64),
+    InvalidDustAllowance(Address),
     InvalidDustAllowanceAmount(u64),
     InvalidEssenceKind(u8),
     InvalidHexadecimalChar(String),
@@ -53,9 +55,11 @@ pub enum Error {
     MilestonePublicKeysSignaturesCountMismatch(usize, usize),
     MissingField(&'static str),
     MissingPayload,
+    NoExactInputMatch(u64),
     ParentsNotUniqueSorted,
     RemainingBytesAfterMessage,
     SignaturePublicKeyMismatch(String, String),
+    Signer(Box<dyn std::error::Error + Send + Sync>),
     TailTransactionHashNotUnique(usize, usize),
     TransactionInputsNotSorted,
     TransactionOutputsNotSorted,
@@ -79,10 +83,16 @@ impl fmt::Display for Error {
                     input, block
                 )
             }
+            Error::InsufficientFunds(available, required) => {
+                write!(f, "Insufficient funds: {} available, {} required.", available, required)
+            }
             Error::InvalidAccumulatedOutput(value) => write!(f, "Invalid accumulated output balance: {}.", value),
             Error::InvalidAddress => write!(f, "Invalid address provided."),
             Error::InvalidAddressKind(k) => write!(f, "Invalid address kind: {}.", k),
             Error::InvalidAmount(amount) => write!(f, "Invalid amount: {}.", amount),
+            Error::InvalidDustAllowance(address) => {
+                write!(f, "Invalid dust allowance for address {:?}.", address)
+            }
             Error::InvalidDustAllowanceAmount(amount) => write!(f, "Invalid dust allowance amount: {}.", amount),
             Error::InvalidEssenceKind(k) => write!(f, "Invalid essence kind: {}.", k),
             Error::InvalidHexadecimalChar(hex) => write!(f, "Invalid hexadecimal character: {}.", hex),
@@ -148,6 +158,7 @@ impl fmt::Display for Error {
             }
             Error::MissingField(s) => write!(f, "Missing required field: {}.", s),
             Error::MissingPayload => write!(f, "Missing payload."),
+            Error::NoExactInputMatch(amount) => write!(f, "No inputs exactly matching amount {}.", amount),
             Error::ParentsNotUniqueSorted => {
                 write!(f, "Parents not unique and/or sorted.")
             }
@@ -161,6 +172,7 @@ impl fmt::Display for Error {
                     expected, actual
                 )
             }
+            Error::Signer(e) => write!(f, "Signer error: {}.", e),
             Error::TailTransactionHashNotUnique(previous, current) => {
                 write!(
                     f,
@@ -0,0 +1,87 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    address::Address,
+    output::{dust_outputs_max, Output, DUST_THRESHOLD},
+    Error,
+};
+
+/// The dust related part of the balance of an address, as tracked by the ledger.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DustBalance {
+    dust_allowance: u64,
+    dust_outputs: u64,
+}
+
+impl DustBalance {
+    /// Creates a new `DustBalance`.
+    pub fn new(dust_allowance: u64, dust_outputs: u64) -> Self {
+        Self {
+            dust_allowance,
+            dust_outputs,
+        }
+    }
+
+    /// Returns the sum of the dust allowance outputs of a `DustBalance`.
+    pub fn dust_allowance(&self) -> u64 {
+        self.dust_allowance
+    }
+
+    /// Returns the number of dust outputs of a `DustBalance`.
+    pub fn dust_outputs(&self) -> u64 {
+        self.dust_outputs
+    }
+}
+
+/// Changes of the dust allowance and number of dust outputs of an address.
+#[derive(Default)]
+struct DustDiff {
+    dust_allowance: i128,
+    dust_outputs: i128,
+}
+
+/// Checks that consuming `inputs` and creating `outputs` leaves no address with more dust outputs than its dust
+/// allowance permits.
+///
+/// This follows the white-flag rules: only the addresses whose dust allowance decreases or whose number of dust outputs
+/// increases are checked, against their `balances` which default to an empty balance.
+pub(crate) fn validate_dust<'a>(
+    inputs: impl Iterator<Item = &'a Output>,
+    outputs: impl Iterator<Item = &'a Output>,
+    balances: &HashMap<Address, DustBalance>,
+) -> Result<(), Error> {
+    let mut diffs = HashMap::<Address, DustDiff>::new();
+
+    for (output, sign) in inputs.map(|input| (input, -1)).chain(outputs.map(|output| (output, 1))) {
+        match output {
+            Output::SignatureLockedSingle(output) => {
+                if output.amount() < DUST_THRESHOLD {
+                    diffs.entry(*output.address()).or_default().dust_outputs += sign;
+                }
+            }
+            Output::SignatureLockedDustAllowance(output) => {
+                diffs.entry(*output.address()).or_default().dust_allowance += sign * i128::from(output.amount());
+            }
+            Output::Treasury(_) => return Err(Error::InvalidOutputKind(output.kind())),
+        }
+    }
+
+    for (address, diff) in diffs {
+        if diff.dust_allowance >= 0 && diff.dust_outputs <= 0 {
+            continue;
+        }
+
+        let balance = balances.get(&address).copied().unwrap_or_default();
+        let dust_allowance = (i128::from(balance.dust_allowance()) + diff.dust_allowance).max(0) as u64;
+        let dust_outputs = (i128::from(balance.dust_outputs()) + diff.dust_outputs).max(0) as u64;
+
+        if dust_outputs > dust_outputs_max(dust_allowance) {
+            return Err(Error::InvalidDustAllowance(address));
+        }
+    }
+
+    Ok(())
+}
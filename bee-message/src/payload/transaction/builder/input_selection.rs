@@ -0,0 +1,222 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    address::Address,
+    constants::INPUT_OUTPUT_COUNT_MAX,
+    output::{Output, OutputId, DUST_THRESHOLD},
+    Error,
+};
+
+/// The maximum number of subsets explored when looking for an exact match.
+const EXACT_MATCH_SEARCH_MAX: usize = 100_000;
+
+/// An unspent output that can be consumed by a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnspentOutput {
+    output_id: OutputId,
+    output: Output,
+}
+
+impl UnspentOutput {
+    /// Creates a new `UnspentOutput`.
+    pub fn new(output_id: OutputId, output: Output) -> Result<Self, Error> {
+        if let Output::Treasury(_) = output {
+            return Err(Error::InvalidOutputKind(output.kind()));
+        }
+
+        Ok(Self { output_id, output })
+    }
+
+    /// Returns the identifier of an `UnspentOutput`.
+    pub fn output_id(&self) -> &OutputId {
+        &self.output_id
+    }
+
+    /// Returns the output of an `UnspentOutput`.
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// Returns the address owning an `UnspentOutput`.
+    pub fn address(&self) -> &Address {
+        match &self.output {
+            Output::SignatureLockedSingle(output) => output.address(),
+            Output::SignatureLockedDustAllowance(output) => output.address(),
+            // Treasury outputs are rejected at construction.
+            Output::Treasury(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the amount of an `UnspentOutput`.
+    pub fn amount(&self) -> u64 {
+        match &self.output {
+            Output::SignatureLockedSingle(output) => output.amount(),
+            Output::SignatureLockedDustAllowance(output) => output.amount(),
+            Output::Treasury(output) => output.amount(),
+        }
+    }
+
+    /// Returns whether an `UnspentOutput` is a dust output.
+    pub fn is_dust(&self) -> bool {
+        matches!(&self.output, Output::SignatureLockedSingle(output) if output.amount() < DUST_THRESHOLD)
+    }
+}
+
+/// The strategy used to select the outputs consumed by a transaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputSelection {
+    /// Consumes the largest outputs first, which minimises the number of inputs.
+    LargestFirst,
+    /// Consumes outputs whose amounts exactly add up to the required amount, so that no remainder is created.
+    ExactMatch,
+    /// Avoids creating a dust remainder and consumes as many dust outputs as the remainder can absorb.
+    MinimiseDust,
+}
+
+impl Default for InputSelection {
+    fn default() -> Self {
+        Self::LargestFirst
+    }
+}
+
+impl InputSelection {
+    /// Selects outputs, among the `available` ones, whose amounts add up to at least `required`.
+    pub fn select(&self, available: &[UnspentOutput], required: u64) -> Result<Vec<UnspentOutput>, Error> {
+        let total = available.iter().map(|output| u128::from(output.amount())).sum::<u128>();
+
+        if total < u128::from(required) {
+            return Err(Error::InsufficientFunds(
+                total.min(u128::from(u64::MAX)) as u64,
+                required,
+            ));
+        }
+
+        let mut candidates = available.iter().collect::<Vec<_>>();
+        // Sorting by output id as well keeps the selection deterministic.
+        candidates.sort_by(|a, b| b.amount().cmp(&a.amount()).then(a.output_id().cmp(b.output_id())));
+
+        let selected = match self {
+            Self::LargestFirst => largest_first(&candidates, required)?,
+            Self::ExactMatch => exact_match(&candidates, required).ok_or(Error::NoExactInputMatch(required))?,
+            Self::MinimiseDust => minimise_dust(&candidates, required)?,
+        };
+
+        Ok(selected.into_iter().cloned().collect())
+    }
+}
+
+// The candidates are sorted by decreasing amount, and add up to at least `required`.
+// Fails if more than `INPUT_OUTPUT_COUNT_MAX` of them are needed, reporting how many would be.
+fn largest_first<'a>(candidates: &[&'a UnspentOutput], required: u64) -> Result<Vec<&'a UnspentOutput>, Error> {
+    let mut count = 0;
+    let mut sum = 0;
+
+    while sum < required {
+        sum += candidates[count].amount();
+        count += 1;
+    }
+
+    if count > INPUT_OUTPUT_COUNT_MAX {
+        return Err(Error::InvalidInputOutputCount(count));
+    }
+
+    Ok(candidates[..count].to_vec())
+}
+
+// Depth-first search over the candidates sorted by decreasing amount, which finds subsets with few inputs first.
+fn exact_match<'a>(candidates: &[&'a UnspentOutput], required: u64) -> Option<Vec<&'a UnspentOutput>> {
+    // The sum of the amounts of the candidates from each index on, used to prune branches that can't reach `required`.
+    let mut suffix_sums = vec![0u64; candidates.len() + 1];
+
+    for (index, candidate) in candidates.iter().enumerate().rev() {
+        suffix_sums[index] = suffix_sums[index + 1] + candidate.amount();
+    }
+
+    let mut selected = Vec::new();
+    let mut explored = 0;
+
+    if search(candidates, &suffix_sums, 0, required, &mut selected, &mut explored) {
+        Some(selected.into_iter().map(|index| candidates[index]).collect())
+    } else {
+        None
+    }
+}
+
+fn search(
+    candidates: &[&UnspentOutput],
+    suffix_sums: &[u64],
+    from: usize,
+    remaining: u64,
+    selected: &mut Vec<usize>,
+    explored: &mut usize,
+) -> bool {
+    if remaining == 0 {
+        return true;
+    }
+
+    if selected.len() == INPUT_OUTPUT_COUNT_MAX || suffix_sums[from] < remaining {
+        return false;
+    }
+
+    for index in from..candidates.len() {
+        *explored += 1;
+
+        if *explored > EXACT_MATCH_SEARCH_MAX || suffix_sums[index] < remaining {
+            return false;
+        }
+
+        let amount = candidates[index].amount();
+
+        if amount > remaining {
+            continue;
+        }
+
+        selected.push(index);
+
+        if search(
+            candidates,
+            suffix_sums,
+            index + 1,
+            remaining - amount,
+            selected,
+            explored,
+        ) {
+            return true;
+        }
+
+        selected.pop();
+    }
+
+    false
+}
+
+// Starts like `largest_first`, then adds outputs until the remainder is either zero or not dust, and finally sweeps the
+// remaining dust outputs unless that turns a zero remainder into dust.
+fn minimise_dust<'a>(candidates: &[&'a UnspentOutput], required: u64) -> Result<Vec<&'a UnspentOutput>, Error> {
+    let mut selected = largest_first(candidates, required)?;
+    let mut unselected = candidates[selected.len()..].iter();
+    let mut remainder = selected.iter().map(|output| output.amount()).sum::<u64>() - required;
+
+    while remainder > 0 && remainder < DUST_THRESHOLD && selected.len() < INPUT_OUTPUT_COUNT_MAX {
+        match unselected.next() {
+            Some(candidate) => {
+                remainder += candidate.amount();
+                selected.push(*candidate);
+            }
+            None => break,
+        }
+    }
+
+    let dust = unselected
+        .filter(|candidate| candidate.is_dust())
+        .take(INPUT_OUTPUT_COUNT_MAX.saturating_sub(selected.len()))
+        .collect::<Vec<_>>();
+    let dust_sum = dust.iter().map(|output| output.amount()).sum::<u64>();
+
+    if remainder > 0 || dust_sum >= DUST_THRESHOLD {
+        selected.extend(dust.into_iter().copied());
+    }
+
+    Ok(selected)
+}
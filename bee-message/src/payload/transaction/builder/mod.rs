@@ -0,0 +1,190 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A builder to create signed transactions out of unspent outputs.
+
+mod dust;
+mod input_selection;
+mod signer;
+
+use std::collections::HashMap;
+
+use bee_common::packable::Packable;
+
+pub use self::{
+    dust::DustBalance,
+    input_selection::{InputSelection, UnspentOutput},
+    signer::Signer,
+};
+use crate::{
+    address::Address,
+    input::{Input, UtxoInput},
+    output::{Output, SignatureLockedSingleOutput},
+    payload::{
+        transaction::{Essence, RegularEssence, TransactionPayload},
+        Payload,
+    },
+    unlock::{ReferenceUnlock, UnlockBlock, UnlockBlocks},
+    Error,
+};
+
+/// A builder to build a signed `TransactionPayload`.
+///
+/// Inputs are selected among the available unspent outputs, the remainder is sent back to the remainder address, and
+/// each consumed address is signed once, the following inputs of the same address being unlocked by reference.
+#[derive(Debug, Default)]
+#[must_use]
+pub struct TransactionBuilder {
+    available_outputs: Vec<UnspentOutput>,
+    outputs: Vec<Output>,
+    remainder_address: Option<Address>,
+    input_selection: InputSelection,
+    dust_balances: HashMap<Address, DustBalance>,
+    payload: Option<Payload>,
+}
+
+impl TransactionBuilder {
+    /// Creates a new `TransactionBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds unspent outputs that can be consumed to a `TransactionBuilder`.
+    pub fn with_available_outputs(mut self, available_outputs: Vec<UnspentOutput>) -> Self {
+        self.available_outputs = available_outputs;
+        self
+    }
+
+    /// Adds an unspent output that can be consumed to a `TransactionBuilder`.
+    pub fn add_available_output(mut self, available_output: UnspentOutput) -> Self {
+        self.available_outputs.push(available_output);
+        self
+    }
+
+    /// Adds outputs to a `TransactionBuilder`.
+    pub fn with_outputs(mut self, outputs: Vec<Output>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    /// Adds an output to a `TransactionBuilder`.
+    pub fn add_output(mut self, output: Output) -> Self {
+        self.outputs.push(output);
+        self
+    }
+
+    /// Adds the address receiving the remainder to a `TransactionBuilder`.
+    pub fn with_remainder_address(mut self, remainder_address: Address) -> Self {
+        self.remainder_address.replace(remainder_address);
+        self
+    }
+
+    /// Sets the input selection strategy of a `TransactionBuilder`.
+    pub fn with_input_selection(mut self, input_selection: InputSelection) -> Self {
+        self.input_selection = input_selection;
+        self
+    }
+
+    /// Adds the current dust balance of an address to a `TransactionBuilder`.
+    ///
+    /// Addresses without a dust balance are considered to have no dust allowance and no dust outputs.
+    pub fn with_dust_balance(mut self, address: Address, dust_balance: DustBalance) -> Self {
+        self.dust_balances.insert(address, dust_balance);
+        self
+    }
+
+    /// Adds a payload to a `TransactionBuilder`.
+    pub fn with_payload(mut self, payload: Payload) -> Self {
+        self.payload.replace(payload);
+        self
+    }
+
+    /// Finishes a `TransactionBuilder` into a `TransactionPayload`, signing its inputs with `signer`.
+    pub fn finish<S: Signer + ?Sized>(self, signer: &S) -> Result<TransactionPayload, Error> {
+        let mut required: u64 = 0;
+
+        for output in self.outputs.iter() {
+            let amount = match output {
+                Output::SignatureLockedSingle(output) => output.amount(),
+                Output::SignatureLockedDustAllowance(output) => output.amount(),
+                Output::Treasury(_) => return Err(Error::InvalidOutputKind(output.kind())),
+            };
+            required = required
+                .checked_add(amount)
+                .ok_or_else(|| Error::InvalidAccumulatedOutput(u128::from(required) + u128::from(amount)))?;
+        }
+
+        let mut inputs = self.input_selection.select(&self.available_outputs, required)?;
+        let mut outputs = self.outputs;
+        // This never underflows because the selected inputs add up to at least the required amount.
+        let remainder = inputs.iter().map(UnspentOutput::amount).sum::<u64>() - required;
+
+        if remainder > 0 {
+            let remainder_address = self.remainder_address.ok_or(Error::MissingField("remainder_address"))?;
+            let existing = outputs.iter_mut().find_map(|output| match output {
+                Output::SignatureLockedSingle(output) if *output.address() == remainder_address => Some(output),
+                _ => None,
+            });
+
+            match existing {
+                Some(output) => {
+                    *output = SignatureLockedSingleOutput::new(remainder_address, output.amount() + remainder)?
+                }
+                None => outputs.push(Output::SignatureLockedSingle(SignatureLockedSingleOutput::new(
+                    remainder_address,
+                    remainder,
+                )?)),
+            }
+        }
+
+        // Inputs and outputs must be lexicographically sorted in their serialised forms.
+        inputs.sort_by_cached_key(|input| input.output_id().pack_new());
+        outputs.sort_by_cached_key(Packable::pack_new);
+
+        dust::validate_dust(
+            inputs.iter().map(UnspentOutput::output),
+            outputs.iter(),
+            &self.dust_balances,
+        )?;
+
+        let mut builder = RegularEssence::builder()
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|input| Input::Utxo(UtxoInput::from(*input.output_id())))
+                    .collect(),
+            )
+            .with_outputs(outputs);
+
+        if let Some(payload) = self.payload {
+            builder = builder.with_payload(payload);
+        }
+
+        let essence = Essence::Regular(builder.finish()?);
+        let hash = essence.hash();
+
+        let mut signature_indexes = HashMap::<&Address, usize>::new();
+        let mut unlock_blocks = Vec::with_capacity(inputs.len());
+
+        for (index, input) in inputs.iter().enumerate() {
+            match signature_indexes.get(input.address()) {
+                Some(signature_index) => {
+                    #[allow(clippy::cast_possible_truncation)] // There are at most 127 inputs.
+                    unlock_blocks.push(UnlockBlock::Reference(ReferenceUnlock::new(*signature_index as u16)?));
+                }
+                None => {
+                    let signature = signer
+                        .sign(input.address(), &hash)
+                        .map_err(|e| Error::Signer(Box::new(e)))?;
+                    unlock_blocks.push(UnlockBlock::Signature(signature));
+                    signature_indexes.insert(input.address(), index);
+                }
+            }
+        }
+
+        TransactionPayload::builder()
+            .with_essence(essence)
+            .with_unlock_blocks(UnlockBlocks::new(unlock_blocks)?)
+            .finish()
+    }
+}
@@ -0,0 +1,13 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{address::Address, signature::SignatureUnlock};
+
+/// Signs transaction essences on behalf of the addresses owning the consumed outputs.
+pub trait Signer {
+    /// The error returned when signing fails.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Signs the hash of a transaction essence with the key unlocking `address`.
+    fn sign(&self, address: &Address, essence_hash: &[u8; 32]) -> Result<SignatureUnlock, Self::Error>;
+}
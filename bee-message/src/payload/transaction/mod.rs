@@ -3,6 +3,7 @@
 
 //! Module describing the transaction payload.
 
+mod builder;
 mod essence;
 mod transaction_id;
 
@@ -10,6 +11,7 @@ use bee_common::packable::{Packable, Read, Write};
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 
 pub use self::{
+    builder::{DustBalance, InputSelection, Signer, TransactionBuilder, UnspentOutput},
     essence::{Essence, RegularEssence, RegularEssenceBuilder},
     transaction_id::{TransactionId, TRANSACTION_ID_LENGTH},
 };
@@ -19,8 +19,8 @@ pub use crate::{
         },
         receipt::{MigratedFundsEntry, ReceiptPayload, TailTransactionHash, TAIL_TRANSACTION_HASH_LEN},
         transaction::{
-            Essence, RegularEssence, RegularEssenceBuilder, TransactionId, TransactionPayload,
-            TransactionPayloadBuilder, TRANSACTION_ID_LENGTH,
+            DustBalance, Essence, InputSelection, RegularEssence, RegularEssenceBuilder, Signer, TransactionBuilder,
+            TransactionId, TransactionPayload, TransactionPayloadBuilder, UnspentOutput, TRANSACTION_ID_LENGTH,
         },
         treasury::TreasuryTransactionPayload,
         Payload,
@@ -0,0 +1,286 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{payload::transaction::Essence, prelude::*};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+
+const TRANSACTION_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+#[derive(Debug, thiserror::Error)]
+#[error("no key for address {0:?}")]
+struct MissingKey(Address);
+
+struct KeySigner(Vec<SecretKey>);
+
+impl KeySigner {
+    fn new(seeds: &[u8]) -> Self {
+        Self(seeds.iter().map(|seed| SecretKey::from_bytes([*seed; 32])).collect())
+    }
+}
+
+impl Signer for KeySigner {
+    type Error = MissingKey;
+
+    fn sign(&self, address: &Address, essence_hash: &[u8; 32]) -> Result<SignatureUnlock, Self::Error> {
+        let key = self
+            .0
+            .iter()
+            .find(|key| key_address(key) == *address)
+            .ok_or(MissingKey(*address))?;
+
+        Ok(SignatureUnlock::Ed25519(Ed25519Signature::new(
+            key.public_key().to_bytes(),
+            key.sign(essence_hash).to_bytes(),
+        )))
+    }
+}
+
+fn key_address(key: &SecretKey) -> Address {
+    Address::Ed25519(Ed25519Address::new(
+        Blake2b256::digest(&key.public_key().to_bytes()).into(),
+    ))
+}
+
+fn address(seed: u8) -> Address {
+    key_address(&SecretKey::from_bytes([seed; 32]))
+}
+
+fn single(seed: u8, amount: u64) -> Output {
+    Output::SignatureLockedSingle(SignatureLockedSingleOutput::new(address(seed), amount).unwrap())
+}
+
+fn dust_allowance(seed: u8, amount: u64) -> Output {
+    Output::SignatureLockedDustAllowance(SignatureLockedDustAllowanceOutput::new(address(seed), amount).unwrap())
+}
+
+fn unspent(index: u16, output: Output) -> UnspentOutput {
+    let txid = TransactionId::new(hex::decode(TRANSACTION_ID).unwrap().try_into().unwrap());
+
+    UnspentOutput::new(OutputId::new(txid, index).unwrap(), output).unwrap()
+}
+
+// Outputs are spread over transactions since an output index can't reach `INPUT_OUTPUT_COUNT_MAX`.
+fn unspent_from(transaction: u8, output: Output) -> UnspentOutput {
+    UnspentOutput::new(OutputId::new(TransactionId::new([transaction; 32]), 0).unwrap(), output).unwrap()
+}
+
+fn essence(payload: &TransactionPayload) -> &RegularEssence {
+    match payload.essence() {
+        Essence::Regular(essence) => essence,
+    }
+}
+
+fn input_indexes(payload: &TransactionPayload) -> Vec<u16> {
+    essence(payload)
+        .inputs()
+        .iter()
+        .map(|input| match input {
+            Input::Utxo(input) => input.output_id().index(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+#[test]
+fn largest_first() {
+    let payload = TransactionBuilder::new()
+        .with_available_outputs(vec![
+            unspent(0, single(0, 1_000_000)),
+            unspent(1, single(0, 5_000_000)),
+            unspent(2, single(0, 3_000_000)),
+        ])
+        .add_output(single(1, 6_000_000))
+        .with_remainder_address(address(0))
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+
+    assert_eq!(input_indexes(&payload), vec![1, 2]);
+    assert_eq!(essence(&payload).outputs().len(), 2);
+    assert!(essence(&payload).outputs().contains(&single(0, 2_000_000)));
+}
+
+#[test]
+fn exact_match() {
+    let payload = TransactionBuilder::new()
+        .with_available_outputs(vec![
+            unspent(0, single(0, 5_000_000)),
+            unspent(1, single(0, 4_000_000)),
+            unspent(2, single(0, 2_000_000)),
+        ])
+        .add_output(single(1, 6_000_000))
+        .with_input_selection(InputSelection::ExactMatch)
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+
+    assert_eq!(input_indexes(&payload), vec![1, 2]);
+    assert_eq!(essence(&payload).outputs(), &[single(1, 6_000_000)]);
+}
+
+#[test]
+fn exact_match_not_found() {
+    let result = TransactionBuilder::new()
+        .with_available_outputs(vec![unspent(0, single(0, 5_000_000)), unspent(1, single(0, 4_000_000))])
+        .add_output(single(1, 6_000_000))
+        .with_input_selection(InputSelection::ExactMatch)
+        .finish(&KeySigner::new(&[0]));
+
+    assert!(matches!(result, Err(Error::NoExactInputMatch(6_000_000))));
+}
+
+#[test]
+fn minimise_dust() {
+    let payload = TransactionBuilder::new()
+        .with_available_outputs(vec![
+            unspent(0, single(0, 2_500_000)),
+            unspent(1, single(0, 2_000_000)),
+            unspent(2, single(0, 1_500_000)),
+            unspent(3, single(0, 400_000)),
+            unspent(4, single(0, 300_000)),
+        ])
+        .add_output(single(1, 4_000_000))
+        .with_remainder_address(address(0))
+        .with_input_selection(InputSelection::MinimiseDust)
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+
+    // Largest-first would leave a 500_000 dust remainder, so another input is added and the dust outputs are swept.
+    assert_eq!(input_indexes(&payload), vec![0, 1, 2, 3, 4]);
+    assert!(essence(&payload).outputs().contains(&single(0, 2_700_000)));
+}
+
+#[test]
+fn too_many_inputs() {
+    for input_selection in [InputSelection::LargestFirst, InputSelection::MinimiseDust] {
+        let result = TransactionBuilder::new()
+            .with_available_outputs(
+                (0..200)
+                    .map(|index| unspent_from(index, single(0, 1_000_000)))
+                    .collect(),
+            )
+            .add_output(single(1, 150_000_000))
+            .with_input_selection(input_selection)
+            .finish(&KeySigner::new(&[0]));
+
+        assert!(matches!(result, Err(Error::InvalidInputOutputCount(150))));
+    }
+}
+
+#[test]
+fn minimise_dust_inputs_capped() {
+    // All 127 inputs are needed, so none of the remaining dust outputs can be swept.
+    let payload = TransactionBuilder::new()
+        .with_available_outputs(
+            (0..127)
+                .map(|index| unspent_from(index, single(0, 1_000_000)))
+                .chain((127..200).map(|index| unspent_from(index, single(0, 100_000))))
+                .collect(),
+        )
+        .add_output(single(1, 127_000_000))
+        .with_remainder_address(address(0))
+        .with_input_selection(InputSelection::MinimiseDust)
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+
+    assert_eq!(input_indexes(&payload).len(), 127);
+    assert_eq!(essence(&payload).outputs(), &[single(1, 127_000_000)]);
+}
+
+#[test]
+fn insufficient_funds() {
+    let result = TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 1_000_000)))
+        .add_output(single(1, 2_000_000))
+        .finish(&KeySigner::new(&[0]));
+
+    assert!(matches!(result, Err(Error::InsufficientFunds(1_000_000, 2_000_000))));
+}
+
+#[test]
+fn missing_remainder_address() {
+    let result = TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 2_000_000)))
+        .add_output(single(1, 1_000_000))
+        .finish(&KeySigner::new(&[0]));
+
+    assert!(matches!(result, Err(Error::MissingField("remainder_address"))));
+}
+
+#[test]
+fn remainder_merged_into_output() {
+    let payload = TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 3_000_000)))
+        .add_output(single(1, 1_000_000))
+        .with_remainder_address(address(1))
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+
+    assert_eq!(essence(&payload).outputs(), &[single(1, 3_000_000)]);
+}
+
+#[test]
+fn reference_unlocks() {
+    let payload = TransactionBuilder::new()
+        .with_available_outputs(vec![
+            unspent(0, single(0, 4_000_000)),
+            unspent(1, single(1, 3_000_000)),
+            unspent(2, single(0, 2_000_000)),
+            unspent(3, single(1, 1_000_000)),
+        ])
+        .add_output(single(2, 10_000_000))
+        .finish(&KeySigner::new(&[0, 1]))
+        .unwrap();
+    let hash = payload.essence().hash();
+    let unlock_blocks = payload.unlock_blocks();
+
+    assert_eq!(input_indexes(&payload), vec![0, 1, 2, 3]);
+    assert!(matches!(&unlock_blocks[0], UnlockBlock::Signature(s) if address(0).verify(&hash, s).is_ok()));
+    assert!(matches!(&unlock_blocks[1], UnlockBlock::Signature(s) if address(1).verify(&hash, s).is_ok()));
+    assert!(matches!(&unlock_blocks[2], UnlockBlock::Reference(r) if r.index() == 0));
+    assert!(matches!(&unlock_blocks[3], UnlockBlock::Reference(r) if r.index() == 1));
+}
+
+#[test]
+fn signer_error() {
+    let result = TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 1_000_000)))
+        .add_output(single(1, 1_000_000))
+        .finish(&KeySigner::new(&[1]));
+
+    assert!(matches!(result, Err(Error::Signer(_))));
+}
+
+#[test]
+fn dust_output_without_allowance() {
+    let result = TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 2_000_000)))
+        .add_output(single(1, 1))
+        .with_remainder_address(address(0))
+        .finish(&KeySigner::new(&[0]));
+
+    assert!(matches!(result, Err(Error::InvalidDustAllowance(a)) if a == address(1)));
+}
+
+#[test]
+fn dust_output_with_allowance() {
+    TransactionBuilder::new()
+        .add_available_output(unspent(0, single(0, 2_000_000)))
+        .add_output(single(1, 1))
+        .with_remainder_address(address(0))
+        .with_dust_balance(address(1), DustBalance::new(1_000_000, 9))
+        .finish(&KeySigner::new(&[0]))
+        .unwrap();
+}
+
+#[test]
+fn dust_allowance_consumed() {
+    let result = TransactionBuilder::new()
+        .add_available_output(unspent(0, dust_allowance(0, 1_000_000)))
+        .add_output(single(1, 1_000_000))
+        .with_dust_balance(address(0), DustBalance::new(1_000_000, 1))
+        .finish(&KeySigner::new(&[0]));
+
+    assert!(matches!(result, Err(Error::InvalidDustAllowance(a)) if a == address(0)));
+}
@@ -15,6 +15,9 @@
 //!          "/dns/entry-mainnet.tanglebay.com/udp/14626/autopeering/iot4By1FD4pFLrGJ6AAe7YEeSu9RbW9xnPUmxMdQenC"
 //!     ],
 //!     "entryNodesPreferIPv6": true,
+//!     "allowPrivateAddresses": false,
+//!     "maxPeersPerSubnet": 4,
+//!     "maxPeersPerResponder": 6
 //! }
 //! ```
 //!
@@ -30,6 +33,9 @@
 //!     "/dns/entry-mainnet.tanglebay.com/udp/14626/autopeering/iot4By1FD4pFLrGJ6AAe7YEeSu9RbW9xnPUmxMdQenC"
 //! ]
 //! entry_nodes_prefer_ipv6 = true
+//! allow_private_addresses = false
+//! max_peers_per_subnet = 4
+//! max_peers_per_responder = 6
 //! ```
 
 use std::{
@@ -47,6 +53,9 @@ const ENTRY_NODES_PREFER_IPV6_DEFAULT: bool = false;
 const RUN_AS_ENTRY_NODE_DEFAULT: bool = false;
 const DROP_NEIGHBORS_ON_SALT_UPDATE_DEFAULT: bool = false;
 const PEER_STORAGE_PATH_DEFAULT: &str = "./storage/mainnet/peers";
+const ALLOW_PRIVATE_ADDRESSES_DEFAULT: bool = true;
+const MAX_PEERS_PER_SUBNET_DEFAULT: usize = 4;
+const MAX_PEERS_PER_RESPONDER_DEFAULT: usize = 6;
 
 /// The autopeering config.
 #[cfg_attr(test, derive(Eq, PartialEq))]
@@ -60,6 +69,9 @@ pub struct AutopeeringConfig {
     run_as_entry_node: bool,
     drop_neighbors_on_salt_update: bool,
     peer_storage_path: PathBuf,
+    allow_private_addresses: bool,
+    max_peers_per_subnet: usize,
+    max_peers_per_responder: usize,
 }
 
 impl AutopeeringConfig {
@@ -107,6 +119,22 @@ impl AutopeeringConfig {
     pub fn peer_storage_path(&self) -> &Path {
         &self.peer_storage_path
     }
+
+    /// Whether discovered peers with private or loopback addresses are accepted. This is the default, so private
+    /// networks work out of the box, and public networks should disable it.
+    pub fn allow_private_addresses(&self) -> bool {
+        self.allow_private_addresses
+    }
+
+    /// The maximum number of known peers within the same /24 (IPv4) or /48 (IPv6) subnet.
+    pub fn max_peers_per_subnet(&self) -> usize {
+        self.max_peers_per_subnet
+    }
+
+    /// The maximum number of new peers accepted from the discovery responses of a single responder.
+    pub fn max_peers_per_responder(&self) -> usize {
+        self.max_peers_per_responder
+    }
 }
 
 // Note: In case someone wonders why we use `Option<bool>`: Although serde actually provides a way to allow for the
@@ -140,6 +168,15 @@ pub struct AutopeeringConfigBuilder {
     /// The peer storage path.
     #[serde(alias = "peerStoragePath")]
     pub peer_storage_path: Option<PathBuf>,
+    /// Whether discovered peers with private or loopback addresses are accepted.
+    #[serde(alias = "allowPrivateAddresses")]
+    pub allow_private_addresses: Option<bool>,
+    /// The maximum number of known peers within the same /24 (IPv4) or /48 (IPv6) subnet.
+    #[serde(alias = "maxPeersPerSubnet")]
+    pub max_peers_per_subnet: Option<usize>,
+    /// The maximum number of new peers accepted from the discovery responses of a single responder.
+    #[serde(alias = "maxPeersPerResponder")]
+    pub max_peers_per_responder: Option<usize>,
 }
 
 impl AutopeeringConfigBuilder {
@@ -165,6 +202,9 @@ impl AutopeeringConfigBuilder {
             peer_storage_path: self
                 .peer_storage_path
                 .unwrap_or_else(|| PEER_STORAGE_PATH_DEFAULT.into()),
+            allow_private_addresses: self.allow_private_addresses.unwrap_or(ALLOW_PRIVATE_ADDRESSES_DEFAULT),
+            max_peers_per_subnet: self.max_peers_per_subnet.unwrap_or(MAX_PEERS_PER_SUBNET_DEFAULT),
+            max_peers_per_responder: self.max_peers_per_responder.unwrap_or(MAX_PEERS_PER_RESPONDER_DEFAULT),
         }
     }
 }
@@ -180,6 +220,9 @@ impl Default for AutopeeringConfigBuilder {
             run_as_entry_node: Some(RUN_AS_ENTRY_NODE_DEFAULT),
             drop_neighbors_on_salt_update: Some(DROP_NEIGHBORS_ON_SALT_UPDATE_DEFAULT),
             peer_storage_path: Some(PEER_STORAGE_PATH_DEFAULT.into()),
+            allow_private_addresses: Some(ALLOW_PRIVATE_ADDRESSES_DEFAULT),
+            max_peers_per_subnet: Some(MAX_PEERS_PER_SUBNET_DEFAULT),
+            max_peers_per_responder: Some(MAX_PEERS_PER_RESPONDER_DEFAULT),
         }
     }
 }
@@ -218,7 +261,10 @@ mod tests {
             "entryNodesPreferIPv6": true,
             "runAsEntryNode": false,
             "dropNeighborsOnSaltUpdate": false,
-            "peerStoragePath": "./storage/mainnet/peers"
+            "peerStoragePath": "./storage/mainnet/peers",
+            "allowPrivateAddresses": false,
+            "maxPeersPerSubnet": 4,
+            "maxPeersPerResponder": 6
         }"#;
 
         serde_json::from_str::<AutopeeringConfigBuilder>(config_json_str)
@@ -240,6 +286,9 @@ mod tests {
             run_as_entry_node = false
             drop_neighbors_on_salt_update = false
             peer_storage_path = "./storage/mainnet/peers"
+            allow_private_addresses = false
+            max_peers_per_subnet = 4
+            max_peers_per_responder = 6
         "#;
 
         toml::from_str::<AutopeeringConfigBuilder>(toml_config_str)
@@ -260,7 +309,10 @@ mod tests {
             entry_nodes_prefer_ipv6: true,
             run_as_entry_node: false,
             drop_neighbors_on_salt_update: false,
-            peer_storage_path: "./storage/mainnet/peers".into()
+            peer_storage_path: "./storage/mainnet/peers".into(),
+            allow_private_addresses: false,
+            max_peers_per_subnet: 4,
+            max_peers_per_responder: 6,
         }
     }
 
@@ -0,0 +1,189 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, net::IpAddr};
+
+use crate::{
+    peer::peer_id::PeerId,
+    time::{self, Timestamp, HOUR},
+};
+
+// The maximum number of peers whose reports are remembered.
+const MAX_VOTERS: usize = 64;
+// The minimum number of peers that need to agree on an external IP address.
+const MIN_VOTES: usize = 3;
+// Time after which a report isn't considered anymore (in seconds).
+const VOTE_LIFETIME_SECS: u64 = HOUR;
+
+#[derive(Clone, Copy, Debug)]
+struct Vote {
+    ip: IpAddr,
+    timestamp: Timestamp,
+}
+
+/// Collects the external IP addresses of the local peer as perceived by other peers, and determines the actual one by
+/// majority vote.
+///
+/// Each peer has a single vote, which is replaced by its latest report. IPv4 and IPv6 addresses are voted on
+/// separately.
+#[derive(Debug, Default)]
+pub(crate) struct ExternalIpVotes {
+    votes: HashMap<PeerId, Vote>,
+}
+
+impl ExternalIpVotes {
+    /// Records the external IP address reported by a peer.
+    ///
+    /// Returns the IP address that holds the majority of the votes of the same address family, if any.
+    pub(crate) fn vote(&mut self, voter: PeerId, ip: IpAddr) -> Option<(IpAddr, usize)> {
+        self.vote_at(voter, ip, time::unix_now_secs())
+    }
+
+    fn vote_at(&mut self, voter: PeerId, ip: IpAddr, now: Timestamp) -> Option<(IpAddr, usize)> {
+        if !is_valid_external_ip(&ip) {
+            return None;
+        }
+
+        self.votes
+            .retain(|_, vote| time::delta(vote.timestamp, now).map_or(true, |age| age < VOTE_LIFETIME_SECS));
+
+        if self.votes.len() >= MAX_VOTERS && !self.votes.contains_key(&voter) {
+            // Make room by removing the oldest vote.
+            if let Some(oldest) = self
+                .votes
+                .iter()
+                .min_by_key(|(_, vote)| vote.timestamp)
+                .map(|(peer_id, _)| *peer_id)
+            {
+                self.votes.remove(&oldest);
+            }
+        }
+
+        self.votes.insert(voter, Vote { ip, timestamp: now });
+
+        self.majority(ip.is_ipv6())
+    }
+
+    /// Returns the IP address of the given address family that holds the majority of the votes, if any.
+    fn majority(&self, ipv6: bool) -> Option<(IpAddr, usize)> {
+        let mut tally = HashMap::<IpAddr, usize>::new();
+
+        for vote in self.votes.values().filter(|vote| vote.ip.is_ipv6() == ipv6) {
+            *tally.entry(vote.ip).or_default() += 1;
+        }
+
+        let total = tally.values().sum::<usize>();
+
+        tally
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .filter(|(_, count)| *count >= MIN_VOTES && *count * 2 > total)
+    }
+}
+
+// Peers reporting these addresses are either misconfigured or lying.
+fn is_valid_external_ip(ip: &IpAddr) -> bool {
+    !(ip.is_unspecified() || ip.is_loopback() || ip.is_multicast())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: Timestamp = 1_000_000;
+
+    fn voters(n: usize) -> Vec<PeerId> {
+        (0..n).map(|_| PeerId::generate()).collect()
+    }
+
+    #[test]
+    fn majority_requires_min_votes() {
+        let mut votes = ExternalIpVotes::default();
+        let ip: IpAddr = "192.0.2.1".parse().unwrap();
+        let voters = voters(MIN_VOTES);
+
+        for voter in &voters[..MIN_VOTES - 1] {
+            assert_eq!(votes.vote_at(*voter, ip, NOW), None);
+        }
+        assert_eq!(votes.vote_at(voters[MIN_VOTES - 1], ip, NOW), Some((ip, MIN_VOTES)));
+    }
+
+    #[test]
+    fn majority_requires_more_than_half() {
+        let mut votes = ExternalIpVotes::default();
+        let ip1: IpAddr = "192.0.2.1".parse().unwrap();
+        let ip2: IpAddr = "192.0.2.2".parse().unwrap();
+        let voters = voters(6);
+
+        for voter in &voters[..3] {
+            votes.vote_at(*voter, ip1, NOW);
+        }
+        for voter in &voters[3..5] {
+            votes.vote_at(*voter, ip2, NOW);
+        }
+        assert_eq!(votes.majority(false), Some((ip1, 3)));
+
+        // A tie isn't a majority.
+        assert_eq!(votes.vote_at(voters[5], ip2, NOW), None);
+    }
+
+    #[test]
+    fn one_vote_per_peer() {
+        let mut votes = ExternalIpVotes::default();
+        let ip1: IpAddr = "192.0.2.1".parse().unwrap();
+        let ip2: IpAddr = "192.0.2.2".parse().unwrap();
+        let voter = PeerId::generate();
+
+        for _ in 0..MIN_VOTES {
+            assert_eq!(votes.vote_at(voter, ip1, NOW), None);
+        }
+
+        let voters = voters(MIN_VOTES);
+        for voter in &voters {
+            votes.vote_at(*voter, ip2, NOW);
+        }
+        assert_eq!(votes.majority(false), Some((ip2, MIN_VOTES)));
+    }
+
+    #[test]
+    fn address_families_are_separate() {
+        let mut votes = ExternalIpVotes::default();
+        let ip4: IpAddr = "192.0.2.1".parse().unwrap();
+        let ip6: IpAddr = "2001:db8::1".parse().unwrap();
+        let voters = voters(2 * MIN_VOTES);
+
+        for voter in &voters[..MIN_VOTES] {
+            votes.vote_at(*voter, ip4, NOW);
+        }
+        for voter in &voters[MIN_VOTES..] {
+            votes.vote_at(*voter, ip6, NOW);
+        }
+
+        assert_eq!(votes.majority(false), Some((ip4, MIN_VOTES)));
+        assert_eq!(votes.majority(true), Some((ip6, MIN_VOTES)));
+    }
+
+    #[test]
+    fn expired_votes_are_ignored() {
+        let mut votes = ExternalIpVotes::default();
+        let ip: IpAddr = "192.0.2.1".parse().unwrap();
+        let voters = voters(MIN_VOTES);
+
+        for voter in &voters[..MIN_VOTES - 1] {
+            votes.vote_at(*voter, ip, NOW);
+        }
+        assert_eq!(votes.vote_at(voters[MIN_VOTES - 1], ip, NOW + VOTE_LIFETIME_SECS), None);
+    }
+
+    #[test]
+    fn invalid_addresses_are_ignored() {
+        let mut votes = ExternalIpVotes::default();
+
+        for ip in ["0.0.0.0", "127.0.0.1", "::1", "224.0.0.1"] {
+            for voter in voters(MIN_VOTES) {
+                assert_eq!(votes.vote_at(voter, ip.parse().unwrap(), NOW), None);
+            }
+        }
+        assert!(votes.votes.is_empty());
+    }
+}
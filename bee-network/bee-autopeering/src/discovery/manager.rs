@@ -1,13 +1,20 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use rand::{seq::index, Rng as _};
 
 use crate::{
     config::AutopeeringConfig,
-    discovery::messages::{DiscoveryRequest, DiscoveryResponse, VerificationRequest, VerificationResponse},
+    discovery::{
+        external_ip::ExternalIpVotes,
+        messages::{DiscoveryRequest, DiscoveryResponse, VerificationRequest, VerificationResponse},
+        sanity::{PeerSanityCheck, ResponderQuotas},
+    },
     event::{Event, EventTx},
     hash::message_hash,
     local::{
@@ -43,6 +50,7 @@ const MIN_VERIFIED_IN_RESPONSE: usize = 1;
 pub(crate) struct DiscoveryManagerConfig {
     pub(crate) entry_nodes: Vec<AutopeeringMultiaddr>,
     pub(crate) entry_nodes_prefer_ipv6: bool,
+    pub(crate) sanity_check: PeerSanityCheck,
     pub(crate) version: u32,
     pub(crate) network_id: u32,
 }
@@ -52,6 +60,7 @@ impl DiscoveryManagerConfig {
         Self {
             entry_nodes: config.entry_nodes().to_vec(),
             entry_nodes_prefer_ipv6: config.entry_nodes_prefer_ipv6(),
+            sanity_check: PeerSanityCheck::new(config),
             version,
             network_id,
         }
@@ -121,6 +130,7 @@ impl<S: PeerStore + 'static> DiscoveryManager<S> {
         let DiscoveryManagerConfig {
             mut entry_nodes,
             entry_nodes_prefer_ipv6,
+            sanity_check,
             version,
             network_id,
         } = config;
@@ -156,6 +166,10 @@ impl<S: PeerStore + 'static> DiscoveryManager<S> {
             event_tx,
             active_peers: active_peers.clone(),
             replacements,
+            external_ip_votes: ExternalIpVotes::default(),
+            sanity_check,
+            responder_quotas: ResponderQuotas::default(),
+            total_rejected: 0,
         };
 
         task_mngr.run::<DiscoveryRecvHandler>(discovery_recv_handler);
@@ -174,6 +188,11 @@ struct DiscoveryRecvHandler {
     event_tx: EventTx,
     active_peers: ActivePeersList,
     replacements: ReplacementPeersList,
+    external_ip_votes: ExternalIpVotes,
+    sanity_check: PeerSanityCheck,
+    responder_quotas: ResponderQuotas,
+    // The number of discovered peers that failed the sanity checks.
+    total_rejected: u64,
 }
 
 #[async_trait::async_trait]
@@ -194,6 +213,10 @@ impl Runnable for DiscoveryRecvHandler {
             event_tx,
             active_peers,
             replacements,
+            mut external_ip_votes,
+            sanity_check,
+            mut responder_quotas,
+            mut total_rejected,
             ..
         } = self;
 
@@ -238,6 +261,17 @@ impl Runnable for DiscoveryRecvHandler {
                                 } else {
                                     log::trace!("Received valid verification request from {}.", &peer_id);
 
+                                    // Only already verified peers have a say in what our external IP address is.
+                                    if peer::is_verified(&peer_id, &active_peers) {
+                                        update_external_ip(
+                                            &peer_id,
+                                            verif_req.target_addr(),
+                                            &mut external_ip_votes,
+                                            &local,
+                                            &event_tx,
+                                        );
+                                    }
+
                                     handle_verification_request(verif_req, ctx);
                                 }
                             }
@@ -253,6 +287,14 @@ impl Runnable for DiscoveryRecvHandler {
                                     Ok(verif_reqval) => {
                                         log::trace!("Received valid verification response from {}.", &peer_id);
 
+                                        update_external_ip(
+                                            &peer_id,
+                                            verif_res.target_addr(),
+                                            &mut external_ip_votes,
+                                            &local,
+                                            &event_tx,
+                                        );
+
                                         handle_verification_response(verif_res, verif_reqval, ctx);
                                     }
                                     Err(e) => {
@@ -290,7 +332,14 @@ impl Runnable for DiscoveryRecvHandler {
                                     Ok(disc_reqval) => {
                                         log::trace!("Received valid discovery response from {}.", &peer_id);
 
-                                        handle_discovery_response(disc_res, disc_reqval, ctx);
+                                        handle_discovery_response(
+                                            disc_res,
+                                            disc_reqval,
+                                            &sanity_check,
+                                            &mut responder_quotas,
+                                            &mut total_rejected,
+                                            ctx,
+                                        );
                                     }
                                     Err(e) => {
                                         log::debug!("Received invalid discovery response from {}. Reason: {:?}", &peer_id, e);
@@ -546,7 +595,6 @@ fn validate_verification_request(
         // NOTE: the validity of the transmitted source and target addresses is ensured through the
         // `VerificationRequest` type.
         // TODO: maybe add check whether the peer sent the correct source address in the packet.
-        // TODO: store own external IP address as perceived by the peer
         Ok(())
     }
 }
@@ -601,11 +649,7 @@ fn validate_discovery_response(
 
     if let Some(reqv) = request_mngr.remove_request::<DiscoveryRequest>(peer_id) {
         if disc_res.request_hash() == &reqv.request_hash[..] {
-            // TODO: consider performing some checks on the peers we received, for example:
-            // * does the peer have necessary services (autopeering, gossip, fpc, ...)
-            // * is the ip address valid (not a 0.0.0.0, etc)
-            // for peer in disc_res.peers() {}
-
+            // Note: the received peers are checked individually when handling the response.
             Ok(reqv)
         } else {
             Err(IncorrectRequestHash)
@@ -687,6 +731,27 @@ fn handle_verification_response(verif_res: VerificationResponse, verif_reqval: R
     }
 }
 
+/// Records the external IP address of the local peer as perceived by a peer, and updates it if the majority of peers
+/// agrees on a new one.
+fn update_external_ip(
+    peer_id: &PeerId,
+    perceived_ip: IpAddr,
+    external_ip_votes: &mut ExternalIpVotes,
+    local: &Local,
+    event_tx: &EventTx,
+) {
+    if let Some((ip, votes)) = external_ip_votes.vote(*peer_id, perceived_ip) {
+        if local.set_external_ip(ip) {
+            log::info!("External IP address is {} according to {} peers.", ip, votes);
+
+            // Panic: we don't allow channel send errors.
+            event_tx
+                .send(Event::ExternalIpUpdated { ip, votes })
+                .expect("error publishing external-ip-updated event");
+        }
+    }
+}
+
 fn handle_discovery_request(_disc_req: DiscoveryRequest, ctx: RecvContext) {
     log::trace!("Handling discovery request from {}.", ctx.peer_id);
 
@@ -708,13 +773,41 @@ fn handle_discovery_request(_disc_req: DiscoveryRequest, ctx: RecvContext) {
         .expect("error sending verification response to server");
 }
 
-fn handle_discovery_response(disc_res: DiscoveryResponse, disc_reqval: RequestValue, ctx: RecvContext) {
+fn handle_discovery_response(
+    disc_res: DiscoveryResponse,
+    disc_reqval: RequestValue,
+    sanity_check: &PeerSanityCheck,
+    responder_quotas: &mut ResponderQuotas,
+    total_rejected: &mut u64,
+    ctx: RecvContext,
+) {
     log::trace!("Handling discovery response from {}.", ctx.peer_id);
 
+    responder_quotas.retain_active(ctx.active_peers);
+
+    let num_added_before = responder_quotas.added_by(ctx.peer_id);
     let mut num_added = 0;
+    let mut num_rejected = 0;
 
     // Add discovered peers to the peer list and peer store.
     for peer in disc_res.into_peers() {
+        if peer::is_known(peer.peer_id(), ctx.local, ctx.active_peers, ctx.replacements) {
+            continue;
+        }
+
+        let num_added_by_responder = num_added_before + num_added;
+
+        if let Err(reason) = sanity_check.check(&peer, num_added_by_responder, ctx.active_peers, ctx.replacements) {
+            log::debug!(
+                "Rejected {} discovered by {}. Reason: {}",
+                peer.peer_id(),
+                ctx.peer_id,
+                reason
+            );
+            num_rejected += 1;
+            continue;
+        }
+
         // Note: we only fire `PeerDiscovered` if it also can be verified, so we don't fire it just yet.
         if let Some(peer_id) = add_peer::<false>(peer, ctx.local, ctx.active_peers, ctx.replacements) {
             log::debug!("Added (unverified): {}.", peer_id);
@@ -734,6 +827,21 @@ fn handle_discovery_response(disc_res: DiscoveryResponse, disc_reqval: RequestVa
         .metrics_mut()
         .set_last_new_peers(num_added);
 
+    responder_quotas.add(*ctx.peer_id, num_added);
+
+    if num_rejected > 0 {
+        *total_rejected += num_rejected as u64;
+
+        // Panic: we don't allow channel send errors.
+        ctx.event_tx
+            .send(Event::DiscoveredPeersRejected {
+                peer_id: *ctx.peer_id,
+                rejected: num_rejected,
+                total_rejected: *total_rejected,
+            })
+            .expect("error publishing discovered-peers-rejected event");
+    }
+
     // Send the response notification.
     if let Some(tx) = disc_reqval.response_tx {
         // Panic: we don't allow channel send errors.
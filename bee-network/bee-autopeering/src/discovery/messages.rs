@@ -49,6 +49,10 @@ impl VerificationRequest {
         self.source_addr
     }
 
+    pub(crate) fn target_addr(&self) -> IpAddr {
+        self.target_addr
+    }
+
     pub(crate) fn from_protobuf(bytes: &[u8]) -> Result<Self, Error> {
         let proto::Ping {
             version,
@@ -132,6 +136,10 @@ impl VerificationResponse {
         &self.services
     }
 
+    pub(crate) fn target_addr(&self) -> IpAddr {
+        self.target_addr
+    }
+
     pub(crate) fn from_protobuf(bytes: &[u8]) -> Result<Self, Error> {
         let proto::Pong {
             req_hash,
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod external_ip;
 pub(crate) mod manager;
 pub(crate) mod messages;
 pub(crate) mod query;
+pub(crate) mod sanity;
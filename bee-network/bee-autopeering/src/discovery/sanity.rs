@@ -0,0 +1,325 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sanity checks for peers received in discovery responses.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{
+    config::AutopeeringConfig,
+    local::services::AUTOPEERING_SERVICE_NAME,
+    peer::{
+        lists::{ActivePeersList, ReplacementPeersList},
+        Peer, PeerId,
+    },
+};
+
+/// The reason a discovered peer was rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RejectReason {
+    /// The peer doesn't announce the autopeering service.
+    NoAutopeeringService,
+    /// The peer's address can never be reached (unspecified, multicast, broadcast).
+    InvalidAddress,
+    /// The peer's address is a private or loopback address, which isn't allowed.
+    PrivateAddress,
+    /// There are already too many peers from the same subnet.
+    SubnetLimitReached,
+    /// The responder already injected the maximum number of peers.
+    ResponderLimitReached,
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoAutopeeringService => write!(f, "no autopeering service"),
+            Self::InvalidAddress => write!(f, "invalid address"),
+            Self::PrivateAddress => write!(f, "private address"),
+            Self::SubnetLimitReached => write!(f, "subnet limit reached"),
+            Self::ResponderLimitReached => write!(f, "responder limit reached"),
+        }
+    }
+}
+
+/// Decides which of the peers received in a discovery response are accepted.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PeerSanityCheck {
+    allow_private_addresses: bool,
+    max_peers_per_subnet: usize,
+    max_peers_per_responder: usize,
+}
+
+impl PeerSanityCheck {
+    pub(crate) fn new(config: &AutopeeringConfig) -> Self {
+        Self {
+            allow_private_addresses: config.allow_private_addresses(),
+            max_peers_per_subnet: config.max_peers_per_subnet(),
+            max_peers_per_responder: config.max_peers_per_responder(),
+        }
+    }
+
+    /// Checks a discovered peer, given the number of peers its responder already added.
+    ///
+    /// The subnet limit only applies to globally routable addresses, so that private networks aren't affected by it.
+    pub(crate) fn check(
+        &self,
+        peer: &Peer,
+        num_added_by_responder: usize,
+        active_peers: &ActivePeersList,
+        replacements: &ReplacementPeersList,
+    ) -> Result<(), RejectReason> {
+        if num_added_by_responder >= self.max_peers_per_responder {
+            return Err(RejectReason::ResponderLimitReached);
+        }
+
+        if !peer.has_service(AUTOPEERING_SERVICE_NAME) {
+            return Err(RejectReason::NoAutopeeringService);
+        }
+
+        let ip = peer.ip_address();
+
+        if !is_valid_address(&ip) {
+            return Err(RejectReason::InvalidAddress);
+        }
+
+        if is_private_address(&ip) {
+            return if self.allow_private_addresses {
+                Ok(())
+            } else {
+                Err(RejectReason::PrivateAddress)
+            };
+        }
+
+        let num_in_subnet = active_peers
+            .read()
+            .iter()
+            .filter(|p| same_subnet(&p.peer().ip_address(), &ip))
+            .count()
+            + replacements
+                .read()
+                .iter()
+                .filter(|p| same_subnet(&p.ip_address(), &ip))
+                .count();
+
+        if num_in_subnet >= self.max_peers_per_subnet {
+            Err(RejectReason::SubnetLimitReached)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Counts the new peers each responder added through its discovery responses, so that a responder can't exceed its
+/// limit by sending several responses.
+#[derive(Debug, Default)]
+pub(crate) struct ResponderQuotas(HashMap<PeerId, usize>);
+
+impl ResponderQuotas {
+    /// Forgets about the responders that aren't active peers anymore.
+    pub(crate) fn retain_active(&mut self, active_peers: &ActivePeersList) {
+        let active_peers = active_peers.read();
+
+        self.0.retain(|peer_id, _| active_peers.contains(peer_id));
+    }
+
+    /// Returns the number of new peers a responder added so far.
+    pub(crate) fn added_by(&self, responder: &PeerId) -> usize {
+        self.0.get(responder).copied().unwrap_or(0)
+    }
+
+    /// Records the number of new peers a responder added.
+    pub(crate) fn add(&mut self, responder: PeerId, num_added: usize) {
+        *self.0.entry(responder).or_default() += num_added;
+    }
+}
+
+// Peers announcing these addresses can't be contacted at all.
+fn is_valid_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => !(ip.is_unspecified() || ip.is_multicast() || ip.is_broadcast()),
+        IpAddr::V6(ip) => !(ip.is_unspecified() || ip.is_multicast()),
+    }
+}
+
+// Addresses that aren't reachable from the public internet.
+fn is_private_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_private() || ip.is_loopback() || ip.is_link_local() || is_shared_v4(ip),
+        IpAddr::V6(ip) => ip.is_loopback() || is_unique_local_v6(ip) || is_unicast_link_local_v6(ip),
+    }
+}
+
+// 100.64.0.0/10 (carrier-grade NAT).
+fn is_shared_v4(ip: &Ipv4Addr) -> bool {
+    ip.octets()[0] == 100 && (ip.octets()[1] & 0b1100_0000 == 0b0100_0000)
+}
+
+// fc00::/7
+fn is_unique_local_v6(ip: &Ipv6Addr) -> bool {
+    (ip.segments()[0] & 0xfe00) == 0xfc00
+}
+
+// fe80::/10
+fn is_unicast_link_local_v6(ip: &Ipv6Addr) -> bool {
+    (ip.segments()[0] & 0xffc0) == 0xfe80
+}
+
+/// Whether two addresses belong to the same /24 (IPv4) or /48 (IPv6) subnet.
+fn same_subnet(a: &IpAddr, b: &IpAddr) -> bool {
+    match (a, b) {
+        (IpAddr::V4(a), IpAddr::V4(b)) => a.octets()[..3] == b.octets()[..3],
+        (IpAddr::V6(a), IpAddr::V6(b)) => a.segments()[..3] == b.segments()[..3],
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::signatures::ed25519::SecretKey as PrivateKey;
+
+    use super::*;
+    use crate::local::services::ServiceProtocol;
+
+    fn sanity_check(allow_private_addresses: bool) -> PeerSanityCheck {
+        PeerSanityCheck {
+            allow_private_addresses,
+            max_peers_per_subnet: 2,
+            max_peers_per_responder: 3,
+        }
+    }
+
+    fn peer(ip: &str) -> Peer {
+        let public_key = PrivateKey::generate().unwrap().public_key();
+        let mut peer = Peer::new(ip.parse().unwrap(), public_key);
+        peer.add_service(AUTOPEERING_SERVICE_NAME, ServiceProtocol::Udp, 14626);
+        peer
+    }
+
+    fn lists() -> (ActivePeersList, ReplacementPeersList) {
+        (ActivePeersList::default(), ReplacementPeersList::default())
+    }
+
+    #[test]
+    fn public_peer_is_accepted() {
+        let (active_peers, replacements) = lists();
+
+        assert_eq!(
+            sanity_check(false).check(&peer("203.0.113.1"), 0, &active_peers, &replacements),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn peer_without_autopeering_service_is_rejected() {
+        let (active_peers, replacements) = lists();
+        let public_key = PrivateKey::generate().unwrap().public_key();
+        let peer = Peer::new("203.0.113.1".parse().unwrap(), public_key);
+
+        assert_eq!(
+            sanity_check(false).check(&peer, 0, &active_peers, &replacements),
+            Err(RejectReason::NoAutopeeringService)
+        );
+    }
+
+    #[test]
+    fn invalid_addresses_are_rejected() {
+        let (active_peers, replacements) = lists();
+
+        for ip in ["0.0.0.0", "255.255.255.255", "224.0.0.1", "::", "ff02::1"] {
+            assert_eq!(
+                sanity_check(true).check(&peer(ip), 0, &active_peers, &replacements),
+                Err(RejectReason::InvalidAddress)
+            );
+        }
+    }
+
+    #[test]
+    fn private_addresses_are_rejected_unless_allowed() {
+        let (active_peers, replacements) = lists();
+
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.0.1",
+            "100.64.0.1",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+        ] {
+            assert_eq!(
+                sanity_check(false).check(&peer(ip), 0, &active_peers, &replacements),
+                Err(RejectReason::PrivateAddress)
+            );
+            assert_eq!(
+                sanity_check(true).check(&peer(ip), 0, &active_peers, &replacements),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn subnet_limit() {
+        let (active_peers, replacements) = lists();
+
+        active_peers.write().insert(peer("203.0.113.1").into());
+        replacements.write().insert(peer("203.0.113.2"));
+
+        assert_eq!(
+            sanity_check(false).check(&peer("203.0.113.3"), 0, &active_peers, &replacements),
+            Err(RejectReason::SubnetLimitReached)
+        );
+        assert_eq!(
+            sanity_check(false).check(&peer("203.0.114.1"), 0, &active_peers, &replacements),
+            Ok(())
+        );
+
+        active_peers.write().insert(peer("2001:db8:1:1::1").into());
+        active_peers.write().insert(peer("2001:db8:1:2::1").into());
+
+        assert_eq!(
+            sanity_check(false).check(&peer("2001:db8:1:3::1"), 0, &active_peers, &replacements),
+            Err(RejectReason::SubnetLimitReached)
+        );
+        assert_eq!(
+            sanity_check(false).check(&peer("2001:db8:2::1"), 0, &active_peers, &replacements),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn responder_limit() {
+        let (active_peers, replacements) = lists();
+
+        assert_eq!(
+            sanity_check(false).check(&peer("203.0.113.1"), 3, &active_peers, &replacements),
+            Err(RejectReason::ResponderLimitReached)
+        );
+    }
+
+    #[test]
+    fn responder_quotas_span_responses() {
+        let (active_peers, _) = lists();
+        let responder = peer("203.0.113.1");
+        let responder_id = *responder.peer_id();
+        let mut quotas = ResponderQuotas::default();
+
+        active_peers.write().insert(responder.into());
+
+        quotas.add(responder_id, 2);
+        quotas.retain_active(&active_peers);
+        quotas.add(responder_id, 1);
+
+        assert_eq!(quotas.added_by(&responder_id), 3);
+
+        active_peers.write().remove(&responder_id);
+        quotas.retain_active(&active_peers);
+
+        assert_eq!(quotas.added_by(&responder_id), 0);
+    }
+}
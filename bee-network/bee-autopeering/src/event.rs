@@ -3,7 +3,7 @@
 
 //! Events published to the user.
 
-use std::fmt;
+use std::{fmt, net::IpAddr};
 
 use tokio::sync::mpsc;
 
@@ -51,6 +51,22 @@ pub enum Event {
         /// The identity of the dropped peer.
         peer_id: PeerId,
     },
+    /// The external IP address of the local peer, as perceived by the majority of its peers, changed.
+    ExternalIpUpdated {
+        /// The new external IP address.
+        ip: IpAddr,
+        /// The number of peers that reported that address.
+        votes: usize,
+    },
+    /// Peers received in a discovery response failed the sanity checks.
+    DiscoveredPeersRejected {
+        /// The identity of the peer that sent the discovery response.
+        peer_id: PeerId,
+        /// The number of peers rejected from that response.
+        rejected: usize,
+        /// The total number of discovered peers rejected since startup.
+        total_rejected: u64,
+    },
 }
 
 /// Exposes autopeering related events.
@@ -79,6 +95,16 @@ impl fmt::Display for Event {
             OutgoingPeering { peer, .. } => write!(f, "Peered: {} (outgoing).", peer.peer_id()),
             IncomingPeering { peer, .. } => write!(f, "Peered: {} (incoming).", peer.peer_id()),
             PeeringDropped { peer_id } => write!(f, "Dropped: {}.", peer_id),
+            ExternalIpUpdated { ip, votes } => write!(f, "External IP updated: {} ({} votes).", ip, votes),
+            DiscoveredPeersRejected {
+                peer_id,
+                rejected,
+                total_rejected,
+            } => write!(
+                f,
+                "Rejected {} peer/s discovered by {} (total: {}).",
+                rejected, peer_id, total_rejected
+            ),
         }
     }
 }
@@ -0,0 +1,341 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Inspection and control of a running autopeering service.
+
+use std::sync::Arc;
+
+use crate::{
+    discovery::{
+        manager,
+        query::{self, QueryContext},
+    },
+    event::EventTx,
+    local::Local,
+    peer::{
+        lists::{ActivePeersList, ReplacementPeersList},
+        Peer, PeerId,
+    },
+    peering::{
+        filter::NeighborFilter,
+        manager::{self as peering_manager, InboundNeighborhood, OutboundNeighborhood},
+        neighbor::Neighbor,
+        Distance,
+    },
+    server::ServerTx,
+    NeighborValidator,
+};
+
+// Erases the validator type of the `NeighborFilter`, so that the handle doesn't need to be generic.
+trait NeighborFilterControl: Send + Sync {
+    fn reject(&self, peer_id: PeerId);
+    fn blacklist(&self, peer_id: PeerId) -> bool;
+}
+
+impl<V: NeighborValidator> NeighborFilterControl for NeighborFilter<V> {
+    fn reject(&self, peer_id: PeerId) {
+        self.add(peer_id);
+    }
+
+    fn blacklist(&self, peer_id: PeerId) -> bool {
+        NeighborFilter::blacklist(self, peer_id)
+    }
+}
+
+/// A handle to inspect and control the autopeering service.
+#[derive(Clone)]
+pub struct AutopeeringHandle {
+    local: Local,
+    active_peers: ActivePeersList,
+    replacements: ReplacementPeersList,
+    inbound_nbh: InboundNeighborhood,
+    outbound_nbh: OutboundNeighborhood,
+    nb_filter: Arc<dyn NeighborFilterControl>,
+    query_ctx: QueryContext,
+    server_tx: ServerTx,
+    event_tx: EventTx,
+}
+
+impl AutopeeringHandle {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new<V: NeighborValidator + 'static>(
+        local: Local,
+        active_peers: ActivePeersList,
+        replacements: ReplacementPeersList,
+        inbound_nbh: InboundNeighborhood,
+        outbound_nbh: OutboundNeighborhood,
+        nb_filter: NeighborFilter<V>,
+        query_ctx: QueryContext,
+        server_tx: ServerTx,
+        event_tx: EventTx,
+    ) -> Self {
+        Self {
+            local,
+            active_peers,
+            replacements,
+            inbound_nbh,
+            outbound_nbh,
+            nb_filter: Arc::new(nb_filter),
+            query_ctx,
+            server_tx,
+            event_tx,
+        }
+    }
+
+    /// Returns all known peers, i.e. the active peers followed by the replacement peers.
+    pub fn known_peers(&self) -> Vec<Peer> {
+        let mut peers = self.active_peers();
+        peers.extend(self.replacement_peers());
+        peers
+    }
+
+    /// Returns the active peers that have been verified.
+    pub fn verified_peers(&self) -> Vec<Peer> {
+        manager::get_verified_peers(&self.active_peers)
+            .into_iter()
+            .map(|p| p.into_peer())
+            .collect()
+    }
+
+    /// Returns the active peers.
+    pub fn active_peers(&self) -> Vec<Peer> {
+        self.active_peers.read().iter().map(|p| p.peer().clone()).collect()
+    }
+
+    /// Returns the replacement peers.
+    pub fn replacement_peers(&self) -> Vec<Peer> {
+        self.replacements.read().iter().cloned().collect()
+    }
+
+    /// Returns the neighbors that requested peering with the local peer, together with their distances.
+    pub fn inbound_neighbors(&self) -> Vec<(Peer, Distance)> {
+        into_peers_with_distance(self.inbound_nbh.neighbors())
+    }
+
+    /// Returns the neighbors the local peer requested peering with, together with their distances.
+    pub fn outbound_neighbors(&self) -> Vec<(Peer, Distance)> {
+        into_peers_with_distance(self.outbound_nbh.neighbors())
+    }
+
+    /// Returns the expiration time of the public salt (in seconds since Unix epoch).
+    pub fn public_salt_expiration_time(&self) -> u64 {
+        self.local.public_salt().expiration_time()
+    }
+
+    /// Returns the expiration time of the private salt (in seconds since Unix epoch).
+    pub fn private_salt_expiration_time(&self) -> u64 {
+        self.local.private_salt().expiration_time()
+    }
+
+    /// Queries peers for new peers right away instead of waiting for the next discovery interval.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn query(&self) {
+        query::query_fn()(&self.query_ctx);
+    }
+
+    /// Drops a neighbor.
+    ///
+    /// Returns `false` if the peer isn't a neighbor.
+    pub fn drop_neighbor(&self, peer_id: &PeerId) -> bool {
+        let inbound = self.inbound_nbh.remove_neighbor(peer_id);
+        let outbound = self.outbound_nbh.remove_neighbor(peer_id);
+
+        if let Some(peer) = outbound.or(inbound) {
+            // Prevent the peer from being selected again right away.
+            self.nb_filter.reject(*peer_id);
+
+            peering_manager::send_drop_peering_request_to_peer(
+                peer,
+                &self.server_tx,
+                &self.event_tx,
+                &self.inbound_nbh,
+                &self.outbound_nbh,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Excludes a peer from becoming a neighbor until the node is restarted, and drops it if it's currently one.
+    ///
+    /// Returns `false` if the peer was already blacklisted.
+    pub fn blacklist(&self, peer_id: PeerId) -> bool {
+        let added = self.nb_filter.blacklist(peer_id);
+
+        self.drop_neighbor(&peer_id);
+
+        added
+    }
+}
+
+fn into_peers_with_distance(neighbors: Vec<Neighbor>) -> Vec<(Peer, Distance)> {
+    neighbors
+        .into_iter()
+        .map(|nb| {
+            let distance = nb.distance();
+            (nb.into_peer(), distance)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::{
+        event::{self, Event, EventRx},
+        packet::{MessageType, OutgoingPacket},
+        peer::lists::EntryPeersList,
+        request::RequestManager,
+    };
+
+    #[derive(Clone)]
+    struct AcceptAllValidator {}
+    impl NeighborValidator for AcceptAllValidator {
+        fn is_valid<P: AsRef<Peer>>(&self, _: P) -> bool {
+            true
+        }
+    }
+
+    struct Scenario {
+        handle: AutopeeringHandle,
+        nb_filter: NeighborFilter<AcceptAllValidator>,
+        server_rx: mpsc::UnboundedReceiver<OutgoingPacket>,
+        event_rx: EventRx,
+    }
+
+    fn setup_scenario() -> Scenario {
+        let local = Local::generate();
+        let active_peers = ActivePeersList::default();
+        let replacements = ReplacementPeersList::default();
+        let nb_filter = NeighborFilter::new(local.peer_id(), AcceptAllValidator {});
+        let (server_tx, server_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = event::event_chan();
+        let query_ctx = QueryContext {
+            request_mngr: RequestManager::new(0, 0, None, None, local.clone()),
+            entry_peers: EntryPeersList::default(),
+            active_peers: active_peers.clone(),
+            replacements: replacements.clone(),
+            server_tx: server_tx.clone(),
+            event_tx: event_tx.clone(),
+        };
+
+        let handle = AutopeeringHandle::new(
+            local,
+            active_peers,
+            replacements,
+            InboundNeighborhood::new(),
+            OutboundNeighborhood::new(),
+            nb_filter.clone(),
+            query_ctx,
+            server_tx,
+            event_tx,
+        );
+
+        Scenario {
+            handle,
+            nb_filter,
+            server_rx,
+            event_rx,
+        }
+    }
+
+    fn assert_dropped(scenario: &mut Scenario, peer_id: PeerId) {
+        let packet = scenario.server_rx.try_recv().unwrap();
+        assert!(matches!(packet.msg_type, MessageType::DropRequest));
+
+        match scenario.event_rx.try_recv().unwrap() {
+            Event::PeeringDropped { peer_id: dropped } => assert_eq!(dropped, peer_id),
+            event => panic!("unexpected event: {}", event),
+        }
+    }
+
+    #[test]
+    fn drop_inbound_neighbor() {
+        let mut scenario = setup_scenario();
+        let peer = Peer::new_test_peer(1);
+        let peer_id = *peer.peer_id();
+
+        assert!(scenario
+            .handle
+            .inbound_nbh
+            .insert_neighbor(peer.clone(), &scenario.handle.local));
+        assert_eq!(scenario.handle.inbound_neighbors().len(), 1);
+
+        assert!(scenario.handle.drop_neighbor(&peer_id));
+        assert!(scenario.handle.inbound_neighbors().is_empty());
+        assert_dropped(&mut scenario, peer_id);
+
+        // The dropped peer isn't selected again right away, and can't be dropped twice.
+        assert!(scenario.nb_filter.apply_list(&[peer]).is_empty());
+        assert!(!scenario.handle.drop_neighbor(&peer_id));
+    }
+
+    #[test]
+    fn drop_outbound_neighbor() {
+        let mut scenario = setup_scenario();
+        let peer = Peer::new_test_peer(1);
+        let peer_id = *peer.peer_id();
+
+        assert!(scenario
+            .handle
+            .outbound_nbh
+            .insert_neighbor(peer.clone(), &scenario.handle.local));
+        assert_eq!(scenario.handle.outbound_neighbors().len(), 1);
+
+        assert!(scenario.handle.drop_neighbor(&peer_id));
+        assert!(scenario.handle.outbound_neighbors().is_empty());
+        assert_dropped(&mut scenario, peer_id);
+
+        // The dropped peer isn't selected again right away.
+        assert!(scenario.nb_filter.apply_list(&[peer]).is_empty());
+    }
+
+    #[test]
+    fn drop_unknown_neighbor() {
+        let mut scenario = setup_scenario();
+        let peer_id = *Peer::new_test_peer(1).peer_id();
+
+        assert!(!scenario.handle.drop_neighbor(&peer_id));
+        assert!(scenario.server_rx.try_recv().is_err());
+        assert!(scenario.event_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn blacklist_neighbor() {
+        let mut scenario = setup_scenario();
+        let peer = Peer::new_test_peer(1);
+        let peer_id = *peer.peer_id();
+
+        assert!(scenario
+            .handle
+            .outbound_nbh
+            .insert_neighbor(peer.clone(), &scenario.handle.local));
+
+        assert!(scenario.handle.blacklist(peer_id));
+        assert!(scenario.handle.outbound_neighbors().is_empty());
+        assert_dropped(&mut scenario, peer_id);
+
+        // Unlike rejected peers, blacklisted peers stay excluded when the filter is reset.
+        scenario.nb_filter.clear();
+        assert!(!scenario.nb_filter.is_valid_neighbor(&peer));
+        assert!(scenario.nb_filter.apply_list(&[peer]).is_empty());
+    }
+
+    #[test]
+    fn blacklist_twice() {
+        let mut scenario = setup_scenario();
+        let peer_id = *Peer::new_test_peer(1).peer_id();
+
+        assert!(scenario.handle.blacklist(peer_id));
+        assert!(!scenario.handle.blacklist(peer_id));
+
+        // The peer wasn't a neighbor, so nothing was dropped.
+        assert!(scenario.server_rx.try_recv().is_err());
+        assert!(scenario.event_rx.try_recv().is_err());
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
         query::{self, QueryContext},
     },
     event::{self, EventRx},
+    handle::AutopeeringHandle,
     hash,
     local::Local,
     multiaddr,
@@ -39,6 +40,8 @@ const BOOTSTRAP_QUERY_DELAY: Duration = Duration::from_secs(2 * SECOND);
 const BOOTSTRAP_UPDATE_DELAY: Duration = Duration::from_secs(4 * SECOND);
 
 /// Initializes the autopeering service.
+///
+/// Returns the receiver of autopeering events, and a handle to inspect and control the service.
 pub async fn init<S, I, Q, V>(
     config: AutopeeringConfig,
     version: u32,
@@ -47,7 +50,7 @@ pub async fn init<S, I, Q, V>(
     peer_store_config: <S as PeerStore>::Config,
     term_signal: Q,
     neighbor_validator: V,
-) -> Result<EventRx, Box<dyn error::Error>>
+) -> Result<(EventRx, AutopeeringHandle), Box<dyn error::Error>>
 where
     S: PeerStore + 'static,
     I: AsRef<str>,
@@ -100,7 +103,13 @@ where
     server.init(&mut task_mngr).await;
 
     // Create a request manager that creates and keeps track of outgoing requests.
-    let request_mngr = RequestManager::new(version, network_id, config.bind_addr_v4(), config.bind_addr_v6());
+    let request_mngr = RequestManager::new(
+        version,
+        network_id,
+        config.bind_addr_v4(),
+        config.bind_addr_v6(),
+        local.clone(),
+    );
 
     // Create the discovery manager handling the discovery request/response protocol.
     let discovery_config = DiscoveryManagerConfig::new(&config, version, network_id);
@@ -149,7 +158,7 @@ where
     let ctx = SaltUpdateContext::new(
         local.clone(),
         nb_filter.clone(),
-        inbound_nbh,
+        inbound_nbh.clone(),
         outbound_nbh.clone(),
         server_tx.clone(),
         event_tx.clone(),
@@ -179,7 +188,19 @@ where
     // Discover new peers regularly.
     let f = query::query_fn();
     let delay = iter::once(BOOTSTRAP_QUERY_DELAY).chain(iter::repeat(QUERY_INTERVAL_DEFAULT));
-    task_mngr.repeat(f, delay, ctx, "Discovery", MAX_SHUTDOWN_PRIORITY);
+    task_mngr.repeat(f, delay, ctx.clone(), "Discovery", MAX_SHUTDOWN_PRIORITY);
+
+    let handle = AutopeeringHandle::new(
+        local.clone(),
+        active_peers.clone(),
+        replacements,
+        inbound_nbh,
+        outbound_nbh.clone(),
+        nb_filter.clone(),
+        ctx,
+        server_tx.clone(),
+        event_tx,
+    );
 
     let ctx = UpdateContext {
         local,
@@ -204,5 +225,5 @@ where
 
     log::debug!("Autopeering initialized.");
 
-    Ok(event_rx)
+    Ok((event_rx, handle))
 }
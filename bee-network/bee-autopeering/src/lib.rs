@@ -78,8 +78,9 @@
 //!     // You need to provide some form of shutdown signal (any `Future` impl is allowed).
 //!     let term_signal = tokio::signal::ctrl_c();
 //!
-//!     // With initializing the autopeering system you receive an event stream receiver.
-//!     let mut event_rx = bee_autopeering::init::<SledPeerStore, _, _, GossipNeighborValidator>(
+//!     // With initializing the autopeering system you receive an event stream receiver, and a handle to inspect and
+//!     // control the system.
+//!     let (mut event_rx, _handle) = bee_autopeering::init::<SledPeerStore, _, _, GossipNeighborValidator>(
 //!         config.clone(),
 //!         VERSION,
 //!         NETWORK,
@@ -136,11 +137,13 @@ mod time;
 
 pub mod config;
 pub mod event;
+pub mod handle;
 pub mod init;
 
 pub use self::{
     config::AutopeeringConfig,
     event::Event,
+    handle::AutopeeringHandle,
     init::init,
     local::{
         services::{ServiceEndpoint, ServiceMap, ServiceName, ServiceProtocol, AUTOPEERING_SERVICE_NAME},
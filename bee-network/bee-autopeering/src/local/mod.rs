@@ -6,6 +6,7 @@ pub mod services;
 
 use std::{
     fmt,
+    net::IpAddr,
     sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
@@ -47,6 +48,8 @@ pub struct LocalInner {
     private_key: PrivateKey,
     private_salt: Salt,
     services: ServiceMap,
+    external_ip_v4: Option<IpAddr>,
+    external_ip_v6: Option<IpAddr>,
 }
 
 impl Local {
@@ -62,6 +65,8 @@ impl Local {
             private_key,
             private_salt: Salt::default(),
             services: ServiceMap::default(),
+            external_ip_v4: None,
+            external_ip_v6: None,
         };
 
         Self {
@@ -113,6 +118,8 @@ impl Local {
                 private_salt: Salt::new(SALT_LIFETIME_SECS),
                 public_salt: Salt::new(SALT_LIFETIME_SECS),
                 services: ServiceMap::default(),
+                external_ip_v4: None,
+                external_ip_v6: None,
             })),
         }
     }
@@ -162,6 +169,26 @@ impl Local {
         self.read().services().clone()
     }
 
+    /// Returns the external IPv4 or IPv6 address of this identity, as perceived by the majority of its peers.
+    pub fn external_ip(&self, ipv6: bool) -> Option<IpAddr> {
+        self.read().external_ip(ipv6)
+    }
+
+    /// Returns the external IP addresses of this identity, as perceived by the majority of its peers.
+    pub fn external_ips(&self) -> Vec<IpAddr> {
+        let read = self.read();
+
+        read.external_ip(false)
+            .into_iter()
+            .chain(read.external_ip(true))
+            .collect()
+    }
+
+    /// Sets the external IP address of this identity, and returns whether it changed.
+    pub(crate) fn set_external_ip(&self, ip: IpAddr) -> bool {
+        self.write().set_external_ip(ip)
+    }
+
     fn read(&self) -> RwLockReadGuard<LocalInner> {
         // Panic: we do not allow the lock to be poisened.
         self.inner.read().expect("error getting read access")
@@ -209,6 +236,24 @@ impl LocalInner {
     fn services(&self) -> &ServiceMap {
         &self.services
     }
+
+    fn external_ip(&self, ipv6: bool) -> Option<IpAddr> {
+        if ipv6 {
+            self.external_ip_v6
+        } else {
+            self.external_ip_v4
+        }
+    }
+
+    fn set_external_ip(&mut self, ip: IpAddr) -> bool {
+        let external_ip = if ip.is_ipv6() {
+            &mut self.external_ip_v6
+        } else {
+            &mut self.external_ip_v4
+        };
+
+        external_ip.replace(ip) != Some(ip)
+    }
 }
 
 impl fmt::Debug for Local {
@@ -35,6 +35,11 @@ impl ServiceMap {
     pub(crate) fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns an iterator over the services and their endpoint data.
+    pub fn iter(&self) -> impl Iterator<Item = (&ServiceName, &ServiceEndpoint)> {
+        self.0.iter()
+    }
 }
 
 impl TryFrom<proto::ServiceMap> for ServiceMap {
@@ -6,6 +6,7 @@
 use std::{
     fmt,
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 
 use crypto::signatures::ed25519::{PublicKey, SecretKey as PrivateKey, PUBLIC_KEY_LENGTH};
@@ -15,7 +16,10 @@ use serde::{
     Deserialize, Serialize,
 };
 
-use crate::hash;
+use crate::{
+    hash,
+    multiaddr::{self, base58_to_pubkey, pubkey_to_base58},
+};
 
 const DISPLAY_LENGTH: usize = 16;
 const DISPLAY_OFFSET: usize = 8;
@@ -58,6 +62,20 @@ impl PeerId {
     pub fn libp2p_peer_id(&self) -> libp2p_core::PeerId {
         libp2p_peer_id(self.public_key())
     }
+
+    /// Returns the base58 encoded public key associated with this identity.
+    pub fn to_base58(&self) -> String {
+        pubkey_to_base58(self.public_key())
+    }
+}
+
+impl FromStr for PeerId {
+    type Err = multiaddr::Error;
+
+    /// Parses a peer identity from a base58 encoded public key.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        base58_to_pubkey(s).map(Self::from_public_key)
+    }
 }
 
 /// Creates the corresponding `libp2p_core::PeerId` from a crypto.rs ED25519 public key.
@@ -198,4 +216,12 @@ mod tests {
         let peer_id = PeerId::new_static();
         let _ = peer_id.libp2p_peer_id();
     }
+
+    #[test]
+    fn base58_roundtrip() {
+        let peer_id = PeerId::new_static();
+
+        assert_eq!(peer_id.to_base58().parse::<PeerId>().unwrap(), peer_id);
+        assert!("not-a-peer-id".parse::<PeerId>().is_err());
+    }
 }
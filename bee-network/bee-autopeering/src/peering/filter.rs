@@ -33,10 +33,19 @@ impl<V: NeighborValidator> NeighborFilter<V> {
     }
 
     /// Resets the filter (i.e. removes all currently rejected peer ids).
+    ///
+    /// Blacklisted peer ids are kept.
     pub(crate) fn clear(&self) {
         self.write().clear();
     }
 
+    /// Adds a peer id that should be rejected until the node is restarted.
+    ///
+    /// Returns `false` if the peer id was already blacklisted.
+    pub(crate) fn blacklist(&self, peer_id: PeerId) -> bool {
+        self.write().blacklist(peer_id)
+    }
+
     /// Applies the filter to a list of candidates.
     pub(crate) fn apply_list<'a, P: AsRef<Peer>>(&self, candidates: &'a [P]) -> Vec<&'a P> {
         self.read().apply_list(candidates)
@@ -44,7 +53,7 @@ impl<V: NeighborValidator> NeighborFilter<V> {
 
     /// Returns whether the peer is a valid neighbor.
     pub(crate) fn is_valid_neighbor<P: AsRef<Peer>>(&self, peer: P) -> bool {
-        self.read().validator.is_valid(peer)
+        self.read().is_valid_neighbor(peer)
     }
 
     fn read(&self) -> RwLockReadGuard<NeighborFilterInner<V>> {
@@ -59,6 +68,7 @@ impl<V: NeighborValidator> NeighborFilter<V> {
 pub(crate) struct NeighborFilterInner<V: NeighborValidator> {
     local_id: PeerId,
     rejected: HashSet<PeerId>,
+    blacklisted: HashSet<PeerId>,
     validator: V,
 }
 
@@ -67,6 +77,7 @@ impl<V: NeighborValidator> NeighborFilterInner<V> {
         Self {
             local_id,
             rejected: HashSet::new(),
+            blacklisted: HashSet::new(),
             validator,
         }
     }
@@ -81,6 +92,18 @@ impl<V: NeighborValidator> NeighborFilterInner<V> {
         self.rejected.clear()
     }
 
+    /// Adds a peer id that should be rejected until the node is restarted.
+    fn blacklist(&mut self, peer_id: PeerId) -> bool {
+        self.blacklisted.insert(peer_id)
+    }
+
+    /// Returns `true` if the peer isn't blacklisted and satisfies the validator.
+    fn is_valid_neighbor(&self, peer: impl AsRef<Peer>) -> bool {
+        let peer = peer.as_ref();
+
+        !self.blacklisted.contains(peer.peer_id()) && self.validator.is_valid(peer)
+    }
+
     /// Returns `true` if the filter is okay with the candidate, otherwise `false`.
     fn ok(&self, candidate: impl AsRef<Peer>) -> bool {
         let peer = candidate.as_ref();
@@ -89,7 +112,7 @@ impl<V: NeighborValidator> NeighborFilterInner<V> {
         if peer_id == &self.local_id || self.rejected.contains(peer_id) {
             false
         } else {
-            self.validator.is_valid(peer)
+            self.is_valid_neighbor(peer)
         }
     }
 
@@ -147,4 +170,17 @@ mod tests {
         let included = filter.write().apply_list(&candidates);
         assert_eq!(1, included.len());
     }
+
+    #[test]
+    fn filter_blacklist() {
+        let (filter, _, peer2) = setup_scenario1();
+
+        assert!(filter.blacklist(*peer2.peer_id()));
+        assert!(!filter.blacklist(*peer2.peer_id()));
+        assert!(!filter.is_valid_neighbor(&peer2));
+
+        // Blacklisted peers survive a reset of the filter.
+        filter.clear();
+        assert!(!filter.read().ok(peer2));
+    }
 }
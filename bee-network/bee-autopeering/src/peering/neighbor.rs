@@ -150,6 +150,11 @@ impl<const N: usize, const INBOUND: bool> Neighborhood<N, INBOUND> {
         self.read().neighbors.iter().map(|d| d.peer()).cloned().collect()
     }
 
+    /// Collect all neighbors, i.e. peers together with their distances, into a `Vec`.
+    pub(crate) fn neighbors(&self) -> Vec<Neighbor> {
+        self.read().neighbors.clone()
+    }
+
     fn read(&self) -> RwLockReadGuard<NeighborhoodInner<N, INBOUND>> {
         self.inner.read().expect("error getting read access")
     }
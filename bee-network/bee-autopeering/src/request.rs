@@ -55,6 +55,7 @@ pub(crate) struct RequestManager {
     network_id: u32,
     source_addr_v4: Option<SocketAddr>,
     source_addr_v6: Option<SocketAddr>,
+    local: Local,
     open_requests: Arc<RwLock<HashMap<RequestKey, RequestValue>>>,
 }
 
@@ -65,12 +66,14 @@ impl RequestManager {
         network_id: u32,
         source_addr_v4: Option<SocketAddr>,
         source_addr_v6: Option<SocketAddr>,
+        local: Local,
     ) -> Self {
         Self {
             version,
             network_id,
             source_addr_v4,
             source_addr_v6,
+            local,
             open_requests: Arc::new(RwLock::new(HashMap::default())),
         }
     }
@@ -94,6 +97,12 @@ impl RequestManager {
             self.source_addr_v4
         }?;
 
+        // Announce the external IP address instead of the bind address once it is known.
+        let source_addr = self
+            .local
+            .external_ip(peer_addr.is_ipv6())
+            .map_or(source_addr, |ip| SocketAddr::new(ip, source_addr.port()));
+
         let verif_req = VerificationRequest::new(self.version, self.network_id, source_addr, peer_addr);
         let timestamp = verif_req.timestamp();
 
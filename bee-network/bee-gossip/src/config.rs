@@ -3,7 +3,13 @@
 
 #![cfg(feature = "full")]
 
-use std::{borrow::Cow, collections::HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
 use serde::Deserialize;
@@ -14,10 +20,31 @@ const DEFAULT_BIND_MULTIADDR: &str = "/ip4/0.0.0.0/tcp/15600";
 
 pub const DEFAULT_RECONNECT_INTERVAL_SECS: u64 = 30;
 const MIN_RECONNECT_INTERVAL_SECS: u64 = 1;
+pub const DEFAULT_MAX_RECONNECT_INTERVAL_SECS: u64 = 1800;
 
 pub const DEFAULT_MAX_UNKNOWN_PEERS: usize = 4;
 pub const DEFAULT_MAX_DISCOVERED_PEERS: usize = 4;
 
+pub const DEFAULT_CONNECTION_TIMEOUT_SECS: u64 = 10;
+pub const DEFAULT_HANDSHAKE_TIMEOUT_SECS: u64 = 10;
+pub const DEFAULT_IDLE_CONNECTION_TIMEOUT_SECS: u64 = 30;
+const MIN_TIMEOUT_SECS: u64 = 1;
+
+pub const DEFAULT_MAX_CONNECTIONS_PER_PEER: u32 = 1;
+const MIN_MAX_CONNECTIONS_PER_PEER: u32 = 1;
+
+pub const DEFAULT_YAMUX_RECEIVE_WINDOW_SIZE: u32 = 256 * 1024;
+const MIN_YAMUX_RECEIVE_WINDOW_SIZE: u32 = 256 * 1024;
+pub const DEFAULT_YAMUX_MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
+pub const DEFAULT_GOSSIP_INBOUND_CAPACITY: usize = 1024;
+pub const DEFAULT_GOSSIP_OUTBOUND_CAPACITY: usize = 1024;
+const MIN_GOSSIP_CAPACITY: usize = 1;
+
+pub const DEFAULT_GOSSIP_COMPRESSION_LEVEL: i32 = 3;
+const MIN_GOSSIP_COMPRESSION_LEVEL: i32 = 1;
+const MAX_GOSSIP_COMPRESSION_LEVEL: i32 = 19;
+
 /// [`NetworkConfigBuilder`] errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -56,15 +83,177 @@ pub enum Error {
     /// The provided [`Multiaddr`] lacks the P2p [`Protocol`].
     #[error("Invalid P2p Multiaddr. Did you forget to add '.../p2p/12D3Koo...'?")]
     MissingP2pProtocol,
+
+    /// The peer group was already added.
+    #[error("Peer group '{}' already added.", .0)]
+    DuplicatePeerGroup(String),
+
+    /// The peer group wasn't added.
+    #[error("Unknown peer group '{}'.", .0)]
+    UnknownPeerGroup(String),
+
+    /// Reading the gossip compression dictionary failed.
+    #[error("Reading the gossip compression dictionary '{}' failed: {}.", .0.display(), .1)]
+    ReadingCompressionDictionaryFailed(PathBuf, std::io::Error),
+}
+
+/// Describes how a full gossip queue of a peer handles new messages.
+///
+/// Inbound gossip is a raw byte stream that can't be dropped partially, so the inbound queue of a peer stops reading
+/// from that peer until it has room again, unless the policy is [`OverflowPolicy::Disconnect`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Drops the oldest queued message to make room for the new one.
+    #[serde(alias = "dropOldest")]
+    DropOldest,
+    /// Drops the new message.
+    #[serde(alias = "dropNewest")]
+    DropNewest,
+    /// Drops the new message and disconnects the peer.
+    #[serde(alias = "disconnect")]
+    Disconnect,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        Self::DropOldest
+    }
+}
+
+/// Describes which stream multiplexers are offered to peers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MuxerSelection {
+    /// Only offers yamux.
+    #[serde(alias = "yamux")]
+    Yamux,
+    /// Only offers mplex.
+    #[serde(alias = "mplex")]
+    Mplex,
+    /// Offers both, preferring yamux.
+    #[serde(alias = "both")]
+    Both,
+}
+
+impl Default for MuxerSelection {
+    fn default() -> Self {
+        Self::Both
+    }
+}
+
+/// A named group of peers, e.g. the peers of an operator's own infrastructure.
+///
+/// Members of a group are only limited by the group's own connection limit, and not by the limits for unknown or
+/// discovered peers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeerGroup {
+    /// The name of the group.
+    pub name: String,
+    /// The maximum number of connected peers of the group, if any.
+    pub max_peers: Option<usize>,
+    /// The priority of the group's peers when reconnecting. Peers of groups with a higher priority are dialed first.
+    pub reconnect_priority: u8,
+}
+
+impl PeerGroup {
+    /// Creates a new [`PeerGroup`] without a connection limit and with the lowest reconnect priority.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            max_peers: None,
+            reconnect_priority: 0,
+        }
+    }
+}
+
+/// The settings of the optional gossip compression protocol extension.
+///
+/// Compression is only used with peers that support it, and both peers need to use the same dictionary.
+#[derive(Clone, Eq, PartialEq)]
+pub struct CompressionConfig {
+    level: i32,
+    dictionary: Option<Arc<[u8]>>,
+}
+
+impl CompressionConfig {
+    /// Creates a new [`CompressionConfig`] with the given zstd compression level and without a dictionary.
+    ///
+    /// The level is clamped to the range `1..=19`.
+    pub fn new(level: i32) -> Self {
+        Self {
+            level: level.clamp(MIN_GOSSIP_COMPRESSION_LEVEL, MAX_GOSSIP_COMPRESSION_LEVEL),
+            dictionary: None,
+        }
+    }
+
+    /// Adds a zstd dictionary, e.g. one trained on gossip packets with `zstd --train`.
+    pub fn with_dictionary(mut self, dictionary: impl Into<Arc<[u8]>>) -> Self {
+        self.dictionary.replace(dictionary.into());
+        self
+    }
+
+    /// Returns the zstd compression level.
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    /// Returns the zstd dictionary, if any.
+    pub fn dictionary(&self) -> Option<&[u8]> {
+        self.dictionary.as_deref()
+    }
+
+    /// Returns the identifier of the dictionary, which is announced during protocol negotiation.
+    ///
+    /// The identifier is the 64-bit FNV-1a hash of the dictionary, so it is the same on all peers.
+    pub fn dictionary_id(&self) -> Option<u64> {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        self.dictionary().map(|dictionary| {
+            dictionary.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+            })
+        })
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_GOSSIP_COMPRESSION_LEVEL)
+    }
+}
+
+impl fmt::Debug for CompressionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressionConfig")
+            .field("level", &self.level)
+            .field("dictionary_id", &self.dictionary_id())
+            .finish()
+    }
 }
 
 /// The network configuration.
 #[derive(Clone)]
 pub struct NetworkConfig {
     pub(crate) bind_multiaddr: Multiaddr,
+    pub(crate) additional_bind_multiaddrs: Vec<Multiaddr>,
     pub(crate) reconnect_interval_secs: u64,
+    pub(crate) max_reconnect_interval_secs: u64,
     pub(crate) max_unknown_peers: usize,
     pub(crate) max_discovered_peers: usize,
+    pub(crate) connection_timeout_secs: u64,
+    pub(crate) handshake_timeout_secs: u64,
+    pub(crate) idle_connection_timeout_secs: u64,
+    pub(crate) max_connections_per_peer: u32,
+    pub(crate) muxer: MuxerSelection,
+    pub(crate) yamux_receive_window_size: u32,
+    pub(crate) yamux_max_buffer_size: usize,
+    pub(crate) gossip_inbound_capacity: usize,
+    pub(crate) gossip_outbound_capacity: usize,
+    pub(crate) gossip_overflow_policy: OverflowPolicy,
+    pub(crate) gossip_compression: Option<CompressionConfig>,
+    pub(crate) peer_groups: HashMap<String, PeerGroup>,
     pub(crate) static_peers: HashSet<Peer>,
 }
 
@@ -130,10 +319,26 @@ impl NetworkConfig {
         multiaddr: Multiaddr,
         alias: Option<String>,
     ) -> Result<(), Error> {
+        self.add_static_peer_with_group(peer_id, multiaddr, alias, None)
+    }
+
+    /// Adds a static peer, optionally as a member of a configured peer group.
+    pub fn add_static_peer_with_group(
+        &mut self,
+        peer_id: PeerId,
+        multiaddr: Multiaddr,
+        alias: Option<String>,
+        group: Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(group) = group.as_ref().filter(|group| !self.peer_groups.contains_key(*group)) {
+            return Err(Error::UnknownPeerGroup(group.clone()));
+        }
+
         if !self.static_peers.insert(Peer {
             peer_id,
             multiaddr,
             alias,
+            group,
         }) {
             return Err(Error::DuplicateStaticPeer(peer_id));
         }
@@ -146,11 +351,21 @@ impl NetworkConfig {
         &self.bind_multiaddr
     }
 
-    /// Returns the number of seconds at which reconnect attempts occur.
+    /// Returns the additionally configured bind addresses, e.g. for the WebSocket transport.
+    pub fn additional_bind_multiaddrs(&self) -> &[Multiaddr] {
+        &self.additional_bind_multiaddrs
+    }
+
+    /// Returns the number of seconds after which the first reconnect attempt to a peer occurs.
     pub fn reconnect_interval_secs(&self) -> u64 {
         self.reconnect_interval_secs
     }
 
+    /// Returns the maximum number of seconds the reconnect interval of a peer backs off to.
+    pub fn max_reconnect_interval_secs(&self) -> u64 {
+        self.max_reconnect_interval_secs
+    }
+
     /// Returns the maximum number of unknown peers that are allowed to connect.
     pub fn max_unknown_peers(&self) -> usize {
         self.max_unknown_peers
@@ -161,6 +376,66 @@ impl NetworkConfig {
         self.max_discovered_peers
     }
 
+    /// Returns the number of seconds after which establishing a connection to a peer times out.
+    pub fn connection_timeout_secs(&self) -> u64 {
+        self.connection_timeout_secs
+    }
+
+    /// Returns the number of seconds after which the Noise and multiplexer handshakes with a peer time out.
+    pub fn handshake_timeout_secs(&self) -> u64 {
+        self.handshake_timeout_secs
+    }
+
+    /// Returns the number of seconds a connection is kept alive without the gossip protocol being established on it.
+    pub fn idle_connection_timeout_secs(&self) -> u64 {
+        self.idle_connection_timeout_secs
+    }
+
+    /// Returns the maximum number of connections established with a single peer.
+    pub fn max_connections_per_peer(&self) -> u32 {
+        self.max_connections_per_peer
+    }
+
+    /// Returns which stream multiplexers are offered to peers.
+    pub fn muxer(&self) -> MuxerSelection {
+        self.muxer
+    }
+
+    /// Returns the yamux receive window size (in bytes) of a stream.
+    pub fn yamux_receive_window_size(&self) -> u32 {
+        self.yamux_receive_window_size
+    }
+
+    /// Returns the maximum yamux buffer size (in bytes) of a stream.
+    pub fn yamux_max_buffer_size(&self) -> usize {
+        self.yamux_max_buffer_size
+    }
+
+    /// Returns the maximum number of messages received from a peer that can be queued.
+    pub fn gossip_inbound_capacity(&self) -> usize {
+        self.gossip_inbound_capacity
+    }
+
+    /// Returns the maximum number of messages to send to a peer that can be queued.
+    pub fn gossip_outbound_capacity(&self) -> usize {
+        self.gossip_outbound_capacity
+    }
+
+    /// Returns how full gossip queues handle new messages.
+    pub fn gossip_overflow_policy(&self) -> OverflowPolicy {
+        self.gossip_overflow_policy
+    }
+
+    /// Returns the gossip compression settings, if compression is enabled.
+    pub fn gossip_compression(&self) -> Option<&CompressionConfig> {
+        self.gossip_compression.as_ref()
+    }
+
+    /// Returns the configured peer groups.
+    pub fn peer_groups(&self) -> &HashMap<String, PeerGroup> {
+        &self.peer_groups
+    }
+
     /// Returns the statically configured peers.
     pub fn static_peers(&self) -> &HashSet<Peer> {
         &self.static_peers
@@ -182,15 +457,72 @@ fn resolve_dns_multiaddr(dns: Cow<'_, str>) -> Result<Protocol, Error> {
     }
 }
 
+/// Validates a bind address of the form `/{ip4,ip6,dns}/<addr>/tcp/<port>[/ws]`, and resolves a DNS address to an IP
+/// address.
+fn validate_bind_multiaddr(multiaddr: Multiaddr, allow_ws: bool) -> Result<Multiaddr, Error> {
+    let mut protocols = multiaddr.iter();
+
+    let addr = match protocols.next() {
+        Some(addr @ (Protocol::Ip4(_) | Protocol::Ip6(_) | Protocol::Dns(_))) => addr,
+        Some(_) => return Err(Error::InvalidProtocol(0)),
+        None => return Err(Error::MultiaddrUnderspecified),
+    };
+
+    let port = match protocols.next() {
+        Some(Protocol::Tcp(port)) => port,
+        Some(_) => return Err(Error::InvalidProtocol(1)),
+        None => return Err(Error::MultiaddrUnderspecified),
+    };
+
+    let ws = match protocols.next() {
+        Some(ws @ Protocol::Ws(_)) if allow_ws => Some(ws),
+        Some(_) if allow_ws => return Err(Error::InvalidProtocol(2)),
+        Some(_) => return Err(Error::MultiaddrOverspecified),
+        None => None,
+    };
+
+    if protocols.next().is_some() {
+        return Err(Error::MultiaddrOverspecified);
+    }
+
+    let addr = if let Protocol::Dns(dns) = addr {
+        resolve_dns_multiaddr(format!("{}:{}", dns, port).into())?
+    } else {
+        addr
+    };
+
+    let mut validated = Multiaddr::empty().with(addr).with(Protocol::Tcp(port));
+
+    if let Some(ws) = ws {
+        validated.push(ws);
+    }
+
+    Ok(validated)
+}
+
 impl Default for NetworkConfig {
     fn default() -> Self {
         Self {
             // Panic:
             // Unwrapping is fine, because we made sure that the default is parsable.
             bind_multiaddr: DEFAULT_BIND_MULTIADDR.parse().unwrap(),
+            additional_bind_multiaddrs: Vec::new(),
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
+            max_reconnect_interval_secs: DEFAULT_MAX_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            connection_timeout_secs: DEFAULT_CONNECTION_TIMEOUT_SECS,
+            handshake_timeout_secs: DEFAULT_HANDSHAKE_TIMEOUT_SECS,
+            idle_connection_timeout_secs: DEFAULT_IDLE_CONNECTION_TIMEOUT_SECS,
+            max_connections_per_peer: DEFAULT_MAX_CONNECTIONS_PER_PEER,
+            muxer: MuxerSelection::default(),
+            yamux_receive_window_size: DEFAULT_YAMUX_RECEIVE_WINDOW_SIZE,
+            yamux_max_buffer_size: DEFAULT_YAMUX_MAX_BUFFER_SIZE,
+            gossip_inbound_capacity: DEFAULT_GOSSIP_INBOUND_CAPACITY,
+            gossip_outbound_capacity: DEFAULT_GOSSIP_OUTBOUND_CAPACITY,
+            gossip_overflow_policy: OverflowPolicy::default(),
+            gossip_compression: None,
+            peer_groups: Default::default(),
             static_peers: Default::default(),
         }
     }
@@ -202,12 +534,43 @@ impl Default for NetworkConfig {
 pub struct NetworkConfigBuilder {
     #[serde(alias = "bindAddress", alias = "bind_address")]
     bind_multiaddr: Option<Multiaddr>,
+    #[serde(alias = "additionalBindAddresses", alias = "additional_bind_addresses")]
+    additional_bind_multiaddrs: Option<Vec<Multiaddr>>,
     #[serde(alias = "reconnectIntervalSecs")]
     reconnect_interval_secs: Option<u64>,
+    #[serde(alias = "maxReconnectIntervalSecs")]
+    max_reconnect_interval_secs: Option<u64>,
     #[serde(alias = "maxUnknownPeers")]
     max_unknown_peers: Option<usize>,
     #[serde(alias = "maxDiscoveredPeers")]
     max_discovered_peers: Option<usize>,
+    #[serde(alias = "connectionTimeoutSecs")]
+    connection_timeout_secs: Option<u64>,
+    #[serde(alias = "handshakeTimeoutSecs")]
+    handshake_timeout_secs: Option<u64>,
+    #[serde(alias = "idleConnectionTimeoutSecs")]
+    idle_connection_timeout_secs: Option<u64>,
+    #[serde(alias = "maxConnectionsPerPeer")]
+    max_connections_per_peer: Option<u32>,
+    muxer: Option<MuxerSelection>,
+    #[serde(alias = "yamuxReceiveWindowSize")]
+    yamux_receive_window_size: Option<u32>,
+    #[serde(alias = "yamuxMaxBufferSize")]
+    yamux_max_buffer_size: Option<usize>,
+    #[serde(alias = "gossipInboundCapacity")]
+    gossip_inbound_capacity: Option<usize>,
+    #[serde(alias = "gossipOutboundCapacity")]
+    gossip_outbound_capacity: Option<usize>,
+    #[serde(alias = "gossipOverflowPolicy")]
+    gossip_overflow_policy: Option<OverflowPolicy>,
+    #[serde(alias = "gossipCompression")]
+    gossip_compression: Option<bool>,
+    #[serde(alias = "gossipCompressionLevel")]
+    gossip_compression_level: Option<i32>,
+    #[serde(alias = "gossipCompressionDictionary")]
+    gossip_compression_dictionary: Option<PathBuf>,
+    #[serde(alias = "peerGroups")]
+    peer_groups: Option<Vec<PeerGroupBuilder>>,
     peering: ManualPeeringConfigBuilder,
 }
 
@@ -217,67 +580,27 @@ impl NetworkConfigBuilder {
         Self::default()
     }
 
-    /// Specifies the bind addresses.
-    pub fn with_bind_multiaddr(mut self, mut multiaddr: Multiaddr) -> Result<Self, Error> {
-        let mut valid = false;
-        let mut is_dns = false;
-
-        for (i, p) in multiaddr.iter().enumerate() {
-            match i {
-                0 => {
-                    if !matches!(p, Protocol::Ip4(_) | Protocol::Ip6(_) | Protocol::Dns(_)) {
-                        return Err(Error::InvalidProtocol(0));
-                    }
-
-                    if matches!(p, Protocol::Dns(_)) {
-                        is_dns = true;
-                    }
-                }
-                1 => {
-                    if !matches!(p, Protocol::Tcp(_)) {
-                        return Err(Error::InvalidProtocol(1));
-                    }
-                    valid = true;
-                }
-                _ => return Err(Error::MultiaddrOverspecified),
-            }
-        }
-        if !valid {
-            return Err(Error::MultiaddrUnderspecified);
-        }
-
-        if is_dns {
-            let port = multiaddr.pop().unwrap();
-            let port = if let Protocol::Tcp(port) = port {
-                port
-            } else {
-                unreachable!("already checked");
-            };
-            // Panic:
-            // We know at this point, that `multiaddr` is valid, so unwrapping is fine.
-            let ip = if let Protocol::Dns(dns) = multiaddr.pop().unwrap() {
-                let socket_dns = {
-                    let mut socket_addr = String::with_capacity(16);
-                    socket_addr.push_str(&dns);
-                    socket_addr.push(':');
-                    socket_addr.push_str(&port.to_string());
-                    socket_addr
-                };
-
-                resolve_dns_multiaddr(socket_dns.into())?
-            } else {
-                unreachable!("already checked");
-            };
-
-            multiaddr.push(ip);
-            multiaddr.push(Protocol::Tcp(port));
-        }
+    /// Specifies the bind address.
+    ///
+    /// The address must use the TCP transport, e.g. `/ip4/0.0.0.0/tcp/15600`.
+    pub fn with_bind_multiaddr(mut self, multiaddr: Multiaddr) -> Result<Self, Error> {
+        self.bind_multiaddr.replace(validate_bind_multiaddr(multiaddr, false)?);
+        Ok(self)
+    }
 
-        self.bind_multiaddr.replace(multiaddr);
+    /// Specifies an additional bind address.
+    ///
+    /// Besides the TCP transport, the address may use the WebSocket transport, e.g. `/ip4/0.0.0.0/tcp/15601/ws`.
+    pub fn with_additional_bind_multiaddr(mut self, multiaddr: Multiaddr) -> Result<Self, Error> {
+        self.additional_bind_multiaddrs
+            .get_or_insert_with(Vec::new)
+            .push(validate_bind_multiaddr(multiaddr, true)?);
         Ok(self)
     }
 
-    /// Specifies the interval (in seconds) at which known peers are automatically reconnected if possible.
+    /// Specifies the interval (in seconds) after which known peers are automatically reconnected if possible.
+    ///
+    /// The interval doubles with every failed attempt, up to the maximum reconnect interval.
     ///
     /// The allowed minimum value for the `secs` argument is `1`.
     pub fn with_reconnect_interval_secs(mut self, secs: u64) -> Self {
@@ -286,6 +609,15 @@ impl NetworkConfigBuilder {
         self
     }
 
+    /// Specifies the maximum interval (in seconds) the reconnect interval of a peer backs off to.
+    ///
+    /// The allowed minimum value for the `secs` argument is `1`.
+    pub fn with_max_reconnect_interval_secs(mut self, secs: u64) -> Self {
+        let secs = secs.max(MIN_RECONNECT_INTERVAL_SECS);
+        self.max_reconnect_interval_secs.replace(secs);
+        self
+    }
+
     /// Specifies the maximum number of gossip connections with unknown peers.
     pub fn with_max_unknown_peers(mut self, n: usize) -> Self {
         self.max_unknown_peers.replace(n);
@@ -298,18 +630,208 @@ impl NetworkConfigBuilder {
         self
     }
 
+    /// Specifies the number of seconds after which establishing a connection to a peer times out.
+    ///
+    /// The allowed minimum value for the `secs` argument is `1`.
+    pub fn with_connection_timeout_secs(mut self, secs: u64) -> Self {
+        self.connection_timeout_secs.replace(secs.max(MIN_TIMEOUT_SECS));
+        self
+    }
+
+    /// Specifies the number of seconds after which the Noise and multiplexer handshakes with a peer time out.
+    ///
+    /// The allowed minimum value for the `secs` argument is `1`.
+    pub fn with_handshake_timeout_secs(mut self, secs: u64) -> Self {
+        self.handshake_timeout_secs.replace(secs.max(MIN_TIMEOUT_SECS));
+        self
+    }
+
+    /// Specifies the number of seconds a connection is kept alive without the gossip protocol being established on it.
+    ///
+    /// The allowed minimum value for the `secs` argument is `1`.
+    pub fn with_idle_connection_timeout_secs(mut self, secs: u64) -> Self {
+        self.idle_connection_timeout_secs.replace(secs.max(MIN_TIMEOUT_SECS));
+        self
+    }
+
+    /// Specifies the maximum number of connections established with a single peer.
+    ///
+    /// The allowed minimum value for the `n` argument is `1`.
+    pub fn with_max_connections_per_peer(mut self, n: u32) -> Self {
+        self.max_connections_per_peer
+            .replace(n.max(MIN_MAX_CONNECTIONS_PER_PEER));
+        self
+    }
+
+    /// Specifies which stream multiplexers are offered to peers.
+    pub fn with_muxer(mut self, muxer: MuxerSelection) -> Self {
+        self.muxer.replace(muxer);
+        self
+    }
+
+    /// Specifies the yamux receive window size (in bytes) of a stream.
+    ///
+    /// The allowed minimum value for the `size` argument is `262144` (256 KiB).
+    pub fn with_yamux_receive_window_size(mut self, size: u32) -> Self {
+        self.yamux_receive_window_size
+            .replace(size.max(MIN_YAMUX_RECEIVE_WINDOW_SIZE));
+        self
+    }
+
+    /// Specifies the maximum yamux buffer size (in bytes) of a stream.
+    pub fn with_yamux_max_buffer_size(mut self, size: usize) -> Self {
+        self.yamux_max_buffer_size.replace(size);
+        self
+    }
+
+    /// Specifies the maximum number of messages received from a peer that can be queued.
+    ///
+    /// The allowed minimum value for the `capacity` argument is `1`.
+    pub fn with_gossip_inbound_capacity(mut self, capacity: usize) -> Self {
+        self.gossip_inbound_capacity.replace(capacity.max(MIN_GOSSIP_CAPACITY));
+        self
+    }
+
+    /// Specifies the maximum number of messages to send to a peer that can be queued.
+    ///
+    /// The allowed minimum value for the `capacity` argument is `1`.
+    pub fn with_gossip_outbound_capacity(mut self, capacity: usize) -> Self {
+        self.gossip_outbound_capacity.replace(capacity.max(MIN_GOSSIP_CAPACITY));
+        self
+    }
+
+    /// Specifies how full gossip queues handle new messages.
+    pub fn with_gossip_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.gossip_overflow_policy.replace(policy);
+        self
+    }
+
+    /// Specifies whether the gossip compression protocol extension is offered to peers.
+    pub fn with_gossip_compression(mut self, enabled: bool) -> Self {
+        self.gossip_compression.replace(enabled);
+        self
+    }
+
+    /// Specifies the zstd compression level of gossip.
+    ///
+    /// The `level` argument is clamped to the range `1..=19`.
+    pub fn with_gossip_compression_level(mut self, level: i32) -> Self {
+        self.gossip_compression_level
+            .replace(level.clamp(MIN_GOSSIP_COMPRESSION_LEVEL, MAX_GOSSIP_COMPRESSION_LEVEL));
+        self
+    }
+
+    /// Specifies the path of the zstd dictionary used to compress gossip.
+    pub fn with_gossip_compression_dictionary(mut self, path: impl Into<PathBuf>) -> Self {
+        self.gossip_compression_dictionary.replace(path.into());
+        self
+    }
+
+    /// Adds a peer group.
+    pub fn with_peer_group(mut self, group: PeerGroup) -> Self {
+        self.peer_groups.get_or_insert_with(Vec::new).push(PeerGroupBuilder {
+            name: group.name,
+            max_peers: group.max_peers,
+            reconnect_priority: Some(group.reconnect_priority),
+        });
+        self
+    }
+
     /// Builds the network config.
     pub fn finish(self) -> Result<NetworkConfig, Error> {
+        let mut peer_groups = HashMap::new();
+
+        for builder in self.peer_groups.unwrap_or_default() {
+            let group = builder.finish();
+
+            if peer_groups.contains_key(&group.name) {
+                return Err(Error::DuplicatePeerGroup(group.name));
+            }
+
+            peer_groups.insert(group.name.clone(), group);
+        }
+
+        let static_peers = self.peering.finish()?.peers;
+
+        if let Some(group) = static_peers
+            .iter()
+            .filter_map(|peer| peer.group.as_ref())
+            .find(|group| !peer_groups.contains_key(*group))
+        {
+            return Err(Error::UnknownPeerGroup(group.clone()));
+        }
+
+        let gossip_compression = if self.gossip_compression.unwrap_or(false) {
+            let config = CompressionConfig::new(
+                self.gossip_compression_level
+                    .unwrap_or(DEFAULT_GOSSIP_COMPRESSION_LEVEL),
+            );
+
+            Some(match self.gossip_compression_dictionary {
+                Some(path) => {
+                    let dictionary =
+                        std::fs::read(&path).map_err(|e| Error::ReadingCompressionDictionaryFailed(path, e))?;
+                    config.with_dictionary(dictionary)
+                }
+                None => config,
+            })
+        } else {
+            None
+        };
+
         Ok(NetworkConfig {
             bind_multiaddr: self
                 .bind_multiaddr
                 // Panic:
                 // We made sure that the default is parsable.
                 .unwrap_or_else(|| DEFAULT_BIND_MULTIADDR.parse().unwrap()),
+            additional_bind_multiaddrs: self
+                .additional_bind_multiaddrs
+                .unwrap_or_default()
+                .into_iter()
+                .map(|multiaddr| validate_bind_multiaddr(multiaddr, true))
+                .collect::<Result<_, _>>()?,
             reconnect_interval_secs: self.reconnect_interval_secs.unwrap_or(DEFAULT_RECONNECT_INTERVAL_SECS),
+            max_reconnect_interval_secs: self
+                .max_reconnect_interval_secs
+                .unwrap_or(DEFAULT_MAX_RECONNECT_INTERVAL_SECS)
+                .max(MIN_RECONNECT_INTERVAL_SECS),
             max_unknown_peers: self.max_unknown_peers.unwrap_or(DEFAULT_MAX_UNKNOWN_PEERS),
             max_discovered_peers: self.max_discovered_peers.unwrap_or(DEFAULT_MAX_DISCOVERED_PEERS),
-            static_peers: self.peering.finish()?.peers,
+            connection_timeout_secs: self
+                .connection_timeout_secs
+                .unwrap_or(DEFAULT_CONNECTION_TIMEOUT_SECS)
+                .max(MIN_TIMEOUT_SECS),
+            handshake_timeout_secs: self
+                .handshake_timeout_secs
+                .unwrap_or(DEFAULT_HANDSHAKE_TIMEOUT_SECS)
+                .max(MIN_TIMEOUT_SECS),
+            idle_connection_timeout_secs: self
+                .idle_connection_timeout_secs
+                .unwrap_or(DEFAULT_IDLE_CONNECTION_TIMEOUT_SECS)
+                .max(MIN_TIMEOUT_SECS),
+            max_connections_per_peer: self
+                .max_connections_per_peer
+                .unwrap_or(DEFAULT_MAX_CONNECTIONS_PER_PEER)
+                .max(MIN_MAX_CONNECTIONS_PER_PEER),
+            muxer: self.muxer.unwrap_or_default(),
+            yamux_receive_window_size: self
+                .yamux_receive_window_size
+                .unwrap_or(DEFAULT_YAMUX_RECEIVE_WINDOW_SIZE)
+                .max(MIN_YAMUX_RECEIVE_WINDOW_SIZE),
+            yamux_max_buffer_size: self.yamux_max_buffer_size.unwrap_or(DEFAULT_YAMUX_MAX_BUFFER_SIZE),
+            gossip_inbound_capacity: self
+                .gossip_inbound_capacity
+                .unwrap_or(DEFAULT_GOSSIP_INBOUND_CAPACITY)
+                .max(MIN_GOSSIP_CAPACITY),
+            gossip_outbound_capacity: self
+                .gossip_outbound_capacity
+                .unwrap_or(DEFAULT_GOSSIP_OUTBOUND_CAPACITY)
+                .max(MIN_GOSSIP_CAPACITY),
+            gossip_overflow_policy: self.gossip_overflow_policy.unwrap_or_default(),
+            gossip_compression,
+            peer_groups,
+            static_peers,
         })
     }
 }
@@ -354,9 +876,23 @@ impl InMemoryNetworkConfigBuilder {
             bind_multiaddr: self
                 .bind_multiaddr
                 .unwrap_or_else(|| DEFAULT_BIND_MULTIADDR_MEM.parse().unwrap()),
+            additional_bind_multiaddrs: Vec::new(),
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
+            max_reconnect_interval_secs: DEFAULT_MAX_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            connection_timeout_secs: DEFAULT_CONNECTION_TIMEOUT_SECS,
+            handshake_timeout_secs: DEFAULT_HANDSHAKE_TIMEOUT_SECS,
+            idle_connection_timeout_secs: DEFAULT_IDLE_CONNECTION_TIMEOUT_SECS,
+            max_connections_per_peer: DEFAULT_MAX_CONNECTIONS_PER_PEER,
+            muxer: MuxerSelection::default(),
+            yamux_receive_window_size: DEFAULT_YAMUX_RECEIVE_WINDOW_SIZE,
+            yamux_max_buffer_size: DEFAULT_YAMUX_MAX_BUFFER_SIZE,
+            gossip_inbound_capacity: DEFAULT_GOSSIP_INBOUND_CAPACITY,
+            gossip_outbound_capacity: DEFAULT_GOSSIP_OUTBOUND_CAPACITY,
+            gossip_overflow_policy: OverflowPolicy::default(),
+            gossip_compression: None,
+            peer_groups: Default::default(),
             static_peers: Default::default(),
         }
     }
@@ -372,6 +908,7 @@ pub struct Peer {
     pub peer_id: PeerId,
     pub multiaddr: Multiaddr,
     pub alias: Option<String>,
+    pub group: Option<String>,
 }
 
 impl Eq for Peer {}
@@ -407,6 +944,7 @@ impl ManualPeeringConfigBuilder {
                         peer_id,
                         multiaddr,
                         alias: builder.alias,
+                        group: builder.group,
                     }) {
                         return Err(Error::DuplicateStaticPeer(peer_id));
                     }
@@ -441,6 +979,27 @@ pub struct PeerBuilder {
     #[serde(alias = "address")]
     multiaddr: String,
     alias: Option<String>,
+    group: Option<String>,
+}
+
+#[derive(Deserialize, PartialEq)]
+#[must_use]
+pub struct PeerGroupBuilder {
+    name: String,
+    #[serde(alias = "maxPeers")]
+    max_peers: Option<usize>,
+    #[serde(alias = "reconnectPriority")]
+    reconnect_priority: Option<u8>,
+}
+
+impl PeerGroupBuilder {
+    pub fn finish(self) -> PeerGroup {
+        PeerGroup {
+            name: self.name,
+            max_peers: self.max_peers,
+            reconnect_priority: self.reconnect_priority.unwrap_or_default(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -495,6 +1054,140 @@ mod tests {
             .finish();
     }
 
+    #[test]
+    fn create_with_builder_and_valid_additional_ws_bind_address() {
+        let config = NetworkConfig::build()
+            .with_additional_bind_multiaddr("/ip4/127.0.0.1/tcp/1338/ws".parse().unwrap())
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            config.additional_bind_multiaddrs(),
+            &["/ip4/127.0.0.1/tcp/1338/ws".parse::<Multiaddr>().unwrap()]
+        );
+    }
+
+    #[test]
+    fn create_with_builder_and_ws_bind_address() {
+        assert!(matches!(
+            NetworkConfig::build().with_bind_multiaddr("/ip4/127.0.0.1/tcp/1338/ws".parse().unwrap()),
+            Err(Error::MultiaddrOverspecified)
+        ));
+    }
+
+    #[test]
+    fn create_with_builder_and_invalid_additional_bind_address() {
+        assert!(matches!(
+            NetworkConfig::build().with_additional_bind_multiaddr("/ip4/127.0.0.1/tcp/1338/http".parse().unwrap()),
+            Err(Error::InvalidProtocol(2))
+        ));
+    }
+
+    #[test]
+    fn create_with_builder_and_connection_settings() {
+        let config = NetworkConfig::build()
+            .with_connection_timeout_secs(30)
+            .with_handshake_timeout_secs(0)
+            .with_max_connections_per_peer(0)
+            .with_muxer(MuxerSelection::Yamux)
+            .with_yamux_receive_window_size(1024)
+            .finish()
+            .unwrap();
+
+        assert_eq!(config.connection_timeout_secs(), 30);
+        assert_eq!(config.handshake_timeout_secs(), MIN_TIMEOUT_SECS);
+        assert_eq!(
+            config.idle_connection_timeout_secs(),
+            DEFAULT_IDLE_CONNECTION_TIMEOUT_SECS
+        );
+        assert_eq!(config.max_connections_per_peer(), MIN_MAX_CONNECTIONS_PER_PEER);
+        assert_eq!(config.muxer(), MuxerSelection::Yamux);
+        assert_eq!(config.yamux_receive_window_size(), MIN_YAMUX_RECEIVE_WINDOW_SIZE);
+        assert_eq!(config.yamux_max_buffer_size(), DEFAULT_YAMUX_MAX_BUFFER_SIZE);
+    }
+
+    #[test]
+    fn create_with_builder_and_gossip_compression() {
+        let config = NetworkConfig::build().finish().unwrap();
+
+        assert_eq!(config.gossip_compression(), None);
+
+        let config = NetworkConfig::build()
+            .with_gossip_compression(true)
+            .with_gossip_compression_level(42)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            config.gossip_compression(),
+            Some(&CompressionConfig::new(MAX_GOSSIP_COMPRESSION_LEVEL))
+        );
+        assert_eq!(config.gossip_compression().unwrap().dictionary_id(), None);
+    }
+
+    #[test]
+    fn create_with_builder_and_missing_gossip_compression_dictionary() {
+        assert!(matches!(
+            NetworkConfig::build()
+                .with_gossip_compression(true)
+                .with_gossip_compression_dictionary("does/not/exist.dict")
+                .finish(),
+            Err(Error::ReadingCompressionDictionaryFailed(..))
+        ));
+    }
+
+    #[test]
+    fn compression_dictionary_id() {
+        let config = CompressionConfig::default().with_dictionary(b"a".to_vec());
+
+        // The 64-bit FNV-1a hash of "a".
+        assert_eq!(config.dictionary_id(), Some(0xaf63_dc4c_8601_ec8c));
+    }
+
+    #[test]
+    fn create_with_builder_and_peer_groups() {
+        let config = NetworkConfig::build()
+            .with_peer_group(PeerGroup {
+                name: "datacenter".into(),
+                max_peers: Some(8),
+                reconnect_priority: 10,
+            })
+            .with_peer_group(PeerGroup::new("partners"))
+            .finish()
+            .unwrap();
+
+        assert_eq!(config.peer_groups().len(), 2);
+        assert_eq!(config.peer_groups()["datacenter"].max_peers, Some(8));
+        assert_eq!(config.peer_groups()["partners"].reconnect_priority, 0);
+    }
+
+    #[test]
+    fn create_with_builder_and_duplicate_peer_group() {
+        assert!(matches!(
+            NetworkConfig::build()
+                .with_peer_group(PeerGroup::new("datacenter"))
+                .with_peer_group(PeerGroup::new("datacenter"))
+                .finish(),
+            Err(Error::DuplicatePeerGroup(name)) if name == "datacenter"
+        ));
+    }
+
+    #[test]
+    fn add_static_peer_with_unknown_group() {
+        let mut config = NetworkConfig::default();
+
+        assert!(matches!(
+            config.add_static_peer_with_group(
+                "12D3KooWJWEKvSFbben74C7H4YtKjhPMTDxd7gP7zxWSUEeF27st".parse().unwrap(),
+                "/ip4/127.0.0.1/tcp/15600".parse().unwrap(),
+                None,
+                Some("partners".into()),
+            ),
+            Err(Error::UnknownPeerGroup(name)) if name == "partners"
+        ));
+    }
+
     #[test]
     #[should_panic]
     fn create_with_mem_builder_and_non_mem_multiaddr() {
@@ -3,19 +3,21 @@
 
 #![cfg(feature = "full")]
 
+use std::{collections::HashMap, time::Duration};
+
 use libp2p::identity;
 use once_cell::sync::OnceCell;
 
 use super::{
-    config::NetworkConfig,
+    config::{CompressionConfig, NetworkConfig, OverflowPolicy, PeerGroup},
     error::Error,
     peer::{
         info::{PeerInfo, PeerRelation},
         list::{PeerList, PeerListWrapper},
     },
     service::{
-        command::{command_channel, NetworkCommandSender},
-        event::{event_channel, Event, InternalEvent, NetworkEventReceiver},
+        command::{command_channel, internal_command_channel, NetworkCommandSender},
+        event::{event_channel, internal_event_channel, Event, NetworkEventReceiver},
     },
     Keypair, PeerId,
 };
@@ -23,16 +25,23 @@ use crate::{
     alias,
     network::host::NetworkHostConfig,
     service::host::{self, ServiceHostConfig},
-    swarm::builder::build_swarm,
+    swarm::builder::{build_swarm, SwarmConfig},
 };
 
 pub mod global {
     use super::*;
 
     static RECONNECT_INTERVAL_SECS: OnceCell<u64> = OnceCell::new();
+    static MAX_RECONNECT_INTERVAL_SECS: OnceCell<u64> = OnceCell::new();
     static NETWORK_ID: OnceCell<u64> = OnceCell::new();
     static MAX_UNKNOWN_PEERS: OnceCell<usize> = OnceCell::new();
     static MAX_DISCOVERED_PEERS: OnceCell<usize> = OnceCell::new();
+    static GOSSIP_INBOUND_CAPACITY: OnceCell<usize> = OnceCell::new();
+    static GOSSIP_OUTBOUND_CAPACITY: OnceCell<usize> = OnceCell::new();
+    static GOSSIP_OVERFLOW_POLICY: OnceCell<OverflowPolicy> = OnceCell::new();
+    static IDLE_CONNECTION_TIMEOUT_SECS: OnceCell<u64> = OnceCell::new();
+    static PEER_GROUPS: OnceCell<HashMap<String, PeerGroup>> = OnceCell::new();
+    static GOSSIP_COMPRESSION: OnceCell<Option<CompressionConfig>> = OnceCell::new();
 
     pub fn set_reconnect_interval_secs(reconnect_interval_secs: u64) {
         if cfg!(test) {
@@ -48,6 +57,20 @@ pub mod global {
         *RECONNECT_INTERVAL_SECS.get().expect("oncecell get")
     }
 
+    pub fn set_max_reconnect_interval_secs(max_reconnect_interval_secs: u64) {
+        if cfg!(test) {
+            let _ = MAX_RECONNECT_INTERVAL_SECS.set(max_reconnect_interval_secs);
+        } else {
+            MAX_RECONNECT_INTERVAL_SECS
+                .set(max_reconnect_interval_secs)
+                .expect("oncecell set");
+        }
+    }
+
+    pub fn max_reconnect_interval_secs() -> u64 {
+        *MAX_RECONNECT_INTERVAL_SECS.get().expect("oncecell get")
+    }
+
     pub fn set_network_id(network_id: u64) {
         if cfg!(test) {
             let _ = NETWORK_ID.set(network_id);
@@ -83,6 +106,88 @@ pub mod global {
     pub fn max_discovered_peers() -> usize {
         *MAX_DISCOVERED_PEERS.get().expect("oncecell get")
     }
+
+    pub fn set_gossip_inbound_capacity(gossip_inbound_capacity: usize) {
+        if cfg!(test) {
+            let _ = GOSSIP_INBOUND_CAPACITY.set(gossip_inbound_capacity);
+        } else {
+            GOSSIP_INBOUND_CAPACITY
+                .set(gossip_inbound_capacity)
+                .expect("oncecell set");
+        }
+    }
+
+    pub fn gossip_inbound_capacity() -> usize {
+        *GOSSIP_INBOUND_CAPACITY.get().expect("oncecell get")
+    }
+
+    pub fn set_gossip_outbound_capacity(gossip_outbound_capacity: usize) {
+        if cfg!(test) {
+            let _ = GOSSIP_OUTBOUND_CAPACITY.set(gossip_outbound_capacity);
+        } else {
+            GOSSIP_OUTBOUND_CAPACITY
+                .set(gossip_outbound_capacity)
+                .expect("oncecell set");
+        }
+    }
+
+    pub fn gossip_outbound_capacity() -> usize {
+        *GOSSIP_OUTBOUND_CAPACITY.get().expect("oncecell get")
+    }
+
+    pub fn set_gossip_overflow_policy(gossip_overflow_policy: OverflowPolicy) {
+        if cfg!(test) {
+            let _ = GOSSIP_OVERFLOW_POLICY.set(gossip_overflow_policy);
+        } else {
+            GOSSIP_OVERFLOW_POLICY
+                .set(gossip_overflow_policy)
+                .expect("oncecell set");
+        }
+    }
+
+    pub fn gossip_overflow_policy() -> OverflowPolicy {
+        *GOSSIP_OVERFLOW_POLICY.get().expect("oncecell get")
+    }
+
+    pub fn set_idle_connection_timeout_secs(idle_connection_timeout_secs: u64) {
+        if cfg!(test) {
+            let _ = IDLE_CONNECTION_TIMEOUT_SECS.set(idle_connection_timeout_secs);
+        } else {
+            IDLE_CONNECTION_TIMEOUT_SECS
+                .set(idle_connection_timeout_secs)
+                .expect("oncecell set");
+        }
+    }
+
+    pub fn idle_connection_timeout_secs() -> u64 {
+        *IDLE_CONNECTION_TIMEOUT_SECS.get().expect("oncecell get")
+    }
+
+    pub fn set_peer_groups(peer_groups: HashMap<String, PeerGroup>) {
+        if cfg!(test) {
+            let _ = PEER_GROUPS.set(peer_groups);
+        } else {
+            PEER_GROUPS.set(peer_groups).expect("oncecell set");
+        }
+    }
+
+    /// Returns the peer group with the given name, if it was configured.
+    pub fn peer_group(name: &str) -> Option<&'static PeerGroup> {
+        PEER_GROUPS.get().and_then(|peer_groups| peer_groups.get(name))
+    }
+
+    pub fn set_gossip_compression(gossip_compression: Option<CompressionConfig>) {
+        if cfg!(test) {
+            let _ = GOSSIP_COMPRESSION.set(gossip_compression);
+        } else {
+            GOSSIP_COMPRESSION.set(gossip_compression).expect("oncecell set");
+        }
+    }
+
+    /// Returns the gossip compression settings, if compression is enabled.
+    pub fn gossip_compression() -> Option<&'static CompressionConfig> {
+        GOSSIP_COMPRESSION.get().and_then(Option::as_ref)
+    }
 }
 
 /// Initializes a "standalone" version of the network layer.
@@ -162,28 +267,49 @@ fn init(
 > {
     let NetworkConfig {
         bind_multiaddr,
+        additional_bind_multiaddrs,
         reconnect_interval_secs,
+        max_reconnect_interval_secs,
         max_unknown_peers,
         max_discovered_peers,
+        connection_timeout_secs,
+        handshake_timeout_secs,
+        idle_connection_timeout_secs,
+        max_connections_per_peer,
+        muxer,
+        yamux_receive_window_size,
+        yamux_max_buffer_size,
+        gossip_inbound_capacity,
+        gossip_outbound_capacity,
+        gossip_overflow_policy,
+        gossip_compression,
+        peer_groups,
         static_peers: peers,
     } = config;
 
     global::set_reconnect_interval_secs(reconnect_interval_secs);
+    global::set_max_reconnect_interval_secs(max_reconnect_interval_secs);
     global::set_network_id(network_id);
     global::set_max_unknown_peers(max_unknown_peers);
     global::set_max_discovered_peers(max_discovered_peers);
+    global::set_gossip_inbound_capacity(gossip_inbound_capacity);
+    global::set_gossip_outbound_capacity(gossip_outbound_capacity);
+    global::set_gossip_overflow_policy(gossip_overflow_policy);
+    global::set_idle_connection_timeout_secs(idle_connection_timeout_secs);
+    global::set_peer_groups(peer_groups);
+    global::set_gossip_compression(gossip_compression);
 
     let (command_sender, command_receiver) = command_channel();
-    let (internal_command_sender, internal_command_receiver) = command_channel();
+    let (internal_command_sender, internal_command_receiver) = internal_command_channel();
 
-    let (event_sender, event_receiver) = event_channel::<Event>();
-    let (internal_event_sender, internal_event_receiver) = event_channel::<InternalEvent>();
+    let (event_sender, event_receiver) = event_channel();
+    let (internal_event_sender, internal_event_receiver) = internal_event_channel();
 
     let local_keys = identity::Keypair::Ed25519(keys);
     let local_id = PeerId::from_public_key(&local_keys.public());
 
     event_sender
-        .send(Event::LocalIdCreated { local_id })
+        .try_send(Event::LocalIdCreated { local_id })
         .map_err(|_| Error::LocalIdAnnouncementFailed)?;
 
     // TODO: rename to PeerStateMap.
@@ -193,19 +319,30 @@ fn init(
     for peer in peers.into_iter() {
         let peer_id = peer.peer_id;
         event_sender
-            .send(Event::PeerAdded {
+            .try_send(Event::PeerAdded {
                 peer_id,
                 info: PeerInfo {
                     address: peer.multiaddr,
                     alias: peer.alias.unwrap_or_else(|| alias!(peer_id).into()),
                     relation: PeerRelation::Known,
+                    group: peer.group,
+                    reconnect_backoff: Default::default(),
                 },
             })
             .map_err(|_| Error::StaticPeersAnnouncementFailed)?;
     }
 
     // Create the transport layer.
-    let swarm = build_swarm(&local_keys, internal_event_sender.clone()).map_err(|_| Error::CreatingTransportFailed)?;
+    let swarm_config = SwarmConfig {
+        connection_timeout: Duration::from_secs(connection_timeout_secs),
+        handshake_timeout: Duration::from_secs(handshake_timeout_secs),
+        max_connections_per_peer,
+        muxer,
+        yamux_receive_window_size,
+        yamux_max_buffer_size,
+    };
+    let swarm = build_swarm(&local_keys, internal_event_sender.clone(), swarm_config)
+        .map_err(|_| Error::CreatingTransportFailed)?;
 
     let network_host_config = NetworkHostConfig {
         internal_event_sender: internal_event_sender.clone(),
@@ -213,6 +350,7 @@ fn init(
         peerlist: peerlist.clone(),
         swarm,
         bind_multiaddr,
+        additional_bind_multiaddrs,
     };
 
     let service_host_config = ServiceHostConfig {
@@ -31,18 +31,21 @@ pub use libp2p_core::{
     PeerId,
 };
 
-pub use self::peer::info::{PeerInfo, PeerRelation};
+pub use self::peer::info::{PeerInfo, PeerRelation, ReconnectBackoff};
 #[cfg(feature = "full")]
 pub use crate::{
-    config::{NetworkConfig, NetworkConfigBuilder},
+    config::{CompressionConfig, MuxerSelection, NetworkConfig, NetworkConfigBuilder, OverflowPolicy, PeerGroup},
     error::Error,
-    init::{integrated, standalone},
+    init::{global::peer_group, integrated, standalone},
     network::host::integrated::NetworkHost,
-    network::origin::Origin,
+    network::{origin::Origin, transport::TransportKind},
     service::{
         command::{Command, NetworkCommandSender},
+        error::Error as ServiceError,
         event::{Event, NetworkEventReceiver},
         host::integrated::ServiceHost,
     },
-    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender},
+    swarm::protocols::iota_gossip::{
+        channel as gossip_channel, GossipCodec, GossipDirection, GossipReceiver, GossipSendError, GossipSender,
+    },
 };
@@ -10,7 +10,7 @@ use crate::{
     alias,
     peer::{info::PeerInfo, list::PeerListWrapper as PeerList},
     service::{
-        command::{Command, CommandReceiver},
+        command::{Command, InternalCommandReceiver},
         event::{InternalEvent, InternalEventSender},
     },
     swarm::behaviour::SwarmBehaviour,
@@ -18,10 +18,11 @@ use crate::{
 
 pub struct NetworkHostConfig {
     pub internal_event_sender: InternalEventSender,
-    pub internal_command_receiver: CommandReceiver,
+    pub internal_command_receiver: InternalCommandReceiver,
     pub peerlist: PeerList,
     pub swarm: Swarm<SwarmBehaviour>,
     pub bind_multiaddr: Multiaddr,
+    pub additional_bind_multiaddrs: Vec<Multiaddr>,
 }
 
 pub mod integrated {
@@ -102,11 +103,15 @@ async fn network_host_processor(
         peerlist,
         mut swarm,
         bind_multiaddr,
+        additional_bind_multiaddrs,
     } = config;
 
-    // Try binding to the configured bind address.
-    info!("Binding to: {}", bind_multiaddr);
-    let _listener_id = Swarm::listen_on(&mut swarm, bind_multiaddr).map_err(|_| crate::Error::BindingAddressFailed)?;
+    // Try binding to the configured bind addresses.
+    for bind_multiaddr in std::iter::once(bind_multiaddr).chain(additional_bind_multiaddrs) {
+        info!("Binding to: {}", bind_multiaddr);
+        let _listener_id =
+            Swarm::listen_on(&mut swarm, bind_multiaddr).map_err(|_| crate::Error::BindingAddressFailed)?;
+    }
 
     // Enter command/event loop.
     loop {
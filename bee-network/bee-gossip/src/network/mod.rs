@@ -6,3 +6,4 @@
 pub mod error;
 pub mod host;
 pub mod origin;
+pub mod transport;
@@ -0,0 +1,84 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use libp2p::{multiaddr::Protocol, Multiaddr};
+
+/// Describes the transport an established connection runs on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransportKind {
+    /// The connection runs on plain TCP.
+    Tcp,
+    /// The connection runs on WebSocket (over TCP).
+    WebSocket,
+    /// The connection runs in-memory (used for testing).
+    Memory,
+}
+
+impl TransportKind {
+    /// Returns the transport of a connection from the address of its remote endpoint.
+    pub fn from_multiaddr(multiaddr: &Multiaddr) -> Option<Self> {
+        let mut transport = None;
+
+        for protocol in multiaddr.iter() {
+            match protocol {
+                Protocol::Tcp(_) => transport = Some(Self::Tcp),
+                Protocol::Ws(_) => return Some(Self::WebSocket),
+                Protocol::Memory(_) => return Some(Self::Memory),
+                _ => {}
+            }
+        }
+
+        transport
+    }
+
+    /// Returns whether the connection runs on plain TCP.
+    pub fn is_tcp(&self) -> bool {
+        matches!(self, Self::Tcp)
+    }
+
+    /// Returns whether the connection runs on WebSocket.
+    pub fn is_websocket(&self) -> bool {
+        matches!(self, Self::WebSocket)
+    }
+}
+
+impl fmt::Display for TransportKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TransportKind::Tcp => f.write_str("tcp"),
+            TransportKind::WebSocket => f.write_str("websocket"),
+            TransportKind::Memory => f.write_str("memory"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_multiaddr() {
+        assert_eq!(
+            TransportKind::from_multiaddr(&"/ip4/127.0.0.1/tcp/15600".parse().unwrap()),
+            Some(TransportKind::Tcp)
+        );
+        assert_eq!(
+            TransportKind::from_multiaddr(&"/ip4/127.0.0.1/tcp/15601/ws".parse().unwrap()),
+            Some(TransportKind::WebSocket)
+        );
+        assert_eq!(
+            TransportKind::from_multiaddr(&"/memory/1337".parse().unwrap()),
+            Some(TransportKind::Memory)
+        );
+        assert_eq!(TransportKind::from_multiaddr(&"/ip4/127.0.0.1".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(&TransportKind::Tcp.to_string(), "tcp");
+        assert_eq!(&TransportKind::WebSocket.to_string(), "websocket");
+        assert_eq!(&TransportKind::Memory.to_string(), "memory");
+    }
+}
@@ -59,4 +59,12 @@ pub enum Error {
     /// A failure due to hitting the maximum number of allowed discovered peers.
     #[error("Tried to add more discovered peers than defined in the config ({0}).")]
     ExceedsDiscoveredPeerLimit(usize),
+
+    /// A failure due to hitting the maximum number of allowed peers of a peer group.
+    #[error("Tried to add more peers to group '{0}' than defined in the config ({1}).")]
+    ExceedsGroupPeerLimit(String, usize),
+
+    /// A failure due to a peer group not being configured.
+    #[error("Unknown peer group: {0}")]
+    UnknownPeerGroup(String),
 }
@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use libp2p_core::Multiaddr;
 
 /// Additional information about a peer.
@@ -12,6 +14,31 @@ pub struct PeerInfo {
     pub alias: String,
     /// The type of relation regarding this peer.
     pub relation: PeerRelation,
+    /// The name of the peer group this peer is a member of, if any.
+    pub group: Option<String>,
+    /// The reconnect back-off state regarding this peer.
+    pub reconnect_backoff: ReconnectBackoff,
+}
+
+/// Describes the reconnect back-off state of a peer.
+///
+/// The delay between two reconnect attempts grows exponentially with every failed attempt, and is reset once the peer
+/// is connected again.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReconnectBackoff {
+    /// The number of reconnect attempts since the peer was last connected.
+    pub attempts: u32,
+    /// The (jittered) delay before the next reconnect attempt.
+    pub delay: Duration,
+    /// The UNIX timestamp (in seconds) of the next reconnect attempt, if one is scheduled.
+    pub next_attempt: Option<u64>,
+}
+
+impl ReconnectBackoff {
+    /// Returns whether a reconnect attempt is due at the given UNIX timestamp (in seconds).
+    pub fn is_due(&self, timestamp: u64) -> bool {
+        self.next_attempt.map_or(true, |next_attempt| timestamp >= next_attempt)
+    }
 }
 
 /// Describes the relation with a peer.
@@ -67,6 +94,16 @@ impl PeerRelation {
 mod tests {
     use super::*;
 
+    #[test]
+    fn reconnect_backoff_is_due() {
+        let mut backoff = ReconnectBackoff::default();
+        assert!(backoff.is_due(0));
+
+        backoff.next_attempt = Some(10);
+        assert!(!backoff.is_due(9));
+        assert!(backoff.is_due(10));
+    }
+
     #[test]
     fn is_and_set_api() {
         let mut pr = PeerRelation::Unknown;
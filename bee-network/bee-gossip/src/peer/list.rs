@@ -61,6 +61,8 @@ impl PeerList {
                         address: peer.multiaddr,
                         alias: peer.alias.unwrap_or_else(|| alias!(peer_id).to_owned()),
                         relation: PeerRelation::Known,
+                        group: peer.group,
+                        reconnect_backoff: Default::default(),
                     },
                     PeerState::default(),
                     PeerMetrics::default(),
@@ -270,6 +272,7 @@ impl PeerList {
         // - Deny banned peers.
         // - Deny banned addresses.
         // - Deny already connected peers.
+        // - Deny more than the configured peers of a group (members aren't subject to the following limits).
         // - Deny more than the configured unknown peers.
         // - Deny more than the configured discovered peers.
         if peer_id == &self.local_id {
@@ -285,6 +288,8 @@ impl PeerList {
             .unwrap_or(false)
         {
             Err(Error::PeerIsConnected(*peer_id))
+        } else if let Some(group) = self.peers.get(peer_id).and_then(|(info, _, _)| info.group.as_ref()) {
+            self.accepts_group_member(group)
         } else if !self.contains(peer_id)
             && self.filter_count(|info, _, _| info.relation.is_unknown()) >= global::max_unknown_peers()
         {
@@ -307,6 +312,7 @@ impl PeerList {
         // - Deny dialing an already connected peer.
         // - Deny dialing a local address.
         // - Deny dialing a banned address.
+        // - Deny dialing more than configured peers of a group (members aren't subject to the following limits).
         // - Deny dialing more than configured unkown peers.
         // - Deny dialing more than configured discovered peers.
         if peer_id == &self.local_id {
@@ -327,6 +333,8 @@ impl PeerList {
                 Err(Error::AddressIsLocal(peer_info.address.clone()))
             } else if self.banned_addrs.contains(&peer_info.address) {
                 Err(Error::AddressIsBanned(peer_info.address.clone()))
+            } else if let Some(group) = &peer_info.group {
+                self.accepts_group_member(group)
             } else if peer_info.relation.is_unknown()
                 && self.filter_count(|info, status, _| info.relation.is_unknown() && status.is_connected())
                     >= global::max_unknown_peers()
@@ -361,6 +369,23 @@ impl PeerList {
         }
     }
 
+    /// Returns the number of connected members of a peer group.
+    pub fn group_connected_count(&self, group: &str) -> usize {
+        self.filter_count(|info, state, _| state.is_connected() && info.group.as_deref() == Some(group))
+    }
+
+    fn accepts_group_member(&self, group: &str) -> Result<(), Error> {
+        match global::peer_group(group) {
+            None => Err(Error::UnknownPeerGroup(group.to_string())),
+            Some(peer_group) => match peer_group.max_peers {
+                Some(max_peers) if self.group_connected_count(group) >= max_peers => {
+                    Err(Error::ExceedsGroupPeerLimit(group.to_string(), max_peers))
+                }
+                _ => Ok(()),
+            },
+        }
+    }
+
     fn find_peer_if_connected(&self, addr: &Multiaddr) -> Option<PeerId> {
         self.filter(|info, state, _| state.is_connected() && info.address == *addr)
             .next()
@@ -373,6 +398,9 @@ mod tests {
     use libp2p::{identity::ed25519::Keypair, multiaddr::Protocol};
 
     use super::*;
+    use crate::{
+        config::OverflowPolicy, swarm::protocols::iota_gossip::channel, tests::common::network_config::test_peer_groups,
+    };
 
     #[test]
     fn new_list() {
@@ -387,13 +415,12 @@ mod tests {
         let mut pl = PeerList::new(local_id);
 
         for i in 1..=3 {
-            assert!(
-                pl.add(
+            assert!(pl
+                .add(
                     gen_random_peer_id(),
                     gen_deterministic_peer_info(i, PeerRelation::Known)
                 )
-                .is_ok()
-            );
+                .is_ok());
             assert_eq!(pl.len(), i as usize);
         }
     }
@@ -438,6 +465,69 @@ mod tests {
         pl.accepts_incoming_peer(&peer_id, &peer_info.address).unwrap();
     }
 
+    #[test]
+    fn deny_dialing_member_of_unknown_group() {
+        let local_id = gen_constant_peer_id();
+        let peer_id = gen_random_peer_id();
+        let mut peer_info = gen_constant_peer_info();
+        peer_info.group = Some("unconfigured".into());
+
+        let mut pl = PeerList::new(local_id);
+
+        pl.add(peer_id, peer_info).unwrap();
+
+        assert_eq!(pl.group_connected_count("unconfigured"), 0);
+        assert!(matches!(
+            pl.allows_dialing_peer(&peer_id),
+            Err(Error::UnknownPeerGroup(group)) if group == "unconfigured"
+        ));
+    }
+
+    #[test]
+    fn deny_exceeding_group_peer_limit() {
+        global::set_peer_groups(test_peer_groups());
+
+        let local_id = gen_constant_peer_id();
+        let mut pl = PeerList::new(local_id);
+
+        let (connected_id, waiting_id, partner_id) = (gen_random_peer_id(), gen_random_peer_id(), gen_random_peer_id());
+
+        for (i, (peer_id, group)) in [
+            (connected_id, "datacenter"),
+            (waiting_id, "datacenter"),
+            (partner_id, "partners"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut peer_info = gen_deterministic_peer_info(i as u16, PeerRelation::Known);
+            peer_info.group = Some(group.into());
+            pl.add(peer_id, peer_info).unwrap();
+        }
+
+        pl.allows_dialing_peer(&waiting_id).unwrap();
+
+        let (tx, _rx) = channel(1, OverflowPolicy::default());
+        pl.update_state(&connected_id, |state| state.set_connected(tx.clone()))
+            .unwrap();
+
+        assert_eq!(pl.group_connected_count("datacenter"), 1);
+        assert!(matches!(
+            pl.allows_dialing_peer(&waiting_id),
+            Err(Error::ExceedsGroupPeerLimit(group, 1)) if group == "datacenter"
+        ));
+        assert!(matches!(
+            pl.accepts_incoming_peer(&waiting_id, &gen_deterministic_addr(1)),
+            Err(Error::ExceedsGroupPeerLimit(group, 1)) if group == "datacenter"
+        ));
+        // Groups without a limit are not affected.
+        pl.allows_dialing_peer(&partner_id).unwrap();
+
+        pl.update_state(&connected_id, PeerState::set_disconnected).unwrap();
+
+        pl.allows_dialing_peer(&waiting_id).unwrap();
+    }
+
     #[test]
     fn conditional_remove() {
         let local_id = gen_constant_peer_id();
@@ -471,6 +561,8 @@ mod tests {
             address: gen_deterministic_addr(port),
             alias: port.to_string(),
             relation,
+            group: None,
+            reconnect_backoff: Default::default(),
         }
     }
 
@@ -479,6 +571,8 @@ mod tests {
             address: gen_deterministic_addr(1),
             alias: String::new(),
             relation: PeerRelation::Known,
+            group: None,
+            reconnect_backoff: Default::default(),
         }
     }
 
@@ -499,6 +593,7 @@ pub enum PeerState {
 #[derive(Clone, Debug, Default)]
 pub struct PeerMetrics {
     pub(crate) num_dials: usize,
+    pub(crate) reported_unreachable: bool,
     pub(crate) identified_at: Option<u64>,
 }
 
@@ -533,7 +628,7 @@ impl PeerState {
 #[cfg(test)]
 mod peerstate_tests {
     use super::*;
-    use crate::swarm::protocols::iota_gossip::channel;
+    use crate::{config::OverflowPolicy, swarm::protocols::iota_gossip::channel};
 
     #[test]
     fn new_peer_state() {
@@ -545,7 +640,7 @@ mod peerstate_tests {
     #[test]
     fn peer_state_change() {
         let mut peerstate = PeerState::Disconnected;
-        let (tx, _rx) = channel();
+        let (tx, _rx) = channel(1, OverflowPolicy::default());
 
         peerstate.set_connected(tx);
         assert!(peerstate.is_connected());
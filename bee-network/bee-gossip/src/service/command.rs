@@ -7,10 +7,22 @@ use tokio::sync::mpsc;
 use super::error::Error;
 use crate::peer::info::PeerRelation;
 
-pub type CommandReceiver = mpsc::UnboundedReceiver<Command>;
-pub type CommandSender = mpsc::UnboundedSender<Command>;
+/// The number of commands that can be queued before sending another one fails.
+const COMMAND_CHANNEL_CAPACITY: usize = 1024;
+
+pub type CommandReceiver = mpsc::Receiver<Command>;
+pub type CommandSender = mpsc::Sender<Command>;
+pub type InternalCommandReceiver = mpsc::UnboundedReceiver<Command>;
+pub type InternalCommandSender = mpsc::UnboundedSender<Command>;
 
 pub fn command_channel() -> (CommandSender, CommandReceiver) {
+    mpsc::channel(COMMAND_CHANNEL_CAPACITY)
+}
+
+// Internal commands are sent by the service host while it processes the internal events of the network host, and the
+// network host sends internal events while it processes internal commands. Bounding both directions could deadlock the
+// two hosts, so the internal commands are unbounded but only ever created by the service host itself.
+pub fn internal_command_channel() -> (InternalCommandSender, InternalCommandReceiver) {
     mpsc::unbounded_channel()
 }
 
@@ -76,6 +88,13 @@ pub enum Command {
         /// The peer's new relation.
         to: PeerRelation,
     },
+    /// Changes the peer group of a peer.
+    ChangeGroup {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The name of the peer's new group, or `None` to remove the peer from its group.
+        to: Option<String>,
+    },
 }
 
 /// Allows the user to send [`Command`]s to the network layer.
@@ -89,8 +108,12 @@ impl NetworkCommandSender {
 
     /// Sends a command to the network.
     ///
-    /// NOTE: Although synchronous, this method never actually blocks.
+    /// NOTE: Although synchronous, this method never actually blocks. It fails with [`Error::CommandQueueFull`] if the
+    /// network layer can't keep up with the commands it is sent.
     pub fn send(&self, command: Command) -> Result<(), Error> {
-        self.0.send(command).map_err(|_| Error::SendingCommandFailed)
+        self.0.try_send(command).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => Error::CommandQueueFull,
+            mpsc::error::TrySendError::Closed(_) => Error::SendingCommandFailed,
+        })
     }
 }
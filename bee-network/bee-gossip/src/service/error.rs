@@ -10,6 +10,10 @@ pub enum Error {
     #[error("Error sending command.")]
     SendingCommandFailed,
 
+    /// A command could not be sent because the command queue is full.
+    #[error("Error sending command: command queue is full.")]
+    CommandQueueFull,
+
     /// An event could not be sent.
     #[error("Error sending command.")]
     SendingEventFailed,
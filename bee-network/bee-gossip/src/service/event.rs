@@ -7,17 +7,31 @@ use tokio::sync::mpsc;
 
 use super::command::Command;
 use crate::{
-    network::origin::Origin,
-    peer::{error::Error as PeerError, info::PeerInfo},
-    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender},
+    config::OverflowPolicy,
+    network::{origin::Origin, transport::TransportKind},
+    peer::{
+        error::Error as PeerError,
+        info::{PeerInfo, ReconnectBackoff},
+    },
+    swarm::protocols::iota_gossip::{GossipCodec, GossipDirection, GossipOverflows, GossipReceiver, GossipSender},
 };
 
-pub type EventSender = mpsc::UnboundedSender<Event>;
-pub type EventReceiver = mpsc::UnboundedReceiver<Event>;
+/// The number of events that can be queued before the network layer waits for the user to receive them.
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+pub type EventSender = mpsc::Sender<Event>;
+pub type EventReceiver = mpsc::Receiver<Event>;
 pub type InternalEventReceiver = mpsc::UnboundedReceiver<InternalEvent>;
 pub type InternalEventSender = mpsc::UnboundedSender<InternalEvent>;
 
-pub fn event_channel<T>() -> (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>) {
+pub fn event_channel() -> (EventSender, EventReceiver) {
+    mpsc::channel(EVENT_CHANNEL_CAPACITY)
+}
+
+// Internal events are sent synchronously from within the swarm, which can't wait for the service host to catch up, and
+// the service host sends internal commands to the network host while it processes them, see
+// `internal_command_channel`.
+pub fn internal_event_channel() -> (InternalEventSender, InternalEventReceiver) {
     mpsc::unbounded_channel()
 }
 
@@ -51,6 +65,18 @@ pub enum Event {
         reason: PeerError,
     },
 
+    /// A gossip queue of a peer overflowed.
+    GossipQueueOverflowed {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The direction of the overflowed queue.
+        direction: GossipDirection,
+        /// The policy that handled the overflow.
+        policy: OverflowPolicy,
+        /// The number of overflowed messages since the last event.
+        count: u64,
+    },
+
     /// The local peer id was created.
     LocalIdCreated {
         /// The created peer id from the Ed25519 keypair.
@@ -77,18 +103,36 @@ pub enum Event {
         peer_id: PeerId,
         /// The peer's info.
         info: PeerInfo,
+        /// The transport the connection with the peer runs on.
+        transport: Option<TransportKind>,
         /// The peer's message recv channel.
         gossip_in: GossipReceiver,
         /// The peer's message send channel.
         gossip_out: GossipSender,
     },
 
+    /// The peer group of a peer was changed.
+    PeerGroupChanged {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The name of the peer's new group, if any.
+        group: Option<String>,
+    },
+
     /// A peer was disconnected.
     PeerDisconnected {
         /// The peer's id.
         peer_id: PeerId,
     },
 
+    /// A reconnect attempt to a peer was scheduled.
+    PeerReconnectScheduled {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The peer's new reconnect back-off state.
+        backoff: ReconnectBackoff,
+    },
+
     /// A peer was removed.
     PeerRemoved {
         /// The peer's id.
@@ -101,7 +145,8 @@ pub enum Event {
         peer_id: PeerId,
     },
 
-    /// A peer didn't answer our repeated calls.
+    /// A peer didn't answer our repeated calls. It is fired once, when the maximum number of dial attempts is
+    /// reached.
     PeerUnreachable {
         /// The peer's id.
         peer_id: PeerId,
@@ -119,6 +164,16 @@ pub enum InternalEvent {
         address: Multiaddr,
     },
 
+    /// A gossip queue of a peer overflowed.
+    GossipQueueOverflowed {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The direction of the overflowed queue.
+        direction: GossipDirection,
+        /// The overflows of the queue that weren't processed yet.
+        overflows: GossipOverflows,
+    },
+
     /// The gossip protocol has been established with a peer.
     ProtocolEstablished {
         /// The peer's id.
@@ -129,6 +184,8 @@ pub enum InternalEvent {
         origin: Origin,
         /// The negotiated substream the protocol is running on.
         substream: Box<NegotiatedSubstream>,
+        /// The negotiated gossip codec.
+        codec: GossipCodec,
     },
 
     /// The gossip protocol with a peer was stopped.
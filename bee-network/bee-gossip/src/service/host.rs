@@ -1,7 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    cmp::Reverse,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use bee_runtime::shutdown_stream::ShutdownStream;
 use futures::{
@@ -13,25 +16,28 @@ use libp2p::{identity, Multiaddr, PeerId};
 use log::*;
 use rand::Rng;
 use tokio::time::{self, Duration, Instant};
-use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
+use tokio_stream::wrappers::{IntervalStream, ReceiverStream, UnboundedReceiverStream};
 
 use super::{
-    command::{Command, CommandReceiver, CommandSender},
+    command::{Command, CommandReceiver, InternalCommandSender},
     error::Error,
     event::{Event, EventSender, InternalEvent, InternalEventReceiver, InternalEventSender},
 };
 use crate::{
     alias,
+    config::OverflowPolicy,
     init::global::{self, reconnect_interval_secs},
+    network::transport::TransportKind,
     peer::{
         error::Error as PeerError,
-        info::{PeerInfo, PeerRelation},
+        info::{PeerInfo, PeerRelation, ReconnectBackoff},
         list::PeerListWrapper as PeerList,
     },
-    swarm::protocols::iota_gossip,
+    swarm::protocols::iota_gossip::{self, GossipDirection},
 };
 
 const MAX_PEER_STATE_CHECKER_DELAY_MILLIS: u64 = 2000;
+const PEER_STATE_CHECKER_INTERVAL: Duration = Duration::from_secs(1);
 const MAX_DIALS: usize = 3;
 
 pub struct ServiceHostConfig {
@@ -45,7 +51,7 @@ pub struct ServiceHostConfig {
 pub struct Senders {
     pub events: EventSender,
     pub internal_events: InternalEventSender,
-    pub internal_commands: CommandSender,
+    pub internal_commands: InternalCommandSender,
 }
 
 pub struct Receivers {
@@ -167,7 +173,7 @@ pub mod standalone {
 async fn command_processor(shutdown: Shutdown, commands: CommandReceiver, senders: Senders, peerlist: PeerList) {
     debug!("Command processor running.");
 
-    let mut commands = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(commands));
+    let mut commands = ShutdownStream::new(shutdown, ReceiverStream::new(commands));
 
     while let Some(command) = commands.next().await {
         if let Err(e) = process_command(command, &senders, &peerlist).await {
@@ -195,7 +201,6 @@ async fn event_processor(shutdown: Shutdown, events: InternalEventReceiver, send
     debug!("Event processor stopped.");
 }
 
-// TODO: implement exponential back-off to not spam the peer with reconnect attempts.
 async fn peerstate_checker(shutdown: Shutdown, senders: Senders, peerlist: PeerList) {
     debug!("Peer checker running.");
 
@@ -206,64 +211,177 @@ async fn peerstate_checker(shutdown: Shutdown, senders: Senders, peerlist: PeerL
     let delay = Duration::from_millis(rand::thread_rng().gen_range(0u64..MAX_PEER_STATE_CHECKER_DELAY_MILLIS));
     let start = Instant::now() + delay;
 
-    // The (currently) constant interval at which peer state checks happen.
-    let period = Duration::from_secs(reconnect_interval_secs());
+    // The interval at which the peer states are reported.
+    let report_period = Duration::from_secs(reconnect_interval_secs());
+    let mut next_report = start;
 
-    let mut interval = ShutdownStream::new(shutdown, IntervalStream::new(time::interval_at(start, period)));
+    let mut interval = ShutdownStream::new(
+        shutdown,
+        IntervalStream::new(time::interval_at(start, PEER_STATE_CHECKER_INTERVAL)),
+    );
 
-    // Check, if there are any disconnected known peers, and schedule a reconnect attempt for each
-    // of those.
+    // Check, if there are any disconnected known peers, and try to reconnect each of those whose back-off elapsed.
     while interval.next().await.is_some() {
         let read = peerlist.0.read().await;
 
-        // To how many known peers are we currently connected.
-        let num_known = read.filter_count(|info, _, _| info.relation.is_known());
-        let num_connected_known = read.filter_count(|info, state, _| info.relation.is_known() && state.is_connected());
-
-        // To how many unknown peers are we currently connected.
-        let num_connected_unknown =
-            read.filter_count(|info, state, _| info.relation.is_unknown() && state.is_connected());
-
-        // To how many discovered peers are we currently connected.
-        let num_connected_discovered =
-            read.filter_count(|info, state, _| info.relation.is_discovered() && state.is_connected());
-
-        // How many peers we know of but are currently disconnected.
-        let num_disconnected = read.filter_count(|_, state, _| state.is_disconnected());
-
-        info!(
-            "Connected peers: known {}/{} unknown {}/{} discovered {}/{} - Disconnected peers: {}.",
-            num_connected_known,
-            num_known,
-            num_connected_unknown,
-            global::max_unknown_peers(),
-            num_connected_discovered,
-            global::max_discovered_peers(),
-            num_disconnected,
-        );
+        if Instant::now() >= next_report {
+            next_report += report_period;
+
+            // To how many known peers are we currently connected.
+            let num_known = read.filter_count(|info, _, _| info.relation.is_known());
+            let num_connected_known =
+                read.filter_count(|info, state, _| info.relation.is_known() && state.is_connected());
+
+            // To how many unknown peers are we currently connected.
+            let num_connected_unknown =
+                read.filter_count(|info, state, _| info.relation.is_unknown() && state.is_connected());
+
+            // To how many discovered peers are we currently connected.
+            let num_connected_discovered =
+                read.filter_count(|info, state, _| info.relation.is_discovered() && state.is_connected());
+
+            // How many peers we know of but are currently disconnected.
+            let num_disconnected = read.filter_count(|_, state, _| state.is_disconnected());
+
+            info!(
+                "Connected peers: known {}/{} unknown {}/{} discovered {}/{} - Disconnected peers: {}.",
+                num_connected_known,
+                num_known,
+                num_connected_unknown,
+                global::max_unknown_peers(),
+                num_connected_discovered,
+                global::max_discovered_peers(),
+                num_disconnected,
+            );
+        }
+
+        let now = unix_timestamp_secs();
 
         // Automatically try to reconnect known **and** discovered peers. The removal of discovered peers is a decision
         // that needs to be made in the autopeering service.
-        for (peer_id, peer_info, peer_metrics) in read.filter(|info, state, _| {
-            (info.relation.is_known() || info.relation.is_discovered()) && state.is_disconnected()
-        }) {
-            if peer_metrics.num_dials >= MAX_DIALS {
-                log::debug!("Peer {} is unreachable.", peer_id);
-
-                let _ = senders.events.send(Event::PeerUnreachable { peer_id, peer_info });
-                continue;
+        let mut due_peers = read
+            .filter(|info, state, _| {
+                (info.relation.is_known() || info.relation.is_discovered())
+                    && state.is_disconnected()
+                    && info.reconnect_backoff.is_due(now)
+            })
+            .collect::<Vec<_>>();
+
+        // We no longer need to hold the lock.
+        drop(read);
+
+        // Dial the members of groups with a higher reconnect priority first, so they get the free slots.
+        sort_by_reconnect_priority(&mut due_peers);
+
+        for (peer_id, peer_info, peer_metrics) in due_peers {
+            let dial = if peer_metrics.num_dials >= MAX_DIALS {
+                // The event is only fired when the threshold is crossed. It is fired again only if the peer gets
+                // identified in between, which resets its dial count.
+                if !peer_metrics.reported_unreachable {
+                    debug!("Peer {} is unreachable.", alias!(peer_id));
+
+                    let _ = senders
+                        .events
+                        .send(Event::PeerUnreachable {
+                            peer_id,
+                            peer_info: peer_info.clone(),
+                        })
+                        .await;
+
+                    let _ = peerlist
+                        .0
+                        .write()
+                        .await
+                        .update_metrics(&peer_id, |m| m.reported_unreachable = true);
+                }
+
+                // Discovered peers are not dialed anymore, their removal is up to the autopeering. Known peers were
+                // added by the operator and are redialed without limit, but no more often than the reconnect back-off,
+                // which is capped by `max_reconnect_interval_secs`, allows.
+                peer_info.relation.is_known()
+            } else {
+                true
+            };
+
+            if dial {
+                debug!(
+                    "Trying to reconnect to: {} ({}) attempt: #{}.",
+                    peer_info.alias,
+                    alias!(peer_id),
+                    peer_info.reconnect_backoff.attempts + 1
+                );
+
+                // Ignore if the command fails. We can always retry the next time.
+                let _ = senders.internal_commands.send(Command::DialPeer { peer_id });
             }
 
-            debug!("Trying to reconnect to: {} ({}).", peer_info.alias, alias!(peer_id));
+            let attempts = peer_info.reconnect_backoff.attempts.saturating_add(dial as u32);
 
-            // Ignore if the command fails. We can always retry the next time.
-            let _ = senders.internal_commands.send(Command::DialPeer { peer_id });
+            schedule_reconnect(peer_id, attempts, now, &senders, &peerlist).await;
         }
     }
 
     debug!("Peer checker stopped.");
 }
 
+/// Sorts peers by the reconnect priority of their group, highest first. Peers without a configured group have the
+/// lowest priority, and the order of peers with the same priority is kept.
+fn sort_by_reconnect_priority<M>(peers: &mut [(PeerId, PeerInfo, M)]) {
+    peers.sort_by_key(|(_, info, _)| {
+        Reverse(
+            info.group
+                .as_deref()
+                .and_then(global::peer_group)
+                .map_or(0, |group| group.reconnect_priority),
+        )
+    });
+}
+
+/// Returns the jittered delay before the next reconnect attempt after a number of failed attempts.
+fn reconnect_delay(attempts: u32) -> Duration {
+    let base = reconnect_interval_secs();
+    let max = global::max_reconnect_interval_secs().max(base);
+    let delay_millis = base
+        .saturating_mul(1u64.checked_shl(attempts).unwrap_or(u64::MAX))
+        .min(max)
+        .saturating_mul(1000);
+
+    // Only half of the delay is fixed, the other half is random, so peers that dropped at the same time don't keep
+    // dialing in sync.
+    Duration::from_millis(delay_millis / 2 + rand::thread_rng().gen_range(0..=delay_millis / 2))
+}
+
+/// Schedules the next reconnect attempt to a peer and publishes its new back-off state.
+async fn schedule_reconnect(peer_id: PeerId, attempts: u32, now: u64, senders: &Senders, peerlist: &PeerList) {
+    let delay = reconnect_delay(attempts);
+    let backoff = ReconnectBackoff {
+        attempts,
+        delay,
+        next_attempt: Some(now + delay.as_secs()),
+    };
+
+    // Ignore errors in-case the peer was removed in the meantime.
+    if peerlist
+        .0
+        .write()
+        .await
+        .update_info(&peer_id, |info| info.reconnect_backoff = backoff)
+        .is_ok()
+    {
+        let _ = senders
+            .events
+            .send(Event::PeerReconnectScheduled { peer_id, backoff })
+            .await;
+    }
+}
+
+fn unix_timestamp_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time")
+        .as_secs()
+}
+
 async fn process_command(command: Command, senders: &Senders, peerlist: &PeerList) -> Result<(), Error> {
     trace!("Received {:?}.", command);
 
@@ -288,6 +406,7 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
             senders
                 .events
                 .send(Event::AddressBanned { address })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
         }
 
@@ -297,6 +416,7 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
             senders
                 .events
                 .send(Event::PeerBanned { peer_id })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
         }
 
@@ -308,6 +428,24 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
                 .update_info(&peer_id, |info| info.relation = to)?;
         }
 
+        Command::ChangeGroup { peer_id, to } => {
+            if let Some(group) = to.as_ref().filter(|group| global::peer_group(group).is_none()) {
+                return Err(PeerError::UnknownPeerGroup(group.clone()).into());
+            }
+
+            peerlist
+                .0
+                .write()
+                .await
+                .update_info(&peer_id, |info| info.group = to.clone())?;
+
+            senders
+                .events
+                .send(Event::PeerGroupChanged { peer_id, group: to })
+                .await
+                .map_err(|_| Error::SendingEventFailed)?;
+        }
+
         Command::DialAddress { address } => {
             senders
                 .internal_commands
@@ -336,6 +474,7 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
             senders
                 .events
                 .send(Event::AddressUnbanned { address })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
         }
 
@@ -345,6 +484,7 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
             senders
                 .events
                 .send(Event::PeerUnbanned { peer_id })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
         }
     }
@@ -362,6 +502,7 @@ async fn process_internal_event(
             senders
                 .events
                 .send(Event::AddressBound { address })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
         }
 
@@ -371,6 +512,17 @@ async fn process_internal_event(
             // Try to disconnect, but ignore errors in-case the peer was disconnected already.
             let _ = peerlist.update_state(&peer_id, |state| state.set_disconnected());
 
+            // Give the peer some time before the first reconnect attempt.
+            let delay = reconnect_delay(0);
+            let backoff = ReconnectBackoff {
+                attempts: 0,
+                delay,
+                next_attempt: Some(unix_timestamp_secs() + delay.as_secs()),
+            };
+            let backoff_updated = peerlist
+                .update_info(&peer_id, |info| info.reconnect_backoff = backoff)
+                .is_ok();
+
             // Only remove unknown peers.
             // NOTE: discovered peers should be removed manually via command if the autopeering protocol suggests it.
             let was_removed = peerlist.filter_remove(&peer_id, |peer_info, _, _| peer_info.relation.is_unknown());
@@ -387,14 +539,24 @@ async fn process_internal_event(
             senders
                 .events
                 .send(Event::PeerDisconnected { peer_id })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
+            if backoff_updated && !was_removed {
+                senders
+                    .events
+                    .send(Event::PeerReconnectScheduled { peer_id, backoff })
+                    .await
+                    .map_err(|_| Error::SendingEventFailed)?;
+            }
+
             if was_removed {
                 log::trace!("Removed unknown peer: {peer_id}");
 
                 senders
                     .events
                     .send(Event::PeerRemoved { peer_id })
+                    .await
                     .map_err(|_| Error::SendingEventFailed)?;
             }
         }
@@ -404,6 +566,7 @@ async fn process_internal_event(
             peer_addr,
             origin,
             substream,
+            codec,
         } => {
             let mut peerlist = peerlist.0.write().await;
             let mut peer_added = false;
@@ -413,6 +576,7 @@ async fn process_internal_event(
             // resources on it - here.
 
             let accepted = peerlist.accepts_incoming_peer(&peer_id, &peer_addr);
+            let transport = TransportKind::from_multiaddr(&peer_addr);
 
             if accepted.is_ok() {
                 // If the peer doesn't exist yet - but is accepted as an "unknown" peer, we insert it now.
@@ -421,11 +585,16 @@ async fn process_internal_event(
                         address: peer_addr,
                         alias: alias!(peer_id).to_string(),
                         relation: PeerRelation::Unknown,
+                        group: None,
+                        reconnect_backoff: Default::default(),
                     };
                     peerlist.add(peer_id, peer_info).map_err(|(_, _, e)| e)?;
                     peer_added = true;
                 }
 
+                // Reset the reconnect back-off now that the connection succeeded.
+                let _ = peerlist.update_info(&peer_id, |info| info.reconnect_backoff = ReconnectBackoff::default());
+
                 // Panic:
                 // We made sure, that the peer id exists in the above if-branch, hence, unwrapping is fine.
                 let peer_info = peerlist.info(&peer_id).unwrap();
@@ -436,19 +605,23 @@ async fn process_internal_event(
                 let inbound_gossip_rx = BufReader::with_capacity(IO_BUFFER_LEN, r);
                 let outbound_gossip_tx = BufWriter::with_capacity(IO_BUFFER_LEN, w);
 
-                let (inbound_gossip_tx, gossip_in) = iota_gossip::channel();
-                let (gossip_out, outbound_gossip_rx) = iota_gossip::channel();
+                let (inbound_gossip_tx, gossip_in) =
+                    iota_gossip::peer_channel(peer_id, GossipDirection::Inbound, senders.internal_events.clone());
+                let (gossip_out, outbound_gossip_rx) =
+                    iota_gossip::peer_channel(peer_id, GossipDirection::Outbound, senders.internal_events.clone());
 
                 iota_gossip::start_inbound_gossip_handler(
                     peer_id,
                     inbound_gossip_rx,
                     inbound_gossip_tx,
+                    codec,
                     senders.internal_events.clone(),
                 );
                 iota_gossip::start_outbound_gossip_handler(
                     peer_id,
                     outbound_gossip_tx,
                     outbound_gossip_rx,
+                    codec,
                     senders.internal_events.clone(),
                 );
 
@@ -468,12 +641,15 @@ async fn process_internal_event(
                             peer_id,
                             info: peer_info.clone(),
                         })
+                        .await
                         .map_err(|_| Error::SendingEventFailed)?;
                 }
 
                 info!(
-                    "Established ({}) protocol with {} ({}).",
+                    "Established ({}, {}, {}) protocol with {} ({}).",
                     origin,
+                    transport.map_or_else(|| "unknown transport".to_string(), |t| t.to_string()),
+                    codec,
                     peer_info.alias,
                     alias!(peer_id)
                 );
@@ -483,9 +659,11 @@ async fn process_internal_event(
                     .send(Event::PeerConnected {
                         peer_id,
                         info: peer_info,
+                        transport,
                         gossip_in,
                         gossip_out,
                     })
+                    .await
                     .map_err(|_| Error::SendingEventFailed)?;
             } else {
                 // Panic:
@@ -494,11 +672,47 @@ async fn process_internal_event(
             }
         }
 
+        InternalEvent::GossipQueueOverflowed {
+            peer_id,
+            direction,
+            overflows,
+        } => {
+            let count = overflows.take();
+            let policy = global::gossip_overflow_policy();
+
+            warn!(
+                "{:?} gossip queue of {} overflowed, {} message(s) affected ({:?}).",
+                direction,
+                alias!(peer_id),
+                count,
+                policy
+            );
+
+            senders
+                .events
+                .send(Event::GossipQueueOverflowed {
+                    peer_id,
+                    direction,
+                    policy,
+                    count,
+                })
+                .await
+                .map_err(|_| Error::SendingEventFailed)?;
+
+            if policy == OverflowPolicy::Disconnect {
+                // Ignore errors in-case the peer was disconnected already.
+                let _ = disconnect_peer(peer_id, senders, peerlist).await;
+            }
+        }
+
         InternalEvent::PeerUnreachable { peer_id } => {
-            if let Ok(peer_info) = peerlist.0.read().await.info(&peer_id) {
+            let peer_info = peerlist.0.read().await.info(&peer_id);
+
+            if let Ok(peer_info) = peer_info {
                 senders
                     .events
                     .send(Event::PeerUnreachable { peer_id, peer_info })
+                    .await
                     .map_err(|_| Error::SendingEventFailed)?;
             }
         }
@@ -507,6 +721,7 @@ async fn process_internal_event(
             let _ = peerlist.0.write().await.update_metrics(&peer_id, |m| {
                 // Reset dial count.
                 m.num_dials = 0;
+                m.reported_unreachable = false;
                 // Update Identify timestamp.
                 m.identified_at = Some(
                     SystemTime::now()
@@ -533,6 +748,8 @@ async fn add_peer(
         address,
         alias,
         relation,
+        group: None,
+        reconnect_backoff: Default::default(),
     };
 
     let mut peerlist = peerlist.0.write().await;
@@ -550,6 +767,7 @@ async fn add_peer(
             senders
                 .events
                 .send(Event::PeerAdded { peer_id, info })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             Ok(())
@@ -564,7 +782,13 @@ async fn add_peer(
             // thoroughly in a live setup to really be sure.
 
             if matches!(e, PeerError::PeerIsDuplicate(_)) {
-                match peerlist.update_info(&peer_id, |info| *info = peer_info.clone()) {
+                // The peer keeps its group membership though.
+                match peerlist.update_info(&peer_id, |info| {
+                    *info = PeerInfo {
+                        group: info.group.take(),
+                        ..peer_info.clone()
+                    }
+                }) {
                     Ok(()) => {
                         // We no longer need to hold the lock.
                         drop(peerlist);
@@ -575,6 +799,7 @@ async fn add_peer(
                                 peer_id,
                                 info: peer_info,
                             })
+                            .await
                             .map_err(|_| Error::SendingEventFailed)?;
 
                         return Ok(());
@@ -599,6 +824,7 @@ async fn add_peer(
                     },
                     reason: e.clone(),
                 })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             Err(e.into())
@@ -616,6 +842,7 @@ async fn remove_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList) ->
             senders
                 .events
                 .send(Event::PeerRemoved { peer_id })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             Ok(())
@@ -627,6 +854,7 @@ async fn remove_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList) ->
                     command: Command::RemovePeer { peer_id },
                     reason: e.clone(),
                 })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             Err(e.into())
@@ -649,6 +877,7 @@ async fn disconnect_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList
             senders
                 .events
                 .send(Event::PeerDisconnected { peer_id })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             // Try to send the shutdown signal. It has to be a Vec<u8>, but it doesn't have to allocate.
@@ -668,9 +897,46 @@ async fn disconnect_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList
                     command: Command::DisconnectPeer { peer_id },
                     reason: e.clone(),
                 })
+                .await
                 .map_err(|_| Error::SendingEventFailed)?;
 
             Err(e.into())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::common::network_config::test_peer_groups;
+
+    #[test]
+    fn sort_by_reconnect_priority_of_groups() {
+        global::set_peer_groups(test_peer_groups());
+
+        let mut peers = [None, Some("unconfigured"), Some("partners"), Some("datacenter")]
+            .into_iter()
+            .map(|group| {
+                let peer_info = PeerInfo {
+                    address: Multiaddr::empty(),
+                    alias: String::new(),
+                    relation: PeerRelation::Known,
+                    group: group.map(Into::into),
+                    reconnect_backoff: ReconnectBackoff::default(),
+                };
+                (PeerId::random(), peer_info, ())
+            })
+            .collect::<Vec<_>>();
+
+        sort_by_reconnect_priority(&mut peers);
+
+        assert_eq!(
+            peers
+                .iter()
+                .map(|(_, info, _)| info.group.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("datacenter"), Some("partners"), None, Some("unconfigured")]
+        );
+    }
+}
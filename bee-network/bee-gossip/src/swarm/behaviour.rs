@@ -84,6 +84,7 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                 peer_addr,
                 origin,
                 substream,
+                codec,
             } => {
                 trace!("Successfully negotiated IOTA gossip protocol with {}.", alias!(peer_id));
 
@@ -93,6 +94,7 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                         peer_addr,
                         origin,
                         substream,
+                        codec,
                     })
                     .expect("send internal event");
             }
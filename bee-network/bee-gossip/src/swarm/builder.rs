@@ -6,57 +6,60 @@ use std::time::Duration;
 use libp2p::{
     core::{
         connection::ConnectionLimits,
+        muxing::StreamMuxerBox,
+        transport::{timeout::TransportTimeout, Boxed},
         upgrade::{self, SelectUpgrade},
     },
     dns, identity, mplex, noise,
     swarm::SwarmBuilder,
-    tcp, yamux, Swarm, Transport,
+    tcp, websocket, yamux, PeerId, Swarm, Transport,
 };
 
 use super::{behaviour::SwarmBehaviour, error::Error};
-use crate::service::event::InternalEventSender;
+use crate::{config::MuxerSelection, service::event::InternalEventSender};
 
-const MAX_CONNECTIONS_PER_PEER: u32 = 1;
-const DEFAULT_CONNECTION_TIMEOUT_SECS: u64 = 10;
+/// The connection related parameters of the [`Swarm`].
+pub struct SwarmConfig {
+    /// The timeout for establishing a connection.
+    pub connection_timeout: Duration,
+    /// The timeout for the Noise and multiplexer handshakes.
+    pub handshake_timeout: Duration,
+    /// The maximum number of connections established with a single peer.
+    pub max_connections_per_peer: u32,
+    /// The offered stream multiplexers.
+    pub muxer: MuxerSelection,
+    /// The yamux receive window size of a stream.
+    pub yamux_receive_window_size: u32,
+    /// The maximum yamux buffer size of a stream.
+    pub yamux_max_buffer_size: usize,
+}
 
 pub fn build_swarm(
     local_keys: &identity::Keypair,
     internal_sender: InternalEventSender,
+    config: SwarmConfig,
 ) -> Result<Swarm<SwarmBehaviour>, Error> {
     let local_pk = local_keys.public();
     let local_id = local_pk.to_peer_id();
 
-    let noise_keys = noise::Keypair::<noise::X25519Spec>::new()
-        .into_authentic(local_keys)
-        .map_err(|_| Error::CreatingNoiseKeysFailed)?;
+    let tcp_config = tcp::TokioTcpConfig::new().nodelay(true).port_reuse(true);
+    let dns_config = dns::TokioDnsConfig::system(tcp_config)?;
+    // Allows peering through proxies that only let HTTP traffic pass.
+    let ws_config = websocket::WsConfig::new(dns_config.clone());
 
-    let noi_config = noise::NoiseConfig::xx(noise_keys);
-    let mpx_config = mplex::MplexConfig::default();
-    let ymx_config = yamux::YamuxConfig::default();
+    let transport = TransportTimeout::new(dns_config.or_transport(ws_config), config.connection_timeout);
 
     let transport = if cfg!(test) {
         use libp2p_core::transport::MemoryTransport;
 
-        MemoryTransport::default()
-            .upgrade(upgrade::Version::V1Lazy)
-            .authenticate(noi_config.into_authenticated())
-            .multiplex(SelectUpgrade::new(ymx_config, mpx_config))
-            .timeout(Duration::from_secs(DEFAULT_CONNECTION_TIMEOUT_SECS))
-            .boxed()
+        // Tests mostly run in-memory, but can still use the other transports.
+        upgrade_transport(MemoryTransport::default().or_transport(transport), local_keys, &config)?
     } else {
-        let tcp_config = tcp::TokioTcpConfig::new().nodelay(true).port_reuse(true);
-        let dns_config = dns::TokioDnsConfig::system(tcp_config)?;
-
-        dns_config
-            .upgrade(upgrade::Version::V1Lazy)
-            .authenticate(noi_config.into_authenticated())
-            .multiplex(SelectUpgrade::new(ymx_config, mpx_config))
-            .timeout(Duration::from_secs(DEFAULT_CONNECTION_TIMEOUT_SECS))
-            .boxed()
+        upgrade_transport(transport, local_keys, &config)?
     };
 
     let behaviour = SwarmBehaviour::new(local_pk, internal_sender);
-    let limits = ConnectionLimits::default().with_max_established_per_peer(Some(MAX_CONNECTIONS_PER_PEER));
+    let limits = ConnectionLimits::default().with_max_established_per_peer(Some(config.max_connections_per_peer));
 
     let swarm = SwarmBuilder::new(transport, behaviour, local_id)
         .connection_limits(limits)
@@ -69,3 +72,54 @@ pub fn build_swarm(
 
     Ok(swarm)
 }
+
+/// Authenticates and multiplexes the connections of a raw transport.
+fn upgrade_transport<T>(
+    transport: T,
+    local_keys: &identity::Keypair,
+    config: &SwarmConfig,
+) -> Result<Boxed<(PeerId, StreamMuxerBox)>, Error>
+where
+    T: Transport + Send + Sync + 'static,
+    T::Output: futures::AsyncRead + futures::AsyncWrite + Unpin + Send + 'static,
+    T::Error: Send + Sync + 'static,
+    T::Listener: Send + 'static,
+    T::ListenerUpgrade: Send + 'static,
+    T::Dial: Send + 'static,
+{
+    let noise_keys = noise::Keypair::<noise::X25519Spec>::new()
+        .into_authentic(local_keys)
+        .map_err(|_| Error::CreatingNoiseKeysFailed)?;
+
+    let noi_config = noise::NoiseConfig::xx(noise_keys);
+    let mpx_config = mplex::MplexConfig::default();
+    let mut ymx_config = yamux::YamuxConfig::default();
+    ymx_config
+        .set_receive_window_size(config.yamux_receive_window_size)
+        .set_max_buffer_size(config.yamux_max_buffer_size);
+
+    let authenticated = transport
+        .upgrade(upgrade::Version::V1Lazy)
+        .authenticate(noi_config.into_authenticated());
+
+    // The handshake timeout covers the Noise handshake as well as the multiplexer negotiation.
+    let transport = match config.muxer {
+        MuxerSelection::Yamux => authenticated
+            .multiplex(ymx_config)
+            .timeout(config.handshake_timeout)
+            .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+            .boxed(),
+        MuxerSelection::Mplex => authenticated
+            .multiplex(mpx_config)
+            .timeout(config.handshake_timeout)
+            .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+            .boxed(),
+        MuxerSelection::Both => authenticated
+            .multiplex(SelectUpgrade::new(ymx_config, mpx_config))
+            .timeout(config.handshake_timeout)
+            .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+            .boxed(),
+    };
+
+    Ok(transport)
+}
@@ -0,0 +1,214 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The optional compression extension of the gossip protocol.
+//!
+//! Peers that negotiated the compressed protocol exchange frames instead of the raw gossip byte stream. A frame
+//! consists of a one byte codec flag, the little endian `u32` length of the payload and the payload itself. Payloads
+//! that don't shrink when compressed are sent raw.
+
+use std::{fmt, io};
+
+use zstd::bulk::{Compressor, Decompressor};
+
+use crate::config::CompressionConfig;
+
+/// The length of a frame header.
+pub(crate) const FRAME_HEADER_LEN: usize = 5;
+/// The maximum length of a frame payload, both before and after decompression.
+pub(crate) const MAX_FRAME_PAYLOAD_LEN: usize = 1024 * 1024;
+/// Payloads below this length aren't worth compressing.
+const MIN_COMPRESSION_LEN: usize = 64;
+
+const FLAG_RAW: u8 = 0;
+const FLAG_ZSTD: u8 = 1;
+
+/// The codec the gossip with a peer is exchanged with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GossipCodec {
+    /// The raw, uncompressed gossip byte stream.
+    Raw,
+    /// Zstd compressed frames.
+    Zstd,
+}
+
+impl fmt::Display for GossipCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Raw => write!(f, "raw"),
+            Self::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// The header of a frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct FrameHeader {
+    compressed: bool,
+    len: usize,
+}
+
+impl FrameHeader {
+    /// Parses a frame header.
+    pub(crate) fn parse(bytes: &[u8; FRAME_HEADER_LEN]) -> io::Result<Self> {
+        let compressed = match bytes[0] {
+            FLAG_RAW => false,
+            FLAG_ZSTD => true,
+            flag => return Err(invalid_data(format!("invalid gossip frame flag {}", flag))),
+        };
+        // Panic: the slice has a length of 4 bytes.
+        let len = u32::from_le_bytes(bytes[1..].try_into().unwrap()) as usize;
+
+        if len > MAX_FRAME_PAYLOAD_LEN {
+            return Err(invalid_data(format!("gossip frame of {} bytes exceeds the limit", len)));
+        }
+
+        Ok(Self { compressed, len })
+    }
+
+    /// Returns the length of the frame payload.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Turns gossip messages into frames.
+pub(crate) struct FrameEncoder {
+    compressor: Compressor<'static>,
+}
+
+impl FrameEncoder {
+    pub(crate) fn new(config: &CompressionConfig) -> io::Result<Self> {
+        let compressor = match config.dictionary() {
+            Some(dictionary) => Compressor::with_dictionary(config.level(), dictionary)?,
+            None => Compressor::new(config.level())?,
+        };
+
+        Ok(Self { compressor })
+    }
+
+    /// Encodes a message as a single frame.
+    pub(crate) fn encode(&mut self, message: &[u8]) -> io::Result<Vec<u8>> {
+        let compressed = if message.len() >= MIN_COMPRESSION_LEN {
+            Some(self.compressor.compress(message)?).filter(|compressed| compressed.len() < message.len())
+        } else {
+            None
+        };
+
+        let (flag, payload) = match &compressed {
+            Some(compressed) => (FLAG_ZSTD, compressed.as_slice()),
+            None => (FLAG_RAW, message),
+        };
+
+        if payload.len() > MAX_FRAME_PAYLOAD_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("gossip message of {} bytes exceeds the frame limit", payload.len()),
+            ));
+        }
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        frame.push(flag);
+        frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame.extend_from_slice(payload);
+
+        Ok(frame)
+    }
+}
+
+/// Turns frames back into gossip messages.
+pub(crate) struct FrameDecoder {
+    decompressor: Decompressor<'static>,
+}
+
+impl FrameDecoder {
+    pub(crate) fn new(config: &CompressionConfig) -> io::Result<Self> {
+        let decompressor = match config.dictionary() {
+            Some(dictionary) => Decompressor::with_dictionary(dictionary)?,
+            None => Decompressor::new()?,
+        };
+
+        Ok(Self { decompressor })
+    }
+
+    /// Decodes the payload of a frame with the given header.
+    pub(crate) fn decode(&mut self, header: FrameHeader, payload: Vec<u8>) -> io::Result<Vec<u8>> {
+        if header.compressed {
+            self.decompressor.decompress(&payload, MAX_FRAME_PAYLOAD_LEN)
+        } else {
+            Ok(payload)
+        }
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(config: &CompressionConfig, message: &[u8]) -> (FrameHeader, Vec<u8>) {
+        let frame = FrameEncoder::new(config).unwrap().encode(message).unwrap();
+        let header = FrameHeader::parse(frame[..FRAME_HEADER_LEN].try_into().unwrap()).unwrap();
+
+        assert_eq!(header.len(), frame.len() - FRAME_HEADER_LEN);
+
+        let decoded = FrameDecoder::new(config)
+            .unwrap()
+            .decode(header, frame[FRAME_HEADER_LEN..].to_vec())
+            .unwrap();
+
+        (header, decoded)
+    }
+
+    #[test]
+    fn compressible_message() {
+        let message = vec![42u8; 4096];
+        let (header, decoded) = roundtrip(&CompressionConfig::default(), &message);
+
+        assert!(header.compressed);
+        assert!(header.len() < message.len());
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn short_message_is_sent_raw() {
+        let message = vec![42u8; MIN_COMPRESSION_LEN - 1];
+        let (header, decoded) = roundtrip(&CompressionConfig::default(), &message);
+
+        assert!(!header.compressed);
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn compressible_message_with_dictionary() {
+        let dictionary = (0..=255u8).cycle().take(1024).collect::<Vec<_>>();
+        let config = CompressionConfig::default().with_dictionary(dictionary.clone());
+        let (header, decoded) = roundtrip(&config, &dictionary[..512]);
+
+        assert!(header.compressed);
+        assert_eq!(decoded, &dictionary[..512]);
+    }
+
+    #[test]
+    fn invalid_flag() {
+        assert_eq!(
+            FrameHeader::parse(&[2, 0, 0, 0, 0]).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn oversized_frame() {
+        let len = (MAX_FRAME_PAYLOAD_LEN as u32 + 1).to_le_bytes();
+
+        assert_eq!(
+            FrameHeader::parse(&[FLAG_RAW, len[0], len[1], len[2], len[3]])
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
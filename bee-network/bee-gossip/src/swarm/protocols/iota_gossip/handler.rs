@@ -5,6 +5,7 @@ use std::{
     collections::VecDeque,
     io,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
 use libp2p::{
@@ -20,14 +21,19 @@ use libp2p::{
 };
 use log::*;
 
-use super::{event::IotaGossipHandlerEvent, id::IotaGossipIdentifier, upgrade::IotaGossipProtocolUpgrade};
-use crate::network::origin::Origin;
+use super::{
+    compression::GossipCodec, event::IotaGossipHandlerEvent, id::IotaGossipIdentifier,
+    upgrade::IotaGossipProtocolUpgrade,
+};
+use crate::{init::global, network::origin::Origin};
 
 pub struct GossipProtocolHandler {
-    /// Exchanged protocol information necessary during negotiation.
-    info: IotaGossipIdentifier,
+    /// Exchanged protocol information necessary during negotiation, in order of preference.
+    info: Vec<IotaGossipIdentifier>,
 
     /// Keep alive setting.
+    ///
+    /// The connection is only kept alive for a limited time until the gossip protocol was negotiated on it.
     keep_alive: KeepAlive,
 
     /// All events produced by this handler.
@@ -40,10 +46,10 @@ pub struct IotaGossipHandlerInEvent {
 }
 
 impl GossipProtocolHandler {
-    pub fn new(info: IotaGossipIdentifier) -> Self {
+    pub fn new(info: Vec<IotaGossipIdentifier>) -> Self {
         Self {
             info,
-            keep_alive: KeepAlive::Yes,
+            keep_alive: KeepAlive::Until(Instant::now() + Duration::from_secs(global::idle_connection_timeout_secs())),
             events: VecDeque::with_capacity(16),
         }
     }
@@ -96,12 +102,19 @@ impl ProtocolsHandler for GossipProtocolHandler {
     /// **libp2p docs**:
     ///
     /// Injects the output of a successful upgrade on a new inbound substream.
-    fn inject_fully_negotiated_inbound(&mut self, new_inbound: NegotiatedSubstream, _: Self::InboundOpenInfo) {
+    fn inject_fully_negotiated_inbound(
+        &mut self,
+        (new_inbound, codec): (NegotiatedSubstream, GossipCodec),
+        _: Self::InboundOpenInfo,
+    ) {
         let negotiated_inbound = ProtocolsHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
             substream: Box::new(new_inbound),
+            codec,
         });
 
-        debug!("gossip handler: fully negotiated inbound.");
+        debug!("gossip handler: fully negotiated inbound ({}).", codec);
+
+        self.keep_alive = KeepAlive::Yes;
 
         self.events.push_back(negotiated_inbound);
     }
@@ -112,12 +125,19 @@ impl ProtocolsHandler for GossipProtocolHandler {
     ///
     /// The second argument is the information that was previously passed to
     /// [`ProtocolsHandlerEvent::OutboundSubstreamRequest`].
-    fn inject_fully_negotiated_outbound(&mut self, new_outbound: NegotiatedSubstream, _: Self::OutboundOpenInfo) {
+    fn inject_fully_negotiated_outbound(
+        &mut self,
+        (new_outbound, codec): (NegotiatedSubstream, GossipCodec),
+        _: Self::OutboundOpenInfo,
+    ) {
         let negotiated_outbound = ProtocolsHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
             substream: Box::new(new_outbound),
+            codec,
         });
 
-        debug!("gossip handler: fully negotiated outbound.");
+        debug!("gossip handler: fully negotiated outbound ({}).", codec);
+
+        self.keep_alive = KeepAlive::Yes;
 
         self.events.push_back(negotiated_outbound);
     }
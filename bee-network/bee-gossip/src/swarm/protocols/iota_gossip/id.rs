@@ -3,23 +3,70 @@
 
 use std::fmt;
 
+use super::compression::GossipCodec;
+
 #[derive(Debug, Clone)]
-pub struct IotaGossipIdentifier(String);
+pub struct IotaGossipIdentifier {
+    id: String,
+    codec: GossipCodec,
+}
 
 impl IotaGossipIdentifier {
     pub fn new(name: impl AsRef<str>, network_id: u64, version: impl AsRef<str>) -> Self {
-        Self(format!("/{}/{}/{}", name.as_ref(), network_id, version.as_ref()))
+        Self {
+            id: format!("/{}/{}/{}", name.as_ref(), network_id, version.as_ref()),
+            codec: GossipCodec::Raw,
+        }
+    }
+
+    /// Returns the identifier of the zstd compressed variant of the protocol.
+    ///
+    /// The identifier contains the id of the used dictionary, so that only peers with the same dictionary agree on it.
+    pub fn zstd(&self, dictionary_id: Option<u64>) -> Self {
+        let id = match dictionary_id {
+            Some(dictionary_id) => format!("{}/zstd/{:016x}", self.id, dictionary_id),
+            None => format!("{}/zstd", self.id),
+        };
+
+        Self {
+            id,
+            codec: GossipCodec::Zstd,
+        }
+    }
+
+    /// Returns the codec of the protocol.
+    pub fn codec(&self) -> GossipCodec {
+        self.codec
     }
 }
 
 impl fmt::Display for IotaGossipIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.id)
     }
 }
 
 impl AsRef<[u8]> for IotaGossipIdentifier {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.id.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zstd_identifiers() {
+        let id = IotaGossipIdentifier::new("iota-gossip", 42, "1.0.0");
+
+        assert_eq!(id.to_string(), "/iota-gossip/42/1.0.0");
+        assert_eq!(id.codec(), GossipCodec::Raw);
+        assert_eq!(id.zstd(None).to_string(), "/iota-gossip/42/1.0.0/zstd");
+        assert_eq!(
+            id.zstd(Some(0xabc)).to_string(),
+            "/iota-gossip/42/1.0.0/zstd/0000000000000abc"
+        );
+        assert_eq!(id.zstd(None).codec(), GossipCodec::Zstd);
     }
 }
@@ -1,53 +1,486 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{
+    collections::VecDeque,
+    fmt, io,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+};
+
 use futures::{
     io::{BufReader, BufWriter, ReadHalf, WriteHalf},
+    stream::Stream,
+    task::AtomicWaker,
     AsyncReadExt, AsyncWriteExt, StreamExt,
 };
 use libp2p::{swarm::NegotiatedSubstream, PeerId};
 use log::*;
-use tokio::sync::mpsc;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio::sync::Notify;
 
+use super::compression::{FrameDecoder, FrameEncoder, FrameHeader, GossipCodec, FRAME_HEADER_LEN};
 use crate::{
     alias,
+    config::{CompressionConfig, OverflowPolicy},
+    init::global,
     service::event::{InternalEvent, InternalEventSender},
 };
 
 const MSG_BUFFER_LEN: usize = 32768;
+const MSG_QUEUE_INITIAL_CAP: usize = 64;
+
+/// The direction of a gossip queue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GossipDirection {
+    /// The queue of messages received from a peer.
+    Inbound,
+    /// The queue of messages to send to a peer.
+    Outbound,
+}
+
+/// Errors that can occur when sending a message to a gossip queue.
+#[derive(Debug, thiserror::Error)]
+pub enum GossipSendError {
+    /// The receiving end of the queue was dropped.
+    #[error("gossip queue closed")]
+    Closed(Vec<u8>),
+    /// The queue is full and its overflow policy rejected the message.
+    #[error("gossip queue overflowed")]
+    Overflow(Vec<u8>),
+}
+
+impl GossipSendError {
+    /// Returns the message that couldn't be sent.
+    pub fn into_inner(self) -> Vec<u8> {
+        match self {
+            Self::Closed(message) | Self::Overflow(message) => message,
+        }
+    }
+}
+
+struct QueueState {
+    messages: VecDeque<Vec<u8>>,
+    senders: usize,
+    receiver_alive: bool,
+    overflows: u64,
+    overflow_reported: bool,
+}
+
+struct OverflowReporter {
+    peer_id: PeerId,
+    direction: GossipDirection,
+    internal_event_tx: InternalEventSender,
+}
+
+struct Queue {
+    state: Mutex<QueueState>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    receiver_waker: AtomicWaker,
+    space: Notify,
+    reporter: Option<OverflowReporter>,
+}
+
+impl Queue {
+    fn state(&self) -> MutexGuard<'_, QueueState> {
+        // Panic: the lock is never held across code that could panic.
+        self.state.lock().expect("gossip queue lock")
+    }
+
+    /// Pushes a message if there's room for it, and applies the overflow policy otherwise.
+    ///
+    /// Returns the message back if the queue is full and `wait` is set, so the caller can wait for room.
+    fn push(self: &Arc<Self>, message: Vec<u8>, wait: bool) -> Result<Option<Vec<u8>>, GossipSendError> {
+        let mut state = self.state();
+
+        if !state.receiver_alive {
+            return Err(GossipSendError::Closed(message));
+        }
+
+        // Note: An empty message is the shutdown signal of a connection, so it always bypasses the capacity.
+        if message.is_empty() || state.messages.len() < self.capacity {
+            state.messages.push_back(message);
+            drop(state);
+            self.receiver_waker.wake();
+
+            return Ok(None);
+        }
+
+        if wait && self.policy != OverflowPolicy::Disconnect {
+            return Ok(Some(message));
+        }
+
+        let result = match self.policy {
+            OverflowPolicy::DropOldest => {
+                // A queued shutdown signal must never be dropped.
+                if let Some(position) = state.messages.iter().position(|m| !m.is_empty()) {
+                    state.messages.remove(position);
+                }
+                state.messages.push_back(message);
+                Ok(None)
+            }
+            OverflowPolicy::DropNewest => Ok(None),
+            OverflowPolicy::Disconnect => Err(GossipSendError::Overflow(message)),
+        };
+
+        state.overflows += 1;
+        let report = !state.overflow_reported;
+        state.overflow_reported = true;
+        drop(state);
+
+        self.receiver_waker.wake();
+
+        if report {
+            self.report_overflow();
+        }
+
+        result
+    }
+
+    fn report_overflow(self: &Arc<Self>) {
+        if let Some(reporter) = &self.reporter {
+            trace!(
+                "{:?} gossip queue of {} overflowed.",
+                reporter.direction,
+                alias!(reporter.peer_id)
+            );
+
+            // The service host may already be stopped during shutdown, in which case nobody is interested anymore.
+            let _ = reporter.internal_event_tx.send(InternalEvent::GossipQueueOverflowed {
+                peer_id: reporter.peer_id,
+                direction: reporter.direction,
+                overflows: GossipOverflows(self.clone()),
+            });
+        }
+    }
+}
+
+/// A handle to the overflows of a gossip queue that weren't processed yet.
+///
+/// Overflows are reported once until they are taken, so a flood of messages results in a single event.
+#[derive(Clone)]
+pub struct GossipOverflows(Arc<Queue>);
+
+impl GossipOverflows {
+    /// Takes the number of messages that overflowed since the last call.
+    pub fn take(&self) -> u64 {
+        let mut state = self.0.state();
 
-/// A type alias for an unbounded channel sender.
-pub type GossipSender = mpsc::UnboundedSender<Vec<u8>>;
+        state.overflow_reported = false;
+        std::mem::take(&mut state.overflows)
+    }
+}
+
+impl fmt::Debug for GossipOverflows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GossipOverflows")
+            .field(&self.0.state().overflows)
+            .finish()
+    }
+}
+
+/// The sending end of a bounded gossip queue.
+pub struct GossipSender(Arc<Queue>);
+
+impl GossipSender {
+    /// Sends a message to the queue.
+    ///
+    /// If the queue is full, the configured [`OverflowPolicy`] decides whether a message gets dropped or the message
+    /// is returned as an [`GossipSendError::Overflow`] error.
+    pub fn send(&self, message: Vec<u8>) -> Result<(), GossipSendError> {
+        self.0.push(message, false).map(|_| ())
+    }
+
+    /// Sends a message to the queue, waiting for room if the queue is full.
+    ///
+    /// The message is only rejected if the overflow policy is [`OverflowPolicy::Disconnect`].
+    pub(crate) async fn send_or_wait(&self, mut message: Vec<u8>) -> Result<(), GossipSendError> {
+        loop {
+            let space = self.0.space.notified();
+
+            match self.0.push(message, true)? {
+                None => return Ok(()),
+                Some(rejected) => message = rejected,
+            }
+
+            space.await;
+        }
+    }
+
+    /// Returns the number of queued messages.
+    pub fn len(&self) -> usize {
+        self.0.state().messages.len()
+    }
+
+    /// Returns whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the queue.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+}
+
+impl Clone for GossipSender {
+    fn clone(&self) -> Self {
+        self.0.state().senders += 1;
+
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for GossipSender {
+    fn drop(&mut self) {
+        let mut state = self.0.state();
+
+        state.senders -= 1;
 
-/// A type alias for an unbounded channel receiver.
-pub type GossipReceiver = UnboundedReceiverStream<Vec<u8>>;
+        if state.senders == 0 {
+            drop(state);
+            self.0.receiver_waker.wake();
+        }
+    }
+}
+
+impl fmt::Debug for GossipSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GossipSender")
+            .field("len", &self.len())
+            .field("capacity", &self.0.capacity)
+            .finish()
+    }
+}
+
+/// The receiving end of a bounded gossip queue.
+pub struct GossipReceiver(Arc<Queue>);
+
+impl Stream for GossipReceiver {
+    type Item = Vec<u8>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.receiver_waker.register(cx.waker());
+
+        let mut state = self.0.state();
+
+        if let Some(message) = state.messages.pop_front() {
+            drop(state);
+            self.0.space.notify_one();
+
+            Poll::Ready(Some(message))
+        } else if state.senders == 0 {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for GossipReceiver {
+    fn drop(&mut self) {
+        let mut state = self.0.state();
+
+        state.receiver_alive = false;
+        state.messages.clear();
+        drop(state);
+
+        // Wake up a sender that waits for room, so it notices that the queue is closed.
+        self.0.space.notify_one();
+    }
+}
+
+impl fmt::Debug for GossipReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GossipReceiver")
+            .field("len", &self.0.state().messages.len())
+            .field("capacity", &self.0.capacity)
+            .finish()
+    }
+}
 
-pub fn channel() -> (GossipSender, GossipReceiver) {
-    let (sender, receiver) = mpsc::unbounded_channel();
-    (sender, UnboundedReceiverStream::new(receiver))
+/// Creates a bounded gossip queue that isn't associated with a peer.
+pub fn channel(capacity: usize, policy: OverflowPolicy) -> (GossipSender, GossipReceiver) {
+    new_queue(capacity, policy, None)
+}
+
+/// Creates a bounded gossip queue of a peer that reports its overflows to the service host.
+pub(crate) fn peer_channel(
+    peer_id: PeerId,
+    direction: GossipDirection,
+    internal_event_tx: InternalEventSender,
+) -> (GossipSender, GossipReceiver) {
+    let capacity = match direction {
+        GossipDirection::Inbound => global::gossip_inbound_capacity(),
+        GossipDirection::Outbound => global::gossip_outbound_capacity(),
+    };
+
+    new_queue(
+        capacity,
+        global::gossip_overflow_policy(),
+        Some(OverflowReporter {
+            peer_id,
+            direction,
+            internal_event_tx,
+        }),
+    )
+}
+
+fn new_queue(
+    capacity: usize,
+    policy: OverflowPolicy,
+    reporter: Option<OverflowReporter>,
+) -> (GossipSender, GossipReceiver) {
+    let queue = Arc::new(Queue {
+        state: Mutex::new(QueueState {
+            messages: VecDeque::with_capacity(capacity.min(MSG_QUEUE_INITIAL_CAP)),
+            senders: 1,
+            receiver_alive: true,
+            overflows: 0,
+            overflow_reported: false,
+        }),
+        capacity: capacity.max(1),
+        policy,
+        receiver_waker: AtomicWaker::new(),
+        space: Notify::new(),
+        reporter,
+    });
+
+    (GossipSender(queue.clone()), GossipReceiver(queue))
+}
+
+/// Reads gossip from a peer according to the negotiated codec.
+enum GossipReader {
+    Raw { buf: Vec<u8> },
+    Framed { decoder: FrameDecoder },
+}
+
+impl GossipReader {
+    fn new(codec: GossipCodec) -> io::Result<Self> {
+        match codec {
+            GossipCodec::Raw => Ok(Self::Raw {
+                buf: vec![0u8; MSG_BUFFER_LEN],
+            }),
+            GossipCodec::Zstd => Ok(Self::Framed {
+                decoder: FrameDecoder::new(compression_config()?)?,
+            }),
+        }
+    }
+
+    /// Reads the next chunk of gossip, or returns `None` if the peer closed the stream.
+    async fn read(
+        &mut self,
+        stream: &mut BufReader<ReadHalf<Box<NegotiatedSubstream>>>,
+    ) -> io::Result<Option<Vec<u8>>> {
+        match self {
+            Self::Raw { buf } => {
+                let len = stream.read(buf).await?;
+
+                Ok((len > 0).then(|| buf[..len].to_vec()))
+            }
+            Self::Framed { decoder } => {
+                let mut header = [0u8; FRAME_HEADER_LEN];
+
+                match stream.read_exact(&mut header).await {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                    Err(e) => return Err(e),
+                }
+
+                let header = FrameHeader::parse(&header)?;
+                let mut payload = vec![0u8; header.len()];
+
+                stream.read_exact(&mut payload).await?;
+
+                decoder.decode(header, payload).map(Some)
+            }
+        }
+    }
+}
+
+/// Writes gossip to a peer according to the negotiated codec.
+enum GossipWriter {
+    Raw,
+    Framed { encoder: FrameEncoder },
+}
+
+impl GossipWriter {
+    fn new(codec: GossipCodec) -> io::Result<Self> {
+        match codec {
+            GossipCodec::Raw => Ok(Self::Raw),
+            GossipCodec::Zstd => Ok(Self::Framed {
+                encoder: FrameEncoder::new(compression_config()?)?,
+            }),
+        }
+    }
+
+    async fn write(
+        &mut self,
+        stream: &mut BufWriter<WriteHalf<Box<NegotiatedSubstream>>>,
+        message: &[u8],
+    ) -> io::Result<()> {
+        match self {
+            Self::Raw => stream.write_all(message).await?,
+            Self::Framed { encoder } => stream.write_all(&encoder.encode(message)?).await?,
+        }
+
+        stream.flush().await
+    }
+}
+
+fn compression_config() -> io::Result<&'static CompressionConfig> {
+    // A compressed protocol is only offered if compression is enabled, so this can only fail if the config is missing.
+    global::gossip_compression().ok_or_else(|| io::Error::new(io::ErrorKind::Other, "gossip compression is disabled"))
 }
 
 pub fn start_inbound_gossip_handler(
     peer_id: PeerId,
     mut inbound_gossip_rx: BufReader<ReadHalf<Box<NegotiatedSubstream>>>,
     inbound_gossip_tx: GossipSender,
+    codec: GossipCodec,
     internal_event_tx: InternalEventSender,
 ) {
     tokio::spawn(async move {
-        let mut buf = vec![0u8; MSG_BUFFER_LEN];
+        let mut reader = match GossipReader::new(codec) {
+            Ok(reader) => reader,
+            Err(e) => {
+                debug!("Reading {} gossip from {} failed: {}.", codec, alias!(peer_id), e);
+
+                // Panic: we made sure that the sender (network host) is always dropped before the receiver (service
+                // host) through the worker dependencies, hence this can never panic.
+                internal_event_tx
+                    .send(InternalEvent::ProtocolStopped { peer_id })
+                    .expect("send internal event");
+
+                return;
+            }
+        };
 
         loop {
-            if let Some(len) = (&mut inbound_gossip_rx)
-                .read(&mut buf)
-                .await
-                .ok()
-                .filter(|len| *len > 0)
-            {
-                if inbound_gossip_tx.send(buf[..len].to_vec()).is_err() {
-                    debug!("Terminating gossip protocol with {}.", alias!(peer_id));
-
-                    break;
+            let message = reader.read(&mut inbound_gossip_rx).await.unwrap_or_else(|e| {
+                debug!("Reading {} gossip from {} failed: {}.", codec, alias!(peer_id), e);
+                None
+            });
+
+            if let Some(message) = message {
+                // Note: The raw stream can't be dropped partially without breaking the packet framing, so instead of
+                // dropping messages we stop reading from the peer until there's room again.
+                match inbound_gossip_tx.send_or_wait(message).await {
+                    Ok(()) => {}
+                    Err(GossipSendError::Overflow(_)) => {
+                        debug!(
+                            "Terminating gossip protocol with {} (inbound queue overflowed).",
+                            alias!(peer_id)
+                        );
+
+                        break;
+                    }
+                    Err(GossipSendError::Closed(_)) => {
+                        debug!("Terminating gossip protocol with {}.", alias!(peer_id));
+
+                        break;
+                    }
                 }
             } else {
                 debug!("Peer {} terminated gossip protocol.", alias!(peer_id));
@@ -70,10 +503,25 @@ pub fn start_outbound_gossip_handler(
     peer_id: PeerId,
     mut outbound_gossip_tx: BufWriter<WriteHalf<Box<NegotiatedSubstream>>>,
     outbound_gossip_rx: GossipReceiver,
+    codec: GossipCodec,
     internal_event_tx: InternalEventSender,
 ) {
     tokio::spawn(async move {
         let mut outbound_gossip_rx = outbound_gossip_rx.fuse();
+        let mut writer = match GossipWriter::new(codec) {
+            Ok(writer) => writer,
+            Err(e) => {
+                debug!("Writing {} gossip to {} failed: {}.", codec, alias!(peer_id), e);
+
+                // Panic: we made sure that the sender (network host) is always dropped before the receiver (service
+                // host) through the worker dependencies, hence this can never panic.
+                internal_event_tx
+                    .send(InternalEvent::ProtocolStopped { peer_id })
+                    .expect("send internal event");
+
+                return;
+            }
+        };
 
         // If the gossip sender dropped we end the connection.
         while let Some(message) = outbound_gossip_rx.next().await {
@@ -93,9 +541,7 @@ pub fn start_outbound_gossip_handler(
                     .expect("send internal event");
 
                 break;
-            } else if (&mut outbound_gossip_tx).write_all(&message).await.is_err()
-                || (&mut outbound_gossip_tx).flush().await.is_err()
-            {
+            } else if writer.write(&mut outbound_gossip_tx, &message).await.is_err() {
                 debug!("Peer {} terminated gossip protocol.", alias!(peer_id));
 
                 break;
@@ -105,3 +551,97 @@ pub fn start_outbound_gossip_handler(
         trace!("Dropping gossip stream writer for {}.", alias!(peer_id));
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+
+    fn drain(receiver: &mut GossipReceiver) -> Vec<Vec<u8>> {
+        let mut messages = Vec::new();
+        while let Some(Some(message)) = receiver.next().now_or_never() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[test]
+    fn drop_oldest() {
+        let (tx, mut rx) = channel(2, OverflowPolicy::DropOldest);
+
+        for i in 1..=3 {
+            tx.send(vec![i]).unwrap();
+        }
+
+        assert_eq!(drain(&mut rx), vec![vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn drop_newest() {
+        let (tx, mut rx) = channel(2, OverflowPolicy::DropNewest);
+
+        for i in 1..=3 {
+            tx.send(vec![i]).unwrap();
+        }
+
+        assert_eq!(drain(&mut rx), vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn disconnect() {
+        let (tx, mut rx) = channel(1, OverflowPolicy::Disconnect);
+
+        tx.send(vec![1]).unwrap();
+        assert!(matches!(tx.send(vec![2]), Err(GossipSendError::Overflow(m)) if m == vec![2]));
+        assert_eq!(drain(&mut rx), vec![vec![1]]);
+    }
+
+    #[test]
+    fn shutdown_signal_bypasses_capacity() {
+        let (tx, mut rx) = channel(1, OverflowPolicy::DropOldest);
+
+        tx.send(vec![1]).unwrap();
+        tx.send(Vec::new()).unwrap();
+        tx.send(vec![2]).unwrap();
+
+        assert_eq!(drain(&mut rx), vec![Vec::new(), vec![2]]);
+    }
+
+    #[test]
+    fn closed() {
+        let (tx, rx) = channel(1, OverflowPolicy::DropOldest);
+
+        drop(rx);
+
+        assert!(matches!(tx.send(vec![1]), Err(GossipSendError::Closed(_))));
+    }
+
+    #[test]
+    fn stream_ends_when_senders_dropped() {
+        let (tx, mut rx) = channel(1, OverflowPolicy::DropOldest);
+        let tx2 = tx.clone();
+
+        tx.send(vec![1]).unwrap();
+        drop(tx);
+        assert_eq!(rx.next().now_or_never(), Some(Some(vec![1])));
+        assert_eq!(rx.next().now_or_never(), None);
+
+        drop(tx2);
+        assert_eq!(rx.next().now_or_never(), Some(None));
+    }
+
+    #[tokio::test]
+    async fn send_or_wait_waits_for_room() {
+        let (tx, mut rx) = channel(1, OverflowPolicy::DropOldest);
+
+        tx.send(vec![1]).unwrap();
+
+        let handle = tokio::spawn(async move { tx.send_or_wait(vec![2]).await });
+
+        assert_eq!(rx.next().await, Some(vec![1]));
+        handle.await.unwrap().unwrap();
+        assert_eq!(rx.next().await, Some(vec![2]));
+        assert_eq!(rx.next().await, None);
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod compression;
 mod event;
 mod handler;
 mod id;
@@ -8,4 +9,4 @@ mod io;
 mod protocol;
 mod upgrade;
 
-pub use self::{event::*, io::*, protocol::*};
+pub use self::{compression::GossipCodec, event::*, io::*, protocol::*};
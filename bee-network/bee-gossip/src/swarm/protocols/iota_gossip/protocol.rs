@@ -18,7 +18,11 @@ use super::{
     handler::{GossipProtocolHandler, IotaGossipHandlerInEvent},
     id::IotaGossipIdentifier,
 };
-use crate::{alias, init::global::network_id, network::origin::Origin};
+use crate::{
+    alias,
+    init::global::{gossip_compression, network_id},
+    network::origin::Origin,
+};
 
 const IOTA_GOSSIP_NAME: &str = "iota-gossip";
 const IOTA_GOSSIP_VERSION: &str = "1.0.0";
@@ -32,8 +36,8 @@ struct ConnectionInfo {
 
 /// Substream upgrade protocol for `/iota-gossip/1.0.0`.
 pub struct IotaGossipProtocol {
-    /// The gossip protocol identifier.
-    id: IotaGossipIdentifier,
+    /// The supported gossip protocol identifiers in order of preference.
+    ids: Vec<IotaGossipIdentifier>,
 
     /// Counts the number of handlers created.
     num_handlers: usize,
@@ -60,7 +64,7 @@ impl IotaGossipProtocol {
 impl Default for IotaGossipProtocol {
     fn default() -> Self {
         Self {
-            id: IotaGossipIdentifier::new(IOTA_GOSSIP_NAME, network_id(), IOTA_GOSSIP_VERSION),
+            ids: protocol_ids(),
             num_handlers: 0,
             num_inbounds: 0,
             num_outbounds: 0,
@@ -70,6 +74,16 @@ impl Default for IotaGossipProtocol {
     }
 }
 
+/// Returns the supported gossip protocol identifiers, preferring the compressed protocol if it is enabled.
+fn protocol_ids() -> Vec<IotaGossipIdentifier> {
+    let raw = IotaGossipIdentifier::new(IOTA_GOSSIP_NAME, network_id(), IOTA_GOSSIP_VERSION);
+
+    match gossip_compression() {
+        Some(config) => vec![raw.zstd(config.dictionary_id()), raw],
+        None => vec![raw],
+    }
+}
+
 impl NetworkBehaviour for IotaGossipProtocol {
     type ProtocolsHandler = GossipProtocolHandler;
     type OutEvent = IotaGossipEvent;
@@ -92,7 +106,7 @@ impl NetworkBehaviour for IotaGossipProtocol {
         self.num_handlers += 1;
         debug!("gossip protocol: new handler ({}).", self.num_handlers);
 
-        GossipProtocolHandler::new(self.id.clone())
+        GossipProtocolHandler::new(self.ids.clone())
     }
 
     /// **libp2p docs**:
@@ -183,13 +197,14 @@ impl NetworkBehaviour for IotaGossipProtocol {
             IotaGossipHandlerEvent::SentUpgradeRequest { to } => {
                 NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::SentUpgradeRequest { to })
             }
-            IotaGossipHandlerEvent::UpgradeCompleted { substream } => {
+            IotaGossipHandlerEvent::UpgradeCompleted { substream, codec } => {
                 if let Some(conn_info) = self.peers.remove(&peer_id) {
                     NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::UpgradeCompleted {
                         peer_id,
                         peer_addr: conn_info.addr,
                         origin: conn_info.origin,
                         substream,
+                        codec,
                     })
                 } else {
                     return;
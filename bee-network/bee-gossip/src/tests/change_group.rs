@@ -0,0 +1,64 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "full")]
+
+use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
+use crate::{standalone::init, Command, PeerRelation};
+
+#[tokio::test]
+#[serial_test::serial]
+async fn change_group() {
+    let config1 = get_in_memory_network_config(1337);
+    let keys1 = gen_random_keys();
+
+    let config2 = get_in_memory_network_config(4242);
+    let keys2 = gen_random_keys();
+
+    let network_id = gen_constant_net_id();
+
+    let (tx1, mut rx1) = init(config1, keys1, network_id, shutdown(10))
+        .await
+        .expect("init failed");
+    let (_, mut rx2) = init(config2, keys2, network_id, shutdown(10))
+        .await
+        .expect("init failed");
+
+    let peer_id2 = get_local_id(&mut rx2).await;
+    let address2 = get_bind_address(&mut rx2).await;
+
+    tx1.send(Command::AddPeer {
+        alias: Some("2".into()),
+        multiaddr: address2,
+        relation: PeerRelation::Known,
+        peer_id: peer_id2,
+    })
+    .expect("send command");
+
+    assert_eq!(get_added_peer_id(&mut rx1).await, peer_id2);
+
+    tx1.send(Command::ChangeGroup {
+        peer_id: peer_id2,
+        to: Some("partners".into()),
+    })
+    .expect("send command");
+
+    assert_eq!(
+        get_changed_peer_group(&mut rx1).await,
+        (peer_id2, Some("partners".to_string()))
+    );
+
+    // Changing to a group that isn't configured fails, so the next change is the removal from the group.
+    tx1.send(Command::ChangeGroup {
+        peer_id: peer_id2,
+        to: Some("unconfigured".into()),
+    })
+    .expect("send command");
+    tx1.send(Command::ChangeGroup {
+        peer_id: peer_id2,
+        to: None,
+    })
+    .expect("send command");
+
+    assert_eq!(get_changed_peer_group(&mut rx1).await, (peer_id2, None));
+}
@@ -5,7 +5,7 @@
 
 use tokio::time::{self, Duration};
 
-use crate::{Event, GossipReceiver, GossipSender, Multiaddr, NetworkEventReceiver, PeerId};
+use crate::{Event, GossipReceiver, GossipSender, Multiaddr, NetworkEventReceiver, PeerId, TransportKind};
 
 pub async fn get_bind_address(rx: &mut NetworkEventReceiver) -> Multiaddr {
     let timeout = time::sleep(Duration::from_secs(5));
@@ -25,6 +25,26 @@ pub async fn get_bind_address(rx: &mut NetworkEventReceiver) -> Multiaddr {
     }
 }
 
+pub async fn get_websocket_bind_address(rx: &mut NetworkEventReceiver) -> Multiaddr {
+    let timeout = time::sleep(Duration::from_secs(5));
+    tokio::pin!(timeout);
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                if let Some(Event::AddressBound { address }) = event {
+                    if TransportKind::from_multiaddr(&address) == Some(TransportKind::WebSocket) {
+                        return address;
+                    }
+                }
+            },
+            () = &mut timeout => {
+                panic!("timed out before receiving `AddressBound` event of the WebSocket address");
+            }
+        }
+    }
+}
+
 pub async fn get_local_id(rx: &mut NetworkEventReceiver) -> PeerId {
     let timeout = time::sleep(Duration::from_secs(5));
     tokio::pin!(timeout);
@@ -61,15 +81,15 @@ pub async fn get_added_peer_id(rx: &mut NetworkEventReceiver) -> PeerId {
     }
 }
 
-pub async fn get_connected_peer_id(rx: &mut NetworkEventReceiver) -> PeerId {
+pub async fn get_connected_peer(rx: &mut NetworkEventReceiver) -> (PeerId, Option<TransportKind>) {
     let timeout = time::sleep(Duration::from_secs(20));
     tokio::pin!(timeout);
 
     loop {
         tokio::select! {
             event = rx.recv() => {
-                if let Some(Event::PeerConnected { peer_id, .. }) = event {
-                    return peer_id;
+                if let Some(Event::PeerConnected { peer_id, transport, .. }) = event {
+                    return (peer_id, transport);
                 }
             },
             () = &mut timeout => {
@@ -96,3 +116,21 @@ pub async fn get_gossip_channels(rx: &mut NetworkEventReceiver) -> (GossipReceiv
         }
     }
 }
+
+pub async fn get_changed_peer_group(rx: &mut NetworkEventReceiver) -> (PeerId, Option<String>) {
+    let timeout = time::sleep(Duration::from_secs(5));
+    tokio::pin!(timeout);
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                if let Some(Event::PeerGroupChanged { peer_id, group }) = event {
+                    return (peer_id, group);
+                }
+            },
+            () = &mut timeout => {
+                panic!("timed out before receiving `PeerGroupChanged` event");
+            }
+        }
+    }
+}
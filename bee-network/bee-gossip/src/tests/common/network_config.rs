@@ -3,20 +3,57 @@
 
 #![cfg(feature = "full")]
 
-use crate::{Multiaddr, NetworkConfig, Protocol};
+use std::collections::HashMap;
+
+use crate::{Multiaddr, NetworkConfig, PeerGroup, Protocol};
+
+/// The peer groups of all test configs. They are stored in a global that can only be set once per test binary, so
+/// every test has to use the same groups.
+pub fn test_peer_groups() -> HashMap<String, PeerGroup> {
+    [
+        PeerGroup {
+            name: "datacenter".into(),
+            max_peers: Some(1),
+            reconnect_priority: 2,
+        },
+        PeerGroup {
+            name: "partners".into(),
+            max_peers: None,
+            reconnect_priority: 1,
+        },
+    ]
+    .into_iter()
+    .map(|group| (group.name.clone(), group))
+    .collect()
+}
 
 pub fn get_network_config_with_port(port: u16) -> NetworkConfig {
     let mut config = NetworkConfig::default();
     config.replace_port(Protocol::Tcp(port)).unwrap();
+    config.peer_groups = test_peer_groups();
     config
 }
 
 pub fn get_in_memory_network_config(port: u64) -> NetworkConfig {
-    NetworkConfig::build_in_memory()
+    let mut config = NetworkConfig::build_in_memory()
         .with_bind_multiaddr({
             let mut m = Multiaddr::empty();
             m.push(Protocol::Memory(port));
             m
         })
+        .finish();
+    config.peer_groups = test_peer_groups();
+    config
+}
+
+pub fn get_websocket_network_config(port: u16) -> NetworkConfig {
+    let mut config = NetworkConfig::build()
+        .with_bind_multiaddr(format!("/ip4/127.0.0.1/tcp/{}", port).parse().unwrap())
+        .unwrap()
+        .with_additional_bind_multiaddr(format!("/ip4/127.0.0.1/tcp/{}/ws", port + 1).parse().unwrap())
+        .unwrap()
         .finish()
+        .unwrap();
+    config.peer_groups = test_peer_groups();
+    config
 }
@@ -4,7 +4,7 @@
 #![cfg(feature = "full")]
 
 use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
-use crate::{standalone::init, Command, PeerRelation};
+use crate::{standalone::init, Command, PeerRelation, TransportKind};
 
 #[tokio::test]
 #[serial_test::serial]
@@ -43,5 +43,8 @@ async fn connect_peer() {
     })
     .expect("send command");
 
-    assert_eq!(get_connected_peer_id(&mut rx1).await, peer_id2);
+    assert_eq!(
+        get_connected_peer(&mut rx1).await,
+        (peer_id2, Some(TransportKind::Memory))
+    );
 }
@@ -0,0 +1,44 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "full")]
+
+use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
+use crate::{standalone::init, Command, PeerRelation, TransportKind};
+
+#[tokio::test]
+#[serial_test::serial]
+async fn connect_websocket_peer() {
+    let config1 = get_websocket_network_config(15651);
+    let keys1 = gen_random_keys();
+
+    let config2 = get_websocket_network_config(15661);
+    let keys2 = gen_random_keys();
+
+    let network_id = gen_constant_net_id();
+
+    let (tx1, mut rx1) = init(config1, keys1, network_id, shutdown(10))
+        .await
+        .expect("init failed");
+    let (_tx2, mut rx2) = init(config2, keys2, network_id, shutdown(10))
+        .await
+        .expect("init failed");
+
+    let _peer_id1 = get_local_id(&mut rx1).await;
+
+    let peer_id2 = get_local_id(&mut rx2).await;
+    let address2 = get_websocket_bind_address(&mut rx2).await;
+
+    tx1.send(Command::AddPeer {
+        alias: Some("2".into()),
+        multiaddr: address2,
+        relation: PeerRelation::Known,
+        peer_id: peer_id2,
+    })
+    .expect("send command");
+
+    assert_eq!(
+        get_connected_peer(&mut rx1).await,
+        (peer_id2, Some(TransportKind::WebSocket))
+    );
+}
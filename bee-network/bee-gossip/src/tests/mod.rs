@@ -6,7 +6,9 @@
 
 mod add_peer;
 mod alias;
-mod common;
+mod change_group;
+pub(crate) mod common;
 mod connect_peer;
+mod connect_websocket_peer;
 mod initialize;
 mod send_recv;
@@ -44,6 +44,12 @@ struct Cli {
     // Whether the node should run with enabled autopeering service.
     #[structopt(long = "autopeering", help = "Enables the autopeering service")]
     enable_autopeering: bool,
+    // Whether the coordinator should bootstrap a new network.
+    #[structopt(
+        long = "coo-bootstrap",
+        help = "Enables the coordinator and bootstraps a new network if no coordinator state exists"
+    )]
+    coordinator_bootstrap: bool,
 }
 
 /// Holds the command line arguments that were passed to the binary.
@@ -100,6 +106,11 @@ impl ClArgs {
     pub fn enable_autopeering(&self) -> bool {
         self.cli.enable_autopeering
     }
+
+    /// Returns whether the coordinator should bootstrap a new network.
+    pub fn coordinator_bootstrap(&self) -> bool {
+        self.cli.coordinator_bootstrap
+    }
 }
 
 fn validate_args(args: &ClArgs) -> bool {
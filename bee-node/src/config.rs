@@ -25,7 +25,10 @@ use serde::Deserialize;
 use crate::plugins::dashboard::config::{DashboardConfig, DashboardConfigBuilder};
 use crate::{
     cli::ClArgs,
-    plugins::mqtt::config::{MqttConfig, MqttConfigBuilder},
+    plugins::{
+        coordinator::config::{CoordinatorConfig, CoordinatorConfigBuilder},
+        mqtt::config::{MqttConfig, MqttConfigBuilder},
+    },
     storage::NodeStorageBackend,
     util, BECH32_HRP_DEFAULT, NETWORK_NAME_DEFAULT,
 };
@@ -59,6 +62,7 @@ pub struct NodeConfig<S: NodeStorageBackend> {
     pub(crate) storage: S::Config,
     pub(crate) tangle: TangleConfig,
     pub(crate) mqtt: MqttConfig,
+    pub(crate) coordinator: CoordinatorConfig,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard: DashboardConfig,
 }
@@ -106,6 +110,7 @@ pub struct NodeConfigBuilder<S: NodeStorageBackend> {
     pub(crate) storage: Option<S::ConfigBuilder>,
     pub(crate) tangle: Option<TangleConfigBuilder>,
     pub(crate) mqtt: Option<MqttConfigBuilder>,
+    pub(crate) coordinator: Option<CoordinatorConfigBuilder>,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard: Option<DashboardConfigBuilder>,
 }
@@ -134,6 +139,7 @@ where
             storage: self_storage,
             tangle: self_tangle,
             mqtt: self_mqtt,
+            coordinator: self_coordinator,
             #[cfg(feature = "dashboard")]
                 dashboard: self_dashboard,
         } = self;
@@ -151,7 +157,8 @@ where
             && (self_pruning == &other.pruning)
             && (self_storage == &other.storage)
             && (self_tangle == &other.tangle)
-            && (self_mqtt == &other.mqtt);
+            && (self_mqtt == &other.mqtt)
+            && (self_coordinator == &other.coordinator);
 
         #[cfg(feature = "dashboard")]
         return cmp && (self_dashboard == &other.dashboard);
@@ -201,6 +208,15 @@ where
             autopeering.enabled = true;
         }
 
+        // Override the coordinator bootstrap mode.
+        if args.coordinator_bootstrap() {
+            // TODO: use 'option_get_or_insert_default' once stable (see issue #82901)
+            let coordinator = self.coordinator.get_or_insert(CoordinatorConfigBuilder::default());
+
+            coordinator.enabled = Some(true);
+            coordinator.bootstrap = Some(true);
+        }
+
         self
     }
 
@@ -233,10 +249,15 @@ where
                 protocol: self.protocol.unwrap_or_default().finish(),
                 rest_api: self.rest_api.unwrap_or_default().finish(),
                 snapshot: self.snapshot.unwrap_or_default().finish(),
-                pruning: self.pruning.unwrap_or_default().finish(),
+                pruning: self
+                    .pruning
+                    .unwrap_or_default()
+                    .finish()
+                    .expect("faulty pruning configuration"),
                 storage: self.storage.unwrap_or_default().into(),
                 tangle: self.tangle.unwrap_or_default().finish(),
                 mqtt: self.mqtt.unwrap_or_default().finish(),
+                coordinator: self.coordinator.unwrap_or_default().finish(),
                 #[cfg(feature = "dashboard")]
                 dashboard: self.dashboard.unwrap_or_default().finish(),
             },
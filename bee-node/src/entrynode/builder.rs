@@ -232,7 +232,7 @@ async fn initialize_autopeering(
 
     let quit_signal = tokio::signal::ctrl_c();
 
-    let autopeering_rx = bee_autopeering::init::<RocksDbPeerStore, _, _, _>(
+    let (autopeering_rx, _) = bee_autopeering::init::<RocksDbPeerStore, _, _, _>(
         builder.config().autopeering.clone(),
         AUTOPEERING_VERSION,
         network_name,
@@ -9,7 +9,7 @@ use std::{
 use async_trait::async_trait;
 use bee_autopeering::{
     stores::{Options as RocksDbPeerStoreConfigOptions, RocksDbPeerStore, RocksDbPeerStoreConfig},
-    NeighborValidator, ServiceProtocol, AUTOPEERING_SERVICE_NAME,
+    AutopeeringHandle, NeighborValidator, ServiceProtocol, AUTOPEERING_SERVICE_NAME,
 };
 use bee_gossip::{Keypair, NetworkEventReceiver, Protocol};
 use bee_runtime::{
@@ -26,7 +26,7 @@ use crate::plugins::Dashboard;
 use crate::{
     config::NetworkSpec,
     core::{Core, CoreError, ResourceRegister, TopologicalOrder, WorkerStart, WorkerStop},
-    plugins::{self, Mqtt, VersionChecker},
+    plugins::{self, Coordinator, Mqtt, VersionChecker},
     shutdown,
     storage::NodeStorageBackend,
     util, AUTOPEERING_VERSION,
@@ -147,10 +147,10 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
 
         // Initialize everything.
         let (gossip_rx, builder) = initialize_gossip_layer(builder)?;
-        let (autopeering_rx, builder) = initialize_autopeering(builder).await?;
+        let (autopeering_rx, autopeering_handle, builder) = initialize_autopeering(builder).await?;
         let builder = initialize_ledger(builder);
         let builder = initialize_protocol(builder, gossip_rx, autopeering_rx);
-        let builder = initialize_api(builder);
+        let builder = initialize_api(builder, autopeering_handle);
         let builder = initialize_tangle(builder);
 
         // Start the version checker.
@@ -160,6 +160,14 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
         let mqtt_cfg = builder.config().mqtt.clone();
         let builder = builder.with_worker_cfg::<Mqtt>(mqtt_cfg);
 
+        // Start issuing milestones (if enabled).
+        let coordinator_cfg = builder.config().coordinator.clone();
+        let builder = if coordinator_cfg.enabled() {
+            builder.with_worker_cfg::<Coordinator>(coordinator_cfg)
+        } else {
+            builder
+        };
+
         // Start serving the dashboard (if enabled).
         #[cfg(feature = "dashboard")]
         let builder = {
@@ -305,13 +313,21 @@ fn initialize_protocol<S: NodeStorageBackend>(
 }
 
 /// Initializes the (optional) autopeering service.
+#[allow(clippy::type_complexity)]
 async fn initialize_autopeering<S: NodeStorageBackend>(
     builder: FullNodeBuilder<S>,
-) -> Result<(Option<bee_autopeering::event::EventRx>, FullNodeBuilder<S>), FullNodeError> {
+) -> Result<
+    (
+        Option<bee_autopeering::event::EventRx>,
+        Option<AutopeeringHandle>,
+        FullNodeBuilder<S>,
+    ),
+    FullNodeError,
+> {
     let config = builder.config();
 
     if !config.autopeering.enabled() {
-        Ok((None, builder))
+        Ok((None, None, builder))
     } else {
         log::info!("Initializing autopeering...");
 
@@ -333,7 +349,7 @@ async fn initialize_autopeering<S: NodeStorageBackend>(
 
         let quit_signal = tokio::signal::ctrl_c();
 
-        let autopeering_rx = bee_autopeering::init::<RocksDbPeerStore, _, _, _>(
+        let (autopeering_rx, autopeering_handle) = bee_autopeering::init::<RocksDbPeerStore, _, _, _>(
             autopeering_cfg,
             AUTOPEERING_VERSION,
             network_name,
@@ -345,7 +361,7 @@ async fn initialize_autopeering<S: NodeStorageBackend>(
         .await
         .map_err(|e| FullNodeError::AutopeeringInitialization(e))?;
 
-        Ok((Some(autopeering_rx), builder))
+        Ok((Some(autopeering_rx), Some(autopeering_handle), builder))
     }
 }
 
@@ -380,7 +396,10 @@ fn create_local_autopeering_entity<S: NodeStorageBackend>(
 }
 
 /// Initializes the API.
-fn initialize_api<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> FullNodeBuilder<S> {
+fn initialize_api<S: NodeStorageBackend>(
+    builder: FullNodeBuilder<S>,
+    autopeering_handle: Option<AutopeeringHandle>,
+) -> FullNodeBuilder<S> {
     log::info!("Initializing REST API...");
 
     let config = builder.config();
@@ -395,7 +414,14 @@ fn initialize_api<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> FullNod
     let rest_api_cfg = config.rest_api.clone();
     let protocol_cfg = config.protocol.clone();
 
-    bee_rest_api::endpoints::init_full_node::<FullNode<S>>(rest_api_cfg, protocol_cfg, network_id, hrp, builder)
+    bee_rest_api::endpoints::init_full_node::<FullNode<S>>(
+        rest_api_cfg,
+        protocol_cfg,
+        network_id,
+        hrp,
+        autopeering_handle,
+        builder,
+    )
 }
 
 /// Initializes the Tangle.
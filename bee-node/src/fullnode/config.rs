@@ -12,7 +12,11 @@ use fern_logger::LoggerConfig;
 #[cfg(feature = "dashboard")]
 use crate::plugins::dashboard::config::DashboardConfig;
 use crate::{
-    config::NetworkSpec, local::Local, plugins::mqtt::config::MqttConfig, storage::NodeStorageBackend, NodeConfig,
+    config::NetworkSpec,
+    local::Local,
+    plugins::{coordinator::config::CoordinatorConfig, mqtt::config::MqttConfig},
+    storage::NodeStorageBackend,
+    NodeConfig,
 };
 
 /// The config of a Bee full node.
@@ -43,6 +47,8 @@ pub struct FullNodeConfig<S: NodeStorageBackend> {
     pub tangle: TangleConfig,
     /// MQTT broker.
     pub mqtt: MqttConfig,
+    /// Coordinator.
+    pub coordinator: CoordinatorConfig,
     /// Node dashboard.
     #[cfg(feature = "dashboard")]
     pub dashboard: DashboardConfig,
@@ -79,6 +85,7 @@ impl<S: NodeStorageBackend> FullNodeConfig<S> {
             storage: node_cfg.storage,
             tangle: node_cfg.tangle,
             mqtt: node_cfg.mqtt,
+            coordinator: node_cfg.coordinator,
             #[cfg(feature = "dashboard")]
             dashboard: node_cfg.dashboard,
         }
@@ -101,6 +108,7 @@ impl<S: NodeStorageBackend> Clone for FullNodeConfig<S> {
             storage: self.storage.clone(),
             tangle: self.tangle.clone(),
             mqtt: self.mqtt.clone(),
+            coordinator: self.coordinator.clone(),
             #[cfg(feature = "dashboard")]
             dashboard: self.dashboard.clone(),
         }
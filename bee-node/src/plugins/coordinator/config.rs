@@ -0,0 +1,137 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::PathBuf, time::Duration};
+
+use serde::Deserialize;
+
+const DEFAULT_ENABLED: bool = false;
+const DEFAULT_INTERVAL: u64 = 10;
+const DEFAULT_STATE_FILE_PATH: &str = "./coordinator.state";
+const DEFAULT_BOOTSTRAP: bool = false;
+const DEFAULT_REMOTE_CONNECT_TIMEOUT_SECS: u64 = 5;
+const DEFAULT_REMOTE_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Default, Deserialize, PartialEq)]
+pub struct CoordinatorSignerConfigBuilder {
+    #[serde(alias = "keyFiles")]
+    key_files: Option<Vec<String>>,
+    #[serde(alias = "remoteUrl")]
+    remote_url: Option<String>,
+    #[serde(alias = "remoteConnectTimeoutSecs")]
+    remote_connect_timeout_secs: Option<u64>,
+    #[serde(alias = "remoteRequestTimeoutSecs")]
+    remote_request_timeout_secs: Option<u64>,
+}
+
+impl CoordinatorSignerConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> CoordinatorSignerConfig {
+        match self.remote_url {
+            Some(url) => CoordinatorSignerConfig::Remote {
+                url,
+                connect_timeout: Duration::from_secs(
+                    self.remote_connect_timeout_secs
+                        .unwrap_or(DEFAULT_REMOTE_CONNECT_TIMEOUT_SECS),
+                ),
+                request_timeout: Duration::from_secs(
+                    self.remote_request_timeout_secs
+                        .unwrap_or(DEFAULT_REMOTE_REQUEST_TIMEOUT_SECS),
+                ),
+            },
+            None => CoordinatorSignerConfig::Local {
+                key_files: self
+                    .key_files
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(PathBuf::from)
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// How the coordinator signs milestones.
+#[derive(Clone)]
+pub enum CoordinatorSignerConfig {
+    /// Signs with the keys of local key files, or of the `COO_PRV_KEYS` environment variable if there are none.
+    Local { key_files: Vec<PathBuf> },
+    /// Signs through a remote signing service.
+    Remote {
+        url: String,
+        connect_timeout: Duration,
+        request_timeout: Duration,
+    },
+}
+
+#[derive(Default, Deserialize, PartialEq)]
+pub struct CoordinatorConfigBuilder {
+    pub(crate) enabled: Option<bool>,
+    interval: Option<u64>,
+    #[serde(alias = "stateFilePath")]
+    state_file_path: Option<String>,
+    pub(crate) bootstrap: Option<bool>,
+    signer: Option<CoordinatorSignerConfigBuilder>,
+}
+
+impl CoordinatorConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> CoordinatorConfig {
+        CoordinatorConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
+            interval: self.interval.unwrap_or(DEFAULT_INTERVAL),
+            state_file_path: PathBuf::from(
+                self.state_file_path
+                    .unwrap_or_else(|| DEFAULT_STATE_FILE_PATH.to_owned()),
+            ),
+            bootstrap: self.bootstrap.unwrap_or(DEFAULT_BOOTSTRAP),
+            signer: self.signer.unwrap_or_default().finish(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CoordinatorConfig {
+    enabled: bool,
+    interval: u64,
+    state_file_path: PathBuf,
+    bootstrap: bool,
+    signer: CoordinatorSignerConfig,
+}
+
+impl CoordinatorConfig {
+    pub fn build() -> CoordinatorConfigBuilder {
+        CoordinatorConfigBuilder::new()
+    }
+
+    /// Returns whether the coordinator is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the interval, in seconds, between two milestones.
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the path of the file the coordinator state is persisted to.
+    pub fn state_file_path(&self) -> &PathBuf {
+        &self.state_file_path
+    }
+
+    /// Returns whether the coordinator should bootstrap a new network when no state file exists.
+    pub fn bootstrap(&self) -> bool {
+        self.bootstrap
+    }
+
+    /// Returns the signer configuration.
+    pub fn signer(&self) -> &CoordinatorSignerConfig {
+        &self.signer
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{milestone::MilestoneIndex, payload::milestone::MilestoneValidationError};
+
+use crate::plugins::coordinator::signer::SignerError;
+
+/// Errors occurring while running the coordinator.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Signer(#[from] SignerError),
+    #[error("no coordinator state file found and bootstrapping is disabled")]
+    MissingState,
+    #[error("accessing the coordinator state file failed: {0}")]
+    StateFile(std::io::Error),
+    #[error("deserializing the coordinator state file failed: {0}")]
+    StateFileDeserialization(serde_json::Error),
+    #[error("invalid message id in the coordinator state file: {0}")]
+    InvalidStateMessageId(String),
+    #[error("only {1} applicable signer keys for milestone {0}, {2} required")]
+    InsufficientSignerKeys(MilestoneIndex, usize, usize),
+    #[error("computing the white flag merkle proof failed: {0}")]
+    WhiteFlag(#[from] bee_ledger::workers::error::Error),
+    #[error("building the milestone message failed: {0}")]
+    Message(#[from] bee_message::Error),
+    #[error("the proof of work of the milestone message failed: {0}")]
+    Pow(tokio::task::JoinError),
+    #[error("issued milestone is invalid: {0:?}")]
+    InvalidMilestone(MilestoneValidationError),
+    #[error("submitting the milestone message failed: {0}")]
+    Submission(String),
+}
@@ -0,0 +1,366 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A coordinator issuing signed milestones at a regular interval, meant to run private networks.
+
+pub mod config;
+pub mod signer;
+
+mod error;
+mod state;
+
+use std::{
+    any::TypeId,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use bee_ledger::workers::consensus::{white_flag, ConsensusWorker, WhiteFlagMetadata};
+use bee_message::{
+    milestone::MilestoneIndex,
+    parents::{Parents, MESSAGE_PARENTS_RANGE},
+    payload::{
+        milestone::{MilestonePayload, MilestonePayloadEssence},
+        Payload,
+    },
+    MessageBuilder, MessageId,
+};
+use bee_pow::providers::{miner::MinerBuilder, NonceProviderBuilder};
+use bee_protocol::{
+    types::milestone_key_manager::MilestoneKeyManager,
+    workers::{MessageSubmitterError, MessageSubmitterWorker, MessageSubmitterWorkerEvent},
+};
+use bee_runtime::{node::Node, resource::ResourceHandle, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_tangle::{Tangle, TangleWorker};
+use futures::{channel::oneshot, StreamExt};
+use log::{debug, error, info, warn};
+use tokio::{sync::mpsc, time::interval};
+use tokio_stream::wrappers::IntervalStream;
+
+pub use self::error::Error;
+use self::{
+    config::{CoordinatorConfig, CoordinatorSignerConfig},
+    signer::{LocalSigner, MilestoneSigner, PublicKey, RemoteSigner},
+    state::CoordinatorState,
+};
+use crate::{fullnode::config::FullNodeConfig, storage::NodeStorageBackend};
+
+/// The coordinator worker.
+#[derive(Default)]
+pub struct Coordinator;
+
+/// Returns the applicable public keys of the milestone `index`, and the ones of the signer among them, sorted.
+fn applicable_keys(
+    key_manager: &MilestoneKeyManager,
+    signer_public_keys: &[PublicKey],
+    index: MilestoneIndex,
+) -> Result<(Vec<String>, Vec<PublicKey>), Error> {
+    let applicable_public_keys = key_manager.get_public_keys(index);
+    let mut public_keys = signer_public_keys
+        .iter()
+        .filter(|public_key| applicable_public_keys.contains(&hex::encode(public_key)))
+        .copied()
+        .collect::<Vec<_>>();
+
+    public_keys.sort_unstable();
+    public_keys.dedup();
+
+    if public_keys.len() < key_manager.min_threshold() {
+        return Err(Error::InsufficientSignerKeys(
+            index,
+            public_keys.len(),
+            key_manager.min_threshold(),
+        ));
+    }
+
+    Ok((applicable_public_keys.into_iter().collect(), public_keys))
+}
+
+struct MilestoneIssuer<B: NodeStorageBackend> {
+    tangle: ResourceHandle<Tangle<B>>,
+    storage: ResourceHandle<B>,
+    message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    key_manager: MilestoneKeyManager,
+    signer: Box<dyn MilestoneSigner>,
+    network_id: u64,
+    minimum_pow_score: f64,
+}
+
+impl<B: NodeStorageBackend> MilestoneIssuer<B> {
+    /// Selects the parents of the next milestone: the previous milestone and tips chosen by the URTS.
+    async fn select_parents(&self, previous_milestone_message_id: MessageId) -> Result<Parents, Error> {
+        let mut parents = self
+            .tangle
+            .get_messages_to_approve()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|tip| *tip != previous_milestone_message_id)
+            .take(*MESSAGE_PARENTS_RANGE.end() - 1)
+            .collect::<Vec<_>>();
+
+        parents.push(previous_milestone_message_id);
+        parents.sort_unstable();
+
+        Ok(Parents::new(parents)?)
+    }
+
+    /// Issues the milestone `index`, referencing the previous milestone, and returns its message id.
+    async fn issue(
+        &self,
+        index: MilestoneIndex,
+        previous_milestone_message_id: MessageId,
+        timestamp: u64,
+    ) -> Result<MessageId, Error> {
+        let parents = self.select_parents(previous_milestone_message_id).await?;
+
+        let mut metadata = WhiteFlagMetadata::new(index);
+
+        white_flag(
+            &self.tangle,
+            &self.storage,
+            &parents.iter().copied().collect::<Vec<_>>(),
+            &mut metadata,
+        )
+        .await?;
+
+        let (applicable_public_keys, public_keys) =
+            applicable_keys(&self.key_manager, self.signer.public_keys(), index)?;
+
+        let essence = MilestonePayloadEssence::new(
+            index,
+            timestamp,
+            parents.clone(),
+            // The white flag merkle proof is a Blake2b-256 digest.
+            metadata.merkle_proof().try_into().unwrap(),
+            0,
+            0,
+            public_keys.clone(),
+            None,
+        )?;
+        let signatures = self.signer.sign(&essence, &public_keys).await?;
+        let milestone = MilestonePayload::new(essence, signatures)?;
+
+        milestone
+            .validate(&applicable_public_keys, self.key_manager.min_threshold())
+            .map_err(Error::InvalidMilestone)?;
+
+        let network_id = self.network_id;
+        let minimum_pow_score = self.minimum_pow_score;
+        let message = tokio::task::spawn_blocking(move || {
+            MessageBuilder::new()
+                .with_network_id(network_id)
+                .with_parents(parents)
+                .with_payload(Payload::from(milestone))
+                .with_nonce_provider(
+                    MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
+                    minimum_pow_score,
+                )
+                .finish()
+        })
+        .await
+        .map_err(Error::Pow)??;
+
+        let (_, message_bytes) = message.id();
+        let (notifier, waiter) = oneshot::channel::<Result<MessageId, MessageSubmitterError>>();
+
+        self.message_submitter
+            .send(MessageSubmitterWorkerEvent {
+                message: message_bytes,
+                notifier,
+            })
+            .map_err(|e| Error::Submission(e.to_string()))?;
+
+        waiter
+            .await
+            .map_err(|e| Error::Submission(e.to_string()))?
+            .map_err(|e| Error::Submission(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<N: Node> Worker<N> for Coordinator
+where
+    N::Backend: NodeStorageBackend,
+{
+    type Config = CoordinatorConfig;
+    type Error = Error;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![
+            TypeId::of::<TangleWorker>(),
+            TypeId::of::<ConsensusWorker>(),
+            TypeId::of::<MessageSubmitterWorker>(),
+        ]
+        .leak()
+    }
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let signer: Box<dyn MilestoneSigner> = match config.signer() {
+            CoordinatorSignerConfig::Local { key_files } if key_files.is_empty() => Box::new(LocalSigner::from_env()?),
+            CoordinatorSignerConfig::Local { key_files } => Box::new(LocalSigner::from_files(key_files)?),
+            CoordinatorSignerConfig::Remote {
+                url,
+                connect_timeout,
+                request_timeout,
+            } => Box::new(RemoteSigner::connect(url, *connect_timeout, *request_timeout).await?),
+        };
+        let state = CoordinatorState::from_file(config.state_file_path())?;
+
+        if state.is_none() && !config.bootstrap() {
+            return Err(Error::MissingState);
+        }
+
+        let node_config = node.resource::<FullNodeConfig<N::Backend>>();
+        let coordinator_config = node_config.protocol.coordinator();
+        let issuer = MilestoneIssuer {
+            tangle: node.resource::<Tangle<N::Backend>>(),
+            storage: node.storage(),
+            message_submitter: node.worker::<MessageSubmitterWorker>().unwrap().tx.clone(),
+            key_manager: MilestoneKeyManager::new(
+                coordinator_config.public_key_count(),
+                coordinator_config.public_key_ranges().to_vec().into_boxed_slice(),
+            ),
+            signer,
+            network_id: node_config.network_spec().id(),
+            minimum_pow_score: node_config.protocol.minimum_pow_score(),
+        };
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let tangle = &issuer.tangle;
+            let mut state = match state {
+                Some(state) => {
+                    info!("Resuming from milestone {}.", *state.latest_milestone_index());
+                    state
+                }
+                None => {
+                    let index = tangle.get_confirmed_milestone_index();
+                    info!("Bootstrapping from milestone {}.", *index);
+                    CoordinatorState::new(
+                        index,
+                        tangle.get_milestone_message_id(index).unwrap_or_else(MessageId::null),
+                        0,
+                    )
+                }
+            };
+
+            let mut ticker = ShutdownStream::new(
+                shutdown,
+                IntervalStream::new(interval(Duration::from_secs(config.interval()))),
+            );
+
+            while ticker.next().await.is_some() {
+                let confirmed_index = tangle.get_confirmed_milestone_index();
+                let latest_index = state.latest_milestone_index();
+
+                if confirmed_index < latest_index {
+                    debug!(
+                        "Waiting for milestone {} to be confirmed, confirmed milestone is {}.",
+                        *latest_index, *confirmed_index
+                    );
+                    continue;
+                }
+
+                // The node stopped after issuing a milestone but before storing the state, so the state is resumed
+                // from the confirmed milestone.
+                if confirmed_index > latest_index {
+                    match tangle.get_milestone(confirmed_index) {
+                        Some(milestone) => {
+                            warn!(
+                                "Confirmed milestone {} is ahead of the latest issued milestone {}, resuming from it.",
+                                *confirmed_index, *latest_index
+                            );
+
+                            state = CoordinatorState::from_milestone(confirmed_index, &milestone);
+
+                            if let Err(e) = state.to_file(config.state_file_path()) {
+                                error!("Storing the coordinator state failed: {}.", e);
+                            }
+                        }
+                        None => {
+                            error!(
+                                "Confirmed milestone {} not found, can't resume from it.",
+                                *confirmed_index
+                            );
+                            continue;
+                        }
+                    }
+                }
+
+                let index = state.latest_milestone_index() + 1;
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("time went backwards")
+                    .as_secs();
+                // Milestone timestamps must never go backwards, even if the clock does.
+                let timestamp = now.max(state.latest_milestone_timestamp() + 1);
+
+                match issuer
+                    .issue(index, state.latest_milestone_message_id(), timestamp)
+                    .await
+                {
+                    Ok(message_id) => {
+                        info!("Issued milestone {} in message {}.", *index, message_id);
+
+                        state = CoordinatorState::new(index, message_id, timestamp);
+
+                        if let Err(e) = state.to_file(config.state_file_path()) {
+                            error!("Storing the coordinator state failed: {}.", e);
+                        }
+                    }
+                    Err(e) => error!("Issuing milestone {} failed: {}.", *index, e),
+                }
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_protocol::types::milestone_key_range::MilestoneKeyRange;
+
+    use super::*;
+
+    fn key_manager(min_threshold: usize, public_keys: &[PublicKey]) -> MilestoneKeyManager {
+        MilestoneKeyManager::new(
+            min_threshold,
+            public_keys
+                .iter()
+                .map(|public_key| {
+                    MilestoneKeyRange::new(hex::encode(public_key), MilestoneIndex(0), MilestoneIndex(10))
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn applicable_keys_sorted_and_filtered() {
+        let key_manager = key_manager(2, &[[3; 32], [1; 32], [2; 32]]);
+        let (applicable_public_keys, public_keys) =
+            applicable_keys(&key_manager, &[[2; 32], [4; 32], [1; 32], [2; 32]], MilestoneIndex(5)).unwrap();
+
+        assert_eq!(applicable_public_keys.len(), 3);
+        assert_eq!(public_keys, vec![[1; 32], [2; 32]]);
+    }
+
+    #[test]
+    fn applicable_keys_below_threshold() {
+        let key_manager = key_manager(2, &[[1; 32], [2; 32]]);
+
+        assert!(matches!(
+            applicable_keys(&key_manager, &[[1; 32], [3; 32]], MilestoneIndex(5)),
+            Err(Error::InsufficientSignerKeys(MilestoneIndex(5), 1, 2))
+        ));
+        // The key ranges end at milestone 10.
+        assert!(matches!(
+            applicable_keys(&key_manager, &[[1; 32], [2; 32]], MilestoneIndex(11)),
+            Err(Error::InsufficientSignerKeys(MilestoneIndex(11), 0, 2))
+        ));
+    }
+}
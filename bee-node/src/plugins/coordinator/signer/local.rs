@@ -0,0 +1,97 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::Path};
+
+use async_trait::async_trait;
+use bee_message::payload::milestone::MilestonePayloadEssence;
+use crypto::signatures::ed25519::SecretKey;
+
+use super::{MilestoneSigner, PublicKey, Signature, SignerError};
+
+/// Name of the environment variable holding the comma-separated, hex encoded, Ed25519 private keys of the coordinator.
+pub const COO_PRIVATE_KEYS_ENV: &str = "COO_PRV_KEYS";
+
+fn parse_private_key(key: &str) -> Result<SecretKey, SignerError> {
+    hex::decode(key)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(SecretKey::from_bytes)
+        .ok_or_else(|| SignerError::InvalidPrivateKey(key.to_owned()))
+}
+
+/// A signer holding the private keys of the coordinator.
+pub struct LocalSigner {
+    keys: Vec<SecretKey>,
+    public_keys: Vec<PublicKey>,
+}
+
+impl LocalSigner {
+    /// Creates a new `LocalSigner` from private keys.
+    pub fn new(keys: Vec<SecretKey>) -> Result<Self, SignerError> {
+        if keys.is_empty() {
+            return Err(SignerError::MissingPrivateKeys);
+        }
+
+        let public_keys = keys.iter().map(|key| key.public_key().to_bytes()).collect();
+
+        Ok(Self { keys, public_keys })
+    }
+
+    /// Creates a new `LocalSigner` from files each holding a hex encoded private key.
+    pub fn from_files<P: AsRef<Path>>(paths: &[P]) -> Result<Self, SignerError> {
+        let keys = paths
+            .iter()
+            .map(|path| {
+                let key = fs::read_to_string(path)
+                    .map_err(|e| SignerError::KeyFile(path.as_ref().to_string_lossy().into_owned(), e))?;
+
+                parse_private_key(key.trim())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(keys)
+    }
+
+    /// Creates a new `LocalSigner` from the private keys of the `COO_PRV_KEYS` environment variable.
+    pub fn from_env() -> Result<Self, SignerError> {
+        let keys = std::env::var(COO_PRIVATE_KEYS_ENV).map_err(|_| SignerError::MissingPrivateKeys)?;
+
+        Self::new(
+            keys.split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(parse_private_key)
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    }
+
+    /// Signs an essence hash with each of the `public_keys`.
+    pub(crate) fn sign_hash(&self, hash: &[u8], public_keys: &[PublicKey]) -> Result<Vec<Signature>, SignerError> {
+        public_keys
+            .iter()
+            .map(|public_key| {
+                self.public_keys
+                    .iter()
+                    .position(|key| key == public_key)
+                    .map(|position| self.keys[position].sign(hash).to_bytes())
+                    .ok_or_else(|| SignerError::UnknownPublicKey(hex::encode(public_key)))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl MilestoneSigner for LocalSigner {
+    fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    async fn sign(
+        &self,
+        essence: &MilestonePayloadEssence,
+        public_keys: &[PublicKey],
+    ) -> Result<Vec<Signature>, SignerError> {
+        self.sign_hash(&essence.hash(), public_keys)
+    }
+}
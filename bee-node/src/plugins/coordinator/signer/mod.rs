@@ -0,0 +1,50 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signers producing the signatures of milestones, either with local keys or through a remote signing service.
+
+mod local;
+mod remote;
+
+use async_trait::async_trait;
+use bee_message::payload::milestone::{
+    MilestonePayloadEssence, MILESTONE_PUBLIC_KEY_LENGTH, MILESTONE_SIGNATURE_LENGTH,
+};
+
+pub use self::{local::LocalSigner, remote::RemoteSigner};
+
+/// An Ed25519 public key of the coordinator.
+pub type PublicKey = [u8; MILESTONE_PUBLIC_KEY_LENGTH];
+/// An Ed25519 signature of a milestone essence.
+pub type Signature = [u8; MILESTONE_SIGNATURE_LENGTH];
+
+/// Errors occurring while signing milestones.
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("no coordinator private keys provided")]
+    MissingPrivateKeys,
+    #[error("reading the key file {0} failed: {1}")]
+    KeyFile(String, std::io::Error),
+    #[error("invalid coordinator private key: {0}")]
+    InvalidPrivateKey(String),
+    #[error("no private key for public key {0}")]
+    UnknownPublicKey(String),
+    #[error("requesting the signing service failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("invalid response from the signing service: {0}")]
+    InvalidResponse(String),
+}
+
+/// Signs milestone essences on behalf of the coordinator.
+#[async_trait]
+pub trait MilestoneSigner: Send + Sync {
+    /// Returns the public keys the signer can sign with.
+    fn public_keys(&self) -> &[PublicKey];
+
+    /// Signs `essence` with each of the `public_keys`, returning the signatures in the same order.
+    async fn sign(
+        &self,
+        essence: &MilestonePayloadEssence,
+        public_keys: &[PublicKey],
+    ) -> Result<Vec<Signature>, SignerError>;
+}
@@ -0,0 +1,271 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A signer delegating to a remote signing service, so that the coordinator host never holds plaintext keys.
+//!
+//! The signing service speaks a simple JSON over HTTP protocol:
+//! - `GET <url>/api/v1/public-keys` replies with `{"publicKeys": ["<hex>", ...]}`;
+//! - `POST <url>/api/v1/sign` with `{"essence": "<hex>", "publicKeys": ["<hex>", ...]}`, the essence being packed,
+//!   replies with `{"signatures": ["<hex>", ...]}`, the signatures being in the order of the public keys.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bee_common::packable::Packable;
+use bee_message::payload::milestone::MilestonePayloadEssence;
+use crypto::signatures::ed25519;
+use serde::{Deserialize, Serialize};
+
+use super::{MilestoneSigner, PublicKey, Signature, SignerError};
+
+const PUBLIC_KEYS_ROUTE: &str = "api/v1/public-keys";
+const SIGN_ROUTE: &str = "api/v1/sign";
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PublicKeysResponse {
+    pub(crate) public_keys: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SignRequest {
+    pub(crate) essence: String,
+    pub(crate) public_keys: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SignResponse {
+    pub(crate) signatures: Vec<String>,
+}
+
+fn decode<const N: usize>(hex: &str) -> Result<[u8; N], SignerError> {
+    hex::decode(hex)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| SignerError::InvalidResponse(format!("invalid hex encoded value {}", hex)))
+}
+
+/// A signer requesting signatures from a remote signing service.
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    public_keys: Vec<PublicKey>,
+}
+
+impl RemoteSigner {
+    /// Connects to the signing service at `url` and fetches the public keys it can sign with.
+    ///
+    /// Establishing a connection fails after `connect_timeout`, and every request, including the connection, after
+    /// `request_timeout`.
+    pub async fn connect(url: &str, connect_timeout: Duration, request_timeout: Duration) -> Result<Self, SignerError> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
+            .build()?;
+        let url = url.trim_end_matches('/').to_owned();
+        let response = client
+            .get(format!("{}/{}", url, PUBLIC_KEYS_ROUTE))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<PublicKeysResponse>()
+            .await?;
+        let public_keys = response
+            .public_keys
+            .iter()
+            .map(|public_key| decode(public_key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if public_keys.is_empty() {
+            return Err(SignerError::MissingPrivateKeys);
+        }
+
+        Ok(Self {
+            client,
+            url,
+            public_keys,
+        })
+    }
+}
+
+#[async_trait]
+impl MilestoneSigner for RemoteSigner {
+    fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    async fn sign(
+        &self,
+        essence: &MilestonePayloadEssence,
+        public_keys: &[PublicKey],
+    ) -> Result<Vec<Signature>, SignerError> {
+        let request = SignRequest {
+            essence: hex::encode(essence.pack_new()),
+            public_keys: public_keys.iter().map(hex::encode).collect(),
+        };
+        let response = self
+            .client
+            .post(format!("{}/{}", self.url, SIGN_ROUTE))
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<SignResponse>()
+            .await?;
+
+        if response.signatures.len() != public_keys.len() {
+            return Err(SignerError::InvalidResponse(format!(
+                "{} signatures for {} public keys",
+                response.signatures.len(),
+                public_keys.len()
+            )));
+        }
+
+        let hash = essence.hash();
+
+        // The signing service is not trusted to sign what it was asked to, so signatures are checked before use.
+        public_keys
+            .iter()
+            .zip(response.signatures.iter())
+            .map(|(public_key, signature)| {
+                let signature = decode(signature)?;
+                let valid = ed25519::PublicKey::try_from_bytes(*public_key)
+                    .map(|key| key.verify(&ed25519::Signature::from_bytes(signature), &hash))
+                    .unwrap_or(false);
+
+                if valid {
+                    Ok(signature)
+                } else {
+                    Err(SignerError::InvalidResponse(format!(
+                        "invalid signature for public key {}",
+                        hex::encode(public_key)
+                    )))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, sync::Arc};
+
+    use bee_message::{
+        milestone::MilestoneIndex,
+        parents::Parents,
+        payload::milestone::{MilestonePayload, MilestonePayloadEssence},
+        MessageId,
+    };
+    use crypto::{
+        hashes::{blake2b::Blake2b256, Digest},
+        signatures::ed25519::SecretKey,
+    };
+    use warp::{http::StatusCode, Filter};
+
+    use super::*;
+    use crate::plugins::coordinator::signer::LocalSigner;
+
+    // A mock signing service signing with local keys.
+    fn mock_service(signer: LocalSigner) -> SocketAddr {
+        let signer = Arc::new(signer);
+        let public_keys_signer = signer.clone();
+
+        let public_keys = warp::get().and(warp::path!("api" / "v1" / "public-keys")).map(move || {
+            warp::reply::json(&PublicKeysResponse {
+                public_keys: public_keys_signer.public_keys().iter().map(hex::encode).collect(),
+            })
+        });
+        let sign = warp::post()
+            .and(warp::path!("api" / "v1" / "sign"))
+            .and(warp::body::json())
+            .map(move |request: SignRequest| {
+                let essence = hex::decode(&request.essence).unwrap();
+                let public_keys = request
+                    .public_keys
+                    .iter()
+                    .map(|public_key| decode(public_key).unwrap())
+                    .collect::<Vec<_>>();
+
+                match signer.sign_hash(&Blake2b256::digest(&essence), &public_keys) {
+                    Ok(signatures) => warp::reply::with_status(
+                        warp::reply::json(&SignResponse {
+                            signatures: signatures.iter().map(hex::encode).collect(),
+                        }),
+                        StatusCode::OK,
+                    ),
+                    Err(e) => warp::reply::with_status(warp::reply::json(&e.to_string()), StatusCode::BAD_REQUEST),
+                }
+            });
+
+        let (address, server) = warp::serve(public_keys.or(sign)).bind_ephemeral(([127, 0, 0, 1], 0));
+
+        tokio::spawn(server);
+
+        address
+    }
+
+    fn essence(public_keys: Vec<PublicKey>) -> MilestonePayloadEssence {
+        MilestonePayloadEssence::new(
+            MilestoneIndex(1),
+            0,
+            Parents::new(vec![MessageId::null()]).unwrap(),
+            [0; 32],
+            0,
+            0,
+            public_keys,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn local_signer(seeds: &[u8]) -> LocalSigner {
+        LocalSigner::new(seeds.iter().map(|seed| SecretKey::from_bytes([*seed; 32])).collect()).unwrap()
+    }
+
+    async fn connect(url: &str) -> Result<RemoteSigner, SignerError> {
+        RemoteSigner::connect(url, Duration::from_secs(1), Duration::from_secs(5)).await
+    }
+
+    #[tokio::test]
+    async fn remote_signer_with_mock_service() {
+        let address = mock_service(local_signer(&[1, 2]));
+        let signer = connect(&format!("http://{}/", address)).await.unwrap();
+
+        let mut public_keys = signer.public_keys().to_vec();
+        public_keys.sort_unstable();
+
+        let essence = essence(public_keys.clone());
+        let signatures = signer.sign(&essence, &public_keys).await.unwrap();
+        let milestone = MilestonePayload::new(essence, signatures).unwrap();
+
+        assert!(milestone
+            .validate(&public_keys.iter().map(hex::encode).collect::<Vec<_>>(), 2)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn remote_signer_unknown_public_key() {
+        let address = mock_service(local_signer(&[1]));
+        let signer = connect(&format!("http://{}", address)).await.unwrap();
+        let public_keys = local_signer(&[2]).public_keys().to_vec();
+
+        assert!(matches!(
+            signer.sign(&essence(public_keys.clone()), &public_keys).await,
+            Err(SignerError::Request(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn remote_signer_request_timeout() {
+        // Connections are established by the OS, but the service never replies.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        assert!(matches!(
+            RemoteSigner::connect(&url, Duration::from_secs(1), Duration::from_millis(100)).await,
+            Err(SignerError::Request(e)) if e.is_timeout()
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::Path, str::FromStr};
+
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    MessageId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::coordinator::error::Error;
+
+/// The state of the coordinator, persisted after every issued milestone so that it can resume after a restart.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CoordinatorState {
+    latest_milestone_index: u32,
+    latest_milestone_message_id: String,
+    latest_milestone_timestamp: u64,
+}
+
+impl CoordinatorState {
+    pub(crate) fn new(index: MilestoneIndex, message_id: MessageId, timestamp: u64) -> Self {
+        Self {
+            latest_milestone_index: *index,
+            latest_milestone_message_id: message_id.to_string(),
+            latest_milestone_timestamp: timestamp,
+        }
+    }
+
+    /// Creates the state of a milestone found in the tangle.
+    pub(crate) fn from_milestone(index: MilestoneIndex, milestone: &Milestone) -> Self {
+        Self::new(index, *milestone.message_id(), milestone.timestamp())
+    }
+
+    /// Loads the state from `path`, returning `None` if the file does not exist.
+    pub(crate) fn from_file<P: AsRef<Path>>(path: P) -> Result<Option<Self>, Error> {
+        if !path.as_ref().exists() {
+            return Ok(None);
+        }
+
+        let state = serde_json::from_str::<Self>(&fs::read_to_string(path).map_err(Error::StateFile)?)
+            .map_err(Error::StateFileDeserialization)?;
+
+        // Makes sure the stored message id is valid before the state is used.
+        MessageId::from_str(&state.latest_milestone_message_id)
+            .map_err(|_| Error::InvalidStateMessageId(state.latest_milestone_message_id.clone()))?;
+
+        Ok(Some(state))
+    }
+
+    /// Stores the state to `path`, writing a temporary file first so that a crash never leaves a truncated state.
+    pub(crate) fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        // Serializing a struct of strings and integers can't fail.
+        let json = serde_json::to_string_pretty(self).expect("serializing the coordinator state failed");
+
+        fs::write(&tmp_path, json).map_err(Error::StateFile)?;
+        fs::rename(&tmp_path, path).map_err(Error::StateFile)
+    }
+
+    pub(crate) fn latest_milestone_index(&self) -> MilestoneIndex {
+        MilestoneIndex(self.latest_milestone_index)
+    }
+
+    pub(crate) fn latest_milestone_message_id(&self) -> MessageId {
+        // The message id is validated when the state is loaded or created.
+        MessageId::from_str(&self.latest_milestone_message_id).unwrap()
+    }
+
+    pub(crate) fn latest_milestone_timestamp(&self) -> u64 {
+        self.latest_milestone_timestamp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn state_file_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bee_coordinator_state_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn state_round_trips() {
+        let path = state_file_path("round_trip");
+        let state = CoordinatorState::new(MilestoneIndex(42), MessageId::new([7; 32]), 1_650_000_000);
+
+        state.to_file(&path).unwrap();
+        let loaded = CoordinatorState::from_file(&path).unwrap().unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.latest_milestone_index(), MilestoneIndex(42));
+        assert_eq!(loaded.latest_milestone_message_id(), MessageId::new([7; 32]));
+        assert_eq!(loaded.latest_milestone_timestamp(), 1_650_000_000);
+    }
+
+    #[test]
+    fn missing_state_file() {
+        assert!(CoordinatorState::from_file(state_file_path("missing"))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_state_message_id() {
+        let path = state_file_path("invalid_message_id");
+
+        fs::write(
+            &path,
+            r#"{"latestMilestoneIndex":1,"latestMilestoneMessageId":"00","latestMilestoneTimestamp":0}"#,
+        )
+        .unwrap();
+        let result = CoordinatorState::from_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(Error::InvalidStateMessageId(_))));
+    }
+
+    #[test]
+    fn resume_from_milestone() {
+        let state = CoordinatorState::from_milestone(MilestoneIndex(43), &Milestone::new(MessageId::new([8; 32]), 10));
+
+        assert_eq!(state.latest_milestone_index(), MilestoneIndex(43));
+        assert_eq!(state.latest_milestone_message_id(), MessageId::new([8; 32]));
+        assert_eq!(state.latest_milestone_timestamp(), 10);
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod coordinator;
 #[cfg(feature = "dashboard")]
 pub mod dashboard;
 pub mod mps;
@@ -14,7 +15,7 @@ use bee_runtime::{event::Bus, node::Node, worker::Worker};
 
 #[cfg(feature = "dashboard")]
 pub use self::dashboard::Dashboard;
-pub use self::{mps::Mps, mqtt::Mqtt, version_checker::VersionChecker};
+pub use self::{coordinator::Coordinator, mps::Mps, mqtt::Mqtt, version_checker::VersionChecker};
 
 #[async_trait]
 pub trait Plugin: Sized + Send + Sync + 'static {
@@ -3,10 +3,13 @@
 
 mod ed25519;
 mod password;
+#[cfg(any(feature = "rocksdb", feature = "sled"))]
+mod proof_bundle;
 #[cfg(feature = "rocksdb")]
 mod rocksdb;
 #[cfg(feature = "sled")]
 mod sled;
+mod snapshot_genesis;
 mod snapshot_info;
 
 use structopt::StructOpt;
@@ -25,8 +28,13 @@ pub enum Tool {
     Sled(sled::SledTool),
     /// Outputs information about a snapshot file.
     SnapshotInfo(snapshot_info::SnapshotInfoTool),
+    /// Generates a genesis full snapshot for a new network.
+    SnapshotGenesis(snapshot_genesis::SnapshotGenesisTool),
     /// Generates password salt and hash.
     Password(password::PasswordTool),
+    /// Exports and verifies self-contained proofs that messages were referenced by milestones.
+    #[cfg(any(feature = "rocksdb", feature = "sled"))]
+    ProofBundle(proof_bundle::ProofBundleTool),
 }
 
 #[derive(Debug, Error)]
@@ -42,7 +50,12 @@ pub enum ToolError {
     #[error("{0}")]
     SnapshotInfo(#[from] snapshot_info::SnapshotInfoError),
     #[error("{0}")]
+    SnapshotGenesis(#[from] snapshot_genesis::SnapshotGenesisError),
+    #[error("{0}")]
     Password(#[from] password::PasswordError),
+    #[cfg(any(feature = "rocksdb", feature = "sled"))]
+    #[error("{0}")]
+    ProofBundle(#[from] proof_bundle::ProofBundleError),
 }
 
 pub fn exec(tool: &Tool) -> Result<(), ToolError> {
@@ -53,7 +66,10 @@ pub fn exec(tool: &Tool) -> Result<(), ToolError> {
         #[cfg(feature = "sled")]
         Tool::Sled(tool) => sled::exec(tool)?,
         Tool::SnapshotInfo(tool) => snapshot_info::exec(tool)?,
+        Tool::SnapshotGenesis(tool) => snapshot_genesis::exec(tool)?,
         Tool::Password(tool) => password::exec(tool)?,
+        #[cfg(any(feature = "rocksdb", feature = "sled"))]
+        Tool::ProofBundle(tool) => proof_bundle::exec(tool)?,
     }
 
     Ok(())
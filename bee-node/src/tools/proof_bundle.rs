@@ -0,0 +1,766 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{self, OpenOptions},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use bee_common::packable::{Packable, Read, Write};
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    payload::{
+        milestone::{MilestoneValidationError, MILESTONE_PUBLIC_KEY_LENGTH},
+        Payload,
+    },
+    Message, MessageId, MESSAGE_LENGTH_MAX,
+};
+use bee_protocol::types::{milestone_key_manager::MilestoneKeyManager, milestone_key_range::MilestoneKeyRange};
+use bee_storage::{access::Fetch, backend::StorageBackend};
+#[cfg(feature = "rocksdb")]
+use bee_storage_rocksdb::storage::Storage;
+#[cfg(all(feature = "sled", not(feature = "rocksdb")))]
+use bee_storage_sled::storage::Storage;
+use bee_tangle::metadata::MessageMetadata;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use crate::config::{NodeConfigBuilder, NodeConfigError};
+
+#[derive(Debug, Error)]
+pub enum ProofBundleError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("Reading the node configuration failed: {0}")]
+    Config(#[from] NodeConfigError),
+    #[error("Storage backend error: {0}")]
+    StorageBackend(Box<dyn std::error::Error + Send>),
+    #[error("Invalid message: {0}")]
+    Message(#[from] bee_message::Error),
+    #[error("Invalid JSON proof bundle: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unknown proof bundle format {0}, expected `binary` or `json`")]
+    UnknownFormat(String),
+    #[error("Invalid message ID: {0}")]
+    InvalidMessageId(String),
+    #[error("Message {0} not found")]
+    MessageNotFound(MessageId),
+    #[error("Message {0} is not referenced by a milestone")]
+    MessageNotReferenced(MessageId),
+    #[error("Milestone {0} not found")]
+    MilestoneNotFound(MilestoneIndex),
+    #[error("No chain of parents from message {0} to milestone {1}")]
+    MissingParentsChain(MessageId, MilestoneIndex),
+    #[error("Invalid coordinator public key: {0}")]
+    InvalidPublicKey(String),
+    #[error("Broken parent link: message {0} is not a parent of message {1}")]
+    BrokenParentLink(MessageId, MessageId),
+    #[error("Message {0} does not hold a milestone payload")]
+    NoMilestonePayload(MessageId),
+    #[error("Invalid milestone: {0:?}")]
+    InvalidMilestone(MilestoneValidationError),
+    #[error(
+        "Invalid parents chain length {0}, at most {} messages are allowed",
+        PARENTS_CHAIN_LENGTH_MAX
+    )]
+    InvalidParentsChainLength(usize),
+    #[error(
+        "Invalid key range count {0}, at most {} key ranges are allowed",
+        KEY_RANGE_COUNT_MAX
+    )]
+    InvalidKeyRangeCount(usize),
+    #[error("No trusted coordinator key ranges, pass a node configuration with --config or --trust-bundle-keys")]
+    UntrustedKeyRanges,
+}
+
+/// The maximum number of messages between a message and its milestone in a proof bundle.
+const PARENTS_CHAIN_LENGTH_MAX: usize = 10_000;
+/// The maximum number of coordinator key ranges in a proof bundle.
+const KEY_RANGE_COUNT_MAX: usize = 256;
+
+fn storage_error<E: std::error::Error + Send + 'static>(error: E) -> ProofBundleError {
+    ProofBundleError::StorageBackend(Box::new(error))
+}
+
+/// The encoding of a proof bundle file.
+#[derive(Clone, Copy, Debug)]
+pub enum ProofBundleFormat {
+    /// `Packable` binary encoding.
+    Binary,
+    /// JSON encoding, messages being hex encoded packed bytes.
+    Json,
+}
+
+impl FromStr for ProofBundleFormat {
+    type Err = ProofBundleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(Self::Binary),
+            "json" => Ok(Self::Json),
+            _ => Err(ProofBundleError::UnknownFormat(s.to_owned())),
+        }
+    }
+}
+
+/// A self-contained proof that a message was referenced by a milestone.
+#[derive(Clone, Eq, PartialEq)]
+pub struct ProofBundle {
+    message: Message,
+    // Each message of the chain is a child of the previous one, the first one being a child of `message` and the
+    // milestone message being a child of the last one.
+    parents_chain: Vec<Message>,
+    milestone: Message,
+    min_threshold: usize,
+    key_ranges: Vec<MilestoneKeyRange>,
+}
+
+impl ProofBundle {
+    /// Verifies that the message of the `ProofBundle` was referenced by its milestone, signed by the coordinator keys
+    /// of the given key manager. Returns the ID of the message and the index of the milestone.
+    pub fn verify(&self, key_manager: &MilestoneKeyManager) -> Result<(MessageId, MilestoneIndex), ProofBundleError> {
+        let (message_id, _) = self.message.id();
+        let (milestone_id, _) = self.milestone.id();
+        let mut parent_id = message_id;
+
+        // A milestone message is referenced by its own milestone, without any parents chain.
+        if message_id != milestone_id || !self.parents_chain.is_empty() {
+            for child in self.parents_chain.iter().chain(std::iter::once(&self.milestone)) {
+                let (child_id, _) = child.id();
+
+                if !child.parents().iter().any(|parent| *parent == parent_id) {
+                    return Err(ProofBundleError::BrokenParentLink(parent_id, child_id));
+                }
+
+                parent_id = child_id;
+            }
+        }
+
+        let milestone = match self.milestone.payload() {
+            Some(Payload::Milestone(milestone)) => milestone,
+            _ => return Err(ProofBundleError::NoMilestonePayload(parent_id)),
+        };
+        let index = milestone.essence().index();
+
+        milestone
+            .validate(
+                &key_manager.get_public_keys(index).into_iter().collect::<Vec<_>>(),
+                key_manager.min_threshold(),
+            )
+            .map_err(ProofBundleError::InvalidMilestone)?;
+
+        Ok((message_id, index))
+    }
+
+    /// Returns a key manager built from the coordinator key ranges of the `ProofBundle`.
+    pub fn key_manager(&self) -> MilestoneKeyManager {
+        MilestoneKeyManager::new(self.min_threshold, self.key_ranges.clone().into_boxed_slice())
+    }
+}
+
+fn decode_public_key(public_key: &str) -> Result<[u8; MILESTONE_PUBLIC_KEY_LENGTH], ProofBundleError> {
+    hex::decode(public_key)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ProofBundleError::InvalidPublicKey(public_key.to_owned()))
+}
+
+// Messages check that they are not followed by any remaining bytes when unpacked, so they are length-prefixed.
+fn pack_message<W: Write>(message: &Message, writer: &mut W) -> Result<(), ProofBundleError> {
+    let bytes = message.pack_new();
+
+    (bytes.len() as u32).pack(writer)?;
+    writer.write_all(&bytes)?;
+
+    Ok(())
+}
+
+// Proof bundles are not trusted, so lengths and counts are bounded before allocating, whatever `CHECK` is.
+fn unpack_message<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Message, ProofBundleError> {
+    let len = u32::unpack_inner::<R, CHECK>(reader)? as usize;
+
+    if len > MESSAGE_LENGTH_MAX {
+        return Err(ProofBundleError::Message(bee_message::Error::InvalidMessageLength(len)));
+    }
+
+    let mut bytes = vec![0u8; len];
+
+    reader.read_exact(&mut bytes)?;
+
+    Ok(Message::unpack_inner::<_, CHECK>(&mut bytes.as_slice())?)
+}
+
+impl Packable for ProofBundle {
+    type Error = ProofBundleError;
+
+    fn packed_len(&self) -> usize {
+        std::iter::once(&self.message)
+            .chain(self.parents_chain.iter())
+            .chain(std::iter::once(&self.milestone))
+            .map(|message| 0u32.packed_len() + message.packed_len())
+            .sum::<usize>()
+            + 0u32.packed_len()
+            + 0u32.packed_len()
+            + 0u32.packed_len()
+            + self.key_ranges.len() * (MILESTONE_PUBLIC_KEY_LENGTH + 2 * 0u32.packed_len())
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        pack_message(&self.message, writer)?;
+        (self.parents_chain.len() as u32).pack(writer)?;
+        for message in self.parents_chain.iter() {
+            pack_message(message, writer)?;
+        }
+        pack_message(&self.milestone, writer)?;
+        (self.min_threshold as u32).pack(writer)?;
+        (self.key_ranges.len() as u32).pack(writer)?;
+        for key_range in self.key_ranges.iter() {
+            decode_public_key(key_range.public_key())?.pack(writer)?;
+            key_range.start().pack(writer)?;
+            key_range.end().pack(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        let message = unpack_message::<R, CHECK>(reader)?;
+        let parents_chain_len = u32::unpack_inner::<R, CHECK>(reader)? as usize;
+        if parents_chain_len > PARENTS_CHAIN_LENGTH_MAX {
+            return Err(ProofBundleError::InvalidParentsChainLength(parents_chain_len));
+        }
+        let mut parents_chain = Vec::with_capacity(parents_chain_len);
+        for _ in 0..parents_chain_len {
+            parents_chain.push(unpack_message::<R, CHECK>(reader)?);
+        }
+        let milestone = unpack_message::<R, CHECK>(reader)?;
+        let min_threshold = u32::unpack_inner::<R, CHECK>(reader)? as usize;
+        let key_ranges_len = u32::unpack_inner::<R, CHECK>(reader)? as usize;
+        if key_ranges_len > KEY_RANGE_COUNT_MAX {
+            return Err(ProofBundleError::InvalidKeyRangeCount(key_ranges_len));
+        }
+        let mut key_ranges = Vec::with_capacity(key_ranges_len);
+        for _ in 0..key_ranges_len {
+            let public_key = <[u8; MILESTONE_PUBLIC_KEY_LENGTH]>::unpack_inner::<R, CHECK>(reader)?;
+            let start = MilestoneIndex::unpack_inner::<R, CHECK>(reader)?;
+            let end = MilestoneIndex::unpack_inner::<R, CHECK>(reader)?;
+            key_ranges.push(MilestoneKeyRange::new(hex::encode(public_key), start, end));
+        }
+
+        Ok(Self {
+            message,
+            parents_chain,
+            milestone,
+            min_threshold,
+            key_ranges,
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyRangeJson {
+    public_key: String,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProofBundleJson {
+    message: String,
+    parents_chain: Vec<String>,
+    milestone: String,
+    min_threshold: usize,
+    key_ranges: Vec<KeyRangeJson>,
+}
+
+fn decode_message(message: &str) -> Result<Message, ProofBundleError> {
+    let bytes = hex::decode(message)
+        .map_err(|_| ProofBundleError::Message(bee_message::Error::InvalidHexadecimalChar(message.to_owned())))?;
+
+    Ok(Message::unpack(&mut bytes.as_slice())?)
+}
+
+impl From<&ProofBundle> for ProofBundleJson {
+    fn from(bundle: &ProofBundle) -> Self {
+        Self {
+            message: hex::encode(bundle.message.pack_new()),
+            parents_chain: bundle
+                .parents_chain
+                .iter()
+                .map(|message| hex::encode(message.pack_new()))
+                .collect(),
+            milestone: hex::encode(bundle.milestone.pack_new()),
+            min_threshold: bundle.min_threshold,
+            key_ranges: bundle
+                .key_ranges
+                .iter()
+                .map(|key_range| KeyRangeJson {
+                    public_key: key_range.public_key().clone(),
+                    start: *key_range.start(),
+                    end: *key_range.end(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&ProofBundleJson> for ProofBundle {
+    type Error = ProofBundleError;
+
+    fn try_from(bundle: &ProofBundleJson) -> Result<Self, Self::Error> {
+        if bundle.parents_chain.len() > PARENTS_CHAIN_LENGTH_MAX {
+            return Err(ProofBundleError::InvalidParentsChainLength(bundle.parents_chain.len()));
+        }
+        if bundle.key_ranges.len() > KEY_RANGE_COUNT_MAX {
+            return Err(ProofBundleError::InvalidKeyRangeCount(bundle.key_ranges.len()));
+        }
+
+        Ok(Self {
+            message: decode_message(&bundle.message)?,
+            parents_chain: bundle
+                .parents_chain
+                .iter()
+                .map(|message| decode_message(message))
+                .collect::<Result<_, _>>()?,
+            milestone: decode_message(&bundle.milestone)?,
+            min_threshold: bundle.min_threshold,
+            key_ranges: bundle
+                .key_ranges
+                .iter()
+                .map(|key_range| {
+                    decode_public_key(&key_range.public_key)?;
+                    Ok(MilestoneKeyRange::new(
+                        key_range.public_key.clone(),
+                        MilestoneIndex(key_range.start),
+                        MilestoneIndex(key_range.end),
+                    ))
+                })
+                .collect::<Result<_, ProofBundleError>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub enum ProofBundleCommand {
+    /// Exports the proof bundle of a message from the database of a node.
+    Export {
+        /// ID of the message to prove.
+        message_id: String,
+        /// Path of the node configuration file, locating the database and the coordinator key ranges.
+        #[structopt(long, default_value = "./config.json")]
+        config: PathBuf,
+        /// Path of the proof bundle file to create.
+        #[structopt(long, short = "o", default_value = "./proof_bundle.bin")]
+        output_path: PathBuf,
+        /// Encoding of the proof bundle file, `binary` or `json`.
+        #[structopt(long, default_value = "binary")]
+        format: ProofBundleFormat,
+    },
+    /// Verifies a proof bundle, without any database.
+    Verify {
+        /// Path of the proof bundle file.
+        path: PathBuf,
+        /// Path of a node configuration file whose coordinator key ranges are trusted.
+        #[structopt(long, conflicts_with = "trust-bundle-keys")]
+        config: Option<PathBuf>,
+        /// Trusts the coordinator key ranges of the bundle itself, which only proves that the bundle is consistent.
+        #[structopt(long)]
+        trust_bundle_keys: bool,
+        /// Encoding of the proof bundle file, `binary` or `json`.
+        #[structopt(long, default_value = "binary")]
+        format: ProofBundleFormat,
+    },
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct ProofBundleTool {
+    #[structopt(subcommand)]
+    command: ProofBundleCommand,
+}
+
+fn fetch_message<B: Fetch<MessageId, Message>>(
+    storage: &B,
+    message_id: &MessageId,
+) -> Result<Message, ProofBundleError> {
+    Fetch::<MessageId, Message>::fetch(storage, message_id)
+        .map_err(storage_error)?
+        .ok_or(ProofBundleError::MessageNotFound(*message_id))
+}
+
+/// Builds the proof bundle of a message from a node database.
+pub fn build_proof_bundle<B>(
+    storage: &B,
+    message_id: &MessageId,
+    min_threshold: usize,
+    key_ranges: Vec<MilestoneKeyRange>,
+) -> Result<ProofBundle, ProofBundleError>
+where
+    B: Fetch<MessageId, Message> + Fetch<MessageId, MessageMetadata> + Fetch<MilestoneIndex, Milestone>,
+{
+    let message = fetch_message(storage, message_id)?;
+    let index = Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+        .map_err(storage_error)?
+        .and_then(|metadata| metadata.milestone_index())
+        .ok_or(ProofBundleError::MessageNotReferenced(*message_id))?;
+    let milestone_id = *Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .map_err(storage_error)?
+        .ok_or(ProofBundleError::MilestoneNotFound(index))?
+        .message_id();
+    let milestone = fetch_message(storage, &milestone_id)?;
+
+    // A milestone message is referenced by its own milestone, without any parents chain.
+    if milestone_id == *message_id {
+        return Ok(ProofBundle {
+            message,
+            parents_chain: Vec::new(),
+            milestone,
+            min_threshold,
+            key_ranges,
+        });
+    }
+
+    // Breadth-first search from the milestone down to the message. Only messages referenced by the same milestone can
+    // link them, otherwise the message would have been referenced by an earlier milestone.
+    let mut children = HashMap::new();
+    let mut queue = VecDeque::from([(milestone_id, milestone.clone())]);
+    let mut found = false;
+
+    while let Some((child_id, child)) = queue.pop_front() {
+        if found {
+            break;
+        }
+
+        for parent_id in child.parents().iter() {
+            if children.contains_key(parent_id) {
+                continue;
+            }
+
+            let referenced = Fetch::<MessageId, MessageMetadata>::fetch(storage, parent_id)
+                .map_err(storage_error)?
+                .and_then(|metadata| metadata.milestone_index())
+                == Some(index);
+
+            if !referenced {
+                continue;
+            }
+
+            children.insert(*parent_id, child_id);
+
+            if parent_id == message_id {
+                found = true;
+                break;
+            }
+
+            queue.push_back((*parent_id, fetch_message(storage, parent_id)?));
+        }
+    }
+
+    if !found {
+        return Err(ProofBundleError::MissingParentsChain(*message_id, index));
+    }
+
+    let mut parents_chain = Vec::new();
+    let mut current = *message_id;
+
+    while let Some(child_id) = children.get(&current) {
+        if *child_id == milestone_id {
+            break;
+        }
+        parents_chain.push(fetch_message(storage, child_id)?);
+        current = *child_id;
+    }
+
+    Ok(ProofBundle {
+        message,
+        parents_chain,
+        milestone,
+        min_threshold,
+        key_ranges,
+    })
+}
+
+fn export(
+    message_id: &str,
+    config: &Path,
+    output_path: &Path,
+    format: ProofBundleFormat,
+) -> Result<(), ProofBundleError> {
+    let message_id =
+        MessageId::from_str(message_id).map_err(|_| ProofBundleError::InvalidMessageId(message_id.to_owned()))?;
+    let (_, config) = NodeConfigBuilder::<Storage>::from_file(config)?.finish();
+    let storage = Storage::start(config.storage.clone()).map_err(storage_error)?;
+    let bundle = build_proof_bundle(
+        &storage,
+        &message_id,
+        config.protocol.coordinator().public_key_count(),
+        config.protocol.coordinator().public_key_ranges().to_vec(),
+    );
+
+    storage.shutdown().map_err(storage_error)?;
+
+    let bundle = bundle?;
+    let mut writer = BufWriter::new(OpenOptions::new().write(true).create_new(true).open(output_path)?);
+
+    match format {
+        ProofBundleFormat::Binary => bundle.pack(&mut writer)?,
+        ProofBundleFormat::Json => serde_json::to_writer_pretty(&mut writer, &ProofBundleJson::from(&bundle))?,
+    }
+    writer.flush()?;
+
+    println!(
+        "Exported the proof bundle of message {} ({} messages to the milestone) to {}.",
+        message_id,
+        bundle.parents_chain.len() + 1,
+        output_path.display()
+    );
+
+    Ok(())
+}
+
+fn trusted_key_manager(
+    bundle: &ProofBundle,
+    config: Option<&Path>,
+    trust_bundle_keys: bool,
+) -> Result<MilestoneKeyManager, ProofBundleError> {
+    match config {
+        Some(config) => {
+            let (_, config) = NodeConfigBuilder::<Storage>::from_file(config)?.finish();
+
+            Ok(MilestoneKeyManager::new(
+                config.protocol.coordinator().public_key_count(),
+                config.protocol.coordinator().public_key_ranges().into(),
+            ))
+        }
+        None if trust_bundle_keys => {
+            println!("Warning: trusting the coordinator key ranges of the proof bundle itself.");
+            Ok(bundle.key_manager())
+        }
+        None => Err(ProofBundleError::UntrustedKeyRanges),
+    }
+}
+
+fn verify(
+    path: &Path,
+    config: Option<&Path>,
+    trust_bundle_keys: bool,
+    format: ProofBundleFormat,
+) -> Result<(), ProofBundleError> {
+    let bundle = match format {
+        ProofBundleFormat::Binary => {
+            ProofBundle::unpack(&mut BufReader::new(OpenOptions::new().read(true).open(path)?))?
+        }
+        ProofBundleFormat::Json => {
+            ProofBundle::try_from(&serde_json::from_str::<ProofBundleJson>(&fs::read_to_string(path)?)?)?
+        }
+    };
+    let key_manager = trusted_key_manager(&bundle, config, trust_bundle_keys)?;
+    let (message_id, index) = bundle.verify(&key_manager)?;
+
+    println!("Message {} is referenced by milestone {}.", message_id, *index);
+
+    Ok(())
+}
+
+pub fn exec(tool: &ProofBundleTool) -> Result<(), ProofBundleError> {
+    match &tool.command {
+        ProofBundleCommand::Export {
+            message_id,
+            config,
+            output_path,
+            format,
+        } => export(message_id, config, output_path, *format),
+        ProofBundleCommand::Verify {
+            path,
+            config,
+            trust_bundle_keys,
+            format,
+        } => verify(path, config.as_deref(), *trust_bundle_keys, *format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_message::{
+        parents::Parents,
+        payload::milestone::{MilestonePayload, MilestonePayloadEssence},
+        MessageBuilder,
+    };
+    use bee_storage::access::{Insert, InsertStrict};
+    use crypto::signatures::ed25519::SecretKey;
+
+    use super::*;
+
+    fn build_message(parents: Vec<MessageId>, payload: Option<Payload>) -> Message {
+        let mut builder = MessageBuilder::<u64>::new()
+            .with_network_id(0)
+            .with_parents(Parents::new(parents).unwrap())
+            .with_nonce_provider(0, 0f64);
+
+        if let Some(payload) = payload {
+            builder = builder.with_payload(payload);
+        }
+
+        builder.finish().unwrap()
+    }
+
+    fn build_milestone(parents: Vec<MessageId>) -> Message {
+        let key = SecretKey::from_bytes([1; 32]);
+        let essence = MilestonePayloadEssence::new(
+            MilestoneIndex(1),
+            0,
+            Parents::new(parents.clone()).unwrap(),
+            [0; 32],
+            0,
+            0,
+            vec![key.public_key().to_bytes()],
+            None,
+        )
+        .unwrap();
+        let signature = key.sign(&essence.hash()).to_bytes();
+
+        build_message(
+            parents,
+            Some(Payload::Milestone(Box::new(
+                MilestonePayload::new(essence, vec![signature]).unwrap(),
+            ))),
+        )
+    }
+
+    fn key_ranges() -> Vec<MilestoneKeyRange> {
+        vec![MilestoneKeyRange::new(
+            hex::encode(SecretKey::from_bytes([1; 32]).public_key().to_bytes()),
+            MilestoneIndex(0),
+            MilestoneIndex(0),
+        )]
+    }
+
+    fn bundle() -> (ProofBundle, MessageId) {
+        let message = build_message(vec![MessageId::null()], None);
+        let message_id = message.id().0;
+        let child = build_message(vec![message_id], None);
+        let milestone = build_milestone(vec![child.id().0]);
+
+        (
+            ProofBundle {
+                message,
+                parents_chain: vec![child],
+                milestone,
+                min_threshold: 1,
+                key_ranges: key_ranges(),
+            },
+            message_id,
+        )
+    }
+
+    #[test]
+    fn proof_bundle_round_trips() {
+        let (bundle, _) = bundle();
+
+        let bytes = bundle.pack_new();
+
+        assert_eq!(bytes.len(), bundle.packed_len());
+        assert!(ProofBundle::unpack(&mut bytes.as_slice()).unwrap() == bundle);
+        assert!(ProofBundle::try_from(&ProofBundleJson::from(&bundle)).unwrap() == bundle);
+    }
+
+    #[test]
+    fn proof_bundle_verifies() {
+        let (bundle, message_id) = bundle();
+
+        assert!(matches!(
+            bundle.verify(&bundle.key_manager()),
+            Ok((id, MilestoneIndex(1))) if id == message_id
+        ));
+    }
+
+    #[test]
+    fn proof_bundle_broken_parent_link() {
+        let (mut bundle, message_id) = bundle();
+
+        bundle.parents_chain.clear();
+
+        assert!(matches!(
+            bundle.verify(&bundle.key_manager()),
+            Err(ProofBundleError::BrokenParentLink(id, _)) if id == message_id
+        ));
+    }
+
+    #[test]
+    fn proof_bundle_of_milestone() {
+        let storage = bee_storage_memory::storage::Storage::start(()).unwrap();
+        let milestone = build_milestone(vec![MessageId::null()]);
+        let milestone_id = milestone.id().0;
+        let mut metadata = MessageMetadata::arrived();
+
+        metadata.set_milestone_index(MilestoneIndex(1));
+
+        Insert::<MessageId, Message>::insert(&storage, &milestone_id, &milestone).unwrap();
+        InsertStrict::<MessageId, MessageMetadata>::insert_strict(&storage, &milestone_id, &metadata).unwrap();
+        Insert::<MilestoneIndex, Milestone>::insert(&storage, &MilestoneIndex(1), &Milestone::new(milestone_id, 0))
+            .unwrap();
+
+        let bundle = build_proof_bundle(&storage, &milestone_id, 1, key_ranges()).unwrap();
+
+        assert!(bundle.parents_chain.is_empty());
+        assert!(matches!(
+            bundle.verify(&bundle.key_manager()),
+            Ok((id, MilestoneIndex(1))) if id == milestone_id
+        ));
+    }
+
+    #[test]
+    fn proof_bundle_untrusted_keys() {
+        let (bundle, _) = bundle();
+        let key_manager = MilestoneKeyManager::new(
+            1,
+            vec![MilestoneKeyRange::new(
+                hex::encode(SecretKey::from_bytes([2; 32]).public_key().to_bytes()),
+                MilestoneIndex(0),
+                MilestoneIndex(0),
+            )]
+            .into_boxed_slice(),
+        );
+
+        assert!(matches!(
+            bundle.verify(&key_manager),
+            Err(ProofBundleError::InvalidMilestone(_))
+        ));
+    }
+
+    #[test]
+    fn proof_bundle_requires_trusted_keys() {
+        let (bundle, _) = bundle();
+
+        assert!(matches!(
+            trusted_key_manager(&bundle, None, false),
+            Err(ProofBundleError::UntrustedKeyRanges)
+        ));
+        assert!(trusted_key_manager(&bundle, None, true).is_ok());
+    }
+
+    #[test]
+    fn proof_bundle_bounded_lengths() {
+        let (bundle, _) = bundle();
+        let message_len = bundle.message.packed_len() as u32;
+
+        assert!(matches!(
+            ProofBundle::unpack(&mut [u32::MAX.to_le_bytes(), [0; 4], [0; 4]].concat().as_slice()),
+            Err(ProofBundleError::Message(bee_message::Error::InvalidMessageLength(_)))
+        ));
+
+        let mut bytes = message_len.to_le_bytes().to_vec();
+        bytes.extend(bundle.message.pack_new());
+        bytes.extend(u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            ProofBundle::unpack(&mut bytes.as_slice()),
+            Err(ProofBundleError::InvalidParentsChainLength(len)) if len == u32::MAX as usize
+        ));
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{
+    snapshot::{FullSnapshotHeader, SnapshotHeader, SnapshotKind},
+    Error as SnapshotError,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    constants::IOTA_SUPPLY,
+    milestone::MilestoneIndex,
+    output::{Output, OutputId, SignatureLockedSingleOutput, DUST_THRESHOLD},
+    payload::{
+        milestone::{MilestoneId, MILESTONE_ID_LENGTH},
+        transaction::TransactionId,
+    },
+    MessageId,
+};
+use bee_tangle::solid_entry_point::SolidEntryPoint;
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use crate::util::create_id_from_network_name;
+
+#[derive(Debug, Error)]
+pub enum SnapshotGenesisError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid allocation {0}, expected `address:amount`")]
+    InvalidAllocation(String),
+    #[error("Network ID {0} does not match the ID {1} of the network name")]
+    NetworkIdMismatch(u64, u64),
+    #[error("Allocation of {1} to {0:?} is below the dust threshold")]
+    DustAllocation(Address, u64),
+    #[error("Allocations and treasury add up to {0} instead of the total supply")]
+    InvalidSupply(u128),
+    #[error("Invalid output: {0}")]
+    InvalidOutput(#[from] bee_message::Error),
+    #[error("Writing the snapshot failed: {0}")]
+    Snapshot(#[from] SnapshotError),
+}
+
+/// An amount allocated to an address in the genesis ledger.
+#[derive(Clone, Debug)]
+pub struct GenesisAllocation {
+    address: Address,
+    amount: u64,
+}
+
+impl FromStr for GenesisAllocation {
+    type Err = SnapshotGenesisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SnapshotGenesisError::InvalidAllocation(s.to_owned());
+        let (address, amount) = s.rsplit_once(':').ok_or_else(invalid)?;
+        let address = address.trim();
+        // Addresses are either bech32 encoded or hex encoded Ed25519 addresses.
+        let address = Address::try_from_bech32(address)
+            .or_else(|_| Ed25519Address::from_str(address).map(Address::Ed25519))
+            .map_err(|_| invalid())?;
+        let amount = amount.trim().parse().map_err(|_| invalid())?;
+
+        Ok(Self { address, amount })
+    }
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct SnapshotGenesisTool {
+    /// Name of the network.
+    #[structopt(long)]
+    network_name: String,
+    /// ID of the network, checked against the ID derived from the network name if given.
+    #[structopt(long)]
+    network_id: Option<u64>,
+    /// Allocation of an amount to a bech32 or hex encoded Ed25519 address, as `address:amount`.
+    #[structopt(long = "allocation", short = "a")]
+    allocations: Vec<GenesisAllocation>,
+    /// Amount held by the treasury.
+    #[structopt(long, default_value = "0")]
+    treasury: u64,
+    /// Path of the full snapshot file to create.
+    #[structopt(long, short = "o", default_value = "./genesis_snapshot.bin")]
+    output_path: PathBuf,
+}
+
+// Genesis outputs are not created by any transaction, so each one gets a unique transaction id derived from its
+// position in the allocations.
+fn genesis_output_id(position: u64) -> OutputId {
+    // The output index is always valid.
+    OutputId::new(
+        TransactionId::new(Blake2b256::digest(&position.to_le_bytes()).into()),
+        0,
+    )
+    .unwrap()
+}
+
+pub fn exec(tool: &SnapshotGenesisTool) -> Result<(), SnapshotGenesisError> {
+    let network_id = create_id_from_network_name(&tool.network_name);
+
+    if let Some(expected_network_id) = tool.network_id {
+        if expected_network_id != network_id {
+            return Err(SnapshotGenesisError::NetworkIdMismatch(expected_network_id, network_id));
+        }
+    }
+
+    let mut supply = u128::from(tool.treasury);
+    let mut outputs = Vec::with_capacity(tool.allocations.len());
+
+    for allocation in tool.allocations.iter() {
+        // Dust outputs would require a dust allowance output that a genesis ledger can't hold.
+        if allocation.amount < DUST_THRESHOLD {
+            return Err(SnapshotGenesisError::DustAllocation(
+                allocation.address,
+                allocation.amount,
+            ));
+        }
+        supply += u128::from(allocation.amount);
+        outputs.push(Output::SignatureLockedSingle(SignatureLockedSingleOutput::new(
+            allocation.address,
+            allocation.amount,
+        )?));
+    }
+
+    if supply != u128::from(IOTA_SUPPLY) {
+        return Err(SnapshotGenesisError::InvalidSupply(supply));
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_secs();
+    // The null message is the only solid entry point, the first milestone will reference it.
+    let seps = [SolidEntryPoint::null()];
+    let header = SnapshotHeader::new(
+        SnapshotKind::Full,
+        timestamp,
+        network_id,
+        MilestoneIndex(0),
+        MilestoneIndex(0),
+    );
+    let full_header = FullSnapshotHeader::new(
+        seps.len() as u64,
+        outputs.len() as u64,
+        0,
+        MilestoneId::new([0; MILESTONE_ID_LENGTH]),
+        tool.treasury,
+    );
+
+    let mut writer = BufWriter::new(
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tool.output_path)?,
+    );
+
+    header.pack(&mut writer)?;
+    full_header.pack(&mut writer)?;
+
+    for sep in seps.iter() {
+        sep.pack(&mut writer)?;
+    }
+
+    for (position, output) in outputs.iter().enumerate() {
+        MessageId::null().pack(&mut writer)?;
+        genesis_output_id(position as u64).pack(&mut writer)?;
+        output.pack(&mut writer)?;
+    }
+
+    writer.flush()?;
+
+    println!("Network ID:\t\t\t{}", network_id);
+    println!("Outputs count:\t\t\t{}", outputs.len());
+    println!("Treasury output amount:\t\t{}", tool.treasury);
+    println!("Snapshot written to {}.", tool.output_path.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_ledger::workers::{
+        snapshot::{config::SnapshotConfig, import_snapshots},
+        storage::{fetch_balance_with_ledger_index, fetch_unspent_treasury_output},
+    };
+    use bee_storage::backend::StorageBackend;
+    #[cfg(feature = "rocksdb")]
+    use bee_storage_rocksdb::{config::RocksDbConfigBuilder as StorageConfigBuilder, storage::Storage};
+    #[cfg(all(feature = "sled", not(feature = "rocksdb")))]
+    use bee_storage_sled::{config::SledConfigBuilder as StorageConfigBuilder, storage::Storage};
+
+    use super::*;
+
+    const HEX_ADDRESS: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bee_snapshot_genesis_{}_{}", name, std::process::id()))
+    }
+
+    fn allocation(seed: u8, amount: u64) -> GenesisAllocation {
+        GenesisAllocation {
+            address: Address::Ed25519(Ed25519Address::new([seed; 32])),
+            amount,
+        }
+    }
+
+    fn tool(allocations: Vec<GenesisAllocation>, treasury: u64, output_path: PathBuf) -> SnapshotGenesisTool {
+        SnapshotGenesisTool {
+            network_name: "private-tangle".to_owned(),
+            network_id: None,
+            allocations,
+            treasury,
+            output_path,
+        }
+    }
+
+    #[test]
+    fn allocation_from_bech32_address() {
+        let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let allocation = GenesisAllocation::from_str(&format!("{}:1000000", address.to_bech32("atoi"))).unwrap();
+
+        assert_eq!(allocation.address, address);
+        assert_eq!(allocation.amount, 1_000_000);
+    }
+
+    #[test]
+    fn allocation_from_hex_address() {
+        let allocation = GenesisAllocation::from_str(&format!(" {} : 42 ", HEX_ADDRESS)).unwrap();
+
+        assert_eq!(
+            allocation.address,
+            Address::Ed25519(Ed25519Address::from_str(HEX_ADDRESS).unwrap())
+        );
+        assert_eq!(allocation.amount, 42);
+    }
+
+    #[test]
+    fn malformed_allocations() {
+        for allocation in [
+            HEX_ADDRESS.to_owned(),
+            format!("{}:", HEX_ADDRESS),
+            format!("{}:-1", HEX_ADDRESS),
+            format!("{}:1.5", HEX_ADDRESS),
+            format!("{}:1000000", &HEX_ADDRESS[2..]),
+            "atoi1invalid:1000000".to_owned(),
+            ":1000000".to_owned(),
+        ] {
+            assert!(
+                matches!(
+                    GenesisAllocation::from_str(&allocation),
+                    Err(SnapshotGenesisError::InvalidAllocation(a)) if a == allocation
+                ),
+                "{}",
+                allocation
+            );
+        }
+    }
+
+    #[test]
+    fn supply_mismatch() {
+        let path = temp_path("supply_mismatch");
+
+        assert!(matches!(
+            exec(&tool(vec![allocation(1, IOTA_SUPPLY - 1)], 0, path.clone())),
+            Err(SnapshotGenesisError::InvalidSupply(supply)) if supply == u128::from(IOTA_SUPPLY - 1)
+        ));
+        assert!(matches!(
+            exec(&tool(vec![allocation(1, IOTA_SUPPLY)], 1, path.clone())),
+            Err(SnapshotGenesisError::InvalidSupply(supply)) if supply == u128::from(IOTA_SUPPLY) + 1
+        ));
+        // Nothing is written for an invalid ledger.
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn dust_allocation() {
+        let path = temp_path("dust_allocation");
+        let dust = allocation(2, DUST_THRESHOLD - 1);
+
+        assert!(matches!(
+            exec(&tool(vec![allocation(1, IOTA_SUPPLY - dust.amount), dust.clone()], 0, path.clone())),
+            Err(SnapshotGenesisError::DustAllocation(address, amount))
+                if address == dust.address && amount == dust.amount
+        ));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn network_id_mismatch() {
+        let mut tool = tool(vec![allocation(1, IOTA_SUPPLY)], 0, temp_path("network_id_mismatch"));
+        tool.network_id = Some(0);
+
+        assert!(matches!(
+            exec(&tool),
+            Err(SnapshotGenesisError::NetworkIdMismatch(0, id)) if id == create_id_from_network_name(&tool.network_name)
+        ));
+    }
+
+    #[tokio::test]
+    async fn import_genesis_snapshot() {
+        let snapshot_path = temp_path("import.bin");
+        let storage_path = temp_path("import_storage");
+        let treasury = 1_000_000_000;
+        let allocations = vec![
+            allocation(1, IOTA_SUPPLY - treasury - DUST_THRESHOLD),
+            allocation(2, DUST_THRESHOLD),
+        ];
+        let tool = tool(allocations.clone(), treasury, snapshot_path.clone());
+
+        exec(&tool).unwrap();
+
+        let storage = Storage::start(
+            StorageConfigBuilder::default()
+                .with_path(storage_path.to_string_lossy().into_owned())
+                .finish(),
+        )
+        .unwrap();
+        let result = import_snapshots(
+            &storage,
+            create_id_from_network_name(&tool.network_name),
+            &SnapshotConfig::build().full_path(snapshot_path.clone()).finish(),
+        )
+        .await;
+
+        std::fs::remove_file(&snapshot_path).unwrap();
+        result.unwrap();
+
+        for allocation in allocations {
+            let (balance, ledger_index) = fetch_balance_with_ledger_index(&storage, &allocation.address).unwrap();
+
+            assert_eq!(balance.unwrap().amount(), allocation.amount);
+            assert_eq!(ledger_index, MilestoneIndex(0).into());
+        }
+        assert_eq!(
+            fetch_unspent_treasury_output(&storage).unwrap().inner().amount(),
+            treasury
+        );
+
+        storage.shutdown().unwrap();
+        std::fs::remove_dir_all(&storage_path).unwrap();
+    }
+}
@@ -11,15 +11,10 @@ use std::{
     thread,
 };
 
+#[allow(deprecated)]
+use bee_crypto::ternary::sponge::{BatchHasher, CurlPRounds};
 use bee_ternary::{b1t6, Btrit, T1B1Buf, TritBuf};
-use crypto::hashes::{
-    blake2b::Blake2b256,
-    ternary::{
-        curl_p::{CurlPBatchHasher, BATCH_SIZE},
-        HASH_LENGTH,
-    },
-    Digest,
-};
+use crypto::hashes::{blake2b::Blake2b256, ternary::HASH_LENGTH, Digest};
 use thiserror::Error;
 
 use crate::providers::{NonceProvider, NonceProviderBuilder};
@@ -106,6 +101,7 @@ pub struct Miner {
 }
 
 impl Miner {
+    #[allow(deprecated)]
     fn worker(
         cancel: MinerCancel,
         pow_digest: TritBuf<T1B1Buf>,
@@ -113,10 +109,12 @@ impl Miner {
         target_zeros: usize,
     ) -> Result<u64, Error> {
         let mut nonce = start_nonce;
-        let mut hasher = CurlPBatchHasher::<T1B1Buf>::new(HASH_LENGTH);
-        let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(BATCH_SIZE);
+        // Uses the widest batched `CurlP` supported by the CPU.
+        let mut hasher = BatchHasher::<T1B1Buf>::new(HASH_LENGTH, CurlPRounds::Rounds81);
+        let batch_size = hasher.batch_size();
+        let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(batch_size);
 
-        for _ in 0..BATCH_SIZE {
+        for _ in 0..batch_size {
             let mut buffer = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
             buffer[..pow_digest.len()].copy_from(&pow_digest);
             buffers.push(buffer);
@@ -129,7 +127,7 @@ impl Miner {
                 hasher.add(buffer.clone());
             }
 
-            for (i, hash) in hasher.hash().enumerate() {
+            for (i, hash) in hasher.hash_batched().enumerate() {
                 let trailing_zeros = hash.iter().rev().take_while(|t| *t == Btrit::Zero).count();
 
                 if trailing_zeros >= target_zeros {
@@ -138,7 +136,7 @@ impl Miner {
                 }
             }
 
-            nonce += BATCH_SIZE as u64;
+            nonce += batch_size as u64;
         }
 
         Err(Error::Cancelled)
@@ -20,6 +20,10 @@ pub struct PeerMetrics {
     messages_sent: AtomicU64,
     message_requests_sent: AtomicU64,
     heartbeats_sent: AtomicU64,
+    rate_limited_packets: AtomicU64,
+    unsolicited_messages: AtomicU64,
+    inbound_gossip_overflows: AtomicU64,
+    outbound_gossip_overflows: AtomicU64,
 }
 
 impl PeerMetrics {
@@ -142,6 +146,46 @@ impl PeerMetrics {
     pub fn heartbeats_sent_inc(&self) -> u64 {
         self.heartbeats_sent.fetch_add(1, Ordering::SeqCst)
     }
+
+    /// Returns the number of rate limited packets of the `PeerMetrics`.
+    pub fn rate_limited_packets(&self) -> u64 {
+        self.rate_limited_packets.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of rate limited packets of the `PeerMetrics`.
+    pub fn rate_limited_packets_inc(&self) -> u64 {
+        self.rate_limited_packets.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of unsolicited messages of the `PeerMetrics`.
+    pub fn unsolicited_messages(&self) -> u64 {
+        self.unsolicited_messages.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of unsolicited messages of the `PeerMetrics`.
+    pub fn unsolicited_messages_inc(&self) -> u64 {
+        self.unsolicited_messages.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of messages affected by overflows of the inbound gossip queue of the `PeerMetrics`.
+    pub fn inbound_gossip_overflows(&self) -> u64 {
+        self.inbound_gossip_overflows.load(Ordering::Relaxed)
+    }
+
+    /// Adds to the number of messages affected by overflows of the inbound gossip queue of the `PeerMetrics`.
+    pub fn inbound_gossip_overflows_add(&self, count: u64) -> u64 {
+        self.inbound_gossip_overflows.fetch_add(count, Ordering::SeqCst)
+    }
+
+    /// Returns the number of messages affected by overflows of the outbound gossip queue of the `PeerMetrics`.
+    pub fn outbound_gossip_overflows(&self) -> u64 {
+        self.outbound_gossip_overflows.load(Ordering::Relaxed)
+    }
+
+    /// Adds to the number of messages affected by overflows of the outbound gossip queue of the `PeerMetrics`.
+    pub fn outbound_gossip_overflows_add(&self, count: u64) -> u64 {
+        self.outbound_gossip_overflows.fetch_add(count, Ordering::SeqCst)
+    }
 }
 
 #[cfg(test)]
@@ -208,4 +252,33 @@ mod tests {
         assert_eq!(metrics.message_requests_sent(), 1);
         assert_eq!(metrics.heartbeats_sent(), 1);
     }
+
+    #[test]
+    fn peer_metrics_misbehaviour() {
+        let metrics = PeerMetrics::default();
+
+        assert_eq!(metrics.rate_limited_packets(), 0);
+        assert_eq!(metrics.unsolicited_messages(), 0);
+
+        metrics.rate_limited_packets_inc();
+        metrics.unsolicited_messages_inc();
+
+        assert_eq!(metrics.rate_limited_packets(), 1);
+        assert_eq!(metrics.unsolicited_messages(), 1);
+    }
+
+    #[test]
+    fn peer_metrics_gossip_overflows() {
+        let metrics = PeerMetrics::default();
+
+        assert_eq!(metrics.inbound_gossip_overflows(), 0);
+        assert_eq!(metrics.outbound_gossip_overflows(), 0);
+
+        metrics.inbound_gossip_overflows_add(3);
+        metrics.outbound_gossip_overflows_add(5);
+        metrics.outbound_gossip_overflows_add(2);
+
+        assert_eq!(metrics.inbound_gossip_overflows(), 3);
+        assert_eq!(metrics.outbound_gossip_overflows(), 7);
+    }
 }
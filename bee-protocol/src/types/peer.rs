@@ -4,11 +4,14 @@
 //! A module that provides a type describing peers.
 
 use std::{
-    sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, Ordering},
-    time::{SystemTime, UNIX_EPOCH},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, Ordering},
+        RwLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use bee_gossip::{Multiaddr, PeerId, PeerInfo, PeerRelation};
+use bee_gossip::{Multiaddr, PeerId, PeerInfo, PeerRelation, ReconnectBackoff, TransportKind};
 use bee_message::milestone::MilestoneIndex;
 
 use crate::types::metrics::PeerMetrics;
@@ -28,11 +31,19 @@ pub struct Peer {
     synced_peers: AtomicU8,
     heartbeat_sent_timestamp: AtomicU64,
     heartbeat_received_timestamp: AtomicU64,
+    reconnect_attempts: AtomicU32,
+    reconnect_delay: AtomicU64,
+    next_reconnect_attempt: AtomicU64,
+    transport: AtomicU8,
+    group: RwLock<Option<String>>,
 }
 
 impl Peer {
     /// Creates a new `Peer`.
     pub fn new(id: PeerId, info: PeerInfo) -> Self {
+        let backoff = info.reconnect_backoff;
+        let group = info.group.clone();
+
         Self {
             id,
             info,
@@ -45,6 +56,11 @@ impl Peer {
             synced_peers: AtomicU8::new(0),
             heartbeat_sent_timestamp: AtomicU64::new(0),
             heartbeat_received_timestamp: AtomicU64::new(0),
+            reconnect_attempts: AtomicU32::new(backoff.attempts),
+            reconnect_delay: AtomicU64::new(backoff.delay.as_millis() as u64),
+            next_reconnect_attempt: AtomicU64::new(backoff.next_attempt.unwrap_or(0)),
+            transport: AtomicU8::new(0),
+            group: RwLock::new(group),
         }
     }
 
@@ -68,6 +84,16 @@ impl Peer {
         self.info.relation
     }
 
+    /// Sets the name of the peer group of the `Peer`.
+    pub fn set_group(&self, group: Option<String>) {
+        *self.group.write().expect("poisoned lock") = group;
+    }
+
+    /// Returns the name of the peer group of the `Peer`, if any.
+    pub fn group(&self) -> Option<String> {
+        self.group.read().expect("poisoned lock").clone()
+    }
+
     /// Returns whether the `Peer` is connected or not.
     pub fn set_connected(&self, connected: bool) {
         self.connected.store(connected, Ordering::Relaxed);
@@ -165,6 +191,46 @@ impl Peer {
         self.heartbeat_received_timestamp.load(Ordering::Relaxed)
     }
 
+    /// Sets the reconnect back-off state of the `Peer`.
+    pub fn set_reconnect_backoff(&self, backoff: ReconnectBackoff) {
+        self.reconnect_attempts.store(backoff.attempts, Ordering::Relaxed);
+        self.reconnect_delay
+            .store(backoff.delay.as_millis() as u64, Ordering::Relaxed);
+        self.next_reconnect_attempt
+            .store(backoff.next_attempt.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Returns the reconnect back-off state of the `Peer`.
+    pub fn reconnect_backoff(&self) -> ReconnectBackoff {
+        ReconnectBackoff {
+            attempts: self.reconnect_attempts.load(Ordering::Relaxed),
+            delay: Duration::from_millis(self.reconnect_delay.load(Ordering::Relaxed)),
+            next_attempt: Some(self.next_reconnect_attempt.load(Ordering::Relaxed)).filter(|t| *t != 0),
+        }
+    }
+
+    /// Sets the transport the connection with the `Peer` runs on.
+    pub fn set_transport(&self, transport: Option<TransportKind>) {
+        let transport = match transport {
+            None => 0,
+            Some(TransportKind::Tcp) => 1,
+            Some(TransportKind::WebSocket) => 2,
+            Some(TransportKind::Memory) => 3,
+        };
+
+        self.transport.store(transport, Ordering::Relaxed);
+    }
+
+    /// Returns the transport the connection with the `Peer` runs on.
+    pub fn transport(&self) -> Option<TransportKind> {
+        match self.transport.load(Ordering::Relaxed) {
+            1 => Some(TransportKind::Tcp),
+            2 => Some(TransportKind::WebSocket),
+            3 => Some(TransportKind::Memory),
+            _ => None,
+        }
+    }
+
     /// Returns whether the `Peer` is synced or not.
     pub fn is_synced(&self) -> bool {
         self.is_synced_threshold(SYNCED_THRESHOLD)
@@ -12,6 +12,19 @@ const DEFAULT_COO_PUBLIC_KEY_RANGES: [(&str, MilestoneIndex, MilestoneIndex); 0]
 const DEFAULT_MESSAGE_WORKER_CACHE: usize = 10000;
 const DEFAULT_STATUS_INTERVAL: u64 = 10;
 const DEFAULT_MILESTONE_SYNC_COUNT: u32 = 200;
+const DEFAULT_MESSAGE_RATE: u32 = 1000;
+const DEFAULT_MESSAGE_BURST: u32 = 2000;
+const DEFAULT_MESSAGE_REQUEST_RATE: u32 = 1000;
+const DEFAULT_MESSAGE_REQUEST_BURST: u32 = 2000;
+const DEFAULT_MILESTONE_REQUEST_RATE: u32 = 20;
+const DEFAULT_MILESTONE_REQUEST_BURST: u32 = 250;
+const DEFAULT_INVALID_MESSAGE_PENALTY: u32 = 10;
+const DEFAULT_INVALID_PACKET_PENALTY: u32 = 20;
+const DEFAULT_UNSOLICITED_RESPONSE_PENALTY: u32 = 2;
+const DEFAULT_EXCESSIVE_REQUEST_PENALTY: u32 = 1;
+const DEFAULT_REPUTATION_RECOVERY: u32 = 1;
+const DEFAULT_DISCONNECT_THRESHOLD: i32 = 0;
+const DEFAULT_BAN_THRESHOLD: i32 = -100;
 
 #[derive(Default, Deserialize, PartialEq)]
 #[must_use]
@@ -33,6 +46,41 @@ struct ProtocolWorkersConfigBuilder {
     milestone_sync_count: Option<u32>,
 }
 
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+struct ProtocolRateLimitConfigBuilder {
+    #[serde(alias = "messageRate")]
+    message_rate: Option<u32>,
+    #[serde(alias = "messageBurst")]
+    message_burst: Option<u32>,
+    #[serde(alias = "messageRequestRate")]
+    message_request_rate: Option<u32>,
+    #[serde(alias = "messageRequestBurst")]
+    message_request_burst: Option<u32>,
+    #[serde(alias = "milestoneRequestRate")]
+    milestone_request_rate: Option<u32>,
+    #[serde(alias = "milestoneRequestBurst")]
+    milestone_request_burst: Option<u32>,
+}
+
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+struct ProtocolReputationConfigBuilder {
+    #[serde(alias = "invalidMessagePenalty")]
+    invalid_message_penalty: Option<u32>,
+    #[serde(alias = "invalidPacketPenalty")]
+    invalid_packet_penalty: Option<u32>,
+    #[serde(alias = "unsolicitedResponsePenalty")]
+    unsolicited_response_penalty: Option<u32>,
+    #[serde(alias = "excessiveRequestPenalty")]
+    excessive_request_penalty: Option<u32>,
+    recovery: Option<u32>,
+    #[serde(alias = "disconnectThreshold")]
+    disconnect_threshold: Option<i32>,
+    #[serde(alias = "banThreshold")]
+    ban_threshold: Option<i32>,
+}
+
 /// Builder for a `ProtocolConfig`.
 #[derive(Default, Deserialize, PartialEq)]
 #[must_use]
@@ -41,6 +89,10 @@ pub struct ProtocolConfigBuilder {
     minimum_pow_score: Option<f64>,
     coordinator: ProtocolCoordinatorConfigBuilder,
     workers: ProtocolWorkersConfigBuilder,
+    #[serde(default, alias = "rateLimit")]
+    rate_limit: ProtocolRateLimitConfigBuilder,
+    #[serde(default)]
+    reputation: ProtocolReputationConfigBuilder,
 }
 
 impl ProtocolConfigBuilder {
@@ -85,6 +137,73 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    /// Sets the rate and burst of messages per peer of the `ProtocolConfigBuilder`.
+    pub fn message_rate_limit(mut self, rate: u32, burst: u32) -> Self {
+        self.rate_limit.message_rate.replace(rate);
+        self.rate_limit.message_burst.replace(burst);
+        self
+    }
+
+    /// Sets the rate and burst of message requests per peer of the `ProtocolConfigBuilder`.
+    pub fn message_request_rate_limit(mut self, rate: u32, burst: u32) -> Self {
+        self.rate_limit.message_request_rate.replace(rate);
+        self.rate_limit.message_request_burst.replace(burst);
+        self
+    }
+
+    /// Sets the rate and burst of milestone requests per peer of the `ProtocolConfigBuilder`.
+    pub fn milestone_request_rate_limit(mut self, rate: u32, burst: u32) -> Self {
+        self.rate_limit.milestone_request_rate.replace(rate);
+        self.rate_limit.milestone_request_burst.replace(burst);
+        self
+    }
+
+    /// Sets the invalid message penalty of the `ProtocolConfigBuilder`.
+    pub fn invalid_message_penalty(mut self, invalid_message_penalty: u32) -> Self {
+        self.reputation.invalid_message_penalty.replace(invalid_message_penalty);
+        self
+    }
+
+    /// Sets the invalid packet penalty of the `ProtocolConfigBuilder`.
+    pub fn invalid_packet_penalty(mut self, invalid_packet_penalty: u32) -> Self {
+        self.reputation.invalid_packet_penalty.replace(invalid_packet_penalty);
+        self
+    }
+
+    /// Sets the unsolicited response penalty of the `ProtocolConfigBuilder`.
+    pub fn unsolicited_response_penalty(mut self, unsolicited_response_penalty: u32) -> Self {
+        self.reputation
+            .unsolicited_response_penalty
+            .replace(unsolicited_response_penalty);
+        self
+    }
+
+    /// Sets the excessive request penalty of the `ProtocolConfigBuilder`.
+    pub fn excessive_request_penalty(mut self, excessive_request_penalty: u32) -> Self {
+        self.reputation
+            .excessive_request_penalty
+            .replace(excessive_request_penalty);
+        self
+    }
+
+    /// Sets the reputation recovery per second of the `ProtocolConfigBuilder`.
+    pub fn reputation_recovery(mut self, reputation_recovery: u32) -> Self {
+        self.reputation.recovery.replace(reputation_recovery);
+        self
+    }
+
+    /// Sets the reputation threshold below which peers are disconnected of the `ProtocolConfigBuilder`.
+    pub fn disconnect_threshold(mut self, disconnect_threshold: i32) -> Self {
+        self.reputation.disconnect_threshold.replace(disconnect_threshold);
+        self
+    }
+
+    /// Sets the reputation threshold below which peers are banned of the `ProtocolConfigBuilder`.
+    pub fn ban_threshold(mut self, ban_threshold: i32) -> Self {
+        self.reputation.ban_threshold.replace(ban_threshold);
+        self
+    }
+
     /// Finishes the `ProtocolConfigBuilder` into a `ProtocolConfig`.
     #[must_use]
     pub fn finish(self) -> ProtocolConfig {
@@ -113,6 +232,56 @@ impl ProtocolConfigBuilder {
                     .milestone_sync_count
                     .unwrap_or(DEFAULT_MILESTONE_SYNC_COUNT),
             },
+            rate_limit: ProtocolRateLimitConfig {
+                message: RateLimit {
+                    rate: self.rate_limit.message_rate.unwrap_or(DEFAULT_MESSAGE_RATE),
+                    burst: self.rate_limit.message_burst.unwrap_or(DEFAULT_MESSAGE_BURST),
+                },
+                message_request: RateLimit {
+                    rate: self
+                        .rate_limit
+                        .message_request_rate
+                        .unwrap_or(DEFAULT_MESSAGE_REQUEST_RATE),
+                    burst: self
+                        .rate_limit
+                        .message_request_burst
+                        .unwrap_or(DEFAULT_MESSAGE_REQUEST_BURST),
+                },
+                milestone_request: RateLimit {
+                    rate: self
+                        .rate_limit
+                        .milestone_request_rate
+                        .unwrap_or(DEFAULT_MILESTONE_REQUEST_RATE),
+                    burst: self
+                        .rate_limit
+                        .milestone_request_burst
+                        .unwrap_or(DEFAULT_MILESTONE_REQUEST_BURST),
+                },
+            },
+            reputation: ProtocolReputationConfig {
+                invalid_message_penalty: self
+                    .reputation
+                    .invalid_message_penalty
+                    .unwrap_or(DEFAULT_INVALID_MESSAGE_PENALTY),
+                invalid_packet_penalty: self
+                    .reputation
+                    .invalid_packet_penalty
+                    .unwrap_or(DEFAULT_INVALID_PACKET_PENALTY),
+                unsolicited_response_penalty: self
+                    .reputation
+                    .unsolicited_response_penalty
+                    .unwrap_or(DEFAULT_UNSOLICITED_RESPONSE_PENALTY),
+                excessive_request_penalty: self
+                    .reputation
+                    .excessive_request_penalty
+                    .unwrap_or(DEFAULT_EXCESSIVE_REQUEST_PENALTY),
+                recovery: self.reputation.recovery.unwrap_or(DEFAULT_REPUTATION_RECOVERY),
+                disconnect_threshold: self
+                    .reputation
+                    .disconnect_threshold
+                    .unwrap_or(DEFAULT_DISCONNECT_THRESHOLD),
+                ban_threshold: self.reputation.ban_threshold.unwrap_or(DEFAULT_BAN_THRESHOLD),
+            },
         }
     }
 }
@@ -124,6 +293,18 @@ pub struct ProtocolCoordinatorConfig {
     pub(crate) public_key_ranges: Vec<MilestoneKeyRange>,
 }
 
+impl ProtocolCoordinatorConfig {
+    /// Returns the minimum number of coordinator signatures required for a milestone.
+    pub fn public_key_count(&self) -> usize {
+        self.public_key_count
+    }
+
+    /// Returns the coordinator public key ranges.
+    pub fn public_key_ranges(&self) -> &[MilestoneKeyRange] {
+        &self.public_key_ranges
+    }
+}
+
 /// Configuration for the protocol workers.
 #[derive(Clone)]
 pub struct ProtocolWorkersConfig {
@@ -132,12 +313,41 @@ pub struct ProtocolWorkersConfig {
     pub(crate) milestone_sync_count: u32,
 }
 
+/// Rate and burst of a per-peer token bucket.
+#[derive(Clone, Copy)]
+pub struct RateLimit {
+    pub(crate) rate: u32,
+    pub(crate) burst: u32,
+}
+
+/// Configuration for the per-peer rate limits.
+#[derive(Clone)]
+pub struct ProtocolRateLimitConfig {
+    pub(crate) message: RateLimit,
+    pub(crate) message_request: RateLimit,
+    pub(crate) milestone_request: RateLimit,
+}
+
+/// Configuration for the peer reputation.
+#[derive(Clone)]
+pub struct ProtocolReputationConfig {
+    pub(crate) invalid_message_penalty: u32,
+    pub(crate) invalid_packet_penalty: u32,
+    pub(crate) unsolicited_response_penalty: u32,
+    pub(crate) excessive_request_penalty: u32,
+    pub(crate) recovery: u32,
+    pub(crate) disconnect_threshold: i32,
+    pub(crate) ban_threshold: i32,
+}
+
 /// Configuration for the protocol.
 #[derive(Clone)]
 pub struct ProtocolConfig {
     pub(crate) minimum_pow_score: f64,
     pub(crate) coordinator: ProtocolCoordinatorConfig,
     pub(crate) workers: ProtocolWorkersConfig,
+    pub(crate) rate_limit: ProtocolRateLimitConfig,
+    pub(crate) reputation: ProtocolReputationConfig,
 }
 
 impl ProtocolConfig {
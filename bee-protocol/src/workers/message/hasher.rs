@@ -21,8 +21,9 @@ use crate::{
             submitter::notify_invalid_message, HashCache, MessageSubmitterError, ProcessorWorker, ProcessorWorkerEvent,
         },
         packets::MessagePacket,
+        report_misbehaviour,
         storage::StorageBackend,
-        MetricsWorker, PeerManager, PeerManagerResWorker,
+        MetricsWorker, Misbehaviour, PeerManager, PeerManagerResWorker, ReputationWorker,
     },
 };
 
@@ -49,6 +50,7 @@ where
             TypeId::of::<ProcessorWorker>(),
             TypeId::of::<MetricsWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<ReputationWorker>(),
         ]
         .leak()
     }
@@ -56,6 +58,7 @@ where
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let (tx, rx) = mpsc::unbounded_channel();
         let processor_worker = node.worker::<ProcessorWorker>().unwrap().tx.clone();
+        let reputation = node.worker::<ReputationWorker>().unwrap().tx.clone();
         let metrics = node.resource::<NodeMetrics>();
         let peer_manager = node.resource::<PeerManager>();
 
@@ -106,6 +109,7 @@ where
                         &metrics,
                         notifier,
                     );
+                    report_misbehaviour(&reputation, from, Misbehaviour::InvalidMessage);
                     continue;
                 }
 
@@ -21,11 +21,12 @@ use crate::{
         message::submitter::{notify_invalid_message, notify_message},
         packets::MessagePacket,
         peer::PeerManager,
+        report_misbehaviour,
         requester::request_message,
         storage::StorageBackend,
         BroadcasterWorker, BroadcasterWorkerEvent, MessageRequesterWorker, MessageSubmitterError, MetricsWorker,
-        PayloadWorker, PayloadWorkerEvent, PeerManagerResWorker, PropagatorWorker, PropagatorWorkerEvent,
-        RequestedMessages, UnreferencedMessageInserterWorker, UnreferencedMessageInserterWorkerEvent,
+        Misbehaviour, PayloadWorker, PayloadWorkerEvent, PeerManagerResWorker, PropagatorWorker, PropagatorWorkerEvent,
+        ReputationWorker, RequestedMessages, UnreferencedMessageInserterWorker, UnreferencedMessageInserterWorkerEvent,
     },
 };
 
@@ -56,6 +57,7 @@ where
             TypeId::of::<MetricsWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
             TypeId::of::<PayloadWorker>(),
+            TypeId::of::<ReputationWorker>(),
             TypeId::of::<UnreferencedMessageInserterWorker>(),
         ]
         .leak()
@@ -69,6 +71,7 @@ where
         let message_requester = node.worker::<MessageRequesterWorker>().unwrap().clone();
         let payload_worker = node.worker::<PayloadWorker>().unwrap().tx.clone();
         let unreferenced_inserted_worker = node.worker::<UnreferencedMessageInserterWorker>().unwrap().tx.clone();
+        let reputation = node.worker::<ReputationWorker>().unwrap().tx.clone();
 
         let tangle = node.resource::<Tangle<N::Backend>>();
         let requested_messages = node.resource::<RequestedMessages>();
@@ -92,6 +95,7 @@ where
                 let message_requester = message_requester.clone();
                 let payload_worker = payload_worker.clone();
                 let unreferenced_inserted_worker = unreferenced_inserted_worker.clone();
+                let reputation = reputation.clone();
                 let tangle = tangle.clone();
                 let requested_messages = requested_messages.clone();
                 let metrics = metrics.clone();
@@ -112,6 +116,7 @@ where
                             Ok(message) => message,
                             Err(e) => {
                                 notify_invalid_message(format!("Invalid message: {:?}.", e), &metrics, notifier);
+                                report_misbehaviour(&reputation, from, Misbehaviour::InvalidMessage);
                                 continue;
                             }
                         };
@@ -122,6 +127,7 @@ where
                                 &metrics,
                                 notifier,
                             );
+                            report_misbehaviour(&reputation, from, Misbehaviour::InvalidMessage);
                             continue;
                         }
 
@@ -135,6 +141,13 @@ where
                                         (*peer).0.metrics().known_messages_inc();
                                     })
                                     .unwrap_or_default();
+
+                                // Peers only broadcast recent messages, old ones are only sent upon request.
+                                if !requested_messages.contains(&message_id)
+                                    && is_old(&tangle, &message_id, tangle.config().below_max_depth())
+                                {
+                                    report_misbehaviour(&reputation, from, Misbehaviour::UnsolicitedResponse);
+                                }
                             }
                             continue;
                         } else {
@@ -222,3 +235,11 @@ where
         Ok(Self { tx })
     }
 }
+
+/// Returns whether a message was referenced by a milestone that is older than `depth`.
+fn is_old<B: StorageBackend>(tangle: &Tangle<B>, message_id: &MessageId, depth: u32) -> bool {
+    tangle
+        .get_metadata(message_id)
+        .and_then(|metadata| metadata.milestone_index())
+        .map_or(false, |index| *index + depth < *tangle.get_latest_milestone_index())
+}
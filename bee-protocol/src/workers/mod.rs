@@ -14,6 +14,7 @@ mod mps;
 mod packets;
 mod peer;
 mod propagator;
+mod reputation;
 mod requester;
 mod responder;
 mod sender;
@@ -37,6 +38,7 @@ pub(crate) use self::{
     mps::MpsWorker,
     peer::{PeerManagerWorker, PeerWorker},
     propagator::{PropagatorWorker, PropagatorWorkerEvent},
+    reputation::{report_misbehaviour, Misbehaviour, ReputationWorker, ReputationWorkerEvent},
     requester::{MilestoneRequesterWorker, MilestoneRequesterWorkerEvent},
     responder::{
         MessageResponderWorker, MessageResponderWorkerEvent, MilestoneResponderWorker, MilestoneResponderWorkerEvent,
@@ -64,10 +66,12 @@ where
     node_builder
         .with_worker::<MetricsWorker>()
         .with_worker::<PeerManagerResWorker>()
+        .with_worker_cfg::<ReputationWorker>(config.reputation.clone())
         .with_worker_cfg::<PeerManagerWorker>(PeerManagerConfig {
             network_rx: network_events,
             peering_rx: autopeering_events,
             network_name: network_id.0,
+            rate_limit: config.rate_limit.clone(),
         })
         .with_worker_cfg::<HasherWorker>(config.clone())
         .with_worker_cfg::<ProcessorWorker>(network_id.1)
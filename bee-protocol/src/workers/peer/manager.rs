@@ -6,23 +6,25 @@ use std::{any::TypeId, convert::Infallible, sync::Arc};
 use async_trait::async_trait;
 use bee_autopeering::event::{Event as AutopeeringEvent, EventRx as AutopeeringEventRx};
 use bee_gossip::{
-    alias, Command, Event as NetworkEvent, NetworkCommandSender, NetworkEventReceiver as NetworkEventRx, PeerRelation,
-    ServiceHost,
+    alias, Command, Event as NetworkEvent, GossipDirection, NetworkCommandSender,
+    NetworkEventReceiver as NetworkEventRx, PeerRelation, ServiceHost,
 };
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{Tangle, TangleWorker};
 use futures::{channel::oneshot, StreamExt};
 use log::{info, trace, warn};
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
 
 use crate::{
     types::{metrics::NodeMetrics, peer::Peer},
     workers::{
+        config::ProtocolRateLimitConfig,
         heartbeater::{new_heartbeat, send_heartbeat},
         peer::PeerManager,
         storage::StorageBackend,
-        HasherWorker, MessageResponderWorker, MetricsWorker, MilestoneRequesterWorker, MilestoneResponderWorker,
-        PeerManagerResWorker, PeerWorker, RequestedMilestones,
+        HasherWorker, MessageRequesterWorker, MessageResponderWorker, MetricsWorker, MilestoneRequesterWorker,
+        MilestoneResponderWorker, PeerManagerResWorker, PeerWorker, ReputationWorker, RequestedMessages,
+        RequestedMilestones,
     },
 };
 
@@ -30,6 +32,7 @@ pub(crate) struct PeerManagerConfig {
     pub(crate) network_rx: NetworkEventRx,
     pub(crate) peering_rx: Option<AutopeeringEventRx>,
     pub(crate) network_name: String,
+    pub(crate) rate_limit: ProtocolRateLimitConfig,
 }
 
 pub(crate) struct PeerManagerWorker {}
@@ -51,8 +54,10 @@ where
             TypeId::of::<HasherWorker>(),
             TypeId::of::<MessageResponderWorker>(),
             TypeId::of::<MilestoneResponderWorker>(),
+            TypeId::of::<MessageRequesterWorker>(),
             TypeId::of::<MilestoneRequesterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<ReputationWorker>(),
         ]
         .leak()
     }
@@ -60,6 +65,7 @@ where
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let peer_manager = node.resource::<PeerManager>();
         let tangle = node.resource::<Tangle<N::Backend>>();
+        let requested_messages = node.resource::<RequestedMessages>();
         let requested_milestones = node.resource::<RequestedMilestones>();
         let metrics = node.resource::<NodeMetrics>();
         let gossip_command_tx = node.resource::<NetworkCommandSender>();
@@ -68,11 +74,13 @@ where
         let message_responder = node.worker::<MessageResponderWorker>().unwrap().tx.clone();
         let milestone_responder = node.worker::<MilestoneResponderWorker>().unwrap().tx.clone();
         let milestone_requester = node.worker::<MilestoneRequesterWorker>().unwrap().tx.clone();
+        let reputation = node.worker::<ReputationWorker>().unwrap().tx.clone();
 
         let PeerManagerConfig {
             network_rx,
             peering_rx,
             network_name,
+            rate_limit,
         } = config;
 
         if let Some(peering_rx) = peering_rx {
@@ -107,7 +115,7 @@ where
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Network handler running.");
 
-            let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(network_rx.into()));
+            let mut receiver = ShutdownStream::new(shutdown, ReceiverStream::new(network_rx.into()));
 
             while let Some(event) = receiver.next().await {
                 trace!("Received event {:?}.", event);
@@ -128,6 +136,7 @@ where
                     NetworkEvent::PeerConnected {
                         peer_id,
                         info: _,
+                        transport,
                         gossip_in: receiver,
                         gossip_out: sender,
                     } => {
@@ -137,7 +146,10 @@ where
                             let message_responder = message_responder.clone();
                             let milestone_responder = milestone_responder.clone();
                             let milestone_requester = milestone_requester.clone();
+                            let reputation = reputation.clone();
+                            let rate_limit = rate_limit.clone();
                             let tangle = tangle.clone();
+                            let requested_messages = requested_messages.clone();
                             let requested_milestones = requested_milestones.clone();
 
                             peer_manager
@@ -145,6 +157,8 @@ where
                                     let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
                                     peer.0.set_connected(true);
+                                    peer.0.set_reconnect_backoff(Default::default());
+                                    peer.0.set_transport(transport);
                                     peer.1 = Some((sender, shutdown_tx));
 
                                     tokio::spawn(
@@ -155,9 +169,12 @@ where
                                             message_responder,
                                             milestone_responder,
                                             milestone_requester,
+                                            reputation,
+                                            rate_limit,
                                         )
                                         .run(
                                             tangle,
+                                            requested_messages,
                                             requested_milestones,
                                             receiver,
                                             shutdown_rx,
@@ -180,6 +197,7 @@ where
                     NetworkEvent::PeerDisconnected { peer_id } => peer_manager
                         .get_mut_map(&peer_id, |peer| {
                             peer.0.set_connected(false);
+                            peer.0.set_transport(None);
                             if let Some((_, shutdown)) = peer.1.take() {
                                 if let Err(e) = shutdown.send(()) {
                                     warn!("Sending shutdown to {} failed: {:?}.", peer.0.alias(), e);
@@ -188,14 +206,31 @@ where
                             info!("Disconnected peer {}.", peer.0.alias());
                         })
                         .unwrap_or_default(),
+                    NetworkEvent::PeerReconnectScheduled { peer_id, backoff } => peer_manager
+                        .get_map(&peer_id, |peer| peer.0.set_reconnect_backoff(backoff))
+                        .unwrap_or_default(),
+                    NetworkEvent::PeerGroupChanged { peer_id, group } => peer_manager
+                        .get_map(&peer_id, |peer| peer.0.set_group(group))
+                        .unwrap_or_default(),
+                    NetworkEvent::GossipQueueOverflowed {
+                        peer_id,
+                        direction,
+                        count,
+                        ..
+                    } => peer_manager
+                        .get_map(&peer_id, |peer| {
+                            match direction {
+                                GossipDirection::Inbound => peer.0.metrics().inbound_gossip_overflows_add(count),
+                                GossipDirection::Outbound => peer.0.metrics().outbound_gossip_overflows_add(count),
+                            };
+                        })
+                        .unwrap_or_default(),
                     NetworkEvent::PeerUnreachable { peer_id, peer_info } => {
                         if peer_info.relation.is_discovered() {
                             // Remove that discovered peer.
-
-                            // Panic: sending commands cannot fail: same explanation as in other sender usages.
-                            gossip_command_tx
-                                .send(Command::RemovePeer { peer_id })
-                                .expect("send gossip command");
+                            if let Err(e) = gossip_command_tx.send(Command::RemovePeer { peer_id }) {
+                                warn!("Removing unreachable peer {} failed: {}.", alias!(peer_id), e);
+                            }
 
                             // TODO: tell the autopeering to remove that peer from the neighborhood.
                         }
@@ -215,25 +250,24 @@ fn handle_new_peering(peer: bee_autopeering::Peer, network_name: &str, gossip_co
     if let Some(multiaddr) = peer.service_multiaddr(network_name) {
         let peer_id = peer.peer_id().libp2p_peer_id();
 
-        // Panic: sending commands cannot fail due to worker dependencies: because the "Peer Manager" depends on
-        // the `bee-gossip` "ServiceHost", it is guaranteed that the receiver of this channel is not dropped
-        // before the sender.
-        gossip_command_tx
-            .send(Command::AddPeer {
-                peer_id,
-                alias: Some(alias!(peer_id).to_string()),
-                multiaddr,
-                relation: PeerRelation::Discovered,
-            })
-            .expect("send command to gossip layer");
+        // Because the "Peer Manager" depends on the `bee-gossip` "ServiceHost", it is guaranteed that the receiver of
+        // this channel is not dropped before the sender, but the command queue may be full.
+        if let Err(e) = gossip_command_tx.send(Command::AddPeer {
+            peer_id,
+            alias: Some(alias!(peer_id).to_string()),
+            multiaddr,
+            relation: PeerRelation::Discovered,
+        }) {
+            warn!("Adding autopeered peer {} failed: {}.", alias!(peer_id), e);
+        }
     }
 }
 
 fn handle_peering_dropped(peer_id: bee_autopeering::PeerId, gossip_command_tx: &NetworkCommandSender) {
     let peer_id = peer_id.libp2p_peer_id();
 
-    // Panic: sending commands cannot fail: same explanation as in other sender usages.
-    gossip_command_tx
-        .send(Command::RemovePeer { peer_id })
-        .expect("send command to gossip layer");
+    // The command queue may be full: same explanation as in other sender usages.
+    if let Err(e) = gossip_command_tx.send(Command::RemovePeer { peer_id }) {
+        warn!("Removing autopeered peer {} failed: {}.", alias!(peer_id), e);
+    }
 }
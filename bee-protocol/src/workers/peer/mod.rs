@@ -4,31 +4,35 @@
 mod manager;
 mod manager_res;
 mod packet_handler;
+mod rate_limiter;
 
 use std::sync::Arc;
 
-use bee_message::milestone::MilestoneIndex;
+use bee_gossip::GossipReceiver;
+use bee_message::{milestone::MilestoneIndex, MessageId};
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 use futures::{channel::oneshot, future::FutureExt};
 use log::{debug, error, info, trace};
 use tokio::sync::mpsc;
-use tokio_stream::wrappers::UnboundedReceiverStream;
 
 pub(crate) use self::manager::{PeerManagerConfig, PeerManagerWorker};
 pub use self::manager_res::{PeerManager, PeerManagerResWorker};
 use crate::{
     types::{metrics::NodeMetrics, peer::Peer},
     workers::{
+        config::ProtocolRateLimitConfig,
         packets::{
             tlv_from_bytes, HeaderPacket, HeartbeatPacket, MessagePacket, MessageRequestPacket, MilestoneRequestPacket,
             Packet, TlvError,
         },
-        peer::packet_handler::PacketHandler,
+        peer::{packet_handler::PacketHandler, rate_limiter::TokenBucket},
+        report_misbehaviour,
         requester::request_latest_milestone,
         storage::StorageBackend,
         HasherWorkerEvent, MessageResponderWorkerEvent, MilestoneRequesterWorkerEvent, MilestoneResponderWorkerEvent,
-        RequestedMilestones,
+        Misbehaviour, ReputationWorkerEvent, RequestedMessages, RequestedMilestones,
     },
 };
 
@@ -51,6 +55,10 @@ pub struct PeerWorker {
     message_responder: mpsc::UnboundedSender<MessageResponderWorkerEvent>,
     milestone_responder: mpsc::UnboundedSender<MilestoneResponderWorkerEvent>,
     milestone_requester: mpsc::UnboundedSender<MilestoneRequesterWorkerEvent>,
+    reputation: mpsc::UnboundedSender<ReputationWorkerEvent>,
+    message_bucket: TokenBucket,
+    message_request_bucket: TokenBucket,
+    milestone_request_bucket: TokenBucket,
 }
 
 impl PeerWorker {
@@ -61,6 +69,8 @@ impl PeerWorker {
         message_responder: mpsc::UnboundedSender<MessageResponderWorkerEvent>,
         milestone_responder: mpsc::UnboundedSender<MilestoneResponderWorkerEvent>,
         milestone_requester: mpsc::UnboundedSender<MilestoneRequesterWorkerEvent>,
+        reputation: mpsc::UnboundedSender<ReputationWorkerEvent>,
+        rate_limit: ProtocolRateLimitConfig,
     ) -> Self {
         Self {
             peer,
@@ -69,14 +79,19 @@ impl PeerWorker {
             message_responder,
             milestone_responder,
             milestone_requester,
+            reputation,
+            message_bucket: TokenBucket::new(rate_limit.message),
+            message_request_bucket: TokenBucket::new(rate_limit.message_request),
+            milestone_request_bucket: TokenBucket::new(rate_limit.milestone_request),
         }
     }
 
     pub(crate) async fn run<B: StorageBackend>(
         mut self,
         tangle: ResourceHandle<Tangle<B>>,
+        requested_messages: ResourceHandle<RequestedMessages>,
         requested_milestones: ResourceHandle<RequestedMilestones>,
-        receiver: UnboundedReceiverStream<Vec<u8>>,
+        receiver: GossipReceiver,
         shutdown: oneshot::Receiver<()>,
     ) {
         info!("[{}] Running.", self.peer.alias());
@@ -98,10 +113,11 @@ impl PeerWorker {
         while let Some((header, bytes)) = packet_handler.fetch_packet().await {
             let tangle = tangle.upgrade().expect("Needed Tangle resource but it was removed");
 
-            if let Err(e) = self.process_packet(&tangle, &header, bytes) {
+            if let Err(e) = self.process_packet(&tangle, &requested_messages, &header, bytes) {
                 error!("[{}] Processing packet failed: {:?}.", self.peer.alias(), e);
                 self.peer.metrics().invalid_packets_inc();
                 self.metrics.invalid_packets_inc();
+                report_misbehaviour(&self.reputation, Some(*self.peer.id()), Misbehaviour::InvalidPacket);
             }
         }
 
@@ -111,6 +127,7 @@ impl PeerWorker {
     fn process_packet<B: StorageBackend>(
         &mut self,
         tangle: &Tangle<B>,
+        requested_messages: &RequestedMessages,
         header: &HeaderPacket,
         bytes: &[u8],
     ) -> Result<(), Error> {
@@ -120,6 +137,11 @@ impl PeerWorker {
 
                 let packet = tlv_from_bytes::<MilestoneRequestPacket>(header, bytes)?;
 
+                if !self.milestone_request_bucket.try_take() {
+                    self.rate_limited(Some(Misbehaviour::ExcessiveRequest));
+                    return Ok(());
+                }
+
                 let _ = self.milestone_responder.send(MilestoneResponderWorkerEvent {
                     peer_id: *self.peer.id(),
                     request: packet,
@@ -133,6 +155,16 @@ impl PeerWorker {
 
                 let packet = tlv_from_bytes::<MessagePacket>(header, bytes)?;
 
+                // Messages we requested are never rate limited, so that syncing isn't slowed down. Other bursts of
+                // messages are legit as well, so they are dropped without hurting the reputation of the peer.
+                // The message is only hashed when the bucket is empty, which keeps the common case cheap.
+                if !self.message_bucket.try_take()
+                    && !requested_messages.contains(&MessageId::new(Blake2b256::digest(&packet.bytes).into()))
+                {
+                    self.rate_limited(None);
+                    return Ok(());
+                }
+
                 let _ = self.hasher.send(HasherWorkerEvent {
                     from: Some(*self.peer.id()),
                     message_packet: packet,
@@ -147,6 +179,11 @@ impl PeerWorker {
 
                 let packet = tlv_from_bytes::<MessageRequestPacket>(header, bytes)?;
 
+                if !self.message_request_bucket.try_take() {
+                    self.rate_limited(Some(Misbehaviour::ExcessiveRequest));
+                    return Ok(());
+                }
+
                 let _ = self.message_responder.send(MessageResponderWorkerEvent {
                     peer_id: *self.peer.id(),
                     request: packet,
@@ -190,4 +227,14 @@ impl PeerWorker {
 
         Ok(())
     }
+
+    fn rate_limited(&self, misbehaviour: Option<Misbehaviour>) {
+        trace!("[{}] Dropping rate limited packet.", self.peer.alias());
+
+        self.peer.metrics().rate_limited_packets_inc();
+
+        if let Some(misbehaviour) = misbehaviour {
+            report_misbehaviour(&self.reputation, Some(*self.peer.id()), misbehaviour);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_gossip::Multiaddr;
+use bee_gossip::{GossipReceiver, Multiaddr};
 use futures::{
     channel::oneshot,
     future::{self, FutureExt},
@@ -9,11 +9,10 @@ use futures::{
 };
 use log::trace;
 use tokio::select;
-use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::workers::packets::{HeaderPacket, HEADER_SIZE};
 
-type EventRecv = UnboundedReceiverStream<Vec<u8>>;
+type EventRecv = GossipReceiver;
 type ShutdownRecv = future::Fuse<oneshot::Receiver<()>>;
 
 /// The read state of the packet handler.
@@ -162,9 +161,9 @@ impl EventHandler {
 mod tests {
     use std::time::Duration;
 
+    use bee_gossip::{gossip_channel, OverflowPolicy};
     use futures::{channel::oneshot, future::FutureExt};
-    use tokio::{spawn, sync::mpsc, time::sleep};
-    use tokio_stream::wrappers::UnboundedReceiverStream;
+    use tokio::{spawn, time::sleep};
 
     use super::*;
 
@@ -194,9 +193,9 @@ mod tests {
         let events = gen_events(event_size, msg_size, msg_count);
         // Create a new packet handler
         let (sender_shutdown, receiver_shutdown) = oneshot::channel::<()>();
-        let (sender, receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (sender, receiver) = gossip_channel(events.len(), OverflowPolicy::Disconnect);
         let mut msg_handler = PacketHandler::new(
-            UnboundedReceiverStream::new(receiver),
+            receiver,
             receiver_shutdown.fuse(),
             "/ip4/0.0.0.0/tcp/8080".parse().unwrap(),
         );
@@ -287,10 +286,10 @@ mod tests {
         let last_event = events.pop().unwrap();
 
         let (sender_shutdown, receiver_shutdown) = oneshot::channel::<()>();
-        let (sender, receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (sender, receiver) = gossip_channel(events.len() + 1, OverflowPolicy::Disconnect);
 
         let mut msg_handler = PacketHandler::new(
-            UnboundedReceiverStream::new(receiver),
+            receiver,
             receiver_shutdown.fuse(),
             "/ip4/0.0.0.0/tcp/8080".parse().unwrap(),
         );
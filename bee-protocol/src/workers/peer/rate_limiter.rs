@@ -0,0 +1,85 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Instant;
+
+use crate::workers::config::RateLimit;
+
+/// A token bucket that limits the rate of packets of a given type received from a peer.
+///
+/// The bucket holds up to `burst` tokens and is refilled with `rate` tokens per second. A rate of `0` disables the
+/// limit.
+pub(crate) struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        Self {
+            rate: limit.rate as f64,
+            burst: limit.burst.max(1) as f64,
+            tokens: limit.burst.max(1) as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Takes a token from the bucket, returns `false` if the bucket is empty.
+    pub(crate) fn try_take(&mut self) -> bool {
+        self.try_take_at(Instant::now())
+    }
+
+    fn try_take_at(&mut self, now: Instant) -> bool {
+        if self.rate == 0.0 {
+            return true;
+        }
+
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn burst_then_refill() {
+        let mut bucket = TokenBucket::new(RateLimit { rate: 2, burst: 3 });
+        let now = bucket.updated;
+
+        assert!((0..3).all(|_| bucket.try_take_at(now)));
+        assert!(!bucket.try_take_at(now));
+
+        let now = now + Duration::from_millis(500);
+
+        assert!(bucket.try_take_at(now));
+        assert!(!bucket.try_take_at(now));
+
+        let now = now + Duration::from_secs(10);
+
+        assert!((0..3).all(|_| bucket.try_take_at(now)));
+        assert!(!bucket.try_take_at(now));
+    }
+
+    #[test]
+    fn unlimited() {
+        let mut bucket = TokenBucket::new(RateLimit { rate: 0, burst: 1 });
+        let now = bucket.updated;
+
+        assert!((0..1000).all(|_| bucket.try_take_at(now)));
+    }
+}
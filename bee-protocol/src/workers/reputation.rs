@@ -0,0 +1,268 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{any::TypeId, collections::HashMap, convert::Infallible, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use bee_gossip::{alias, Command, NetworkCommandSender, PeerId, ServiceError, ServiceHost};
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use futures::stream::StreamExt;
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+use tokio::{
+    sync::mpsc::{self, UnboundedSender},
+    time::interval,
+};
+use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
+
+use crate::workers::{config::ProtocolReputationConfig, peer::PeerManager, PeerManagerResWorker};
+
+const REPUTATION_RECOVERY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The reputation of a peer that didn't misbehave, which is also the maximum reputation a peer can recover to.
+pub(crate) const MAX_REPUTATION: i32 = 100;
+/// The minimum reputation of a peer, which bounds the time it takes to recover.
+pub(crate) const MIN_REPUTATION: i32 = -1000;
+
+/// Describes a misbehaviour of a peer that lowers its reputation.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Misbehaviour {
+    /// The peer sent an invalid message.
+    InvalidMessage,
+    /// The peer sent an invalid packet.
+    InvalidPacket,
+    /// The peer sent an old message that wasn't requested.
+    UnsolicitedResponse,
+    /// The peer sent more requests than allowed by the rate limits.
+    ExcessiveRequest,
+}
+
+/// Reputations of peers, keyed by their identifier so that they survive disconnections and re-additions of the peers.
+/// Only peers whose reputation is below `MAX_REPUTATION` are tracked.
+#[derive(Default)]
+pub(crate) struct Reputations(Mutex<HashMap<PeerId, i32>>);
+
+impl Reputations {
+    /// Decreases the reputation of a peer by a penalty and returns its reputation before and after the penalty.
+    pub(crate) fn penalize(&self, peer_id: PeerId, penalty: u32) -> (i32, i32) {
+        let mut reputations = self.0.lock();
+        let reputation = reputations.entry(peer_id).or_insert(MAX_REPUTATION);
+        let before = *reputation;
+
+        *reputation = before
+            .saturating_sub(penalty.min(i32::MAX as u32) as i32)
+            .max(MIN_REPUTATION);
+
+        (before, *reputation)
+    }
+
+    /// Increases the reputation of all peers up to `MAX_REPUTATION`, and stops tracking the peers that reached it.
+    pub(crate) fn recover(&self, recovery: u32) {
+        self.0.lock().retain(|_, reputation| {
+            *reputation = reputation
+                .saturating_add(recovery.min(i32::MAX as u32) as i32)
+                .min(MAX_REPUTATION);
+
+            *reputation < MAX_REPUTATION
+        });
+    }
+}
+
+/// An action taken against a peer with a low reputation.
+#[derive(Clone, Copy, Debug)]
+enum PeerAction {
+    Disconnect,
+    Ban,
+}
+
+impl PeerAction {
+    fn command(self, peer_id: PeerId) -> Command {
+        match self {
+            PeerAction::Disconnect => Command::DisconnectPeer { peer_id },
+            PeerAction::Ban => Command::BanPeer { peer_id },
+        }
+    }
+}
+
+/// Actions that couldn't be sent to the network layer because its command queue was full, retried on every recovery
+/// tick.
+#[derive(Default)]
+struct PendingActions(Mutex<Vec<(PeerId, PeerAction)>>);
+
+impl PendingActions {
+    /// Sends the command of an action to the network layer, and re-queues the action if the command queue is full.
+    fn send(&self, gossip_command_tx: &NetworkCommandSender, peer_id: PeerId, action: PeerAction) {
+        match gossip_command_tx.send(action.command(peer_id)) {
+            Ok(()) => {}
+            Err(ServiceError::CommandQueueFull) => {
+                warn!(
+                    "{:?} of {} failed: command queue is full, retrying.",
+                    action,
+                    alias!(peer_id)
+                );
+                self.0.lock().push((peer_id, action));
+            }
+            // The network layer may already be stopped during shutdown, in which case the peer is gone anyway.
+            Err(e) => debug!("{:?} of {} failed: {}.", action, alias!(peer_id), e),
+        }
+    }
+
+    /// Sends the commands of all re-queued actions again.
+    fn retry(&self, gossip_command_tx: &NetworkCommandSender) {
+        let pending = std::mem::take(&mut *self.0.lock());
+
+        for (peer_id, action) in pending {
+            self.send(gossip_command_tx, peer_id, action);
+        }
+    }
+}
+
+pub(crate) struct ReputationWorkerEvent {
+    pub(crate) peer_id: PeerId,
+    pub(crate) misbehaviour: Misbehaviour,
+}
+
+pub(crate) struct ReputationWorker {
+    pub(crate) tx: UnboundedSender<ReputationWorkerEvent>,
+}
+
+/// Reports a misbehaviour of a peer, if the data came from a peer at all.
+pub(crate) fn report_misbehaviour(
+    reputation: &UnboundedSender<ReputationWorkerEvent>,
+    peer_id: Option<PeerId>,
+    misbehaviour: Misbehaviour,
+) {
+    if let Some(peer_id) = peer_id {
+        // The reputation worker may already be stopped during shutdown, in which case penalties don't matter anymore.
+        let _ = reputation.send(ReputationWorkerEvent { peer_id, misbehaviour });
+    }
+}
+
+#[async_trait]
+impl<N: Node> Worker<N> for ReputationWorker {
+    type Config = ProtocolReputationConfig;
+    type Error = Infallible;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![TypeId::of::<PeerManagerResWorker>(), TypeId::of::<ServiceHost>()].leak()
+    }
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let peer_manager = node.resource::<PeerManager>();
+        let reputations = Arc::new(Reputations::default());
+        let pending_actions = Arc::new(PendingActions::default());
+        let gossip_command_tx = node.resource::<NetworkCommandSender>();
+
+        if config.ban_threshold > config.disconnect_threshold {
+            warn!(
+                "Configuration value for \"protocol.reputation.ban_threshold\" ({}) is higher than \
+                \"protocol.reputation.disconnect_threshold\" ({}).",
+                config.ban_threshold, config.disconnect_threshold
+            );
+        }
+
+        {
+            let reputations = reputations.clone();
+            let pending_actions = pending_actions.clone();
+            let gossip_command_tx = gossip_command_tx.clone();
+            let recovery = config.recovery;
+
+            node.spawn::<Self, _, _>(|shutdown| async move {
+                info!("Recovery running.");
+
+                let mut ticker =
+                    ShutdownStream::new(shutdown, IntervalStream::new(interval(REPUTATION_RECOVERY_INTERVAL)));
+
+                while ticker.next().await.is_some() {
+                    reputations.recover(recovery);
+                    pending_actions.retry(&gossip_command_tx);
+                }
+
+                info!("Recovery stopped.");
+            });
+        }
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+
+            while let Some(ReputationWorkerEvent { peer_id, misbehaviour }) = receiver.next().await {
+                let penalty = match misbehaviour {
+                    Misbehaviour::InvalidMessage => config.invalid_message_penalty,
+                    Misbehaviour::InvalidPacket => config.invalid_packet_penalty,
+                    Misbehaviour::UnsolicitedResponse => config.unsolicited_response_penalty,
+                    Misbehaviour::ExcessiveRequest => config.excessive_request_penalty,
+                };
+
+                peer_manager
+                    .get_map(&peer_id, |peer| match misbehaviour {
+                        Misbehaviour::InvalidMessage => {
+                            peer.0.metrics().invalid_messages_inc();
+                        }
+                        Misbehaviour::UnsolicitedResponse => {
+                            peer.0.metrics().unsolicited_messages_inc();
+                        }
+                        Misbehaviour::InvalidPacket | Misbehaviour::ExcessiveRequest => {}
+                    })
+                    .unwrap_or_default();
+
+                let (before, after) = reputations.penalize(peer_id, penalty);
+
+                debug!(
+                    "Reputation of {} decreased from {} to {} ({:?}).",
+                    alias!(peer_id),
+                    before,
+                    after,
+                    misbehaviour
+                );
+
+                if after <= config.disconnect_threshold && peer_manager.is_connected(&peer_id) {
+                    warn!(
+                        "Disconnecting {} because of a low reputation ({}).",
+                        alias!(peer_id),
+                        after
+                    );
+
+                    pending_actions.send(&gossip_command_tx, peer_id, PeerAction::Disconnect);
+                }
+
+                if before > config.ban_threshold && after <= config.ban_threshold {
+                    warn!("Banning {} because of a low reputation ({}).", alias!(peer_id), after);
+
+                    pending_actions.send(&gossip_command_tx, peer_id, PeerAction::Ban);
+                }
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self { tx })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn reputations_penalize_recover() {
+        let reputations = Reputations::default();
+        let peer_id = PeerId::random();
+
+        assert_eq!(reputations.penalize(peer_id, 30), (MAX_REPUTATION, MAX_REPUTATION - 30));
+        assert_eq!(
+            reputations.penalize(peer_id, u32::MAX),
+            (MAX_REPUTATION - 30, MIN_REPUTATION)
+        );
+
+        reputations.recover(10);
+        assert_eq!(reputations.0.lock().get(&peer_id), Some(&(MIN_REPUTATION + 10)));
+
+        reputations.recover(u32::MAX);
+        assert!(reputations.0.lock().is_empty());
+        assert_eq!(reputations.penalize(peer_id, 0), (MAX_REPUTATION, MAX_REPUTATION));
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::address::Address;
+use thiserror::Error;
+
+/// Errors occuring when deriving keys or signing with them.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Invalid seed length.
+    #[error("Invalid seed length, should be between 16 and 64 bytes, was {0}.")]
+    InvalidSeedLength(usize),
+    /// Invalid path segment, only hardened segments below 2^31 are supported.
+    #[error("Invalid path segment {0}.")]
+    InvalidPathSegment(u32),
+    /// Invalid path string.
+    #[error("Invalid path {0}.")]
+    InvalidPath(String),
+    /// The number of keys doesn't match the number of inputs of the essence.
+    #[error("Mismatch between the number of inputs {0} and keys {1}.")]
+    InputKeyCountMismatch(usize, usize),
+    /// None of the keys unlocks the address.
+    #[error("No key unlocking address {0:?}.")]
+    MissingKey(Address),
+    /// Message error.
+    #[error("Message error: {0}.")]
+    Message(#[from] bee_message::Error),
+}
@@ -0,0 +1,46 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use bee_message::{
+    payload::transaction::Essence,
+    signature::SignatureUnlock,
+    unlock::{ReferenceUnlock, UnlockBlock, UnlockBlocks},
+};
+
+use crate::binary::{Ed25519PrivateKey, Error};
+
+/// Signs a transaction essence, given the private key unlocking each of its inputs, in order.
+///
+/// Only the first input unlocked by a key gets a signature unlock block, the following ones reference it.
+pub fn sign_essence(essence: &Essence, keys: &[&Ed25519PrivateKey]) -> Result<UnlockBlocks, Error> {
+    let inputs = match essence {
+        Essence::Regular(essence) => essence.inputs(),
+    };
+
+    if inputs.len() != keys.len() {
+        return Err(Error::InputKeyCountMismatch(inputs.len(), keys.len()));
+    }
+
+    let hash = essence.hash();
+    let mut signature_indexes = HashMap::new();
+    let mut unlock_blocks = Vec::with_capacity(keys.len());
+
+    for (index, key) in keys.iter().enumerate() {
+        #[allow(clippy::cast_possible_truncation)] // The number of inputs has already been checked by the essence.
+        let index = index as u16;
+
+        let unlock_block = match signature_indexes.get(key.public_key()) {
+            Some(signature_index) => UnlockBlock::Reference(ReferenceUnlock::new(*signature_index)?),
+            None => {
+                signature_indexes.insert(*key.public_key(), index);
+                UnlockBlock::Signature(SignatureUnlock::Ed25519(key.sign(&hash)))
+            }
+        };
+
+        unlock_blocks.push(unlock_block);
+    }
+
+    Ok(UnlockBlocks::new(unlock_blocks)?)
+}
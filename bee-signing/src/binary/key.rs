@@ -0,0 +1,77 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common_derive::{SecretDebug, SecretDisplay, SecretDrop};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    payload::transaction::Signer,
+    signature::{Ed25519Signature, SignatureUnlock},
+};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::{SecretKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH},
+};
+use zeroize::Zeroize;
+
+use crate::binary::Error;
+
+/// Ed25519 private key to sign transaction essences with.
+#[derive(SecretDebug, SecretDisplay, SecretDrop)]
+pub struct Ed25519PrivateKey {
+    secret: [u8; SECRET_KEY_LENGTH],
+    public: [u8; PUBLIC_KEY_LENGTH],
+}
+
+impl Zeroize for Ed25519PrivateKey {
+    fn zeroize(&mut self) {
+        self.secret.zeroize()
+    }
+}
+
+impl Ed25519PrivateKey {
+    /// Creates an `Ed25519PrivateKey` from bytes.
+    pub fn from_bytes(secret: [u8; SECRET_KEY_LENGTH]) -> Self {
+        let public = SecretKey::from_bytes(secret).public_key().to_bytes();
+
+        Self { secret, public }
+    }
+
+    /// Returns the inner bytes.
+    pub fn as_bytes(&self) -> &[u8; SECRET_KEY_LENGTH] {
+        &self.secret
+    }
+
+    /// Returns the public key of the private key.
+    pub fn public_key(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
+        &self.public
+    }
+
+    /// Returns the Ed25519 address, i.e. the Blake2b hash of the public key, unlocked by the private key.
+    pub fn address(&self) -> Ed25519Address {
+        Ed25519Address::new(Blake2b256::digest(&self.public).into())
+    }
+
+    /// Returns the bech32 encoding of the address unlocked by the private key.
+    pub fn bech32_address(&self, hrp: &str) -> String {
+        Address::from(self.address()).to_bech32(hrp)
+    }
+
+    /// Signs a message.
+    pub fn sign(&self, msg: &[u8]) -> Ed25519Signature {
+        let signature = SecretKey::from_bytes(self.secret).sign(msg);
+
+        Ed25519Signature::new(self.public, signature.to_bytes())
+    }
+}
+
+impl Signer for Ed25519PrivateKey {
+    type Error = Error;
+
+    fn sign(&self, address: &Address, essence_hash: &[u8; 32]) -> Result<SignatureUnlock, Self::Error> {
+        if *address != Address::from(self.address()) {
+            return Err(Error::MissingKey(*address));
+        }
+
+        Ok(SignatureUnlock::Ed25519(Ed25519PrivateKey::sign(self, essence_hash)))
+    }
+}
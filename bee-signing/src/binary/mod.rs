@@ -0,0 +1,20 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Binary signing scheme primitives.
+//!
+//! Ed25519 keys are derived from a seed along SLIP-10 paths and used to unlock the inputs of Chrysalis transactions.
+
+mod error;
+mod essence;
+mod key;
+mod path;
+mod seed;
+
+pub use self::{
+    error::Error,
+    essence::sign_essence,
+    key::Ed25519PrivateKey,
+    path::{Bip44Path, BIP44_PURPOSE, IOTA_COIN_TYPE},
+    seed::{Ed25519Seed, ED25519_SEED_LENGTH_RANGE},
+};
@@ -0,0 +1,94 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{fmt, str::FromStr};
+
+use crypto::keys::slip10::{Chain, Segment};
+
+use crate::binary::Error;
+
+/// The BIP-44 purpose of a derivation path.
+pub const BIP44_PURPOSE: u32 = 44;
+/// The SLIP-44 coin type of IOTA.
+pub const IOTA_COIN_TYPE: u32 = 4218;
+
+/// A BIP-44 path `m/44'/4218'/account'/change'/index'` to derive Ed25519 keys along.
+///
+/// SLIP-10 only defines hardened derivation for Ed25519, hence all the segments of the path are hardened.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Bip44Path {
+    account: u32,
+    change: u32,
+    index: u32,
+}
+
+impl Bip44Path {
+    /// Creates a new `Bip44Path`.
+    pub fn new(account: u32, change: u32, index: u32) -> Result<Self, Error> {
+        for segment in [account, change, index] {
+            if segment >= Segment::HARDEN_MASK {
+                return Err(Error::InvalidPathSegment(segment));
+            }
+        }
+
+        Ok(Self { account, change, index })
+    }
+
+    /// Returns the account of a `Bip44Path`.
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+
+    /// Returns the change of a `Bip44Path`.
+    pub fn change(&self) -> u32 {
+        self.change
+    }
+
+    /// Returns the address index of a `Bip44Path`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(crate) fn to_chain(self) -> Chain {
+        Chain::from_u32_hardened([BIP44_PURPOSE, IOTA_COIN_TYPE, self.account, self.change, self.index])
+    }
+}
+
+impl fmt::Display for Bip44Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m/{}'/{}'/{}'/{}'/{}'",
+            BIP44_PURPOSE, IOTA_COIN_TYPE, self.account, self.change, self.index
+        )
+    }
+}
+
+impl FromStr for Bip44Path {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidPath(s.to_owned());
+        let mut segments = s.split('/');
+
+        if segments.next() != Some("m") {
+            return Err(invalid());
+        }
+
+        let mut values = [0u32; 5];
+
+        for value in values.iter_mut() {
+            *value = segments
+                .next()
+                .and_then(|segment| segment.strip_suffix('\''))
+                .and_then(|segment| segment.parse().ok())
+                .ok_or_else(invalid)?;
+        }
+
+        if segments.next().is_some() || values[0] != BIP44_PURPOSE || values[1] != IOTA_COIN_TYPE {
+            return Err(invalid());
+        }
+
+        Self::new(values[2], values[3], values[4])
+    }
+}
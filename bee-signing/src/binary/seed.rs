@@ -0,0 +1,64 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::RangeInclusive;
+
+use bee_common_derive::{SecretDebug, SecretDisplay, SecretDrop};
+use crypto::keys::slip10::{Curve, Seed};
+use rand::RngCore;
+use zeroize::Zeroize;
+
+use crate::binary::{Bip44Path, Ed25519PrivateKey, Error};
+
+/// The range of valid seed lengths in bytes, as defined by BIP-32.
+pub const ED25519_SEED_LENGTH_RANGE: RangeInclusive<usize> = 16..=64;
+
+/// The length in bytes of a randomly generated seed.
+const RANDOM_SEED_LENGTH: usize = 32;
+
+/// Binary seed to derive Ed25519 private keys from along SLIP-10 paths.
+#[derive(SecretDebug, SecretDisplay, SecretDrop)]
+pub struct Ed25519Seed(Vec<u8>);
+
+impl Zeroize for Ed25519Seed {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Ed25519Seed {
+    /// Creates a new random `Ed25519Seed`.
+    pub fn rand() -> Self {
+        // `ThreadRng` implements `CryptoRng` so it is safe to use in cryptographic contexts.
+        // https://rust-random.github.io/rand/rand/trait.CryptoRng.html
+        let mut seed = vec![0u8; RANDOM_SEED_LENGTH];
+
+        rand::thread_rng().fill_bytes(&mut seed);
+
+        Self(seed)
+    }
+
+    /// Creates an `Ed25519Seed` from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if !ED25519_SEED_LENGTH_RANGE.contains(&bytes.len()) {
+            return Err(Error::InvalidSeedLength(bytes.len()));
+        }
+
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Returns the inner bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Derives the Ed25519 private key of a path.
+    pub fn derive(&self, path: &Bip44Path) -> Ed25519PrivateKey {
+        let key = Seed::from_bytes(&self.0)
+            .derive(Curve::Ed25519, &path.to_chain())
+            // Safe to unwrap since derivation only fails for non-hardened segments.
+            .unwrap();
+
+        Ed25519PrivateKey::from_bytes(key.secret_key().to_bytes())
+    }
+}
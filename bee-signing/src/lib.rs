@@ -12,5 +12,7 @@
     clippy::cast_possible_wrap
 )]
 
+pub mod binary;
+
 #[deprecated(note = "`bee-signing` will no longer be supported.")]
 pub mod ternary;
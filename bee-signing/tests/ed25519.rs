@@ -0,0 +1,220 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{input::Input, payload::transaction::Essence, prelude::*};
+use bee_signing::binary::{sign_essence, Bip44Path, Ed25519PrivateKey, Ed25519Seed, Error};
+
+const SEED: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+const TRANSACTION_ID: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+// Fields are: account, change, index, private key, public key, address, bech32 address.
+const KEYS: [(u32, u32, u32, &str, &str, &str, &str); 3] = [
+    (
+        0,
+        0,
+        0,
+        "af9d2be25f70db5f3ffd0a0b76b712623c4bef435a7a9e6ea09fcb123c03a4a3",
+        "d48c252cba31d45382b25bee05f28c49e9a3485b4a85dda965829b9e5368463a",
+        "4573b0090df3bc80368d9067d73e4671585a97a2bde6c4da8b7ba7ea18a279bc",
+        "iota1qpzh8vqfphemeqpk3kgx04e7gec4sk5h5277d3x63da606sc5fumcl0sr8t",
+    ),
+    (
+        0,
+        0,
+        1,
+        "7b1c9c441425792634d9f8bf3dbd3322669ff87b9dbc3b236473e6d9be749c33",
+        "5b6f78c2a5ae5d8baa00c0d04f16a8b5b9b212922a56c427a338699818471962",
+        "5c63470ad4a278a8c3cc969eb783ddafd943286b8027a680b7307023f4900f2e",
+        "iota1qpwxx3c26j3832xrejtfadurmkhajsegdwqz0f5qkuc8qgl5jq8ju7gcdw4",
+    ),
+    (
+        1,
+        1,
+        5,
+        "d26ef7ff171dee1f33dc1d95ed11c92457f4ba9c3aae5543409a85f46b584e0c",
+        "0fcc46d386fdd47f415669f7164de38ba950dce169469cc37eebdf1ee20d0c9c",
+        "8de30b3a59668c5dcffd0ec6507dbf442349ff43e1d1668b4b304f3a48b0b4c6",
+        "iota1qzx7xze6t9ngchw0l58vv5rahazzxj0lg0saze5tfvcy7wjgkz6vv7cw8hw",
+    ),
+];
+
+fn seed() -> Ed25519Seed {
+    Ed25519Seed::from_bytes(&hex::decode(SEED).unwrap()).unwrap()
+}
+
+fn essence(input_count: u16) -> Essence {
+    let txid = TransactionId::new(hex::decode(TRANSACTION_ID).unwrap().try_into().unwrap());
+    let inputs = (0..input_count)
+        .map(|index| Input::Utxo(UtxoInput::new(txid, index).unwrap()))
+        .collect();
+    let address = Address::from(Ed25519Address::new([0; 32]));
+    let output = Output::SignatureLockedSingle(SignatureLockedSingleOutput::new(address, 1_000_000).unwrap());
+
+    Essence::Regular(
+        RegularEssence::builder()
+            .with_inputs(inputs)
+            .with_outputs(vec![output])
+            .finish()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn seed_invalid_length() {
+    assert!(matches!(
+        Ed25519Seed::from_bytes(&[0; 15]),
+        Err(Error::InvalidSeedLength(15))
+    ));
+    assert!(matches!(
+        Ed25519Seed::from_bytes(&[0; 65]),
+        Err(Error::InvalidSeedLength(65))
+    ));
+}
+
+#[test]
+fn seed_rand() {
+    let path = Bip44Path::new(0, 0, 0).unwrap();
+
+    assert_ne!(
+        Ed25519Seed::rand().derive(&path).public_key(),
+        Ed25519Seed::rand().derive(&path).public_key()
+    );
+}
+
+#[test]
+fn path_new_invalid_segment() {
+    assert!(matches!(
+        Bip44Path::new(0, 1 << 31, 0),
+        Err(Error::InvalidPathSegment(0x8000_0000))
+    ));
+}
+
+#[test]
+fn path_display_from_str() {
+    let path = Bip44Path::new(1, 0, 42).unwrap();
+
+    assert_eq!(path.to_string(), "m/44'/4218'/1'/0'/42'");
+    assert_eq!(path.to_string().parse::<Bip44Path>().unwrap(), path);
+}
+
+#[test]
+fn path_from_str_invalid() {
+    for path in [
+        "",
+        "m",
+        "44'/4218'/0'/0'/0'",
+        "m/44'/4218'/0'/0'",
+        "m/44'/4218'/0'/0'/0'/0'",
+        "m/44'/4218'/0'/0'/0",
+        "m/44'/4219'/0'/0'/0'",
+        "m/45'/4218'/0'/0'/0'",
+        "m/44'/4218'/a'/0'/0'",
+    ] {
+        assert!(
+            matches!(path.parse::<Bip44Path>(), Err(Error::InvalidPath(_))),
+            "{}",
+            path
+        );
+    }
+
+    assert!(matches!(
+        "m/44'/4218'/2147483648'/0'/0'".parse::<Bip44Path>(),
+        Err(Error::InvalidPathSegment(0x8000_0000))
+    ));
+}
+
+#[test]
+fn derive() {
+    let seed = seed();
+
+    for (account, change, index, private_key, public_key, address, bech32_address) in KEYS {
+        let key = seed.derive(&Bip44Path::new(account, change, index).unwrap());
+
+        assert_eq!(hex::encode(key.as_bytes()), private_key);
+        assert_eq!(hex::encode(key.public_key()), public_key);
+        assert_eq!(key.address().to_string(), address);
+        assert_eq!(key.bech32_address("iota"), bech32_address);
+    }
+}
+
+#[test]
+fn sign_verify() {
+    let key = seed().derive(&Bip44Path::new(0, 0, 0).unwrap());
+    let signature = key.sign(b"message");
+
+    assert!(key.address().verify(b"message", &signature).is_ok());
+    assert!(key.address().verify(b"other message", &signature).is_err());
+}
+
+#[test]
+fn sign_essence_references() {
+    let seed = seed();
+    let key_0 = seed.derive(&Bip44Path::new(0, 0, 0).unwrap());
+    let key_1 = seed.derive(&Bip44Path::new(0, 0, 1).unwrap());
+    let essence = essence(4);
+
+    let unlock_blocks = sign_essence(&essence, &[&key_0, &key_1, &key_0, &key_1]).unwrap();
+
+    assert!(matches!(unlock_blocks[0], UnlockBlock::Signature(_)));
+    assert!(matches!(unlock_blocks[1], UnlockBlock::Signature(_)));
+    assert!(matches!(&unlock_blocks[2], UnlockBlock::Reference(r) if r.index() == 0));
+    assert!(matches!(&unlock_blocks[3], UnlockBlock::Reference(r) if r.index() == 1));
+
+    for (index, key) in [&key_0, &key_1, &key_0, &key_1].iter().enumerate() {
+        match unlock_blocks.get(index) {
+            Some(UnlockBlock::Signature(signature)) => {
+                Address::from(key.address()).verify(&essence.hash(), signature).unwrap()
+            }
+            _ => panic!("missing signature for input {}", index),
+        }
+    }
+
+    TransactionPayload::builder()
+        .with_essence(essence)
+        .with_unlock_blocks(unlock_blocks)
+        .finish()
+        .unwrap();
+}
+
+#[test]
+fn sign_essence_key_count_mismatch() {
+    let key = Ed25519PrivateKey::from_bytes([1; 32]);
+
+    assert!(matches!(
+        sign_essence(&essence(2), &[&key]),
+        Err(Error::InputKeyCountMismatch(2, 1))
+    ));
+}
+
+#[test]
+fn transaction_builder_signer() {
+    let seed = seed();
+    let key_0 = seed.derive(&Bip44Path::new(0, 0, 0).unwrap());
+    let key_1 = seed.derive(&Bip44Path::new(0, 0, 1).unwrap());
+    let txid = TransactionId::new(hex::decode(TRANSACTION_ID).unwrap().try_into().unwrap());
+    let address = Address::from(key_0.address());
+    let available_outputs = (0..2)
+        .map(|index| {
+            UnspentOutput::new(
+                OutputId::new(txid, index).unwrap(),
+                Output::SignatureLockedSingle(SignatureLockedSingleOutput::new(address, 1_000_000).unwrap()),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let builder = || {
+        TransactionBuilder::new()
+            .with_available_outputs(available_outputs.clone())
+            .add_output(Output::SignatureLockedSingle(
+                SignatureLockedSingleOutput::new(Address::from(Ed25519Address::new([0; 32])), 2_000_000).unwrap(),
+            ))
+    };
+
+    let payload = builder().finish(&key_0).unwrap();
+
+    assert!(
+        matches!(&payload.unlock_blocks()[0], UnlockBlock::Signature(s) if address.verify(&payload.essence().hash(), s).is_ok())
+    );
+    assert!(matches!(&payload.unlock_blocks()[1], UnlockBlock::Reference(r) if r.index() == 0));
+    assert!(matches!(builder().finish(&key_1), Err(bee_message::Error::Signer(_))));
+}
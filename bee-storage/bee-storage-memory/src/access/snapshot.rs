@@ -0,0 +1,74 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Snapshot access operations.
+
+use std::marker::PhantomData;
+
+use bee_ledger::types::{Balance, ConsumedOutput, CreatedOutput, LedgerIndex, TreasuryOutput};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    output::OutputId,
+};
+use bee_storage::{
+    access::{Snapshot, SnapshotBuilder},
+    backend::StorageBackend,
+};
+
+use crate::{
+    storage::Storage,
+    table::{SingletonTable, Table, VecBinTable, VecTable},
+};
+
+/// A read-only view of the storage at the point in time it was taken.
+///
+/// Tables are copy-on-write, so a snapshot shares the tables it can read with the storage and never blocks writes.
+/// Writes that happen while a snapshot is alive copy the tables they modify instead.
+pub struct StorageSnapshot<'a> {
+    ledger_index: SingletonTable<LedgerIndex>,
+    output_id_to_created_output: Table<OutputId, CreatedOutput>,
+    output_id_to_consumed_output: Table<OutputId, ConsumedOutput>,
+    ed25519_address_to_output_id: VecBinTable<Ed25519Address, OutputId>,
+    address_to_balance: Table<Address, Balance>,
+    spent_to_treasury_output: VecTable<bool, TreasuryOutput>,
+    _storage: PhantomData<&'a Storage>,
+}
+
+impl<'a> SnapshotBuilder<'a> for Storage {
+    type Snapshot = StorageSnapshot<'a>;
+
+    fn snapshot(&'a self) -> Result<Self::Snapshot, <Self as StorageBackend>::Error> {
+        let inner = self.inner.read()?;
+
+        Ok(StorageSnapshot {
+            ledger_index: inner.ledger_index.clone(),
+            output_id_to_created_output: inner.output_id_to_created_output.clone(),
+            output_id_to_consumed_output: inner.output_id_to_consumed_output.clone(),
+            ed25519_address_to_output_id: inner.ed25519_address_to_output_id.clone(),
+            address_to_balance: inner.address_to_balance.clone(),
+            spent_to_treasury_output: inner.spent_to_treasury_output.clone(),
+            _storage: PhantomData,
+        })
+    }
+}
+
+macro_rules! impl_snapshot {
+    ($key:ty, $value:ty, $field:ident) => {
+        impl<'a> Snapshot<'a, $key, $value> for Storage {
+            fn snapshot_fetch(
+                &self,
+                snapshot: &Self::Snapshot,
+                k: &$key,
+            ) -> Result<Option<$value>, <Self as StorageBackend>::Error> {
+                Ok(snapshot.$field.fetch(k))
+            }
+        }
+    };
+}
+
+impl_snapshot!((), LedgerIndex, ledger_index);
+impl_snapshot!(OutputId, CreatedOutput, output_id_to_created_output);
+impl_snapshot!(OutputId, ConsumedOutput, output_id_to_consumed_output);
+impl_snapshot!(Ed25519Address, Vec<OutputId>, ed25519_address_to_output_id);
+impl_snapshot!(Address, Balance, address_to_balance);
+impl_snapshot!(bool, Vec<TreasuryOutput>, spent_to_treasury_output);
@@ -6,6 +6,7 @@ use std::{
     hash::Hash,
     iter::Map,
     option::IntoIter as OptionIter,
+    sync::Arc,
     vec::IntoIter as VecIter,
 };
 
@@ -14,8 +15,10 @@ use crate::storage::Error;
 pub(crate) type TableIter<K, V> = Map<HashMapIter<K, V>, fn((K, V)) -> Result<(K, V), Error>>;
 pub(crate) type TableMultiFetchIter<V> = Map<VecIter<Option<V>>, fn(Option<V>) -> Result<Option<V>, Error>>;
 
+// Tables are copy-on-write: cloning one is cheap, and the first write to a shared table copies its content. This is
+// how snapshots get a point-in-time view without blocking writes.
 pub(crate) struct Table<K, V> {
-    inner: HashMap<K, V>,
+    inner: Arc<HashMap<K, V>>,
 }
 
 impl<K, V> Default for Table<K, V> {
@@ -26,6 +29,14 @@ impl<K, V> Default for Table<K, V> {
     }
 }
 
+impl<K, V> Clone for Table<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<K: Hash + Eq + Clone, V: Clone> Table<K, V> {
     pub(crate) fn fetch(&self, k: &K) -> Option<V> {
         self.inner.get(k).cloned()
@@ -36,30 +47,32 @@ impl<K: Hash + Eq + Clone, V: Clone> Table<K, V> {
     }
 
     pub(crate) fn insert(&mut self, k: &K, v: &V) {
-        self.inner.insert(k.clone(), v.clone());
+        Arc::make_mut(&mut self.inner).insert(k.clone(), v.clone());
     }
 
     pub(crate) fn delete(&mut self, k: &K) {
-        self.inner.remove(k);
+        Arc::make_mut(&mut self.inner).remove(k);
     }
 
     pub(crate) fn truncate(&mut self) {
-        self.inner.clear();
+        self.inner = Default::default();
     }
 
     pub(crate) fn update(&mut self, k: &K, f: impl FnOnce(&mut V)) {
-        self.inner.get_mut(k).map(f);
+        Arc::make_mut(&mut self.inner).get_mut(k).map(f);
     }
 
     pub(crate) fn iter(&self) -> TableIter<K, V> {
-        self.inner.clone().into_iter().map(Ok)
+        (*self.inner).clone().into_iter().map(Ok)
     }
 
     pub(crate) fn batch_commit(&mut self, batch: TableBatch<K, V>) {
+        let inner = Arc::make_mut(&mut self.inner);
+
         for op in batch.0 {
             match op {
-                BatchOp::Insert(k, v) => self.inner.insert(k, v),
-                BatchOp::Delete(k) => self.inner.remove(&k),
+                BatchOp::Insert(k, v) => inner.insert(k, v),
+                BatchOp::Delete(k) => inner.remove(&k),
             };
         }
     }
@@ -108,7 +121,7 @@ impl<K: Clone, V> Iterator for VecTableIter<K, V> {
 }
 
 pub(crate) struct VecTable<K, V> {
-    inner: HashMap<K, Vec<V>>,
+    inner: Arc<HashMap<K, Vec<V>>>,
 }
 
 impl<K, V> Default for VecTable<K, V> {
@@ -119,6 +132,14 @@ impl<K, V> Default for VecTable<K, V> {
     }
 }
 
+impl<K, V> Clone for VecTable<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
     pub(crate) fn fetch(&self, k: &K) -> Option<Vec<V>> {
         self.inner.get(k).cloned().or_else(|| Some(vec![]))
@@ -129,7 +150,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
     }
 
     pub(crate) fn insert(&mut self, (k, v): &(K, V), _: &()) {
-        let vs = self.inner.entry(k.clone()).or_default();
+        let vs = Arc::make_mut(&mut self.inner).entry(k.clone()).or_default();
 
         if !vs.contains(v) {
             vs.push(v.clone());
@@ -137,7 +158,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
     }
 
     pub(crate) fn delete(&mut self, (k, v): &(K, V)) {
-        if let Some(vs) = self.inner.get_mut(k) {
+        if let Some(vs) = Arc::make_mut(&mut self.inner).get_mut(k) {
             for (i, found) in vs.iter().enumerate() {
                 if found == v {
                     vs.remove(i);
@@ -148,25 +169,27 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
     }
 
     pub(crate) fn truncate(&mut self) {
-        self.inner.clear();
+        self.inner = Default::default();
     }
 
     pub(crate) fn iter(&self) -> VecTableIter<K, V> {
-        VecTableIter::new(self.inner.clone().into_iter())
+        VecTableIter::new((*self.inner).clone().into_iter())
     }
 
     pub(crate) fn batch_commit(&mut self, batch: TableBatch<(K, V), ()>) {
+        let inner = Arc::make_mut(&mut self.inner);
+
         for op in batch.0 {
             match op {
                 BatchOp::Insert((k, v), ()) => {
-                    let vs = self.inner.entry(k).or_default();
+                    let vs = inner.entry(k).or_default();
 
                     if !vs.contains(&v) {
                         vs.push(v);
                     }
                 }
                 BatchOp::Delete((k, v)) => {
-                    if let Some(vs) = self.inner.get_mut(&k) {
+                    if let Some(vs) = inner.get_mut(&k) {
                         for (i, found) in vs.iter().enumerate() {
                             if found == &v {
                                 vs.remove(i);
@@ -181,7 +204,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
 }
 
 pub(crate) struct VecBinTable<K, V> {
-    inner: HashMap<K, Vec<V>>,
+    inner: Arc<HashMap<K, Vec<V>>>,
 }
 
 impl<K, V> Default for VecBinTable<K, V> {
@@ -192,6 +215,14 @@ impl<K, V> Default for VecBinTable<K, V> {
     }
 }
 
+impl<K, V> Clone for VecBinTable<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
     pub(crate) fn fetch(&self, k: &K) -> Option<Vec<V>> {
         self.inner.get(k).cloned().or_else(|| Some(vec![]))
@@ -202,7 +233,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
     }
 
     pub(crate) fn insert(&mut self, (k, v): &(K, V), _: &()) {
-        let vs = self.inner.entry(k.clone()).or_default();
+        let vs = Arc::make_mut(&mut self.inner).entry(k.clone()).or_default();
 
         if let Err(i) = vs.binary_search(v) {
             vs.insert(i, v.clone());
@@ -210,7 +241,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
     }
 
     pub(crate) fn delete(&mut self, (k, v): &(K, V)) {
-        if let Some(vs) = self.inner.get_mut(k) {
+        if let Some(vs) = Arc::make_mut(&mut self.inner).get_mut(k) {
             if let Ok(i) = vs.binary_search(v) {
                 vs.remove(i);
             }
@@ -218,25 +249,27 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
     }
 
     pub(crate) fn truncate(&mut self) {
-        self.inner.clear();
+        self.inner = Default::default();
     }
 
     pub(crate) fn iter(&self) -> VecTableIter<K, V> {
-        VecTableIter::new(self.inner.clone().into_iter())
+        VecTableIter::new((*self.inner).clone().into_iter())
     }
 
     pub(crate) fn batch_commit(&mut self, batch: TableBatch<(K, V), ()>) {
+        let inner = Arc::make_mut(&mut self.inner);
+
         for op in batch.0 {
             match op {
                 BatchOp::Insert((k, v), ()) => {
-                    let vs = self.inner.entry(k).or_default();
+                    let vs = inner.entry(k).or_default();
 
                     if let Err(i) = vs.binary_search(&v) {
                         vs.insert(i, v);
                     }
                 }
                 BatchOp::Delete((k, v)) => {
-                    if let Some(vs) = self.inner.get_mut(&k) {
+                    if let Some(vs) = inner.get_mut(&k) {
                         if let Ok(i) = vs.binary_search(&v) {
                             vs.remove(i);
                         }
@@ -249,6 +282,7 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
 
 pub(crate) type SingletonTableIter<V> = Map<OptionIter<V>, fn(V) -> Result<((), V), Error>>;
 
+#[derive(Clone)]
 pub(crate) struct SingletonTable<V> {
     inner: Option<V>,
 }
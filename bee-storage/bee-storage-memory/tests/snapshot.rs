@@ -0,0 +1,37 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(snapshot_access_memory, snapshot_access);
+
+#[test]
+fn snapshot_point_in_time_memory() {
+    use bee_ledger::types::LedgerIndex;
+    use bee_message::milestone::MilestoneIndex;
+    use bee_storage::{
+        access::{Batch, BatchBuilder, Snapshot, SnapshotBuilder},
+        backend::StorageBackend,
+    };
+    use bee_storage_memory::storage::Storage;
+
+    let storage = Storage::start(()).unwrap();
+    let snapshot = storage.snapshot().unwrap();
+
+    let mut batch = Storage::batch_begin();
+    Batch::<(), LedgerIndex>::batch_insert(&storage, &mut batch, &(), &LedgerIndex::from(MilestoneIndex::from(42)))
+        .unwrap();
+    storage.batch_commit(batch, true).unwrap();
+
+    assert!(Snapshot::<(), LedgerIndex>::snapshot_fetch(&storage, &snapshot, &())
+        .unwrap()
+        .is_none());
+
+    let snapshot = storage.snapshot().unwrap();
+
+    assert_eq!(
+        Snapshot::<(), LedgerIndex>::snapshot_fetch(&storage, &snapshot, &()).unwrap(),
+        Some(LedgerIndex::from(MilestoneIndex::from(42)))
+    );
+}
@@ -8,5 +8,6 @@ pub mod fetch;
 pub mod insert;
 pub mod iter;
 pub mod multi_fetch;
+pub mod snapshot;
 pub mod truncate;
 pub mod update;
@@ -0,0 +1,20 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::access::{Snapshot, SnapshotBuilder};
+
+use crate::Storage;
+
+impl<'a> SnapshotBuilder<'a> for Storage {
+    type Snapshot = ();
+
+    fn snapshot(&'a self) -> Result<Self::Snapshot, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a, K, V> Snapshot<'a, K, V> for Storage {
+    fn snapshot_fetch(&self, _snapshot: &Self::Snapshot, _key: &K) -> Result<Option<V>, Self::Error> {
+        Ok(None)
+    }
+}
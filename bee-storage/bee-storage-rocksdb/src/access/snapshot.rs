@@ -0,0 +1,130 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{Balance, ConsumedOutput, CreatedOutput, LedgerIndex, TreasuryOutput};
+use bee_message::{
+    address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
+    output::{OutputId, OUTPUT_ID_LENGTH},
+};
+use bee_storage::access::{Snapshot, SnapshotBuilder};
+use rocksdb::{Direction, IteratorMode, ReadOptions};
+
+use crate::{
+    column_families::*,
+    storage::{Storage, StorageBackend},
+};
+
+impl<'a> SnapshotBuilder<'a> for Storage {
+    type Snapshot = rocksdb::Snapshot<'a>;
+
+    fn snapshot(&'a self) -> Result<Self::Snapshot, <Self as StorageBackend>::Error> {
+        Ok(self.inner.snapshot())
+    }
+}
+
+impl<'a> Snapshot<'a, (), LedgerIndex> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        (): &(),
+    ) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
+        Ok(snapshot
+            .get_cf(self.cf_handle(CF_LEDGER_INDEX)?, [0x00u8])?
+            // Unpacking from storage is fine.
+            .map(|v| LedgerIndex::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl<'a> Snapshot<'a, OutputId, CreatedOutput> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        output_id: &OutputId,
+    ) -> Result<Option<CreatedOutput>, <Self as StorageBackend>::Error> {
+        Ok(snapshot
+            .get_cf(self.cf_handle(CF_OUTPUT_ID_TO_CREATED_OUTPUT)?, output_id.pack_new())?
+            // Unpacking from storage is fine.
+            .map(|v| CreatedOutput::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl<'a> Snapshot<'a, OutputId, ConsumedOutput> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        output_id: &OutputId,
+    ) -> Result<Option<ConsumedOutput>, <Self as StorageBackend>::Error> {
+        Ok(snapshot
+            .get_cf(self.cf_handle(CF_OUTPUT_ID_TO_CONSUMED_OUTPUT)?, output_id.pack_new())?
+            // Unpacking from storage is fine.
+            .map(|v| ConsumedOutput::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        address: &Ed25519Address,
+    ) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let mut options = ReadOptions::default();
+        options.set_prefix_same_as_start(true);
+
+        Ok(Some(
+            snapshot
+                .iterator_cf_opt(
+                    self.cf_handle(CF_ED25519_ADDRESS_TO_OUTPUT_ID)?,
+                    options,
+                    IteratorMode::From(address.as_ref(), Direction::Forward),
+                )
+                .map(|(key, _)| {
+                    let (_, output_id) = key.split_at(ED25519_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    TryFrom::<[u8; OUTPUT_ID_LENGTH]>::try_from(output_id.try_into().unwrap()).unwrap()
+                })
+                .take(self.config.fetch_output_id_limit)
+                .collect(),
+        ))
+    }
+}
+
+impl<'a> Snapshot<'a, Address, Balance> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        address: &Address,
+    ) -> Result<Option<Balance>, <Self as StorageBackend>::Error> {
+        Ok(snapshot
+            .get_cf(self.cf_handle(CF_ADDRESS_TO_BALANCE)?, address.pack_new())?
+            // Unpacking from storage is fine.
+            .map(|v| Balance::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+impl<'a> Snapshot<'a, bool, Vec<TreasuryOutput>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        spent: &bool,
+    ) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
+        let mut options = ReadOptions::default();
+        options.set_prefix_same_as_start(true);
+
+        Ok(Some(
+            snapshot
+                .iterator_cf_opt(
+                    self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?,
+                    options,
+                    IteratorMode::From(&spent.pack_new(), Direction::Forward),
+                )
+                .map(|(mut key, _)| {
+                    let (_, output) = key.split_at_mut(std::mem::size_of::<bool>());
+                    // Unpacking from storage is fine.
+                    #[allow(clippy::useless_asref)]
+                    TreasuryOutput::unpack_unchecked(&mut output.as_ref()).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
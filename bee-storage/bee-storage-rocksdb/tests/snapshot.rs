@@ -0,0 +1,7 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(snapshot_access_rocksdb, snapshot_access);
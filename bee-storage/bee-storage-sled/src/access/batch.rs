@@ -32,6 +32,7 @@ use crate::{storage::Storage, trees::*};
 #[derive(Default)]
 pub struct StorageBatch {
     inner: BTreeMap<&'static str, sled::Batch>,
+    snapshot_keys: BTreeMap<&'static str, Vec<Vec<u8>>>,
     key_buf: Vec<u8>,
     value_buf: Vec<u8>,
 }
@@ -46,6 +47,12 @@ impl BatchBuilder for Storage {
             .map(|tree| self.inner.open_tree(tree))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let _guard = self.commit_lock.write();
+
+        for (tree, keys) in batch.snapshot_keys.iter() {
+            self.record_snapshot_values(tree, keys)?;
+        }
+
         let transaction_result = Transactional::<Infallible>::transaction::<_, ()>(trees.as_slice(), |trees| {
             for (tree, batch) in trees.iter().zip(batch.inner.values()) {
                 tree.apply_batch(batch)?;
@@ -234,6 +241,11 @@ impl Batch<OutputId, CreatedOutput> for Storage {
             .entry(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)
             .or_default()
             .insert(batch.key_buf.as_slice(), batch.value_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -252,6 +264,11 @@ impl Batch<OutputId, CreatedOutput> for Storage {
             .entry(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)
             .or_default()
             .remove(batch.key_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -276,6 +293,11 @@ impl Batch<OutputId, ConsumedOutput> for Storage {
             .entry(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)
             .or_default()
             .insert(batch.key_buf.as_slice(), batch.value_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -294,6 +316,11 @@ impl Batch<OutputId, ConsumedOutput> for Storage {
             .entry(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)
             .or_default()
             .remove(batch.key_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -345,6 +372,11 @@ impl Batch<(Ed25519Address, OutputId), ()> for Storage {
             .entry(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)
             .or_default()
             .insert(batch.key_buf.as_slice(), &[]);
+        batch
+            .snapshot_keys
+            .entry(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -363,6 +395,11 @@ impl Batch<(Ed25519Address, OutputId), ()> for Storage {
             .entry(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)
             .or_default()
             .remove(batch.key_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -384,12 +421,22 @@ impl Batch<(), LedgerIndex> for Storage {
             .entry(TREE_LEDGER_INDEX)
             .or_default()
             .insert(&[0x00u8], batch.value_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_LEDGER_INDEX)
+            .or_default()
+            .push(vec![0x00u8]);
 
         Ok(())
     }
 
     fn batch_delete(&self, batch: &mut Self::Batch, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
         batch.inner.entry(TREE_LEDGER_INDEX).or_default().remove(&[0x00u8]);
+        batch
+            .snapshot_keys
+            .entry(TREE_LEDGER_INDEX)
+            .or_default()
+            .push(vec![0x00u8]);
 
         Ok(())
     }
@@ -551,21 +598,35 @@ impl Batch<Address, Balance> for Storage {
         address: &Address,
         balance: &Balance,
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        let key = address.pack_new();
+
         batch
             .inner
             .entry(TREE_ADDRESS_TO_BALANCE)
             .or_default()
-            .insert(address.pack_new(), balance.pack_new());
+            .insert(key.as_slice(), balance.pack_new());
+        batch
+            .snapshot_keys
+            .entry(TREE_ADDRESS_TO_BALANCE)
+            .or_default()
+            .push(key);
 
         Ok(())
     }
 
     fn batch_delete(&self, batch: &mut Self::Batch, address: &Address) -> Result<(), <Self as StorageBackend>::Error> {
+        let key = address.pack_new();
+
         batch
             .inner
             .entry(TREE_ADDRESS_TO_BALANCE)
             .or_default()
-            .remove(address.pack_new());
+            .remove(key.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_ADDRESS_TO_BALANCE)
+            .or_default()
+            .push(key);
 
         Ok(())
     }
@@ -665,6 +726,11 @@ impl Batch<(bool, TreasuryOutput), ()> for Storage {
             .entry(TREE_SPENT_TO_TREASURY_OUTPUT)
             .or_default()
             .insert(batch.key_buf.as_slice(), &[]);
+        batch
+            .snapshot_keys
+            .entry(TREE_SPENT_TO_TREASURY_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -683,6 +749,11 @@ impl Batch<(bool, TreasuryOutput), ()> for Storage {
             .entry(TREE_SPENT_TO_TREASURY_OUTPUT)
             .or_default()
             .remove(batch.key_buf.as_slice());
+        batch
+            .snapshot_keys
+            .entry(TREE_SPENT_TO_TREASURY_OUTPUT)
+            .or_default()
+            .push(batch.key_buf.clone());
 
         Ok(())
     }
@@ -62,9 +62,12 @@ impl Delete<(PaddedIndex, MessageId), ()> for Storage {
 
 impl Delete<OutputId, CreatedOutput> for Storage {
     fn delete(&self, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
-            .remove(output_id.pack_new())?;
+        let _guard = self.commit_lock.write();
+
+        let key = output_id.pack_new();
+
+        self.record_snapshot_values(TREE_OUTPUT_ID_TO_CREATED_OUTPUT, [&key])?;
+        self.inner.open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?.remove(key)?;
 
         Ok(())
     }
@@ -72,9 +75,12 @@ impl Delete<OutputId, CreatedOutput> for Storage {
 
 impl Delete<OutputId, ConsumedOutput> for Storage {
     fn delete(&self, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
-            .remove(output_id.pack_new())?;
+        let _guard = self.commit_lock.write();
+
+        let key = output_id.pack_new();
+
+        self.record_snapshot_values(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT, [&key])?;
+        self.inner.open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?.remove(key)?;
 
         Ok(())
     }
@@ -92,9 +98,12 @@ impl Delete<Unspent, ()> for Storage {
 
 impl Delete<(Ed25519Address, OutputId), ()> for Storage {
     fn delete(&self, (address, output_id): &(Ed25519Address, OutputId)) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
         let mut key = address.as_ref().to_vec();
         key.extend_from_slice(&output_id.pack_new());
 
+        self.record_snapshot_values(TREE_ED25519_ADDRESS_TO_OUTPUT_ID, [&key])?;
         self.inner.open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?.remove(key)?;
 
         Ok(())
@@ -103,6 +112,9 @@ impl Delete<(Ed25519Address, OutputId), ()> for Storage {
 
 impl Delete<(), LedgerIndex> for Storage {
     fn delete(&self, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
+        self.record_snapshot_values(TREE_LEDGER_INDEX, [[0x00u8]])?;
         self.inner.open_tree(TREE_LEDGER_INDEX)?.remove([0x00u8])?;
 
         Ok(())
@@ -149,9 +161,12 @@ impl Delete<MilestoneIndex, OutputDiff> for Storage {
 
 impl Delete<Address, Balance> for Storage {
     fn delete(&self, address: &Address) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .remove(address.pack_new())?;
+        let _guard = self.commit_lock.write();
+
+        let key = address.pack_new();
+
+        self.record_snapshot_values(TREE_ADDRESS_TO_BALANCE, [&key])?;
+        self.inner.open_tree(TREE_ADDRESS_TO_BALANCE)?.remove(key)?;
 
         Ok(())
     }
@@ -186,9 +201,12 @@ impl Delete<(MilestoneIndex, Receipt), ()> for Storage {
 
 impl Delete<(bool, TreasuryOutput), ()> for Storage {
     fn delete(&self, (spent, output): &(bool, TreasuryOutput)) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
         let mut key = spent.pack_new();
         key.extend_from_slice(&output.pack_new());
 
+        self.record_snapshot_values(TREE_SPENT_TO_TREASURY_OUTPUT, [&key])?;
         self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.remove(key)?;
 
         Ok(())
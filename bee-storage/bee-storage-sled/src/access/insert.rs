@@ -88,9 +88,14 @@ impl Insert<(PaddedIndex, MessageId), ()> for Storage {
 
 impl Insert<OutputId, CreatedOutput> for Storage {
     fn insert(&self, output_id: &OutputId, output: &CreatedOutput) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
+        let key = output_id.pack_new();
+
+        self.record_snapshot_values(TREE_OUTPUT_ID_TO_CREATED_OUTPUT, [&key])?;
         self.inner
             .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
-            .insert(output_id.pack_new(), output.pack_new())?;
+            .insert(key, output.pack_new())?;
 
         Ok(())
     }
@@ -98,9 +103,14 @@ impl Insert<OutputId, CreatedOutput> for Storage {
 
 impl Insert<OutputId, ConsumedOutput> for Storage {
     fn insert(&self, output_id: &OutputId, output: &ConsumedOutput) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
+        let key = output_id.pack_new();
+
+        self.record_snapshot_values(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT, [&key])?;
         self.inner
             .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
-            .insert(output_id.pack_new(), output.pack_new())?;
+            .insert(key, output.pack_new())?;
 
         Ok(())
     }
@@ -122,9 +132,12 @@ impl Insert<(Ed25519Address, OutputId), ()> for Storage {
         (address, output_id): &(Ed25519Address, OutputId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
         let mut key = address.as_ref().to_vec();
         key.extend_from_slice(&output_id.pack_new());
 
+        self.record_snapshot_values(TREE_ED25519_ADDRESS_TO_OUTPUT_ID, [&key])?;
         self.inner
             .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
             .insert(key, &[])?;
@@ -135,6 +148,9 @@ impl Insert<(Ed25519Address, OutputId), ()> for Storage {
 
 impl Insert<(), LedgerIndex> for Storage {
     fn insert(&self, (): &(), index: &LedgerIndex) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
+        self.record_snapshot_values(TREE_LEDGER_INDEX, [[0x00u8]])?;
         self.inner
             .open_tree(TREE_LEDGER_INDEX)?
             .insert([0x00u8], index.pack_new())?;
@@ -185,9 +201,14 @@ impl Insert<MilestoneIndex, OutputDiff> for Storage {
 
 impl Insert<Address, Balance> for Storage {
     fn insert(&self, address: &Address, balance: &Balance) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
+        let key = address.pack_new();
+
+        self.record_snapshot_values(TREE_ADDRESS_TO_BALANCE, [&key])?;
         self.inner
             .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .insert(address.pack_new(), balance.pack_new())?;
+            .insert(key, balance.pack_new())?;
 
         Ok(())
     }
@@ -229,9 +250,12 @@ impl Insert<(MilestoneIndex, Receipt), ()> for Storage {
 
 impl Insert<(bool, TreasuryOutput), ()> for Storage {
     fn insert(&self, (spent, output): &(bool, TreasuryOutput), (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.write();
+
         let mut key = spent.pack_new();
         key.extend_from_slice(&output.pack_new());
 
+        self.record_snapshot_values(TREE_SPENT_TO_TREASURY_OUTPUT, [&key])?;
         self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.insert(key, &[])?;
 
         Ok(())
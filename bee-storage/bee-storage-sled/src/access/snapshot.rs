@@ -0,0 +1,195 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Snapshot access operations.
+
+use std::{collections::BTreeSet, marker::PhantomData, sync::Arc};
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{Balance, ConsumedOutput, CreatedOutput, LedgerIndex, TreasuryOutput};
+use bee_message::{
+    address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
+    output::{OutputId, OUTPUT_ID_LENGTH},
+};
+use bee_storage::{
+    access::{Snapshot, SnapshotBuilder},
+    backend::StorageBackend,
+};
+use parking_lot::Mutex;
+use sled::IVec;
+
+use crate::{
+    storage::{Error, SnapshotOverlay, Storage},
+    trees::*,
+};
+
+/// A read-only view of the storage at the point in time it was taken.
+///
+/// Sled has no native snapshots. Instead, writes to the key/value pairs readable through snapshots record the values
+/// they overwrite in the overlays of the live snapshots, and snapshot reads prefer these recorded values. Reads and
+/// writes only share the commit lock for the duration of a single operation, so snapshots never block batch commits.
+/// Overlays grow with the writes that happen while a snapshot is alive, snapshots are therefore meant to be
+/// short-lived.
+pub struct StorageSnapshot<'a> {
+    overlay: Arc<Mutex<SnapshotOverlay>>,
+    _storage: PhantomData<&'a Storage>,
+}
+
+impl StorageSnapshot<'_> {
+    /// Reads the value of a key of a tree as it was when the snapshot was taken. Must be called while holding the
+    /// commit lock.
+    fn get(&self, storage: &Storage, tree: &'static str, key: &[u8]) -> Result<Option<IVec>, Error> {
+        if let Some(value) = self.overlay.lock().get(tree).and_then(|overlay| overlay.get(key)) {
+            return Ok(value.clone());
+        }
+
+        Ok(storage.inner.open_tree(tree)?.get(key)?)
+    }
+
+    /// Reads up to `limit` keys with the given prefix of a tree as they were when the snapshot was taken. Must be
+    /// called while holding the commit lock.
+    fn scan_prefix_keys(
+        &self,
+        storage: &Storage,
+        tree: &'static str,
+        prefix: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let overlay = self.overlay.lock();
+        let overlay_entries = overlay
+            .get(tree)
+            .map(|overlay| {
+                overlay
+                    .range(prefix.to_vec()..)
+                    .take_while(|(key, _)| key.starts_with(prefix))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // Every recorded value hides at most one of the current keys, scanning that many more keys is enough.
+        let mut keys = storage
+            .inner
+            .open_tree(tree)?
+            .scan_prefix(prefix)
+            .keys()
+            .take(limit.saturating_add(overlay_entries.len()))
+            .map(|key| key.map(|key| key.to_vec()))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+
+        for (key, value) in overlay_entries {
+            if value.is_some() {
+                keys.insert(key.clone());
+            } else {
+                keys.remove(key);
+            }
+        }
+
+        Ok(keys.into_iter().take(limit).collect())
+    }
+}
+
+impl<'a> SnapshotBuilder<'a> for Storage {
+    type Snapshot = StorageSnapshot<'a>;
+
+    fn snapshot(&'a self) -> Result<Self::Snapshot, <Self as StorageBackend>::Error> {
+        // Registering the overlay can't interleave with a write.
+        let _guard = self.commit_lock.read();
+        let overlay = Arc::default();
+
+        self.snapshot_overlays.lock().push(Arc::downgrade(&overlay));
+
+        Ok(StorageSnapshot {
+            overlay,
+            _storage: PhantomData,
+        })
+    }
+}
+
+impl<'a> Snapshot<'a, (), LedgerIndex> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        (): &(),
+    ) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.read();
+
+        Ok(snapshot
+            .get(self, TREE_LEDGER_INDEX, &[0x00u8])?
+            // Unpacking from storage is fine.
+            .map(|v| LedgerIndex::unpack_unchecked(&mut v.as_ref()).unwrap()))
+    }
+}
+
+macro_rules! impl_snapshot {
+    ($key:ty, $value:ty, $tree:expr) => {
+        impl<'a> Snapshot<'a, $key, $value> for Storage {
+            fn snapshot_fetch(
+                &self,
+                snapshot: &Self::Snapshot,
+                key: &$key,
+            ) -> Result<Option<$value>, <Self as StorageBackend>::Error> {
+                let _guard = self.commit_lock.read();
+
+                Ok(snapshot
+                    .get(self, $tree, &key.pack_new())?
+                    // Unpacking from storage is fine.
+                    .map(|v| <$value>::unpack_unchecked(&mut v.as_ref()).unwrap()))
+            }
+        }
+    };
+}
+
+impl_snapshot!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_snapshot!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_snapshot!(Address, Balance, TREE_ADDRESS_TO_BALANCE);
+
+impl<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        address: &Ed25519Address,
+    ) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.read();
+
+        Ok(Some(
+            snapshot
+                .scan_prefix_keys(
+                    self,
+                    TREE_ED25519_ADDRESS_TO_OUTPUT_ID,
+                    address.as_ref(),
+                    self.config.storage.fetch_output_id_limit,
+                )?
+                .into_iter()
+                .map(|key| {
+                    let (_, output_id) = key.split_at(ED25519_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    (<[u8; OUTPUT_ID_LENGTH]>::try_from(output_id).unwrap())
+                        .try_into()
+                        .unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl<'a> Snapshot<'a, bool, Vec<TreasuryOutput>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        spent: &bool,
+    ) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
+        let _guard = self.commit_lock.read();
+
+        Ok(Some(
+            snapshot
+                .scan_prefix_keys(self, TREE_SPENT_TO_TREASURY_OUTPUT, &spent.pack_new(), usize::MAX)?
+                .into_iter()
+                .map(|key| {
+                    let (_, output) = key.split_at(std::mem::size_of::<bool>());
+                    // Unpacking from storage is fine.
+                    TreasuryOutput::unpack_unchecked(&mut &output[..]).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
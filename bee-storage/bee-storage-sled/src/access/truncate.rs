@@ -33,15 +33,41 @@ macro_rules! impl_truncate {
     };
 }
 
+/// Truncates a tree readable through snapshots, recording its content in the overlays of the live snapshots first.
+macro_rules! impl_snapshot_truncate {
+    ($key:ty, $value:ty, $cf:expr) => {
+        impl Truncate<$key, $value> for Storage {
+            fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+                let _guard = self.commit_lock.write();
+
+                if !self.live_snapshot_overlays().is_empty() {
+                    let keys = self
+                        .inner
+                        .open_tree($cf)?
+                        .iter()
+                        .keys()
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    self.record_snapshot_values($cf, keys)?;
+                }
+
+                self.inner.drop_tree($cf)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
 impl_truncate!(MessageId, Message, TREE_MESSAGE_ID_TO_MESSAGE);
 impl_truncate!(MessageId, MessageMetadata, TREE_MESSAGE_ID_TO_METADATA);
 impl_truncate!((MessageId, MessageId), (), TREE_MESSAGE_ID_TO_MESSAGE_ID);
 impl_truncate!((PaddedIndex, MessageId), (), TREE_INDEX_TO_MESSAGE_ID);
-impl_truncate!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
-impl_truncate!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_snapshot_truncate!(OutputId, CreatedOutput, TREE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_snapshot_truncate!(OutputId, ConsumedOutput, TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_truncate!(Unspent, (), TREE_OUTPUT_ID_UNSPENT);
-impl_truncate!((Ed25519Address, OutputId), (), TREE_ED25519_ADDRESS_TO_OUTPUT_ID);
-impl_truncate!((), LedgerIndex, TREE_LEDGER_INDEX);
+impl_snapshot_truncate!((Ed25519Address, OutputId), (), TREE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_snapshot_truncate!((), LedgerIndex, TREE_LEDGER_INDEX);
 impl_truncate!(MilestoneIndex, Milestone, TREE_MILESTONE_INDEX_TO_MILESTONE);
 impl_truncate!((), SnapshotInfo, TREE_SNAPSHOT_INFO);
 impl_truncate!(
@@ -50,11 +76,11 @@ impl_truncate!(
     TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
 );
 impl_truncate!(MilestoneIndex, OutputDiff, TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
-impl_truncate!(Address, Balance, TREE_ADDRESS_TO_BALANCE);
+impl_snapshot_truncate!(Address, Balance, TREE_ADDRESS_TO_BALANCE);
 impl_truncate!(
     (MilestoneIndex, UnreferencedMessage),
     (),
     TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
 );
 impl_truncate!((MilestoneIndex, Receipt), (), TREE_MILESTONE_INDEX_TO_RECEIPT);
-impl_truncate!((bool, TreasuryOutput), (), TREE_SPENT_TO_TREASURY_OUTPUT);
+impl_snapshot_truncate!((bool, TreasuryOutput), (), TREE_SPENT_TO_TREASURY_OUTPUT);
@@ -3,11 +3,18 @@
 
 //! The sled storage backend.
 
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Weak},
+};
+
 use bee_storage::{
     access::{Fetch, Insert},
     backend::StorageBackend,
     system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
 };
+use parking_lot::{Mutex, RwLock};
+use sled::IVec;
 use thiserror::Error;
 
 use crate::config::{SledConfig, SledConfigBuilder};
@@ -29,10 +36,19 @@ pub enum Error {
 
 pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
 
+/// The values that key/value pairs readable through a snapshot had when the snapshot was taken, per tree, recorded by
+/// the writes that happened since. `None` stands for a key that didn't exist.
+pub(crate) type SnapshotOverlay = HashMap<&'static str, BTreeMap<Vec<u8>, Option<IVec>>>;
+
 /// The sled database.
 pub struct Storage {
     pub(crate) inner: sled::Db,
     pub(crate) config: SledConfig,
+    /// Held exclusively while a batch is committed or while a key/value pair readable through snapshots is written,
+    /// and shared while a snapshot is taken or read from.
+    pub(crate) commit_lock: RwLock<()>,
+    /// The overlays of the snapshots, dropped snapshots are cleaned up lazily.
+    pub(crate) snapshot_overlays: Mutex<Vec<Weak<Mutex<SnapshotOverlay>>>>,
 }
 
 impl Storage {
@@ -53,7 +69,55 @@ impl Storage {
 
         let inner = sled_cfg.open()?;
 
-        Ok(Self { inner, config })
+        Ok(Self {
+            inner,
+            config,
+            commit_lock: RwLock::new(()),
+            snapshot_overlays: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Returns the overlays of the live snapshots.
+    pub(crate) fn live_snapshot_overlays(&self) -> Vec<Arc<Mutex<SnapshotOverlay>>> {
+        let mut overlays = self.snapshot_overlays.lock();
+
+        overlays.retain(|overlay| overlay.strong_count() > 0);
+        overlays.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    /// Records the current values of keys of a tree that are about to be written in the overlays of the live snapshots
+    /// that don't have them yet. Must be called while holding the commit lock exclusively.
+    pub(crate) fn record_snapshot_values<K: AsRef<[u8]>>(
+        &self,
+        tree: &'static str,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Result<(), Error> {
+        let overlays = self.live_snapshot_overlays();
+
+        if overlays.is_empty() {
+            return Ok(());
+        }
+
+        let tree_handle = self.inner.open_tree(tree)?;
+
+        for key in keys {
+            let key = key.as_ref();
+            let mut value = None;
+
+            for overlay in overlays.iter() {
+                let mut overlay = overlay.lock();
+                let tree_overlay = overlay.entry(tree).or_default();
+
+                if !tree_overlay.contains_key(key) {
+                    if value.is_none() {
+                        value = Some(tree_handle.get(key)?);
+                    }
+                    tree_overlay.insert(key.to_vec(), value.clone().flatten());
+                }
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,81 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(snapshot_access_sled, snapshot_access);
+
+#[test]
+fn snapshot_point_in_time_sled() {
+    use bee_ledger::types::{Balance, LedgerIndex};
+    use bee_message::{
+        address::{Address, Ed25519Address},
+        milestone::MilestoneIndex,
+        output::OutputId,
+    };
+    use bee_storage::{
+        access::{Batch, BatchBuilder, Insert, Snapshot, SnapshotBuilder, Truncate},
+        backend::StorageBackend,
+    };
+    use bee_storage_sled::{config::SledConfigBuilder, storage::Storage};
+    use bee_test::rand::{address::rand_ed25519_address, balance::rand_balance, output::rand_output_id};
+
+    let path = String::from("./tests/database/snapshot_point_in_time");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    let address = rand_ed25519_address();
+    let (output_id, balance) = (rand_output_id(), rand_balance());
+
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::from(MilestoneIndex::from(42))).unwrap();
+    Insert::<(Ed25519Address, OutputId), ()>::insert(&storage, &(address, output_id), &()).unwrap();
+
+    let snapshot = storage.snapshot().unwrap();
+
+    let mut batch = Storage::batch_begin();
+    Batch::<(), LedgerIndex>::batch_insert(&storage, &mut batch, &(), &LedgerIndex::from(MilestoneIndex::from(43)))
+        .unwrap();
+    Batch::<Address, Balance>::batch_insert(&storage, &mut batch, &Address::Ed25519(address), &balance).unwrap();
+    Batch::<(Ed25519Address, OutputId), ()>::batch_delete(&storage, &mut batch, &(address, output_id)).unwrap();
+    Batch::<(Ed25519Address, OutputId), ()>::batch_insert(&storage, &mut batch, &(address, rand_output_id()), &())
+        .unwrap();
+    // Committing doesn't wait for the snapshot to be dropped.
+    storage.batch_commit(batch, true).unwrap();
+    Truncate::<(), LedgerIndex>::truncate(&storage).unwrap();
+
+    assert_eq!(
+        Snapshot::<(), LedgerIndex>::snapshot_fetch(&storage, &snapshot, &()).unwrap(),
+        Some(LedgerIndex::from(MilestoneIndex::from(42)))
+    );
+    assert!(
+        Snapshot::<Address, Balance>::snapshot_fetch(&storage, &snapshot, &Address::Ed25519(address))
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(&storage, &snapshot, &address).unwrap(),
+        Some(vec![output_id])
+    );
+
+    let snapshot = storage.snapshot().unwrap();
+
+    assert!(Snapshot::<(), LedgerIndex>::snapshot_fetch(&storage, &snapshot, &())
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        Snapshot::<Address, Balance>::snapshot_fetch(&storage, &snapshot, &Address::Ed25519(address)).unwrap(),
+        Some(balance)
+    );
+    assert_eq!(
+        Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(&storage, &snapshot, &address)
+            .unwrap()
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let _ = std::fs::remove_dir_all(&path);
+}
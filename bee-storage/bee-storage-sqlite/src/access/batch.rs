@@ -0,0 +1,100 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Batch access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{
+    access::{Batch, BatchBuilder},
+    backend::StorageBackend,
+};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+use rusqlite::{params_from_iter, types::Value};
+
+use crate::{statement, storage::Storage, tables::*};
+
+/// A writing batch that can be applied atomically.
+#[derive(Default)]
+pub struct StorageBatch {
+    operations: Vec<(String, Vec<Value>)>,
+}
+
+impl BatchBuilder for Storage {
+    type Batch = StorageBatch;
+
+    fn batch_commit(&self, batch: Self::Batch, durability: bool) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut connection = self.connection.lock();
+
+        // A durable batch waits for the write-ahead log to be synced to disk, a non-durable one may be lost on power
+        // failure.
+        connection.pragma_update(None, "synchronous", if durability { 2 } else { 1 })?;
+
+        let transaction = connection.transaction()?;
+
+        for (sql, params) in batch.operations {
+            transaction.prepare_cached(&sql)?.execute(params_from_iter(params))?;
+        }
+
+        transaction.commit()?;
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_batch {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Batch<$key, $value> for Storage {
+            fn batch_insert(
+                &self,
+                batch: &mut Self::Batch,
+                key: &$key,
+                value: &$value,
+            ) -> Result<(), <Self as StorageBackend>::Error> {
+                batch.operations.push((
+                    statement::insert_sql::<$table>(),
+                    statement::insert_params::<$table>(key, value),
+                ));
+
+                Ok(())
+            }
+
+            fn batch_delete(&self, batch: &mut Self::Batch, key: &$key) -> Result<(), <Self as StorageBackend>::Error> {
+                batch
+                    .operations
+                    .push((statement::delete_sql::<$table>(), <$table>::key_to_sql(key)));
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_batch!(MessageId, Message, MessageTable);
+impl_batch!(MessageId, MessageMetadata, MetadataTable);
+impl_batch!((MessageId, MessageId), (), EdgeTable);
+impl_batch!((PaddedIndex, MessageId), (), IndexationTable);
+impl_batch!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_batch!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_batch!(Unspent, (), UnspentTable);
+impl_batch!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_batch!((), LedgerIndex, LedgerIndexTable);
+impl_batch!(MilestoneIndex, Milestone, MilestoneTable);
+impl_batch!((), SnapshotInfo, SnapshotInfoTable);
+impl_batch!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_batch!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_batch!(Address, Balance, BalanceTable);
+impl_batch!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_batch!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_batch!((bool, TreasuryOutput), (), TreasuryOutputTable);
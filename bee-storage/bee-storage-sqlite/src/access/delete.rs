@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Delete access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{access::Delete, backend::StorageBackend};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use crate::{statement, storage::Storage, tables::*};
+
+macro_rules! impl_delete {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Delete<$key, $value> for Storage {
+            fn delete(&self, key: &$key) -> Result<(), <Self as StorageBackend>::Error> {
+                statement::delete::<$table>(&self.connection.lock(), key)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_delete!(MessageId, Message, MessageTable);
+impl_delete!(MessageId, MessageMetadata, MetadataTable);
+impl_delete!((MessageId, MessageId), (), EdgeTable);
+impl_delete!((PaddedIndex, MessageId), (), IndexationTable);
+impl_delete!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_delete!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_delete!(Unspent, (), UnspentTable);
+impl_delete!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_delete!((), LedgerIndex, LedgerIndexTable);
+impl_delete!(MilestoneIndex, Milestone, MilestoneTable);
+impl_delete!((), SnapshotInfo, SnapshotInfoTable);
+impl_delete!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_delete!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_delete!(Address, Balance, BalanceTable);
+impl_delete!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_delete!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_delete!((bool, TreasuryOutput), (), TreasuryOutputTable);
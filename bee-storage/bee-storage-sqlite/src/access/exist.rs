@@ -0,0 +1,50 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exist access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{access::Exist, backend::StorageBackend};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use crate::{statement, storage::Storage, tables::*};
+
+macro_rules! impl_exist {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Exist<$key, $value> for Storage {
+            fn exist(&self, key: &$key) -> Result<bool, <Self as StorageBackend>::Error> {
+                Ok(statement::exist::<$table>(&self.connection.lock(), key)?)
+            }
+        }
+    };
+}
+
+impl_exist!(MessageId, Message, MessageTable);
+impl_exist!(MessageId, MessageMetadata, MetadataTable);
+impl_exist!((MessageId, MessageId), (), EdgeTable);
+impl_exist!((PaddedIndex, MessageId), (), IndexationTable);
+impl_exist!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_exist!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_exist!(Unspent, (), UnspentTable);
+impl_exist!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_exist!((), LedgerIndex, LedgerIndexTable);
+impl_exist!(MilestoneIndex, Milestone, MilestoneTable);
+impl_exist!((), SnapshotInfo, SnapshotInfoTable);
+impl_exist!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_exist!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_exist!(Address, Balance, BalanceTable);
+impl_exist!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_exist!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_exist!((bool, TreasuryOutput), (), TreasuryOutputTable);
@@ -0,0 +1,130 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{access::Fetch, backend::StorageBackend, system::System};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+use rusqlite::types::Value;
+
+use crate::{statement, storage::Storage, tables::*};
+
+macro_rules! impl_fetch {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Fetch<$key, $value> for Storage {
+            fn fetch(&self, key: &$key) -> Result<Option<$value>, <Self as StorageBackend>::Error> {
+                Ok(statement::fetch::<$table>(&self.connection.lock(), key)?)
+            }
+        }
+    };
+}
+
+impl_fetch!(u8, System, SystemTable);
+impl_fetch!(MessageId, Message, MessageTable);
+impl_fetch!(MessageId, MessageMetadata, MetadataTable);
+impl_fetch!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_fetch!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_fetch!((), LedgerIndex, LedgerIndexTable);
+impl_fetch!(MilestoneIndex, Milestone, MilestoneTable);
+impl_fetch!((), SnapshotInfo, SnapshotInfoTable);
+impl_fetch!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_fetch!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_fetch!(Address, Balance, BalanceTable);
+
+impl Fetch<MessageId, Vec<MessageId>> for Storage {
+    fn fetch(&self, parent: &MessageId) -> Result<Option<Vec<MessageId>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<EdgeTable>(
+                &self.connection.lock(),
+                blob(parent),
+                Some(self.config.storage.fetch_edge_limit),
+            )?
+            .into_iter()
+            .map(|(_, child)| child)
+            .collect(),
+        ))
+    }
+}
+
+impl Fetch<PaddedIndex, Vec<MessageId>> for Storage {
+    fn fetch(&self, index: &PaddedIndex) -> Result<Option<Vec<MessageId>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<IndexationTable>(
+                &self.connection.lock(),
+                Value::Blob(index.as_ref().to_vec()),
+                Some(self.config.storage.fetch_index_limit),
+            )?
+            .into_iter()
+            .map(|(_, message_id)| message_id)
+            .collect(),
+        ))
+    }
+}
+
+impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
+    fn fetch(&self, address: &Ed25519Address) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<Ed25519AddressOutputTable>(
+                &self.connection.lock(),
+                blob(address),
+                Some(self.config.storage.fetch_output_id_limit),
+            )?
+            .into_iter()
+            .map(|(_, output_id)| output_id)
+            .collect(),
+        ))
+    }
+}
+
+impl Fetch<MilestoneIndex, Vec<UnreferencedMessage>> for Storage {
+    fn fetch(
+        &self,
+        index: &MilestoneIndex,
+    ) -> Result<Option<Vec<UnreferencedMessage>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<UnreferencedMessageTable>(&self.connection.lock(), integer(*index), None)?
+                .into_iter()
+                .map(|(_, unreferenced_message)| unreferenced_message)
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<MilestoneIndex, Vec<Receipt>> for Storage {
+    fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<Receipt>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<ReceiptTable>(&self.connection.lock(), integer(*index), None)?
+                .into_iter()
+                .map(|(_, receipt)| receipt)
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
+    fn fetch(&self, spent: &bool) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<TreasuryOutputTable>(
+                &self.connection.lock(),
+                Value::Integer(i64::from(*spent)),
+                None,
+            )?
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect(),
+        ))
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Insert access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{
+    access::{Insert, InsertStrict},
+    backend::StorageBackend,
+    system::System,
+};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use crate::{statement, storage::Storage, tables::*};
+
+macro_rules! impl_insert {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Insert<$key, $value> for Storage {
+            fn insert(&self, key: &$key, value: &$value) -> Result<(), <Self as StorageBackend>::Error> {
+                statement::insert::<$table>(&self.connection.lock(), key, value)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_insert!(u8, System, SystemTable);
+impl_insert!(MessageId, Message, MessageTable);
+impl_insert!((MessageId, MessageId), (), EdgeTable);
+impl_insert!((PaddedIndex, MessageId), (), IndexationTable);
+impl_insert!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_insert!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_insert!(Unspent, (), UnspentTable);
+impl_insert!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_insert!((), LedgerIndex, LedgerIndexTable);
+impl_insert!(MilestoneIndex, Milestone, MilestoneTable);
+impl_insert!((), SnapshotInfo, SnapshotInfoTable);
+impl_insert!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_insert!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_insert!(Address, Balance, BalanceTable);
+impl_insert!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_insert!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_insert!((bool, TreasuryOutput), (), TreasuryOutputTable);
+
+impl InsertStrict<MessageId, MessageMetadata> for Storage {
+    fn insert_strict(
+        &self,
+        message_id: &MessageId,
+        metadata: &MessageMetadata,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        statement::insert_strict::<MetadataTable>(&self.connection.lock(), message_id, metadata)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Iter access operations.
+
+use std::collections::VecDeque;
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{access::AsIterator, backend::StorageBackend, system::System};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use crate::{
+    statement,
+    storage::{Error, Storage},
+    tables::*,
+};
+
+/// Type used to iterate a table.
+///
+/// Rows are read in batches, following their insertion order, so that the connection is not held by the iterator
+/// between two batches.
+pub struct StorageIterator<'a, K, V> {
+    storage: &'a Storage,
+    buffer: VecDeque<(K, V)>,
+    last_rowid: i64,
+    exhausted: bool,
+}
+
+impl<'a, K, V> StorageIterator<'a, K, V> {
+    fn new(storage: &'a Storage) -> Self {
+        StorageIterator::<K, V> {
+            storage,
+            buffer: VecDeque::new(),
+            last_rowid: i64::MIN,
+            exhausted: false,
+        }
+    }
+
+    fn next_pair<T: Table<Key = K, Value = V>>(&mut self) -> Option<Result<(K, V), Error>> {
+        if self.buffer.is_empty() && !self.exhausted {
+            let batch_size = self.storage.config.storage.iteration_batch_size;
+            let rows = match statement::iter_from::<T>(&self.storage.connection.lock(), self.last_rowid, batch_size) {
+                Ok(rows) => rows,
+                Err(e) => {
+                    self.exhausted = true;
+                    return Some(Err(e.into()));
+                }
+            };
+
+            self.exhausted = rows.len() < batch_size;
+
+            for (rowid, key, value) in rows {
+                self.last_rowid = rowid;
+                self.buffer.push_back((key, value));
+            }
+        }
+
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+macro_rules! impl_iter {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl<'a> AsIterator<'a, $key, $value> for Storage {
+            type AsIter = StorageIterator<'a, $key, $value>;
+
+            fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
+                Ok(StorageIterator::new(self))
+            }
+        }
+
+        /// An iterator over all key-value pairs of a table.
+        impl<'a> Iterator for StorageIterator<'a, $key, $value> {
+            type Item = Result<($key, $value), <Storage as StorageBackend>::Error>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.next_pair::<$table>()
+            }
+        }
+    };
+}
+
+impl_iter!(u8, System, SystemTable);
+impl_iter!(MessageId, Message, MessageTable);
+impl_iter!(MessageId, MessageMetadata, MetadataTable);
+impl_iter!((MessageId, MessageId), (), EdgeTable);
+impl_iter!((PaddedIndex, MessageId), (), IndexationTable);
+impl_iter!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_iter!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_iter!(Unspent, (), UnspentTable);
+impl_iter!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_iter!((), LedgerIndex, LedgerIndexTable);
+impl_iter!(MilestoneIndex, Milestone, MilestoneTable);
+impl_iter!((), SnapshotInfo, SnapshotInfoTable);
+impl_iter!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_iter!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_iter!(Address, Balance, BalanceTable);
+impl_iter!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_iter!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_iter!((bool, TreasuryOutput), (), TreasuryOutputTable);
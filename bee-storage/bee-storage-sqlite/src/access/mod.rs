@@ -0,0 +1,15 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Access operations for the storage.
+
+pub mod batch;
+pub mod delete;
+pub mod exist;
+pub mod fetch;
+pub mod insert;
+pub mod iter;
+pub mod multi_fetch;
+pub mod snapshot;
+pub mod truncate;
+pub mod update;
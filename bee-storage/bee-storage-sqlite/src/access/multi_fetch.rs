@@ -0,0 +1,68 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Multi-fetch access operations.
+
+use std::{marker::PhantomData, slice::Iter};
+
+use bee_ledger::types::{Balance, ConsumedOutput, CreatedOutput, OutputDiff};
+use bee_message::{
+    address::Address,
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    Message, MessageId,
+};
+use bee_storage::{
+    access::{Fetch, MultiFetch},
+    backend::StorageBackend,
+    system::System,
+};
+use bee_tangle::{metadata::MessageMetadata, solid_entry_point::SolidEntryPoint};
+
+use crate::storage::{Error, Storage};
+
+/// Multi-fetch iterator over a table.
+pub struct MultiIter<'a, K, V> {
+    storage: &'a Storage,
+    keys: Iter<'a, K>,
+    marker: PhantomData<V>,
+}
+
+impl<'a, K, V> Iterator for MultiIter<'a, K, V>
+where
+    Storage: Fetch<K, V>,
+{
+    type Item = Result<Option<V>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+
+        Some(Fetch::<K, V>::fetch(self.storage, key))
+    }
+}
+
+macro_rules! impl_multi_fetch {
+    ($key:ty, $value:ty) => {
+        impl<'a> MultiFetch<'a, $key, $value> for Storage {
+            type Iter = MultiIter<'a, $key, $value>;
+
+            fn multi_fetch(&'a self, keys: &'a [$key]) -> Result<Self::Iter, <Self as StorageBackend>::Error> {
+                Ok(MultiIter {
+                    storage: self,
+                    keys: keys.iter(),
+                    marker: PhantomData,
+                })
+            }
+        }
+    };
+}
+
+impl_multi_fetch!(u8, System);
+impl_multi_fetch!(MessageId, Message);
+impl_multi_fetch!(MessageId, MessageMetadata);
+impl_multi_fetch!(OutputId, CreatedOutput);
+impl_multi_fetch!(OutputId, ConsumedOutput);
+impl_multi_fetch!(MilestoneIndex, Milestone);
+impl_multi_fetch!(SolidEntryPoint, MilestoneIndex);
+impl_multi_fetch!(MilestoneIndex, OutputDiff);
+impl_multi_fetch!(Address, Balance);
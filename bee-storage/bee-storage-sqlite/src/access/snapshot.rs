@@ -0,0 +1,102 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Snapshot access operations.
+
+use std::marker::PhantomData;
+
+use bee_ledger::types::{Balance, ConsumedOutput, CreatedOutput, LedgerIndex, TreasuryOutput};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    output::OutputId,
+};
+use bee_storage::{
+    access::{Snapshot, SnapshotBuilder},
+    backend::StorageBackend,
+};
+use rusqlite::{types::Value, Connection};
+
+use crate::{statement, storage::Storage, tables::*};
+
+/// A read-only view of the storage at the point in time it was taken.
+///
+/// It is backed by a dedicated connection holding a read transaction open; thanks to write-ahead logging, batches
+/// committed in the meantime are neither visible to it nor blocked by it.
+pub struct StorageSnapshot<'a> {
+    connection: Connection,
+    marker: PhantomData<&'a Storage>,
+}
+
+impl<'a> SnapshotBuilder<'a> for Storage {
+    type Snapshot = StorageSnapshot<'a>;
+
+    fn snapshot(&'a self) -> Result<Self::Snapshot, <Self as StorageBackend>::Error> {
+        let connection = self.open_read_only()?;
+
+        connection.execute_batch("BEGIN DEFERRED")?;
+        // A deferred transaction only starts reading, and hence pins its view of the database, on its first read.
+        connection.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(()))?;
+
+        Ok(StorageSnapshot {
+            connection,
+            marker: PhantomData,
+        })
+    }
+}
+
+macro_rules! impl_snapshot {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl<'a> Snapshot<'a, $key, $value> for Storage {
+            fn snapshot_fetch(
+                &self,
+                snapshot: &Self::Snapshot,
+                key: &$key,
+            ) -> Result<Option<$value>, <Self as StorageBackend>::Error> {
+                Ok(statement::fetch::<$table>(&snapshot.connection, key)?)
+            }
+        }
+    };
+}
+
+impl_snapshot!((), LedgerIndex, LedgerIndexTable);
+impl_snapshot!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_snapshot!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_snapshot!(Address, Balance, BalanceTable);
+
+impl<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        address: &Ed25519Address,
+    ) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<Ed25519AddressOutputTable>(
+                &snapshot.connection,
+                blob(address),
+                Some(self.config.storage.fetch_output_id_limit),
+            )?
+            .into_iter()
+            .map(|(_, output_id)| output_id)
+            .collect(),
+        ))
+    }
+}
+
+impl<'a> Snapshot<'a, bool, Vec<TreasuryOutput>> for Storage {
+    fn snapshot_fetch(
+        &self,
+        snapshot: &Self::Snapshot,
+        spent: &bool,
+    ) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            statement::fetch_prefix::<TreasuryOutputTable>(
+                &snapshot.connection,
+                Value::Integer(i64::from(*spent)),
+                None,
+            )?
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect(),
+        ))
+    }
+}
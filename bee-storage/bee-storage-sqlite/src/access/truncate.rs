@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Truncate access operations.
+
+use bee_ledger::types::{
+    snapshot::info::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt,
+    TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::{access::Truncate, backend::StorageBackend};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use crate::{statement, storage::Storage, tables::*};
+
+macro_rules! impl_truncate {
+    ($key:ty, $value:ty, $table:ty) => {
+        impl Truncate<$key, $value> for Storage {
+            fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+                statement::truncate::<$table>(&self.connection.lock())?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_truncate!(MessageId, Message, MessageTable);
+impl_truncate!(MessageId, MessageMetadata, MetadataTable);
+impl_truncate!((MessageId, MessageId), (), EdgeTable);
+impl_truncate!((PaddedIndex, MessageId), (), IndexationTable);
+impl_truncate!(OutputId, CreatedOutput, CreatedOutputTable);
+impl_truncate!(OutputId, ConsumedOutput, ConsumedOutputTable);
+impl_truncate!(Unspent, (), UnspentTable);
+impl_truncate!((Ed25519Address, OutputId), (), Ed25519AddressOutputTable);
+impl_truncate!((), LedgerIndex, LedgerIndexTable);
+impl_truncate!(MilestoneIndex, Milestone, MilestoneTable);
+impl_truncate!((), SnapshotInfo, SnapshotInfoTable);
+impl_truncate!(SolidEntryPoint, MilestoneIndex, SolidEntryPointTable);
+impl_truncate!(MilestoneIndex, OutputDiff, OutputDiffTable);
+impl_truncate!(Address, Balance, BalanceTable);
+impl_truncate!((MilestoneIndex, UnreferencedMessage), (), UnreferencedMessageTable);
+impl_truncate!((MilestoneIndex, Receipt), (), ReceiptTable);
+impl_truncate!((bool, TreasuryOutput), (), TreasuryOutputTable);
@@ -0,0 +1,26 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Update access operations.
+
+use bee_message::MessageId;
+use bee_storage::access::Update;
+use bee_tangle::metadata::MessageMetadata;
+
+use crate::{statement, storage::Storage, tables::*};
+
+impl Update<MessageId, MessageMetadata> for Storage {
+    fn update(&self, message_id: &MessageId, mut f: impl FnMut(&mut MessageMetadata)) -> Result<(), Self::Error> {
+        let mut connection = self.connection.lock();
+        let transaction = connection.transaction()?;
+
+        if let Some(mut metadata) = statement::fetch::<MetadataTable>(&transaction, message_id)? {
+            f(&mut metadata);
+            statement::insert::<MetadataTable>(&transaction, message_id, &metadata)?;
+        }
+
+        transaction.commit()?;
+
+        Ok(())
+    }
+}
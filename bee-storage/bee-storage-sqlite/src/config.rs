@@ -0,0 +1,121 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types related to the backend configuration.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+const DEFAULT_PATH: &str = "./storage/mainnet/tangle";
+const DEFAULT_CACHE_CAPACITY: usize = 64 * 1_024 * 1_024;
+const DEFAULT_BUSY_TIMEOUT: u64 = 5_000;
+const DEFAULT_FETCH_EDGE_LIMIT: usize = 1_000;
+const DEFAULT_FETCH_INDEX_LIMIT: usize = 1_000;
+const DEFAULT_FETCH_OUTPUT_ID_LIMIT: usize = 1_000;
+const DEFAULT_ITERATION_BATCH_SIZE: usize = 1_000;
+
+/// Configuration for the SQLite storage backend.
+#[derive(Clone)]
+pub struct SqliteConfig {
+    pub(crate) storage: StorageConfig,
+    pub(crate) path: PathBuf,
+    pub(crate) cache_capacity: usize,
+    pub(crate) busy_timeout: u64,
+}
+
+/// Configuration builder for the SQLite storage backend.
+#[derive(Default, Deserialize)]
+#[must_use]
+pub struct SqliteConfigBuilder {
+    storage: Option<StorageConfigBuilder>,
+    path: Option<PathBuf>,
+    #[serde(alias = "cacheCapacity")]
+    cache_capacity: Option<usize>,
+    #[serde(alias = "busyTimeout")]
+    busy_timeout: Option<u64>,
+}
+
+impl SqliteConfigBuilder {
+    /// Create a new builder with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the path of the folder where the database will be stored.
+    pub fn with_path(mut self, path: String) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the page cache maximum capacity in bytes.
+    pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity = Some(cache_capacity);
+        self
+    }
+
+    /// Set how long, in milliseconds, an operation waits for a lock held by another connection, e.g. an external
+    /// analytics session, before failing.
+    pub fn with_busy_timeout(mut self, busy_timeout: u64) -> Self {
+        self.busy_timeout = Some(busy_timeout);
+        self
+    }
+
+    /// Build the configuration.
+    #[must_use]
+    pub fn finish(self) -> SqliteConfig {
+        SqliteConfig {
+            storage: self.storage.unwrap_or_default().finish(),
+            path: self.path.unwrap_or_else(|| DEFAULT_PATH.into()),
+            cache_capacity: self.cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY),
+            busy_timeout: self.busy_timeout.unwrap_or(DEFAULT_BUSY_TIMEOUT),
+        }
+    }
+}
+
+impl From<SqliteConfigBuilder> for SqliteConfig {
+    fn from(builder: SqliteConfigBuilder) -> Self {
+        builder.finish()
+    }
+}
+
+/// Configuration related to the access operations of the storage.
+#[derive(Clone)]
+pub struct StorageConfig {
+    pub(crate) fetch_edge_limit: usize,
+    pub(crate) fetch_index_limit: usize,
+    pub(crate) fetch_output_id_limit: usize,
+    pub(crate) iteration_batch_size: usize,
+}
+
+/// Configuration builder related to the access operations of the storage.
+#[derive(Default, Deserialize)]
+#[must_use]
+pub struct StorageConfigBuilder {
+    #[serde(alias = "fetchEdgeLimit")]
+    fetch_edge_limit: Option<usize>,
+    #[serde(alias = "fetchIndexLimit")]
+    fetch_index_limit: Option<usize>,
+    #[serde(alias = "fetchOutputIdLimit")]
+    fetch_output_id_limit: Option<usize>,
+    #[serde(alias = "iterationBatchSize")]
+    iteration_batch_size: Option<usize>,
+}
+
+impl StorageConfigBuilder {
+    /// Create a new builder with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the configuration.
+    #[must_use]
+    pub fn finish(self) -> StorageConfig {
+        StorageConfig {
+            fetch_edge_limit: self.fetch_edge_limit.unwrap_or(DEFAULT_FETCH_EDGE_LIMIT),
+            fetch_index_limit: self.fetch_index_limit.unwrap_or(DEFAULT_FETCH_INDEX_LIMIT),
+            fetch_output_id_limit: self.fetch_output_id_limit.unwrap_or(DEFAULT_FETCH_OUTPUT_ID_LIMIT),
+            iteration_batch_size: self.iteration_batch_size.unwrap_or(DEFAULT_ITERATION_BATCH_SIZE),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bee storage backend using [SQLite](https://sqlite.org).
+
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+pub mod access;
+pub mod config;
+pub mod storage;
+pub mod tables;
+
+mod statement;
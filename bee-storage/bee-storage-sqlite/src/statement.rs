@@ -0,0 +1,171 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Statements shared by the access operations, generated from the layout of each table.
+
+use rusqlite::{params, params_from_iter, types::Value, Connection, OptionalExtension};
+
+use crate::tables::Table;
+
+/// A (key, value) pair along with the identifier of its row.
+pub(crate) type IdentifiedPair<T> = (i64, <T as Table>::Key, <T as Table>::Value);
+
+fn columns<T: Table>() -> String {
+    T::KEY_COLUMNS
+        .iter()
+        .chain(T::VALUE_COLUMNS)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn placeholders<T: Table>() -> String {
+    (1..=T::KEY_COLUMNS.len() + T::VALUE_COLUMNS.len())
+        .map(|i| format!("?{}", i))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn key_predicate<T: Table>() -> String {
+    T::KEY_COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(i, column)| format!("{} = ?{}", column, i + 1))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// Statement inserting a (key, value) pair, overwriting the value of an existing key.
+pub(crate) fn insert_sql<T: Table>() -> String {
+    let conflict = if T::VALUE_COLUMNS.is_empty() {
+        "NOTHING".to_owned()
+    } else {
+        format!(
+            "UPDATE SET {}",
+            T::VALUE_COLUMNS
+                .iter()
+                .map(|column| format!("{0} = excluded.{0}", column))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+
+    format!(
+        "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO {}",
+        T::NAME,
+        columns::<T>(),
+        placeholders::<T>(),
+        T::KEY_COLUMNS.join(", "),
+        conflict
+    )
+}
+
+/// Statement deleting a (key, value) pair.
+pub(crate) fn delete_sql<T: Table>() -> String {
+    format!("DELETE FROM {} WHERE {}", T::NAME, key_predicate::<T>())
+}
+
+/// Parameters of the statement returned by `insert_sql`.
+pub(crate) fn insert_params<T: Table>(key: &T::Key, value: &T::Value) -> Vec<Value> {
+    let mut params = T::key_to_sql(key);
+    params.extend(T::value_to_sql(value));
+    params
+}
+
+pub(crate) fn fetch<T: Table>(connection: &Connection, key: &T::Key) -> rusqlite::Result<Option<T::Value>> {
+    connection
+        .prepare_cached(&format!(
+            "SELECT {} FROM {} WHERE {}",
+            columns::<T>(),
+            T::NAME,
+            key_predicate::<T>()
+        ))?
+        .query_row(params_from_iter(T::key_to_sql(key)), T::value_from_row)
+        .optional()
+}
+
+/// Fetches the keys whose first column is equal to `prefix`, e.g. the children of a parent.
+pub(crate) fn fetch_prefix<T: Table>(
+    connection: &Connection,
+    prefix: Value,
+    limit: Option<usize>,
+) -> rusqlite::Result<Vec<T::Key>> {
+    // A negative limit means no limit to SQLite.
+    let limit = limit.map_or(-1, |limit| limit as i64);
+
+    connection
+        .prepare_cached(&format!(
+            "SELECT {} FROM {} WHERE {} = ?1 LIMIT ?2",
+            columns::<T>(),
+            T::NAME,
+            T::KEY_COLUMNS[0]
+        ))?
+        .query_map(params![prefix, limit], T::key_from_row)?
+        .collect()
+}
+
+pub(crate) fn exist<T: Table>(connection: &Connection, key: &T::Key) -> rusqlite::Result<bool> {
+    connection
+        .prepare_cached(&format!(
+            "SELECT EXISTS (SELECT 1 FROM {} WHERE {})",
+            T::NAME,
+            key_predicate::<T>()
+        ))?
+        .query_row(params_from_iter(T::key_to_sql(key)), |row| row.get(0))
+}
+
+pub(crate) fn insert<T: Table>(connection: &Connection, key: &T::Key, value: &T::Value) -> rusqlite::Result<()> {
+    connection
+        .prepare_cached(&insert_sql::<T>())?
+        .execute(params_from_iter(insert_params::<T>(key, value)))?;
+
+    Ok(())
+}
+
+/// Inserts a (key, value) pair only if the key is not already present.
+pub(crate) fn insert_strict<T: Table>(connection: &Connection, key: &T::Key, value: &T::Value) -> rusqlite::Result<()> {
+    connection
+        .prepare_cached(&format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT DO NOTHING",
+            T::NAME,
+            columns::<T>(),
+            placeholders::<T>()
+        ))?
+        .execute(params_from_iter(insert_params::<T>(key, value)))?;
+
+    Ok(())
+}
+
+pub(crate) fn delete<T: Table>(connection: &Connection, key: &T::Key) -> rusqlite::Result<()> {
+    connection
+        .prepare_cached(&delete_sql::<T>())?
+        .execute(params_from_iter(T::key_to_sql(key)))?;
+
+    Ok(())
+}
+
+pub(crate) fn truncate<T: Table>(connection: &Connection) -> rusqlite::Result<()> {
+    connection
+        .prepare_cached(&format!("DELETE FROM {}", T::NAME))?
+        .execute([])?;
+
+    Ok(())
+}
+
+/// Fetches at most `limit` pairs stored after the row `after`, along with their row identifiers.
+pub(crate) fn iter_from<T: Table>(
+    connection: &Connection,
+    after: i64,
+    limit: usize,
+) -> rusqlite::Result<Vec<IdentifiedPair<T>>> {
+    connection
+        .prepare_cached(&format!(
+            "SELECT rowid, {} FROM {} WHERE rowid > ?1 ORDER BY rowid LIMIT ?2",
+            columns::<T>(),
+            T::NAME
+        ))?
+        .query_map(params![after, limit as i64], |row| {
+            Ok((row.get(0)?, T::key_from_row(row)?, T::value_from_row(row)?))
+        })?
+        .collect()
+}
@@ -0,0 +1,152 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The SQLite storage backend.
+
+use std::{path::PathBuf, time::Duration};
+
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+    system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
+};
+use parking_lot::Mutex;
+use rusqlite::{Connection, OpenFlags};
+use thiserror::Error;
+
+use crate::{
+    config::{SqliteConfig, SqliteConfigBuilder},
+    tables::SCHEMA,
+};
+
+/// Name of the database file within the storage folder.
+pub const DATABASE_FILE_NAME: &str = "tangle.sqlite";
+
+const PREPARED_STATEMENT_CACHE_CAPACITY: usize = 256;
+
+/// Error to be raised when a backend operation fails.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A SQLite operation failed.
+    #[error("SQLite internal error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    /// The storage folder could not be created.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// There is a storage version mismatch between the storage folder and this version of the
+    /// storage.
+    #[error("Storage version mismatch, {0:?} != {1:?}, remove storage folder and restart")]
+    VersionMismatch(StorageVersion, StorageVersion),
+    /// The storage was not closed properly.
+    #[error("Unhealthy storage: {0:?}, remove storage folder and restart")]
+    UnhealthyStorage(StorageHealth),
+}
+
+pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
+
+/// The SQLite database.
+pub struct Storage {
+    pub(crate) connection: Mutex<Connection>,
+    pub(crate) config: SqliteConfig,
+}
+
+impl Storage {
+    /// Create a new database from the provided configuration.
+    pub fn new(config: SqliteConfig) -> Result<Self, Error> {
+        std::fs::create_dir_all(&config.path)?;
+
+        let connection = Connection::open(config.database_path())?;
+
+        connection.set_prepared_statement_cache_capacity(PREPARED_STATEMENT_CACHE_CAPACITY);
+        connection.busy_timeout(Duration::from_millis(config.busy_timeout))?;
+        // Write-ahead logging lets snapshots and external readers run concurrently with the node writing.
+        connection.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+        connection.pragma_update(None, "synchronous", 1)?;
+        // A negative value is interpreted by SQLite as a number of KiB instead of a number of pages.
+        connection.pragma_update(None, "cache_size", -((config.cache_capacity / 1_024) as i64))?;
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+            config,
+        })
+    }
+
+    /// Opens a new read-only connection to the database.
+    pub(crate) fn open_read_only(&self) -> Result<Connection, Error> {
+        let connection = Connection::open_with_flags(
+            self.config.database_path(),
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+
+        connection.busy_timeout(Duration::from_millis(self.config.busy_timeout))?;
+
+        Ok(connection)
+    }
+}
+
+impl SqliteConfig {
+    fn database_path(&self) -> PathBuf {
+        self.path.join(DATABASE_FILE_NAME)
+    }
+}
+
+impl StorageBackend for Storage {
+    type ConfigBuilder = SqliteConfigBuilder;
+    type Config = SqliteConfig;
+    type Error = Error;
+
+    fn start(config: Self::Config) -> Result<Self, Self::Error> {
+        let storage = Self::new(config)?;
+
+        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(version)) => {
+                if version != STORAGE_VERSION {
+                    return Err(Error::VersionMismatch(version, STORAGE_VERSION));
+                }
+            }
+            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
+            _ => panic!("Another system value was inserted on the version key."),
+        }
+
+        if let Some(health) = storage.get_health()? {
+            if health != StorageHealth::Healthy {
+                return Err(Self::Error::UnhealthyStorage(health));
+            }
+        }
+
+        storage.set_health(StorageHealth::Idle)?;
+
+        Ok(storage)
+    }
+
+    fn shutdown(self) -> Result<(), Self::Error> {
+        self.set_health(StorageHealth::Healthy)?;
+
+        let connection = self.connection.into_inner();
+        connection.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
+        connection.close().map_err(|(_, e)| e)?;
+
+        Ok(())
+    }
+
+    fn size(&self) -> Result<Option<usize>, Self::Error> {
+        let connection = self.connection.lock();
+        let page_count = connection.pragma_query_value(None, "page_count", |row| row.get::<_, usize>(0))?;
+        let page_size = connection.pragma_query_value(None, "page_size", |row| row.get::<_, usize>(0))?;
+
+        Ok(Some(page_count * page_size))
+    }
+
+    fn get_health(&self) -> Result<Option<StorageHealth>, Self::Error> {
+        Ok(match Fetch::<u8, System>::fetch(self, &SYSTEM_HEALTH_KEY)? {
+            Some(System::Health(health)) => Some(health),
+            None => None,
+            _ => panic!("Another system value was inserted on the health key."),
+        })
+    }
+
+    fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error> {
+        Insert::<u8, System>::insert(self, &SYSTEM_HEALTH_KEY, &System::Health(health))
+    }
+}
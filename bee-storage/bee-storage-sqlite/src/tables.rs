@@ -0,0 +1,776 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Identifiers and layout of each table.
+//!
+//! Every (key, value) pair is stored in its own table of the relational schema. Identifiers, addresses and other
+//! composite types are stored as their packed bytes in `BLOB` columns while indexes and amounts are stored as `INTEGER`
+//! columns. Some fields of the values are duplicated into dedicated, indexed columns so that they can be used in ad-hoc
+//! SQL queries.
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
+    Message, MessageId,
+};
+use bee_storage::system::System;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+use rusqlite::{types::Value, Row};
+
+/// Identifier for the `u8` to `System` table.
+pub const TABLE_SYSTEM: &str = "system";
+/// Identifier for the `MessageId` to `Message` table.
+pub const TABLE_MESSAGE_ID_TO_MESSAGE: &str = "message";
+/// Identifier for the `MessageId` to `MessageMetadata` table.
+pub const TABLE_MESSAGE_ID_TO_METADATA: &str = "message_metadata";
+/// Identifier for the `MessageId` to `Vec<MessageId>` table.
+pub const TABLE_MESSAGE_ID_TO_MESSAGE_ID: &str = "message_edge";
+/// Identifier for the `PaddedIndex` to `Vec<MessageId>` table.
+pub const TABLE_INDEX_TO_MESSAGE_ID: &str = "indexation";
+/// Identifier for the `OutputId` to `CreatedOutput` table.
+pub const TABLE_OUTPUT_ID_TO_CREATED_OUTPUT: &str = "created_output";
+/// Identifier for the `OutputId` to `ConsumedOutput` table.
+pub const TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT: &str = "consumed_output";
+/// Identifier for the `Unspent` table.
+pub const TABLE_OUTPUT_ID_UNSPENT: &str = "unspent_output";
+/// Identifier for the `Ed25519Address` to `OutputId` table.
+pub const TABLE_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_output";
+/// Identifier for the `LedgerIndex` table.
+pub const TABLE_LEDGER_INDEX: &str = "ledger_index";
+/// Identifier for the `MilestoneIndex` to `Milestone` table.
+pub const TABLE_MILESTONE_INDEX_TO_MILESTONE: &str = "milestone";
+/// Identifier for the `SnapshotInfo` table.
+pub const TABLE_SNAPSHOT_INFO: &str = "snapshot_info";
+/// Identifier for the `SolidEntryPoint` to `MilestoneIndex` table.
+pub const TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX: &str = "solid_entry_point";
+/// Identifier for the `MilestoneIndex` to `OutputDiff` table.
+pub const TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF: &str = "output_diff";
+/// Identifier for the `Address` to `Balance` table.
+pub const TABLE_ADDRESS_TO_BALANCE: &str = "balance";
+/// Identifier for the `MilestoneIndex` to `Vec<UnreferencedMessage>` table.
+pub const TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE: &str = "unreferenced_message";
+/// Identifier for the `MilestoneIndex` to `Vec<Receipt>` table.
+pub const TABLE_MILESTONE_INDEX_TO_RECEIPT: &str = "receipt";
+/// Identifier for the `bool` to `Vec<TreasuryOutput>` table.
+pub const TABLE_SPENT_TO_TREASURY_OUTPUT: &str = "treasury_output";
+
+/// Statements creating the schema of the database if it does not exist yet.
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS system (
+    key INTEGER PRIMARY KEY,
+    value BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS message (
+    message_id BLOB PRIMARY KEY,
+    message BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS message_metadata (
+    message_id BLOB PRIMARY KEY,
+    milestone_index INTEGER,
+    arrival_timestamp INTEGER NOT NULL,
+    metadata BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS message_metadata_milestone_index ON message_metadata (milestone_index);
+
+CREATE TABLE IF NOT EXISTS message_edge (
+    parent BLOB NOT NULL,
+    child BLOB NOT NULL,
+    PRIMARY KEY (parent, child)
+);
+CREATE INDEX IF NOT EXISTS message_edge_child ON message_edge (child);
+
+CREATE TABLE IF NOT EXISTS indexation (
+    padded_index BLOB NOT NULL,
+    message_id BLOB NOT NULL,
+    PRIMARY KEY (padded_index, message_id)
+);
+CREATE INDEX IF NOT EXISTS indexation_message_id ON indexation (message_id);
+
+CREATE TABLE IF NOT EXISTS created_output (
+    output_id BLOB PRIMARY KEY,
+    message_id BLOB NOT NULL,
+    output BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS created_output_message_id ON created_output (message_id);
+
+CREATE TABLE IF NOT EXISTS consumed_output (
+    output_id BLOB PRIMARY KEY,
+    target BLOB NOT NULL,
+    milestone_index INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS consumed_output_target ON consumed_output (target);
+CREATE INDEX IF NOT EXISTS consumed_output_milestone_index ON consumed_output (milestone_index);
+
+CREATE TABLE IF NOT EXISTS unspent_output (
+    output_id BLOB PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS ed25519_address_output (
+    address BLOB NOT NULL,
+    output_id BLOB NOT NULL,
+    PRIMARY KEY (address, output_id)
+);
+
+CREATE TABLE IF NOT EXISTS ledger_index (
+    key INTEGER PRIMARY KEY CHECK (key = 0),
+    milestone_index INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS milestone (
+    milestone_index INTEGER PRIMARY KEY,
+    message_id BLOB NOT NULL,
+    timestamp INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS milestone_message_id ON milestone (message_id);
+CREATE INDEX IF NOT EXISTS milestone_timestamp ON milestone (timestamp);
+
+CREATE TABLE IF NOT EXISTS snapshot_info (
+    key INTEGER PRIMARY KEY CHECK (key = 0),
+    snapshot_index INTEGER NOT NULL,
+    entry_point_index INTEGER NOT NULL,
+    pruning_index INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    info BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS solid_entry_point (
+    message_id BLOB PRIMARY KEY,
+    milestone_index INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS solid_entry_point_milestone_index ON solid_entry_point (milestone_index);
+
+CREATE TABLE IF NOT EXISTS output_diff (
+    milestone_index INTEGER PRIMARY KEY,
+    diff BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS balance (
+    address BLOB PRIMARY KEY,
+    amount INTEGER NOT NULL,
+    dust_allowance INTEGER NOT NULL,
+    dust_outputs INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS unreferenced_message (
+    milestone_index INTEGER NOT NULL,
+    message_id BLOB NOT NULL,
+    PRIMARY KEY (milestone_index, message_id)
+);
+
+CREATE TABLE IF NOT EXISTS receipt (
+    milestone_index INTEGER NOT NULL,
+    receipt BLOB NOT NULL,
+    PRIMARY KEY (milestone_index, receipt)
+);
+
+CREATE TABLE IF NOT EXISTS treasury_output (
+    spent INTEGER NOT NULL,
+    output BLOB NOT NULL,
+    PRIMARY KEY (spent, output)
+);
+";
+
+/// Describes how a (key, value) pair is mapped to the columns of its table.
+pub(crate) trait Table {
+    /// Type of the key.
+    type Key;
+    /// Type of the value.
+    type Value;
+
+    /// Name of the table.
+    const NAME: &'static str;
+    /// Columns forming the primary key of the table, the first one being used for prefix fetches.
+    const KEY_COLUMNS: &'static [&'static str];
+    /// Columns holding the value.
+    const VALUE_COLUMNS: &'static [&'static str];
+
+    /// Maps a key to the values of the `KEY_COLUMNS`.
+    fn key_to_sql(key: &Self::Key) -> Vec<Value>;
+
+    /// Maps a value to the values of the `VALUE_COLUMNS`.
+    fn value_to_sql(value: &Self::Value) -> Vec<Value>;
+
+    /// Reads a key from a row containing the `KEY_COLUMNS`.
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<Self::Key>;
+
+    /// Reads a value from a row containing the `VALUE_COLUMNS`.
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<Self::Value>;
+}
+
+pub(crate) fn blob<P: Packable>(packable: &P) -> Value {
+    Value::Blob(packable.pack_new())
+}
+
+pub(crate) fn integer(index: MilestoneIndex) -> Value {
+    Value::Integer(i64::from(*index))
+}
+
+// SQLite integers are signed, amounts and timestamps are stored as their two's complement so that they round-trip.
+fn unsigned(value: u64) -> Value {
+    Value::Integer(value as i64)
+}
+
+fn unpack<P: Packable>(row: &Row<'_>, column: &str) -> rusqlite::Result<P> {
+    let bytes = row.get::<_, Vec<u8>>(column)?;
+    // Unpacking from storage is fine.
+    Ok(P::unpack_unchecked(&mut bytes.as_slice()).unwrap())
+}
+
+fn milestone_index(row: &Row<'_>, column: &str) -> rusqlite::Result<MilestoneIndex> {
+    Ok(MilestoneIndex(row.get(column)?))
+}
+
+fn unsigned_from_row(row: &Row<'_>, column: &str) -> rusqlite::Result<u64> {
+    Ok(row.get::<_, i64>(column)? as u64)
+}
+
+/// The `u8` to `System` table.
+pub(crate) struct SystemTable;
+
+impl Table for SystemTable {
+    type Key = u8;
+    type Value = System;
+
+    const NAME: &'static str = TABLE_SYSTEM;
+    const KEY_COLUMNS: &'static [&'static str] = &["key"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["value"];
+
+    fn key_to_sql(key: &u8) -> Vec<Value> {
+        vec![Value::Integer(i64::from(*key))]
+    }
+
+    fn value_to_sql(value: &System) -> Vec<Value> {
+        vec![blob(value)]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<u8> {
+        row.get("key")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<System> {
+        unpack(row, "value")
+    }
+}
+
+/// The `MessageId` to `Message` table.
+pub(crate) struct MessageTable;
+
+impl Table for MessageTable {
+    type Key = MessageId;
+    type Value = Message;
+
+    const NAME: &'static str = TABLE_MESSAGE_ID_TO_MESSAGE;
+    const KEY_COLUMNS: &'static [&'static str] = &["message_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["message"];
+
+    fn key_to_sql(message_id: &MessageId) -> Vec<Value> {
+        vec![blob(message_id)]
+    }
+
+    fn value_to_sql(message: &Message) -> Vec<Value> {
+        vec![blob(message)]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<MessageId> {
+        unpack(row, "message_id")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<Message> {
+        unpack(row, "message")
+    }
+}
+
+/// The `MessageId` to `MessageMetadata` table.
+pub(crate) struct MetadataTable;
+
+impl Table for MetadataTable {
+    type Key = MessageId;
+    type Value = MessageMetadata;
+
+    const NAME: &'static str = TABLE_MESSAGE_ID_TO_METADATA;
+    const KEY_COLUMNS: &'static [&'static str] = &["message_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["milestone_index", "arrival_timestamp", "metadata"];
+
+    fn key_to_sql(message_id: &MessageId) -> Vec<Value> {
+        vec![blob(message_id)]
+    }
+
+    fn value_to_sql(metadata: &MessageMetadata) -> Vec<Value> {
+        vec![
+            metadata.milestone_index().map_or(Value::Null, integer),
+            unsigned(metadata.arrival_timestamp()),
+            blob(metadata),
+        ]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<MessageId> {
+        unpack(row, "message_id")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<MessageMetadata> {
+        unpack(row, "metadata")
+    }
+}
+
+/// The `(MessageId, MessageId)` to `()` table.
+pub(crate) struct EdgeTable;
+
+impl Table for EdgeTable {
+    type Key = (MessageId, MessageId);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_MESSAGE_ID_TO_MESSAGE_ID;
+    const KEY_COLUMNS: &'static [&'static str] = &["parent", "child"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((parent, child): &(MessageId, MessageId)) -> Vec<Value> {
+        vec![blob(parent), blob(child)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(MessageId, MessageId)> {
+        Ok((unpack(row, "parent")?, unpack(row, "child")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `(PaddedIndex, MessageId)` to `()` table.
+pub(crate) struct IndexationTable;
+
+impl Table for IndexationTable {
+    type Key = (PaddedIndex, MessageId);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_INDEX_TO_MESSAGE_ID;
+    const KEY_COLUMNS: &'static [&'static str] = &["padded_index", "message_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((index, message_id): &(PaddedIndex, MessageId)) -> Vec<Value> {
+        vec![Value::Blob(index.as_ref().to_vec()), blob(message_id)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(PaddedIndex, MessageId)> {
+        let index = row.get::<_, Vec<u8>>("padded_index")?;
+        // Unpacking from storage is fine.
+        let index: [u8; INDEXATION_PADDED_INDEX_LENGTH] = index.try_into().unwrap();
+
+        Ok((PaddedIndex::new(index), unpack(row, "message_id")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `OutputId` to `CreatedOutput` table.
+pub(crate) struct CreatedOutputTable;
+
+impl Table for CreatedOutputTable {
+    type Key = OutputId;
+    type Value = CreatedOutput;
+
+    const NAME: &'static str = TABLE_OUTPUT_ID_TO_CREATED_OUTPUT;
+    const KEY_COLUMNS: &'static [&'static str] = &["output_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["message_id", "output"];
+
+    fn key_to_sql(output_id: &OutputId) -> Vec<Value> {
+        vec![blob(output_id)]
+    }
+
+    fn value_to_sql(output: &CreatedOutput) -> Vec<Value> {
+        vec![blob(output.message_id()), blob(output)]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<OutputId> {
+        unpack(row, "output_id")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<CreatedOutput> {
+        unpack(row, "output")
+    }
+}
+
+/// The `OutputId` to `ConsumedOutput` table.
+pub(crate) struct ConsumedOutputTable;
+
+impl Table for ConsumedOutputTable {
+    type Key = OutputId;
+    type Value = ConsumedOutput;
+
+    const NAME: &'static str = TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT;
+    const KEY_COLUMNS: &'static [&'static str] = &["output_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["target", "milestone_index"];
+
+    fn key_to_sql(output_id: &OutputId) -> Vec<Value> {
+        vec![blob(output_id)]
+    }
+
+    fn value_to_sql(output: &ConsumedOutput) -> Vec<Value> {
+        vec![blob(output.target()), integer(output.index())]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<OutputId> {
+        unpack(row, "output_id")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<ConsumedOutput> {
+        Ok(ConsumedOutput::new(
+            unpack(row, "target")?,
+            milestone_index(row, "milestone_index")?,
+        ))
+    }
+}
+
+/// The `Unspent` to `()` table.
+pub(crate) struct UnspentTable;
+
+impl Table for UnspentTable {
+    type Key = Unspent;
+    type Value = ();
+
+    const NAME: &'static str = TABLE_OUTPUT_ID_UNSPENT;
+    const KEY_COLUMNS: &'static [&'static str] = &["output_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql(unspent: &Unspent) -> Vec<Value> {
+        vec![blob(unspent.id())]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<Unspent> {
+        Ok(Unspent::new(unpack(row, "output_id")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `(Ed25519Address, OutputId)` to `()` table.
+pub(crate) struct Ed25519AddressOutputTable;
+
+impl Table for Ed25519AddressOutputTable {
+    type Key = (Ed25519Address, OutputId);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_ED25519_ADDRESS_TO_OUTPUT_ID;
+    const KEY_COLUMNS: &'static [&'static str] = &["address", "output_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((address, output_id): &(Ed25519Address, OutputId)) -> Vec<Value> {
+        vec![blob(address), blob(output_id)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(Ed25519Address, OutputId)> {
+        Ok((unpack(row, "address")?, unpack(row, "output_id")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `()` to `LedgerIndex` table.
+pub(crate) struct LedgerIndexTable;
+
+impl Table for LedgerIndexTable {
+    type Key = ();
+    type Value = LedgerIndex;
+
+    const NAME: &'static str = TABLE_LEDGER_INDEX;
+    const KEY_COLUMNS: &'static [&'static str] = &["key"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["milestone_index"];
+
+    fn key_to_sql((): &()) -> Vec<Value> {
+        vec![Value::Integer(0)]
+    }
+
+    fn value_to_sql(index: &LedgerIndex) -> Vec<Value> {
+        vec![integer(index.0)]
+    }
+
+    fn key_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<LedgerIndex> {
+        Ok(LedgerIndex::new(milestone_index(row, "milestone_index")?))
+    }
+}
+
+/// The `MilestoneIndex` to `Milestone` table.
+pub(crate) struct MilestoneTable;
+
+impl Table for MilestoneTable {
+    type Key = MilestoneIndex;
+    type Value = Milestone;
+
+    const NAME: &'static str = TABLE_MILESTONE_INDEX_TO_MILESTONE;
+    const KEY_COLUMNS: &'static [&'static str] = &["milestone_index"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["message_id", "timestamp"];
+
+    fn key_to_sql(index: &MilestoneIndex) -> Vec<Value> {
+        vec![integer(*index)]
+    }
+
+    fn value_to_sql(milestone: &Milestone) -> Vec<Value> {
+        vec![blob(milestone.message_id()), unsigned(milestone.timestamp())]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<MilestoneIndex> {
+        milestone_index(row, "milestone_index")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<Milestone> {
+        Ok(Milestone::new(
+            unpack(row, "message_id")?,
+            unsigned_from_row(row, "timestamp")?,
+        ))
+    }
+}
+
+/// The `()` to `SnapshotInfo` table.
+pub(crate) struct SnapshotInfoTable;
+
+impl Table for SnapshotInfoTable {
+    type Key = ();
+    type Value = SnapshotInfo;
+
+    const NAME: &'static str = TABLE_SNAPSHOT_INFO;
+    const KEY_COLUMNS: &'static [&'static str] = &["key"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[
+        "snapshot_index",
+        "entry_point_index",
+        "pruning_index",
+        "timestamp",
+        "info",
+    ];
+
+    fn key_to_sql((): &()) -> Vec<Value> {
+        vec![Value::Integer(0)]
+    }
+
+    fn value_to_sql(info: &SnapshotInfo) -> Vec<Value> {
+        vec![
+            integer(info.snapshot_index()),
+            integer(info.entry_point_index()),
+            integer(info.pruning_index()),
+            unsigned(info.timestamp()),
+            blob(info),
+        ]
+    }
+
+    fn key_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<SnapshotInfo> {
+        unpack(row, "info")
+    }
+}
+
+/// The `SolidEntryPoint` to `MilestoneIndex` table.
+pub(crate) struct SolidEntryPointTable;
+
+impl Table for SolidEntryPointTable {
+    type Key = SolidEntryPoint;
+    type Value = MilestoneIndex;
+
+    const NAME: &'static str = TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX;
+    const KEY_COLUMNS: &'static [&'static str] = &["message_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["milestone_index"];
+
+    fn key_to_sql(sep: &SolidEntryPoint) -> Vec<Value> {
+        vec![blob(sep)]
+    }
+
+    fn value_to_sql(index: &MilestoneIndex) -> Vec<Value> {
+        vec![integer(*index)]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<SolidEntryPoint> {
+        unpack(row, "message_id")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<MilestoneIndex> {
+        milestone_index(row, "milestone_index")
+    }
+}
+
+/// The `MilestoneIndex` to `OutputDiff` table.
+pub(crate) struct OutputDiffTable;
+
+impl Table for OutputDiffTable {
+    type Key = MilestoneIndex;
+    type Value = OutputDiff;
+
+    const NAME: &'static str = TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF;
+    const KEY_COLUMNS: &'static [&'static str] = &["milestone_index"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["diff"];
+
+    fn key_to_sql(index: &MilestoneIndex) -> Vec<Value> {
+        vec![integer(*index)]
+    }
+
+    fn value_to_sql(diff: &OutputDiff) -> Vec<Value> {
+        vec![blob(diff)]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<MilestoneIndex> {
+        milestone_index(row, "milestone_index")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<OutputDiff> {
+        unpack(row, "diff")
+    }
+}
+
+/// The `Address` to `Balance` table.
+pub(crate) struct BalanceTable;
+
+impl Table for BalanceTable {
+    type Key = Address;
+    type Value = Balance;
+
+    const NAME: &'static str = TABLE_ADDRESS_TO_BALANCE;
+    const KEY_COLUMNS: &'static [&'static str] = &["address"];
+    const VALUE_COLUMNS: &'static [&'static str] = &["amount", "dust_allowance", "dust_outputs"];
+
+    fn key_to_sql(address: &Address) -> Vec<Value> {
+        vec![blob(address)]
+    }
+
+    fn value_to_sql(balance: &Balance) -> Vec<Value> {
+        vec![
+            unsigned(balance.amount()),
+            unsigned(balance.dust_allowance()),
+            unsigned(balance.dust_outputs()),
+        ]
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<Address> {
+        unpack(row, "address")
+    }
+
+    fn value_from_row(row: &Row<'_>) -> rusqlite::Result<Balance> {
+        Ok(Balance::new(
+            unsigned_from_row(row, "amount")?,
+            unsigned_from_row(row, "dust_allowance")?,
+            unsigned_from_row(row, "dust_outputs")?,
+        )
+        // Unpacking from storage is fine.
+        .unwrap())
+    }
+}
+
+/// The `(MilestoneIndex, UnreferencedMessage)` to `()` table.
+pub(crate) struct UnreferencedMessageTable;
+
+impl Table for UnreferencedMessageTable {
+    type Key = (MilestoneIndex, UnreferencedMessage);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE;
+    const KEY_COLUMNS: &'static [&'static str] = &["milestone_index", "message_id"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage)) -> Vec<Value> {
+        vec![integer(*index), blob(unreferenced_message)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(MilestoneIndex, UnreferencedMessage)> {
+        Ok((milestone_index(row, "milestone_index")?, unpack(row, "message_id")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `(MilestoneIndex, Receipt)` to `()` table.
+pub(crate) struct ReceiptTable;
+
+impl Table for ReceiptTable {
+    type Key = (MilestoneIndex, Receipt);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_MILESTONE_INDEX_TO_RECEIPT;
+    const KEY_COLUMNS: &'static [&'static str] = &["milestone_index", "receipt"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((index, receipt): &(MilestoneIndex, Receipt)) -> Vec<Value> {
+        vec![integer(*index), blob(receipt)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(MilestoneIndex, Receipt)> {
+        Ok((milestone_index(row, "milestone_index")?, unpack(row, "receipt")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
+
+/// The `(bool, TreasuryOutput)` to `()` table.
+pub(crate) struct TreasuryOutputTable;
+
+impl Table for TreasuryOutputTable {
+    type Key = (bool, TreasuryOutput);
+    type Value = ();
+
+    const NAME: &'static str = TABLE_SPENT_TO_TREASURY_OUTPUT;
+    const KEY_COLUMNS: &'static [&'static str] = &["spent", "output"];
+    const VALUE_COLUMNS: &'static [&'static str] = &[];
+
+    fn key_to_sql((spent, output): &(bool, TreasuryOutput)) -> Vec<Value> {
+        vec![Value::Integer(i64::from(*spent)), blob(output)]
+    }
+
+    fn value_to_sql((): &()) -> Vec<Value> {
+        Vec::new()
+    }
+
+    fn key_from_row(row: &Row<'_>) -> rusqlite::Result<(bool, TreasuryOutput)> {
+        Ok((row.get("spent")?, unpack(row, "output")?))
+    }
+
+    fn value_from_row(_: &Row<'_>) -> rusqlite::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[allow(unused_macros)]
+macro_rules! impl_access_test {
+    ($name_sqlite:ident, $name:ident) => {
+        #[test]
+        fn $name_sqlite() {
+            use bee_storage::backend::StorageBackend;
+
+            let path = String::from("./tests/database/") + stringify!($name);
+            let _ = std::fs::remove_dir_all(&path);
+
+            let config = bee_storage_sqlite::config::SqliteConfigBuilder::default()
+                .with_path(path.clone())
+                .finish();
+            let storage = bee_storage_sqlite::storage::Storage::start(config).unwrap();
+
+            bee_storage_test::$name(&storage);
+
+            let _ = std::fs::remove_dir_all(&path);
+        }
+    };
+}
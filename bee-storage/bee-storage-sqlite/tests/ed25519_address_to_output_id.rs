@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(
+    ed25519_address_to_output_id_access_sqlite,
+    ed25519_address_to_output_id_access
+);
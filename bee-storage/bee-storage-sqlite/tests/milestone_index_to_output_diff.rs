@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(
+    milestone_index_to_output_diff_access_sqlite,
+    milestone_index_to_output_diff_access
+);
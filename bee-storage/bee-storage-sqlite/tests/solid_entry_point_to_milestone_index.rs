@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(
+    solid_entry_point_to_milestone_index_access_sqlite,
+    solid_entry_point_to_milestone_index_access
+);
@@ -15,6 +15,7 @@ mod milestone_index_to_unreferenced_message;
 mod output_id_to_consumed_output;
 mod output_id_to_created_output;
 mod output_id_unspent;
+mod snapshot;
 mod snapshot_info;
 mod solid_entry_point_to_milestone_index;
 mod spent_to_treasury_output;
@@ -30,7 +31,7 @@ pub use self::{
     milestone_index_to_unreferenced_message::milestone_index_to_unreferenced_message_access,
     output_id_to_consumed_output::output_id_to_consumed_output_access,
     output_id_to_created_output::output_id_to_created_output_access, output_id_unspent::output_id_unspent_access,
-    snapshot_info::snapshot_info_access,
+    snapshot::snapshot_access, snapshot_info::snapshot_info_access,
     solid_entry_point_to_milestone_index::solid_entry_point_to_milestone_index_access,
     spent_to_treasury_output::spent_to_treasury_output_access,
 };
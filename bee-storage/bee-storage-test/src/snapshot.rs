@@ -0,0 +1,139 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{Balance, CreatedOutput, LedgerIndex};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::MilestoneIndex,
+    output::OutputId,
+};
+use bee_storage::{
+    access::{Batch, BatchBuilder, Snapshot},
+    backend,
+};
+use bee_test::rand::{
+    address::rand_ed25519_address,
+    balance::rand_balance,
+    output::{rand_created_output, rand_output_id},
+};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + BatchBuilder
+    + Batch<(), LedgerIndex>
+    + Batch<Address, Balance>
+    + Batch<OutputId, CreatedOutput>
+    + Batch<(Ed25519Address, OutputId), ()>
+    + for<'a> Snapshot<'a, (), LedgerIndex>
+    + for<'a> Snapshot<'a, Address, Balance>
+    + for<'a> Snapshot<'a, OutputId, CreatedOutput>
+    + for<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + BatchBuilder
+        + Batch<(), LedgerIndex>
+        + Batch<Address, Balance>
+        + Batch<OutputId, CreatedOutput>
+        + Batch<(Ed25519Address, OutputId), ()>
+        + for<'a> Snapshot<'a, (), LedgerIndex>
+        + for<'a> Snapshot<'a, Address, Balance>
+        + for<'a> Snapshot<'a, OutputId, CreatedOutput>
+        + for<'a> Snapshot<'a, Ed25519Address, Vec<OutputId>>
+{
+}
+
+pub fn snapshot_access<B: StorageBackend>(storage: &B) {
+    let address = rand_ed25519_address();
+    let (output_id, created_output) = (rand_output_id(), rand_created_output());
+    let balance = rand_balance();
+
+    let snapshot = storage.snapshot().unwrap();
+
+    assert!(Snapshot::<(), LedgerIndex>::snapshot_fetch(storage, &snapshot, &())
+        .unwrap()
+        .is_none());
+    assert!(
+        Snapshot::<Address, Balance>::snapshot_fetch(storage, &snapshot, &Address::Ed25519(address))
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(storage, &snapshot, &address)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    drop(snapshot);
+
+    let mut batch = B::batch_begin();
+
+    Batch::<(), LedgerIndex>::batch_insert(storage, &mut batch, &(), &LedgerIndex::from(MilestoneIndex::from(42)))
+        .unwrap();
+    Batch::<Address, Balance>::batch_insert(storage, &mut batch, &Address::Ed25519(address), &balance).unwrap();
+    Batch::<OutputId, CreatedOutput>::batch_insert(storage, &mut batch, &output_id, &created_output).unwrap();
+    Batch::<(Ed25519Address, OutputId), ()>::batch_insert(storage, &mut batch, &(address, output_id), &()).unwrap();
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let snapshot = storage.snapshot().unwrap();
+
+    assert_eq!(
+        Snapshot::<(), LedgerIndex>::snapshot_fetch(storage, &snapshot, &())
+            .unwrap()
+            .unwrap(),
+        LedgerIndex::from(MilestoneIndex::from(42))
+    );
+    assert_eq!(
+        Snapshot::<Address, Balance>::snapshot_fetch(storage, &snapshot, &Address::Ed25519(address))
+            .unwrap()
+            .unwrap(),
+        balance
+    );
+    assert_eq!(
+        Snapshot::<OutputId, CreatedOutput>::snapshot_fetch(storage, &snapshot, &output_id)
+            .unwrap()
+            .unwrap(),
+        created_output
+    );
+    assert_eq!(
+        Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(storage, &snapshot, &address)
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    drop(snapshot);
+
+    let mut batch = B::batch_begin();
+
+    Batch::<(), LedgerIndex>::batch_insert(storage, &mut batch, &(), &LedgerIndex::from(MilestoneIndex::from(43)))
+        .unwrap();
+    Batch::<Address, Balance>::batch_delete(storage, &mut batch, &Address::Ed25519(address)).unwrap();
+    Batch::<(Ed25519Address, OutputId), ()>::batch_delete(storage, &mut batch, &(address, output_id)).unwrap();
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let snapshot = storage.snapshot().unwrap();
+
+    assert_eq!(
+        Snapshot::<(), LedgerIndex>::snapshot_fetch(storage, &snapshot, &())
+            .unwrap()
+            .unwrap(),
+        LedgerIndex::from(MilestoneIndex::from(43))
+    );
+    assert!(
+        Snapshot::<Address, Balance>::snapshot_fetch(storage, &snapshot, &Address::Ed25519(address))
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        Snapshot::<Ed25519Address, Vec<OutputId>>::snapshot_fetch(storage, &snapshot, &address)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+}
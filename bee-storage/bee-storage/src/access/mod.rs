@@ -18,6 +18,8 @@ mod insert;
 mod iter;
 /// Holds the contract for multiple fetch access operation.
 mod multi_fetch;
+/// Holds the contract for snapshot access operations.
+mod snapshot;
 /// Holds the contract for truncate access operations.
 mod truncate;
 /// Holds the contract for update access operations.
@@ -31,6 +33,7 @@ pub use self::{
     insert::{Insert, InsertStrict},
     iter::AsIterator,
     multi_fetch::MultiFetch,
+    snapshot::{Snapshot, SnapshotBuilder},
     truncate::Truncate,
     update::Update,
 };
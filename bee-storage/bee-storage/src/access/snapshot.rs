@@ -0,0 +1,22 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::backend::StorageBackend;
+
+/// `SnapshotBuilder<'a>` trait extends the `StorageBackend` with read-only snapshot functionality; therefore it should
+/// be explicitly implemented for the corresponding `StorageBackend`.
+pub trait SnapshotBuilder<'a>: StorageBackend {
+    /// Type that acts like a read-only view of the storage at the point in time it was taken.
+    type Snapshot: 'a;
+
+    /// Creates and returns a `Snapshot` object.
+    /// Batches committed while the snapshot is alive are not visible through it.
+    fn snapshot(&'a self) -> Result<Self::Snapshot, Self::Error>;
+}
+
+/// `Snapshot<'a, K, V>` trait extends the `StorageBackend` with snapshot operations for the (key: K, value: V) pair;
+/// therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+pub trait Snapshot<'a, K, V>: SnapshotBuilder<'a> {
+    /// Fetches the value associated with the key from the provided snapshot of the storage.
+    fn snapshot_fetch(&self, snapshot: &Self::Snapshot, key: &K) -> Result<Option<V>, Self::Error>;
+}
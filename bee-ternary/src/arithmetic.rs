@@ -0,0 +1,120 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::Ordering;
+
+use crate::{Btrit, RawEncoding, RawEncodingBuf, TritBuf, Trits, T1B1};
+
+/// Adds three balanced trits, returning the resulting trit and the carry.
+fn full_add(lhs: Btrit, rhs: Btrit, carry: Btrit) -> (Btrit, Btrit) {
+    match i8::from(lhs) + i8::from(rhs) + i8::from(carry) {
+        sum @ -1..=1 => (Btrit::try_from(sum).unwrap(), Btrit::Zero),
+        sum if sum > 1 => (Btrit::try_from(sum - 3).unwrap(), Btrit::PlusOne),
+        sum => (Btrit::try_from(sum + 3).unwrap(), Btrit::NegOne),
+    }
+}
+
+impl<T> Trits<T>
+where
+    T: RawEncoding<Trit = Btrit> + ?Sized,
+{
+    /// Adds `sign * rhs + carry` to this trit slice in place and returns the outgoing carry.
+    fn add_signed_assign<U: RawEncoding<Trit = Btrit> + ?Sized>(
+        &mut self,
+        rhs: &Trits<U>,
+        sign: Btrit,
+        mut carry: Btrit,
+    ) -> Btrit {
+        assert!(
+            rhs.len() <= self.len(),
+            "Right-hand side trit slice must not be longer than the target"
+        );
+        for i in 0..self.len() {
+            let rhs_trit = if i < rhs.len() {
+                // SAFETY: `i` was just checked to be within the bounds of `rhs`.
+                unsafe { rhs.get_unchecked(i) }
+            } else if carry == Btrit::Zero {
+                break;
+            } else {
+                Btrit::Zero
+            };
+            let rhs_trit = if sign == Btrit::NegOne { -rhs_trit } else { rhs_trit };
+            // SAFETY: `i` ranges over `0..self.len()`, so it is within the bounds of `self`.
+            let (trit, next_carry) = full_add(unsafe { self.get_unchecked(i) }, rhs_trit, carry);
+
+            // SAFETY: Same index as above, still within the bounds of `self`.
+            unsafe { self.set_unchecked(i, trit) };
+            carry = next_carry;
+        }
+
+        carry
+    }
+
+    /// Adds the balanced integer represented by `rhs` and an incoming carry to the one represented by this mutable
+    /// trit slice, in place. Trits are read in little-endian order and the outgoing carry is returned, which is
+    /// [`Btrit::Zero`] if and only if the sum fits within the length of this slice.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is longer than this slice.
+    pub fn carrying_add<U: RawEncoding<Trit = Btrit> + ?Sized>(&mut self, rhs: &Trits<U>, carry: Btrit) -> Btrit {
+        self.add_signed_assign(rhs, Btrit::PlusOne, carry)
+    }
+
+    /// Subtracts the balanced integer represented by `rhs` and an incoming borrow from the one represented by this
+    /// mutable trit slice, in place. Trits are read in little-endian order and the outgoing borrow is returned, which
+    /// is [`Btrit::Zero`] if and only if the difference fits within the length of this slice.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is longer than this slice.
+    pub fn borrowing_sub<U: RawEncoding<Trit = Btrit> + ?Sized>(&mut self, rhs: &Trits<U>, borrow: Btrit) -> Btrit {
+        -self.add_signed_assign(rhs, Btrit::NegOne, -borrow)
+    }
+
+    /// Increments the balanced integer represented by this mutable trit slice and returns the outgoing carry.
+    pub fn increment(&mut self) -> Btrit {
+        self.carrying_add(Trits::<T1B1>::empty(), Btrit::PlusOne)
+    }
+
+    /// Decrements the balanced integer represented by this mutable trit slice and returns the outgoing borrow.
+    pub fn decrement(&mut self) -> Btrit {
+        self.borrowing_sub(Trits::<T1B1>::empty(), Btrit::PlusOne)
+    }
+
+    /// Multiplies the balanced integers represented by this trit slice and `rhs`. The product is returned in a new
+    /// [`TritBuf`] whose length is the sum of both lengths, which is always enough to hold it.
+    pub fn widening_mul<U, V>(&self, rhs: &Trits<U>) -> TritBuf<V>
+    where
+        U: RawEncoding<Trit = Btrit> + ?Sized,
+        V: RawEncodingBuf,
+        V::Slice: RawEncoding<Trit = Btrit>,
+    {
+        let mut product = TritBuf::<V>::zeros(self.len() + rhs.len());
+
+        for (i, trit) in rhs.iter().enumerate() {
+            if trit != Btrit::Zero {
+                let carry = product[i..].add_signed_assign(self, trit, Btrit::Zero);
+                debug_assert_eq!(carry, Btrit::Zero);
+            }
+        }
+
+        product
+    }
+
+    /// Compares the balanced integers represented by this trit slice and `rhs`, regardless of their lengths.
+    ///
+    /// Unlike the [`PartialOrd`] implementation of [`Trits`], which only compares slices of equal length trit by trit
+    /// from the first one, this ordering takes the most significant trits into account first.
+    pub fn balanced_cmp<U: RawEncoding<Trit = Btrit> + ?Sized>(&self, rhs: &Trits<U>) -> Ordering {
+        (0..self.len().max(rhs.len()))
+            .rev()
+            .map(|i| {
+                self.get(i)
+                    .unwrap_or(Btrit::Zero)
+                    .cmp(&rhs.get(i).unwrap_or(Btrit::Zero))
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+
+use crate::{T1B1Buf, TritBuf, Trits, Tryte, TryteBuf};
+
+/// The number of trytes of a legacy address without checksum.
+pub const ADDRESS_LENGTH: usize = 81;
+/// The number of trytes of a legacy address checksum.
+pub const CHECKSUM_LENGTH: usize = 9;
+/// The number of trytes of a legacy address with checksum.
+pub const CHECKSUMMED_ADDRESS_LENGTH: usize = ADDRESS_LENGTH + CHECKSUM_LENGTH;
+
+/// An error that may be produced when parsing legacy addresses.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The tryte string does not have the expected length.
+    InvalidLength(usize, usize),
+    /// The tryte string contains a character that is not a valid tryte.
+    InvalidTryte(char),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidLength(expected, actual) => {
+                write!(f, "invalid length: expected {} trytes, got {}", expected, actual)
+            }
+            Error::InvalidTryte(c) => write!(f, "invalid tryte: {:?}", c),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Parses a tryte string of exactly `N` trytes.
+pub fn trytes_from_str<const N: usize>(s: &str) -> Result<[Tryte; N], Error> {
+    let mut trytes = [Tryte::Nine; N];
+    let mut len = 0;
+
+    for c in s.chars() {
+        let tryte = Tryte::try_from(c).map_err(|_| Error::InvalidTryte(c))?;
+
+        if let Some(slot) = trytes.get_mut(len) {
+            *slot = tryte;
+        }
+        len += 1;
+    }
+
+    if len != N {
+        return Err(Error::InvalidLength(N, len));
+    }
+
+    Ok(trytes)
+}
+
+/// Parses a legacy address without checksum.
+pub fn address_from_str(s: &str) -> Result<[Tryte; ADDRESS_LENGTH], Error> {
+    trytes_from_str(s)
+}
+
+/// Parses a legacy address with checksum. The checksum is not verified, see [`verify_checksum`].
+pub fn checksummed_address_from_str(s: &str) -> Result<[Tryte; CHECKSUMMED_ADDRESS_LENGTH], Error> {
+    trytes_from_str(s)
+}
+
+/// Computes the checksum of a legacy address, which is made of the last trytes of its digest.
+/// The digest function is provided by the caller and is expected to be Kerl.
+///
+/// # Panics
+///
+/// This function will panic if the digest is shorter than the checksum.
+pub fn checksum<F, E>(address: &[Tryte; ADDRESS_LENGTH], digest: F) -> Result<[Tryte; CHECKSUM_LENGTH], E>
+where
+    F: FnOnce(&Trits) -> Result<TritBuf, E>,
+{
+    let trits = TryteBuf::from(&address[..]).as_trits().encode::<T1B1Buf>();
+    let digest = digest(&trits)?;
+
+    assert!(
+        digest.len() >= CHECKSUM_LENGTH * 3,
+        "Digest must be at least as long as the checksum"
+    );
+
+    let mut checksum = [Tryte::Nine; CHECKSUM_LENGTH];
+
+    for (slot, tryte) in checksum
+        .iter_mut()
+        .zip(digest[digest.len() - CHECKSUM_LENGTH * 3..].iter_trytes())
+    {
+        *slot = tryte;
+    }
+
+    Ok(checksum)
+}
+
+/// Appends its checksum to a legacy address.
+///
+/// # Panics
+///
+/// This function will panic if the digest is shorter than the checksum.
+pub fn add_checksum<F, E>(
+    address: &[Tryte; ADDRESS_LENGTH],
+    digest: F,
+) -> Result<[Tryte; CHECKSUMMED_ADDRESS_LENGTH], E>
+where
+    F: FnOnce(&Trits) -> Result<TritBuf, E>,
+{
+    let mut checksummed_address = [Tryte::Nine; CHECKSUMMED_ADDRESS_LENGTH];
+
+    checksummed_address[..ADDRESS_LENGTH].copy_from_slice(address);
+    checksummed_address[ADDRESS_LENGTH..].copy_from_slice(&checksum(address, digest)?);
+
+    Ok(checksummed_address)
+}
+
+/// Removes the checksum of a legacy address, without verifying it.
+pub fn remove_checksum(checksummed_address: &[Tryte; CHECKSUMMED_ADDRESS_LENGTH]) -> [Tryte; ADDRESS_LENGTH] {
+    let mut address = [Tryte::Nine; ADDRESS_LENGTH];
+
+    address.copy_from_slice(&checksummed_address[..ADDRESS_LENGTH]);
+
+    address
+}
+
+/// Verifies the checksum of a legacy address.
+///
+/// # Panics
+///
+/// This function will panic if the digest is shorter than the checksum.
+pub fn verify_checksum<F, E>(checksummed_address: &[Tryte; CHECKSUMMED_ADDRESS_LENGTH], digest: F) -> Result<bool, E>
+where
+    F: FnOnce(&Trits) -> Result<TritBuf, E>,
+{
+    Ok(checksum(&remove_checksum(checksummed_address), digest)?[..] == checksummed_address[ADDRESS_LENGTH..])
+}
@@ -9,6 +9,8 @@
 //! - Safe encoding API that allows the efficient manipulation and sharing of trit and tryte buffers and slices
 //! - Mutation of trit buffers and slices
 //! - Ternary BigInt implementation
+//! - Balanced ternary arithmetic on trit slices of any encoding
+//! - Legacy checksummed tryte address utilities
 //! - Balanced and unbalanced ternary
 //! - `serde` support
 //!
@@ -59,6 +61,8 @@ extern crate alloc;
 pub mod b1t6;
 /// Conversions between to and from standard types.
 pub mod convert;
+/// Utilities for legacy checksummed tryte addresses.
+pub mod legacy;
 /// Types and traits that allow the implementation of new encoding formats.
 pub mod raw;
 /// The [`T1B1`] and [`T1B1Buf`] encodings.
@@ -76,6 +80,7 @@ pub mod trit;
 /// Types and traits used to represent trytes and buffers of trytes.
 pub mod tryte;
 
+mod arithmetic;
 #[cfg(feature = "serde1")]
 mod serde;
 
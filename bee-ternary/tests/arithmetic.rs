@@ -0,0 +1,84 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::Ordering;
+
+use bee_ternary::{Btrit, T1B1Buf, T3B1Buf, T5B1Buf, TritBuf};
+
+const LEN: usize = 6;
+const MAX: i64 = (3i64.pow(LEN as u32) - 1) / 2;
+
+fn trits(x: i64) -> TritBuf<T5B1Buf> {
+    let x = TritBuf::<T1B1Buf>::from(x);
+    let mut trits = TritBuf::<T5B1Buf>::zeros(LEN);
+
+    trits[..x.len()].copy_from(&x);
+    trits
+}
+
+#[test]
+fn carrying_add() {
+    for x in -MAX..=MAX {
+        for y in (-MAX..=MAX).step_by(7) {
+            let mut sum = trits(x);
+            let carry = sum.carrying_add(&TritBuf::<T3B1Buf>::from(y), Btrit::Zero);
+
+            assert_eq!(
+                i64::try_from(&sum[..]).unwrap() + i64::from(i8::from(carry)) * 3i64.pow(LEN as u32),
+                x + y
+            );
+            assert_eq!(carry == Btrit::Zero, (x + y).abs() <= MAX);
+        }
+    }
+}
+
+#[test]
+fn borrowing_sub() {
+    for x in -MAX..=MAX {
+        for y in (-MAX..=MAX).step_by(7) {
+            let mut difference = trits(x);
+            let borrow = difference.borrowing_sub(&trits(y), Btrit::PlusOne);
+
+            assert_eq!(
+                i64::try_from(&difference[..]).unwrap() - i64::from(i8::from(borrow)) * 3i64.pow(LEN as u32),
+                x - y - 1
+            );
+        }
+    }
+}
+
+#[test]
+fn increment_decrement() {
+    let mut x = trits(-MAX);
+
+    for expected in -MAX + 1..=MAX {
+        assert_eq!(x.increment(), Btrit::Zero);
+        assert_eq!(i64::try_from(&x[..]).unwrap(), expected);
+    }
+    assert_eq!(x.increment(), Btrit::PlusOne);
+    assert_eq!(i64::try_from(&x[..]).unwrap(), -MAX);
+    assert_eq!(x.decrement(), Btrit::PlusOne);
+    assert_eq!(i64::try_from(&x[..]).unwrap(), MAX);
+}
+
+#[test]
+fn widening_mul() {
+    for x in (-MAX..=MAX).step_by(5) {
+        for y in (-MAX..=MAX).step_by(11) {
+            let product = trits(x).widening_mul::<_, T1B1Buf>(&trits(y));
+
+            assert_eq!(product.len(), 2 * LEN);
+            assert_eq!(i64::try_from(&product[..]).unwrap(), x * y);
+        }
+    }
+}
+
+#[test]
+fn balanced_cmp() {
+    for x in (-MAX..=MAX).step_by(3) {
+        for y in (-MAX..=MAX).step_by(13) {
+            assert_eq!(trits(x).balanced_cmp(&TritBuf::<T1B1Buf>::from(y)), x.cmp(&y));
+        }
+    }
+    assert_eq!(trits(0).balanced_cmp(&TritBuf::<T1B1Buf>::new()), Ordering::Equal);
+}
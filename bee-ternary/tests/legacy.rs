@@ -0,0 +1,57 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use bee_ternary::{
+    legacy::{
+        add_checksum, address_from_str, checksum, checksummed_address_from_str, remove_checksum, verify_checksum, Error,
+    },
+    T1B1Buf, TritBuf, Trits, Tryte,
+};
+
+const ADDRESS: &str = "XUERGHWTYRTFUYKFKXURKHMFEVLOIFTTCNTXOGLDPCZ9CJLKHROOPGNAQYFJEPGK9OKUQROUECBAVNXRX";
+
+// Stands in for Kerl, only the last trytes of the digest matter for the checksum.
+fn digest(trits: &Trits) -> Result<TritBuf, Infallible> {
+    let mut digest = trits.to_buf::<T1B1Buf>();
+
+    digest.negate();
+    Ok(digest)
+}
+
+#[test]
+fn parse() {
+    assert_eq!(address_from_str(ADDRESS).unwrap().len(), 81);
+    assert_eq!(address_from_str(&ADDRESS[1..]), Err(Error::InvalidLength(81, 80)));
+    assert_eq!(
+        address_from_str(&ADDRESS.replace('X', "x")),
+        Err(Error::InvalidTryte('x'))
+    );
+    assert_eq!(checksummed_address_from_str(ADDRESS), Err(Error::InvalidLength(90, 81)));
+}
+
+#[test]
+fn checksum_round_trip() {
+    let address = address_from_str(ADDRESS).unwrap();
+    let checksummed_address = add_checksum(&address, digest).unwrap();
+    let negated_suffix = ADDRESS[72..]
+        .chars()
+        .map(|c| -(Tryte::try_from(c).unwrap() as i8))
+        .map(|x| Tryte::try_from(x).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(checksum(&address, digest).unwrap()[..], negated_suffix[..]);
+    assert_eq!(remove_checksum(&checksummed_address), address);
+    assert!(verify_checksum(&checksummed_address, digest).unwrap());
+}
+
+#[test]
+fn checksum_mismatch() {
+    let address = address_from_str(ADDRESS).unwrap();
+    let mut checksummed_address = add_checksum(&address, digest).unwrap();
+
+    checksummed_address[89] = Tryte::Nine;
+
+    assert!(!verify_checksum(&checksummed_address, digest).unwrap());
+}